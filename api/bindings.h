@@ -213,12 +213,38 @@ typedef struct {
                              uint64_t*,
                              libmovevm_UnmanagedVector*);
   int32_t (*unbond_timestamp)(const libmovevm_ApiT*, uint64_t*, libmovevm_UnmanagedVector*);
+  int32_t (*unbonding_entries)(const libmovevm_ApiT*,
+                               libmovevm_U8SliceView,
+                               libmovevm_U8SliceView,
+                               libmovevm_UnmanagedVector*,
+                               libmovevm_UnmanagedVector*);
   int32_t (*get_price)(const libmovevm_ApiT*,
                        libmovevm_U8SliceView,
                        libmovevm_UnmanagedVector*,
                        uint64_t*,
                        uint64_t*,
                        libmovevm_UnmanagedVector*);
+  int32_t (*get_price_at)(const libmovevm_ApiT*,
+                          libmovevm_U8SliceView,
+                          uint64_t,
+                          libmovevm_UnmanagedVector*,
+                          uint64_t*,
+                          uint64_t*,
+                          libmovevm_UnmanagedVector*);
+  int32_t (*get_twap)(const libmovevm_ApiT*,
+                      libmovevm_U8SliceView,
+                      uint64_t,
+                      libmovevm_UnmanagedVector*,
+                      uint64_t*,
+                      uint64_t*,
+                      libmovevm_UnmanagedVector*);
+  int32_t (*dispatch_message)(const libmovevm_ApiT*,
+                              libmovevm_U8SliceView,
+                              libmovevm_U8SliceView,
+                              uint64_t,
+                              libmovevm_UnmanagedVector*,
+                              uint64_t*,
+                              libmovevm_UnmanagedVector*);
 } libmovevm_ApiVTable;
 
 typedef struct {
@@ -231,15 +257,50 @@ libmovevm_VmT *libmovevm_allocate_vm(libmovevm_ByteSliceView config_payload);
 libmovevm_UnmanagedVector libmovevm_decode_module_bytes(libmovevm_UnmanagedVector *errmsg,
                                                         libmovevm_ByteSliceView module_bytes);
 
+libmovevm_UnmanagedVector libmovevm_diff_modules(libmovevm_UnmanagedVector *errmsg,
+                                                 libmovevm_ByteSliceView old_module_bytes,
+                                                 libmovevm_ByteSliceView new_module_bytes);
+
+libmovevm_UnmanagedVector libmovevm_disassemble_module(libmovevm_UnmanagedVector *errmsg,
+                                                       libmovevm_ByteSliceView module_bytes,
+                                                       libmovevm_ByteSliceView source_map);
+
+libmovevm_UnmanagedVector libmovevm_disassemble_script(libmovevm_UnmanagedVector *errmsg,
+                                                       libmovevm_ByteSliceView script_bytes,
+                                                       libmovevm_ByteSliceView source_map);
+
+libmovevm_UnmanagedVector libmovevm_generate_json_schema(libmovevm_UnmanagedVector *errmsg,
+                                                         libmovevm_ByteSliceView module_bytes);
+
 libmovevm_UnmanagedVector libmovevm_decode_move_resource(libmovevm_GoDb db,
                                                          libmovevm_UnmanagedVector *errmsg,
                                                          libmovevm_ByteSliceView struct_tag,
-                                                         libmovevm_ByteSliceView resource_bytes);
+                                                         libmovevm_ByteSliceView resource_bytes,
+                                                         libmovevm_ByteSliceView json_options);
 
 libmovevm_UnmanagedVector libmovevm_decode_move_value(libmovevm_GoDb db,
                                                       libmovevm_UnmanagedVector *errmsg,
                                                       libmovevm_ByteSliceView type_tag,
-                                                      libmovevm_ByteSliceView value_bytes);
+                                                      libmovevm_ByteSliceView value_bytes,
+                                                      libmovevm_ByteSliceView json_options);
+
+libmovevm_UnmanagedVector libmovevm_decode_event(libmovevm_GoDb db,
+                                                 libmovevm_UnmanagedVector *errmsg,
+                                                 libmovevm_ByteSliceView type_tag,
+                                                 libmovevm_ByteSliceView event_bytes,
+                                                 libmovevm_ByteSliceView json_options);
+
+libmovevm_UnmanagedVector libmovevm_decode_events(libmovevm_GoDb db,
+                                                  libmovevm_UnmanagedVector *errmsg,
+                                                  libmovevm_ByteSliceView events,
+                                                  libmovevm_ByteSliceView json_options);
+
+libmovevm_UnmanagedVector libmovevm_decode_table_item(libmovevm_GoDb db,
+                                                      libmovevm_UnmanagedVector *errmsg,
+                                                      libmovevm_ByteSliceView table_handle,
+                                                      libmovevm_ByteSliceView key_bytes,
+                                                      libmovevm_ByteSliceView value_bytes,
+                                                      libmovevm_ByteSliceView json_options);
 
 libmovevm_UnmanagedVector libmovevm_decode_script_bytes(libmovevm_UnmanagedVector *errmsg,
                                                         libmovevm_ByteSliceView script_bytes);
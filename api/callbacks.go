@@ -17,7 +17,11 @@ typedef libmovevm_GoError (*get_account_info_fn)(libmovevm_ApiT *ptr, libmovevm_
 typedef libmovevm_GoError (*amount_to_share_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, uint64_t amount, libmovevm_UnmanagedVector *share,  libmovevm_UnmanagedVector *errOut);
 typedef libmovevm_GoError (*share_to_amount_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_U8SliceView share, uint64_t *amount,  libmovevm_UnmanagedVector *errOut);
 typedef libmovevm_GoError (*unbond_timestamp_fn)(libmovevm_ApiT *ptr, uint64_t *unbondTimestamp,  libmovevm_UnmanagedVector *errOut);
+typedef libmovevm_GoError (*unbonding_entries_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_UnmanagedVector *entries, libmovevm_UnmanagedVector *errOut);
 typedef libmovevm_GoError (*get_price_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+typedef libmovevm_GoError (*get_price_at_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t height, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+typedef libmovevm_GoError (*get_twap_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t window, libmovevm_UnmanagedVector *twap, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+typedef libmovevm_GoError (*dispatch_message_fn)(libmovevm_ApiT *ptr, libmovevm_U8SliceView sender, libmovevm_U8SliceView message, uint64_t gasBalance, libmovevm_UnmanagedVector *response, uint64_t *usedGas, libmovevm_UnmanagedVector *errOut);
 // and iterator
 typedef libmovevm_GoError (*next_db_fn)(libmovevm_IteratorT ptr, libmovevm_UnmanagedVector *key, libmovevm_UnmanagedVector *errOut);
 
@@ -32,7 +36,11 @@ libmovevm_GoError cGetAccountInfo_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView
 libmovevm_GoError cAmountToShare_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, uint64_t amount, libmovevm_UnmanagedVector *share, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cShareToAmount_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_U8SliceView share, uint64_t *amount, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cUnbondTimestamp_cgo(libmovevm_ApiT *ptr, uint64_t *unbondTimestamp, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cUnbondingEntries_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_UnmanagedVector *entries, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cGetPrice_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cGetPriceAt_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t height, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cGetTWAP_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t window, libmovevm_UnmanagedVector *twap, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cDispatchMessage_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView sender, libmovevm_U8SliceView message, uint64_t gasBalance, libmovevm_UnmanagedVector *response, uint64_t *usedGas, libmovevm_UnmanagedVector *errOut);
 // iterator
 libmovevm_GoError cNext_cgo(libmovevm_IteratorT ptr, libmovevm_UnmanagedVector *key, libmovevm_UnmanagedVector *errOut);
 */
@@ -306,16 +314,36 @@ type GoAPI interface {
 	AmountToShare([]byte, types.AccountAddress, uint64) (string, error)
 	ShareToAmount([]byte, types.AccountAddress, string) (uint64, error)
 	UnbondTimestamp() uint64
+	// UnbondingEntries returns the pending unbonding entries of the delegation,
+	// held by the staking module, to the validator.
+	UnbondingEntries([]byte, types.AccountAddress) ([]types.UnbondingEntry, error)
 	GetPrice(string) ([]byte, uint64, uint64, error)
+	// GetPriceAt returns the price of the pair at the given block height.
+	GetPriceAt(string, uint64) ([]byte, uint64, uint64, error)
+	// GetTWAP returns the time weighted average price of the pair over the given window in seconds.
+	GetTWAP(string, uint64) ([]byte, uint64, uint64, error)
+	// DispatchMessage executes a cosmos message synchronously during the move execution
+	// and returns the message response and the used gas. The message must be executed
+	// on a branch of the current context which is discarded when the message fails.
+	// Only the message types listed in InitiaVMConfig.AllowedDispatchMessages are
+	// dispatched, and they must not execute move or access move state, as the move
+	// state changes are written after the move execution. The returned error is
+	// reported back to move as a message failure; out of gas must be reported with
+	// the used gas above the gas balance.
+	DispatchMessage(types.AccountAddress, []byte, uint64) ([]byte, uint64, error)
 }
 
 var apiVTable = C.libmovevm_ApiVTable{
-	query:            (C.query_fn)(C.cQuery_cgo),
-	get_account_info: (C.get_account_info_fn)(C.cGetAccountInfo_cgo),
-	amount_to_share:  (C.amount_to_share_fn)(C.cAmountToShare_cgo),
-	share_to_amount:  (C.share_to_amount_fn)(C.cShareToAmount_cgo),
-	unbond_timestamp: (C.unbond_timestamp_fn)(C.cUnbondTimestamp_cgo),
-	get_price:        (C.get_price_fn)(C.cGetPrice_cgo),
+	query:             (C.query_fn)(C.cQuery_cgo),
+	get_account_info:  (C.get_account_info_fn)(C.cGetAccountInfo_cgo),
+	amount_to_share:   (C.amount_to_share_fn)(C.cAmountToShare_cgo),
+	share_to_amount:   (C.share_to_amount_fn)(C.cShareToAmount_cgo),
+	unbond_timestamp:  (C.unbond_timestamp_fn)(C.cUnbondTimestamp_cgo),
+	unbonding_entries: (C.unbonding_entries_fn)(C.cUnbondingEntries_cgo),
+	get_price:         (C.get_price_fn)(C.cGetPrice_cgo),
+	get_price_at:      (C.get_price_at_fn)(C.cGetPriceAt_cgo),
+	get_twap:          (C.get_twap_fn)(C.cGetTWAP_cgo),
+	dispatch_message:  (C.dispatch_message_fn)(C.cDispatchMessage_cgo),
 }
 
 // contract: original pointer/struct referenced must live longer than C.libmovevm_GoApi struct
@@ -494,6 +522,47 @@ func cUnbondTimestamp(ptr *C.libmovevm_ApiT, unbondTimestamp *C.uint64_t, errOut
 	return C.libmovevm_GoError_None
 }
 
+//export cUnbondingEntries
+func cUnbondingEntries(ptr *C.libmovevm_ApiT, validator C.libmovevm_U8SliceView, metadata C.libmovevm_U8SliceView, entries *C.libmovevm_UnmanagedVector, errOut *C.libmovevm_UnmanagedVector) (ret C.libmovevm_GoError) {
+	defer recoverPanic(&ret)
+
+	if entries == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if errOut == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	v := copyU8Slice(validator)
+	m := copyU8Slice(metadata)
+
+	t, err := types.BcsDeserializeAccountAddress(m)
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	es, err := api.UnbondingEntries(v, t)
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	bz, err := types.SerializeUnbondingEntries(es)
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	*entries = newUnmanagedVector(bz)
+	return C.libmovevm_GoError_None
+}
+
 //export cGetPrice
 func cGetPrice(ptr *C.libmovevm_ApiT, pairId C.libmovevm_U8SliceView, price *C.libmovevm_UnmanagedVector, updatedAt *C.uint64_t, decimals *C.uint64_t, errOut *C.libmovevm_UnmanagedVector) (ret C.libmovevm_GoError) {
 	defer recoverPanic(&ret)
@@ -522,3 +591,99 @@ func cGetPrice(ptr *C.libmovevm_ApiT, pairId C.libmovevm_U8SliceView, price *C.l
 	*decimals = C.uint64_t(d)
 	return C.libmovevm_GoError_None
 }
+
+//export cGetPriceAt
+func cGetPriceAt(ptr *C.libmovevm_ApiT, pairId C.libmovevm_U8SliceView, height C.uint64_t, price *C.libmovevm_UnmanagedVector, updatedAt *C.uint64_t, decimals *C.uint64_t, errOut *C.libmovevm_UnmanagedVector) (ret C.libmovevm_GoError) {
+	defer recoverPanic(&ret)
+
+	if price == nil || updatedAt == nil || decimals == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if errOut == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	pid := copyU8Slice(pairId)
+	p, u, d, err := api.GetPriceAt(string(pid), uint64(height))
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	*price = newUnmanagedVector(p)
+	*updatedAt = C.uint64_t(u)
+	*decimals = C.uint64_t(d)
+	return C.libmovevm_GoError_None
+}
+
+//export cGetTWAP
+func cGetTWAP(ptr *C.libmovevm_ApiT, pairId C.libmovevm_U8SliceView, window C.uint64_t, twap *C.libmovevm_UnmanagedVector, updatedAt *C.uint64_t, decimals *C.uint64_t, errOut *C.libmovevm_UnmanagedVector) (ret C.libmovevm_GoError) {
+	defer recoverPanic(&ret)
+
+	if twap == nil || updatedAt == nil || decimals == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if errOut == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	pid := copyU8Slice(pairId)
+	p, u, d, err := api.GetTWAP(string(pid), uint64(window))
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	*twap = newUnmanagedVector(p)
+	*updatedAt = C.uint64_t(u)
+	*decimals = C.uint64_t(d)
+	return C.libmovevm_GoError_None
+}
+
+//export cDispatchMessage
+func cDispatchMessage(ptr *C.libmovevm_ApiT, sender C.libmovevm_U8SliceView, message C.libmovevm_U8SliceView, gasBalance C.uint64_t, response *C.libmovevm_UnmanagedVector, usedGas *C.uint64_t, errOut *C.libmovevm_UnmanagedVector) (ret C.libmovevm_GoError) {
+	defer recoverPanic(&ret)
+
+	if response == nil || usedGas == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if errOut == nil {
+		return C.libmovevm_GoError_BadArgument
+	}
+	if !(*response).is_none || !(*errOut).is_none {
+		panic("Got a non-none UnmanagedVector we're about to override. This is a bug because someone has to drop the old one.")
+	}
+
+	api := *(*GoAPI)(unsafe.Pointer(ptr))
+
+	s := copyU8Slice(sender)
+	senderAddr, err := types.NewAccountAddressFromBytes(s)
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	msg := copyU8Slice(message)
+	gb := uint64(gasBalance)
+
+	// gas is consumed even if the message fails
+	res, ug, err := api.DispatchMessage(senderAddr, msg, gb)
+	*usedGas = C.uint64_t(ug)
+	if err != nil {
+		*errOut = newUnmanagedVector([]byte(err.Error()))
+		return C.libmovevm_GoError_User
+	}
+
+	*response = newUnmanagedVector(res)
+	return C.libmovevm_GoError_None
+}
@@ -15,7 +15,11 @@ libmovevm_GoError cGetAccountInfo(libmovevm_ApiT *ptr, libmovevm_U8SliceView add
 libmovevm_GoError cAmountToShare(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, uint64_t amount, libmovevm_UnmanagedVector *share, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cShareToAmount(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_U8SliceView share, uint64_t *amount, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cUnbondTimestamp(libmovevm_ApiT *ptr, uint64_t *unbondTimestamp, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cUnbondingEntries(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_UnmanagedVector *entries, libmovevm_UnmanagedVector *errOut);
 libmovevm_GoError cGetPrice(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cGetPriceAt(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t height, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cGetTWAP(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t window, libmovevm_UnmanagedVector *twap, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut);
+libmovevm_GoError cDispatchMessage(libmovevm_ApiT *ptr, libmovevm_U8SliceView sender, libmovevm_U8SliceView message, uint64_t gasBalance, libmovevm_UnmanagedVector *response, uint64_t *usedGas, libmovevm_UnmanagedVector *errOut);
 // imports (iterator)
 libmovevm_GoError cNext(libmovevm_IteratorT ptr, libmovevm_UnmanagedVector *key, libmovevm_UnmanagedVector *errOut);
 
@@ -54,9 +58,21 @@ libmovevm_GoError cShareToAmount_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView
 libmovevm_GoError cUnbondTimestamp_cgo(libmovevm_ApiT *ptr, uint64_t *unbondTimestamp, libmovevm_UnmanagedVector *errOut) {
     return cUnbondTimestamp(ptr, unbondTimestamp, errOut);
 }
+libmovevm_GoError cUnbondingEntries_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView validator, libmovevm_U8SliceView metadata, libmovevm_UnmanagedVector *entries, libmovevm_UnmanagedVector *errOut) {
+    return cUnbondingEntries(ptr, validator, metadata, entries, errOut);
+}
 libmovevm_GoError cGetPrice_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut) {
     return cGetPrice(ptr, pairId, price, updatedAt, decimals, errOut);
 }
+libmovevm_GoError cGetPriceAt_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t height, libmovevm_UnmanagedVector *price, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut) {
+    return cGetPriceAt(ptr, pairId, height, price, updatedAt, decimals, errOut);
+}
+libmovevm_GoError cGetTWAP_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView pairId, uint64_t window, libmovevm_UnmanagedVector *twap, uint64_t *updatedAt, uint64_t *decimals, libmovevm_UnmanagedVector *errOut) {
+    return cGetTWAP(ptr, pairId, window, twap, updatedAt, decimals, errOut);
+}
+libmovevm_GoError cDispatchMessage_cgo(libmovevm_ApiT *ptr, libmovevm_U8SliceView sender, libmovevm_U8SliceView message, uint64_t gasBalance, libmovevm_UnmanagedVector *response, uint64_t *usedGas, libmovevm_UnmanagedVector *errOut) {
+    return cDispatchMessage(ptr, sender, message, gasBalance, response, usedGas, errOut);
+}
 */
 import "C"
 
@@ -49,12 +49,29 @@ func DecodeMoveResource(
 	store KVStore,
 	structTag types.StructTag,
 	resourceBytes []byte,
+) ([]byte, error) {
+	return DecodeMoveResourceWithOptions(store, structTag, resourceBytes, nil)
+}
+
+// DecodeMoveResourceWithOptions decode resource bytes to JSON in the
+// shape of view function results, customized by the json options.
+// A nil options decodes in the same format as DecodeMoveResource.
+func DecodeMoveResourceWithOptions(
+	store KVStore,
+	structTag types.StructTag,
+	resourceBytes []byte,
+	jsonOptions *types.JsonOptions,
 ) ([]byte, error) {
 	structTagBz, err := structTag.BcsSerialize()
 	if err != nil {
 		return nil, err
 	}
 
+	jsonOptionsBz, err := serializeJsonOptions(jsonOptions)
+	if err != nil {
+		return nil, err
+	}
+
 	callID := startCall()
 	defer endCall(callID)
 
@@ -67,9 +84,12 @@ func DecodeMoveResource(
 	resourceBytesView := makeView(resourceBytes)
 	defer runtime.KeepAlive(resourceBytesView)
 
+	jsonOptionsView := makeView(jsonOptionsBz)
+	defer runtime.KeepAlive(jsonOptionsView)
+
 	errmsg := uninitializedUnmanagedVector()
 
-	res, err := C.libmovevm_decode_move_resource(db, &errmsg, structTagView, resourceBytesView)
+	res, err := C.libmovevm_decode_move_resource(db, &errmsg, structTagView, resourceBytesView, jsonOptionsView)
 	return handleFFIResult(res, errmsg, err)
 }
 
@@ -79,12 +99,29 @@ func DecodeMoveValue(
 	store KVStore,
 	typeTag types.TypeTag,
 	valueBytes []byte,
+) ([]byte, error) {
+	return DecodeMoveValueWithOptions(store, typeTag, valueBytes, nil)
+}
+
+// DecodeMoveValueWithOptions decode move value bytes to JSON in the
+// shape of view function results, customized by the json options.
+// A nil options decodes in the same format as DecodeMoveValue.
+func DecodeMoveValueWithOptions(
+	store KVStore,
+	typeTag types.TypeTag,
+	valueBytes []byte,
+	jsonOptions *types.JsonOptions,
 ) ([]byte, error) {
 	typeTagBz, err := typeTag.BcsSerialize()
 	if err != nil {
 		return nil, err
 	}
 
+	jsonOptionsBz, err := serializeJsonOptions(jsonOptions)
+	if err != nil {
+		return nil, err
+	}
+
 	callID := startCall()
 	defer endCall(callID)
 
@@ -97,12 +134,141 @@ func DecodeMoveValue(
 	valueBytesView := makeView(valueBytes)
 	defer runtime.KeepAlive(valueBytesView)
 
+	jsonOptionsView := makeView(jsonOptionsBz)
+	defer runtime.KeepAlive(jsonOptionsView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_decode_move_value(db, &errmsg, typeTagView, valueBytesView, jsonOptionsView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// DecodeEvent decode bcs encoded event bytes to JSON in the same
+// shape as the events emitted during execution, customized by the
+// json options. A nil options uses the default format.
+func DecodeEvent(
+	store KVStore,
+	typeTag types.TypeTag,
+	eventBytes []byte,
+	jsonOptions *types.JsonOptions,
+) ([]byte, error) {
+	typeTagBz, err := typeTag.BcsSerialize()
+	if err != nil {
+		return nil, err
+	}
+
+	jsonOptionsBz, err := serializeJsonOptions(jsonOptions)
+	if err != nil {
+		return nil, err
+	}
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	typeTagView := makeView(typeTagBz)
+	defer runtime.KeepAlive(typeTagView)
+
+	eventBytesView := makeView(eventBytes)
+	defer runtime.KeepAlive(eventBytesView)
+
+	jsonOptionsView := makeView(jsonOptionsBz)
+	defer runtime.KeepAlive(jsonOptionsView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_decode_event(db, &errmsg, typeTagView, eventBytesView, jsonOptionsView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// DecodeEvents decode a batch of bcs encoded events to a JSON array
+// of event data in the order of the given events. The layout of
+// each event type is resolved once per batch.
+func DecodeEvents(
+	store KVStore,
+	events []types.RawEvent,
+	jsonOptions *types.JsonOptions,
+) ([]byte, error) {
+	eventsBz, err := types.SerializeRawEventVector(events)
+	if err != nil {
+		return nil, err
+	}
+
+	jsonOptionsBz, err := serializeJsonOptions(jsonOptions)
+	if err != nil {
+		return nil, err
+	}
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	eventsView := makeView(eventsBz)
+	defer runtime.KeepAlive(eventsView)
+
+	jsonOptionsView := makeView(jsonOptionsBz)
+	defer runtime.KeepAlive(jsonOptionsView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_decode_events(db, &errmsg, eventsView, jsonOptionsView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// DecodeTableItem decode bcs encoded table key and value bytes to
+// JSON using the key and value types recorded at table creation.
+// A nil valueBytes decodes only the key, and a nil options uses
+// the default format.
+func DecodeTableItem(
+	store KVStore,
+	tableHandle types.AccountAddress,
+	keyBytes []byte,
+	valueBytes []byte,
+	jsonOptions *types.JsonOptions,
+) ([]byte, error) {
+	jsonOptionsBz, err := serializeJsonOptions(jsonOptions)
+	if err != nil {
+		return nil, err
+	}
+
+	callID := startCall()
+	defer endCall(callID)
+
+	dbState := buildDBState(store, callID)
+	db := buildDB(&dbState)
+
+	tableHandleView := makeView(tableHandle[:])
+	defer runtime.KeepAlive(tableHandleView)
+
+	keyBytesView := makeView(keyBytes)
+	defer runtime.KeepAlive(keyBytesView)
+
+	valueBytesView := makeView(valueBytes)
+	defer runtime.KeepAlive(valueBytesView)
+
+	jsonOptionsView := makeView(jsonOptionsBz)
+	defer runtime.KeepAlive(jsonOptionsView)
+
 	errmsg := uninitializedUnmanagedVector()
 
-	res, err := C.libmovevm_decode_move_value(db, &errmsg, typeTagView, valueBytesView)
+	res, err := C.libmovevm_decode_table_item(db, &errmsg, tableHandleView, keyBytesView, valueBytesView, jsonOptionsView)
 	return handleFFIResult(res, errmsg, err)
 }
 
+// serializeJsonOptions returns nil for nil options, which selects the
+// default decoding format.
+func serializeJsonOptions(jsonOptions *types.JsonOptions) ([]byte, error) {
+	if jsonOptions == nil {
+		return nil, nil
+	}
+
+	return jsonOptions.BcsSerialize()
+}
+
 // DecodeModuleBytes decode module bytes to MoveModule
 // instance and return as jSON string
 func DecodeModuleBytes(
@@ -119,6 +285,81 @@ func DecodeModuleBytes(
 	return handleFFIResult(res, errmsg, err)
 }
 
+// DiffModules diffs two versions of a module and reports whether
+// the new one can be published as an upgrade of the old one
+// and return as jSON string
+func DiffModules(
+	oldModuleBytes []byte,
+	newModuleBytes []byte,
+) ([]byte, error) {
+	var err error
+
+	oldModuleBytesView := makeView([]byte(oldModuleBytes))
+	defer runtime.KeepAlive(oldModuleBytesView)
+	newModuleBytesView := makeView([]byte(newModuleBytes))
+	defer runtime.KeepAlive(newModuleBytesView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_diff_modules(&errmsg, oldModuleBytesView, newModuleBytesView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// DisassembleModule disassembles module bytes to human readable
+// text, annotated with the bcs encoded source map when it is not nil
+func DisassembleModule(
+	moduleBytes []byte,
+	sourceMap []byte,
+) ([]byte, error) {
+	var err error
+
+	moduleBytesView := makeView([]byte(moduleBytes))
+	defer runtime.KeepAlive(moduleBytesView)
+	sourceMapView := makeView(sourceMap)
+	defer runtime.KeepAlive(sourceMapView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_disassemble_module(&errmsg, moduleBytesView, sourceMapView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// DisassembleScript disassembles script bytes to human readable
+// text, annotated with the bcs encoded source map when it is not nil
+func DisassembleScript(
+	scriptBytes []byte,
+	sourceMap []byte,
+) ([]byte, error) {
+	var err error
+
+	scriptBytesView := makeView([]byte(scriptBytes))
+	defer runtime.KeepAlive(scriptBytesView)
+	sourceMapView := makeView(sourceMap)
+	defer runtime.KeepAlive(sourceMapView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_disassemble_script(&errmsg, scriptBytesView, sourceMapView)
+	return handleFFIResult(res, errmsg, err)
+}
+
+// GenerateJSONSchema generates JSON Schemas for the arguments and
+// return values of the entry and view functions of the module
+// and return as jSON string
+func GenerateJSONSchema(
+	moduleBytes []byte,
+) ([]byte, error) {
+	var err error
+
+	moduleBytesView := makeView([]byte(moduleBytes))
+	defer runtime.KeepAlive(moduleBytesView)
+
+	errmsg := uninitializedUnmanagedVector()
+
+	res, err := C.libmovevm_generate_json_schema(&errmsg, moduleBytesView)
+	return handleFFIResult(res, errmsg, err)
+}
+
 // DecodeScriptBytes decode script bytes to MoveFunction
 // instance and return as jSON string
 func DecodeScriptBytes(
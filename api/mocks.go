@@ -81,6 +81,7 @@ type MockAPI struct {
 	StakingAPI *MockStakingAPI
 	QueryAPI   *MockQueryAPI
 	OracleAPI  *MockOracleAPI
+	CosmosAPI  *MockCosmosAPI
 	BlockTime  uint64
 }
 
@@ -90,6 +91,7 @@ func NewMockAPI(
 	stakingAPI *MockStakingAPI,
 	queryAPI *MockQueryAPI,
 	oracleAPI *MockOracleAPI,
+	cosmosAPI *MockCosmosAPI,
 ) *MockAPI {
 
 	return &MockAPI{
@@ -97,6 +99,7 @@ func NewMockAPI(
 		StakingAPI: stakingAPI,
 		QueryAPI:   queryAPI,
 		OracleAPI:  oracleAPI,
+		CosmosAPI:  cosmosAPI,
 		BlockTime:  blockTime,
 	}
 }
@@ -106,11 +109,13 @@ func NewEmptyMockAPI(blockTime uint64) *MockAPI {
 	stakingAPI := NewMockStakingAPI()
 	oracleAPI := NewMockOracleAPI()
 	queryAPI := NewMockQueryAPI()
+	cosmosAPI := NewMockCosmosAPI()
 	return &MockAPI{
 		AccountAPI: &accountAPI,
 		StakingAPI: &stakingAPI,
 		OracleAPI:  &oracleAPI,
 		QueryAPI:   &queryAPI,
+		CosmosAPI:  &cosmosAPI,
 		BlockTime:  blockTime,
 	}
 }
@@ -119,6 +124,10 @@ func (m MockAPI) Query(request types.QueryRequest, gasBalance uint64) ([]byte, u
 	return m.QueryAPI.Query(request, gasBalance)
 }
 
+func (m MockAPI) DispatchMessage(sender types.AccountAddress, message []byte, gasBalance uint64) ([]byte, uint64, error) {
+	return m.CosmosAPI.DispatchMessage(sender, message, gasBalance)
+}
+
 func (m MockAPI) GetAccountInfo(addr types.AccountAddress) (bool, uint64, uint64, uint8, bool) {
 	return m.AccountAPI.GetAccountInfo(addr)
 }
@@ -135,10 +144,22 @@ func (m MockAPI) UnbondTimestamp() uint64 {
 	return m.BlockTime + 60*60*24*7
 }
 
+func (m MockAPI) UnbondingEntries(validator []byte, metadata types.AccountAddress) ([]types.UnbondingEntry, error) {
+	return m.StakingAPI.UnbondingEntries(validator, metadata)
+}
+
 func (m MockAPI) GetPrice(pairId string) ([]byte, uint64, uint64, error) {
 	return m.OracleAPI.GetPrice(pairId)
 }
 
+func (m MockAPI) GetPriceAt(pairId string, height uint64) ([]byte, uint64, uint64, error) {
+	return m.OracleAPI.GetPriceAt(pairId, height)
+}
+
+func (m MockAPI) GetTWAP(pairId string, window uint64) ([]byte, uint64, uint64, error) {
+	return m.OracleAPI.GetTWAP(pairId, window)
+}
+
 type MockAccountAPI struct {
 	accounts map[string][]uint64
 }
@@ -169,14 +190,32 @@ type ShareAmountRatio struct {
 }
 
 type MockStakingAPI struct {
-	validators map[string]map[types.AccountAddress]ShareAmountRatio
+	validators       map[string]map[types.AccountAddress]ShareAmountRatio
+	unbondingEntries map[string]map[types.AccountAddress][]types.UnbondingEntry
 }
 
 // NewMockStakingAPI return MockStakingAPI instance
 func NewMockStakingAPI() MockStakingAPI {
 	return MockStakingAPI{
-		validators: make(map[string]map[types.AccountAddress]ShareAmountRatio),
+		validators:       make(map[string]map[types.AccountAddress]ShareAmountRatio),
+		unbondingEntries: make(map[string]map[types.AccountAddress][]types.UnbondingEntry),
+	}
+}
+
+func (m *MockStakingAPI) SetUnbondingEntries(validator []byte, metadata types.AccountAddress, entries []types.UnbondingEntry) {
+	if _, ok := m.unbondingEntries[string(validator)]; !ok {
+		m.unbondingEntries[string(validator)] = make(map[types.AccountAddress][]types.UnbondingEntry)
 	}
+
+	m.unbondingEntries[string(validator)][metadata] = entries
+}
+
+func (m MockStakingAPI) UnbondingEntries(validator []byte, metadata types.AccountAddress) ([]types.UnbondingEntry, error) {
+	if _, ok := m.validators[string(validator)]; !ok {
+		return nil, errors.New("validator not found")
+	}
+
+	return m.unbondingEntries[string(validator)][metadata], nil
 }
 
 func (m *MockStakingAPI) SetShareRatio(validator []byte, metadata types.AccountAddress, share string, amount uint64) {
@@ -248,14 +287,23 @@ func (m MockQueryAPI) Query(request types.QueryRequest, gasBalance uint64) ([]by
 	return nil, 0, nil
 }
 
+type oracleKey struct {
+	pairId string
+	param  uint64
+}
+
 type MockOracleAPI struct {
-	prices map[string][]uint64
+	prices           map[string][]uint64
+	historicalPrices map[oracleKey][]uint64
+	twaps            map[oracleKey][]uint64
 }
 
 // NewMockOracleAPI return MockOracleAPI instance
 func NewMockOracleAPI() MockOracleAPI {
 	return MockOracleAPI{
-		prices: make(map[string][]uint64),
+		prices:           make(map[string][]uint64),
+		historicalPrices: make(map[oracleKey][]uint64),
+		twaps:            make(map[oracleKey][]uint64),
 	}
 }
 
@@ -263,12 +311,42 @@ func (m *MockOracleAPI) SetPrice(pairId string, price, updatedAt, decimals uint6
 	m.prices[pairId] = []uint64{price, updatedAt, decimals}
 }
 
+func (m *MockOracleAPI) SetPriceAt(pairId string, height, price, updatedAt, decimals uint64) {
+	m.historicalPrices[oracleKey{pairId, height}] = []uint64{price, updatedAt, decimals}
+}
+
+func (m *MockOracleAPI) SetTWAP(pairId string, window, twap, updatedAt, decimals uint64) {
+	m.twaps[oracleKey{pairId, window}] = []uint64{twap, updatedAt, decimals}
+}
+
 func (m MockOracleAPI) GetPrice(pairId string) ([]byte, uint64, uint64, error) {
 	info, found := m.prices[pairId]
 	if !found {
 		return nil, 0, 0, errors.New("pair not found")
 	}
 
+	return serializePriceInfo(info)
+}
+
+func (m MockOracleAPI) GetPriceAt(pairId string, height uint64) ([]byte, uint64, uint64, error) {
+	info, found := m.historicalPrices[oracleKey{pairId, height}]
+	if !found {
+		return nil, 0, 0, errors.New("historical price not found")
+	}
+
+	return serializePriceInfo(info)
+}
+
+func (m MockOracleAPI) GetTWAP(pairId string, window uint64) ([]byte, uint64, uint64, error) {
+	info, found := m.twaps[oracleKey{pairId, window}]
+	if !found {
+		return nil, 0, 0, errors.New("twap not found")
+	}
+
+	return serializePriceInfo(info)
+}
+
+func serializePriceInfo(info []uint64) ([]byte, uint64, uint64, error) {
 	priceBz, err := types.SerializeUint256(0, 0, 0, info[0])
 	if err != nil {
 		return nil, 0, 0, err
@@ -276,3 +354,37 @@ func (m MockOracleAPI) GetPrice(pairId string) ([]byte, uint64, uint64, error) {
 
 	return priceBz, info[1], info[2], nil
 }
+
+type MockCosmosAPI struct {
+	responses map[string][]byte
+	errors    map[string]string
+}
+
+// NewMockCosmosAPI return MockCosmosAPI instance
+func NewMockCosmosAPI() MockCosmosAPI {
+	return MockCosmosAPI{
+		responses: make(map[string][]byte),
+		errors:    make(map[string]string),
+	}
+}
+
+func (m *MockCosmosAPI) SetResponse(message []byte, response []byte) {
+	m.responses[string(message)] = response
+}
+
+func (m *MockCosmosAPI) SetError(message []byte, err string) {
+	m.errors[string(message)] = err
+}
+
+func (m MockCosmosAPI) DispatchMessage(sender types.AccountAddress, message []byte, gasBalance uint64) ([]byte, uint64, error) {
+	if err, ok := m.errors[string(message)]; ok {
+		return nil, 0, errors.New(err)
+	}
+
+	res, ok := m.responses[string(message)]
+	if !ok {
+		return nil, 0, errors.New("not registered message")
+	}
+
+	return res, 0, nil
+}
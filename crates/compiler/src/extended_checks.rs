@@ -1,6 +1,6 @@
 use initia_move_types::metadata::{
     KnownAttribute, RuntimeModuleMetadataV0, ERROR_PREFIX, EVENT_STRUCT_ATTRIBUTE,
-    INIT_MODULE_FUNCTION_NAME, VIEW_FUN_ATTRIBUTE,
+    INIT_MODULE_FUNCTION_NAME, RANDOMNESS_ATTRIBUTE, VIEW_FUN_ATTRIBUTE,
 };
 use move_binary_format::file_format::Visibility;
 use move_core_types::{
@@ -61,6 +61,7 @@ impl<'a> ExtendedChecker<'a> {
                 self.check_and_record_view_functions(module);
                 self.check_entry_functions(module);
                 self.check_and_record_events(module);
+                self.check_and_record_randomness(module);
                 self.check_init_module(module);
                 self.build_error_map(module)
             }
@@ -346,6 +347,61 @@ impl ExtendedChecker<'_> {
     }
 }
 
+// ----------------------------------------------------------------------------------
+// Randomness
+
+impl ExtendedChecker<'_> {
+    fn check_and_record_randomness(&mut self, module: &ModuleEnv) {
+        let is_randomness_module = module.get_full_name_str() == "0x1::randomness";
+        for ref fun in module.get_functions() {
+            if self.has_attribute(fun, RANDOMNESS_ATTRIBUTE) {
+                // Randomness functions must not be composable, otherwise the caller can
+                // abort the transaction on unfavorable outcomes (test-and-abort attack).
+                if !fun.is_entry() || fun.visibility() == Visibility::Public {
+                    self.env.error(
+                        &fun.get_loc(),
+                        "`#[randomness]` attribute is only allowed on private or friend entry functions",
+                    );
+                }
+
+                // Remember the runtime info that this function uses randomness
+                let module_id = self.get_runtime_module_id(module);
+                self.output
+                    .entry(module_id)
+                    .or_default()
+                    .fun_attributes
+                    .entry(fun.get_simple_name_string().to_string())
+                    .or_default()
+                    .push(KnownAttribute::randomness());
+            }
+
+            if is_randomness_module
+                || fun.visibility() != Visibility::Public
+                || fun.is_inline()
+                || fun.is_native()
+            {
+                continue;
+            }
+
+            // Public functions must not expose randomness to other modules.
+            let data = self.get_stackless_data(fun);
+            let target = FunctionTarget::new(fun, &data);
+            for bc in target.get_bytecode() {
+                if let Bytecode::Call(attr_id, _, Operation::Function(mid, fid, _), _, _) = bc {
+                    let callee = self.env.get_function(mid.qualified(*fid));
+                    if callee.module_env.get_full_name_str() == "0x1::randomness" {
+                        let loc = target.get_bytecode_loc(*attr_id);
+                        self.env.error(
+                            &loc,
+                            "public function cannot call `0x1::randomness` functions; use a private or friend entry function with `#[randomness]` attribute",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------
 // Error Map
 
@@ -4,9 +4,10 @@ use crate::mocks::{BlankAPIImpl, BlankTableViewImpl};
 use initia_move_natives::{
     account::NativeAccountContext, block::NativeBlockContext, code::NativeCodeContext,
     cosmos::NativeCosmosContext, event::NativeEventContext, oracle::NativeOracleContext,
-    query::NativeQueryContext, staking::NativeStakingContext, table::NativeTableContext,
-    transaction_context::NativeTransactionContext,
+    query::NativeQueryContext, randomness::NativeRandomnessContext, staking::NativeStakingContext,
+    table::NativeTableContext, transaction_context::NativeTransactionContext,
 };
+use move_core_types::account_address::AccountAddress;
 use move_unit_test;
 use move_vm_runtime::native_extensions::NativeContextExtensions;
 use once_cell::sync::Lazy;
@@ -23,12 +24,26 @@ fn unit_test_extensions_hook(exts: &mut NativeContextExtensions) {
     exts.add(NativeTableContext::new([0; 32], unsafe {
         addr_of_mut!(BLANK_TABLE_RESOLVER).as_mut().unwrap()
     }));
-    exts.add(NativeBlockContext::new("test".to_string(), 0, 0));
+    exts.add(NativeBlockContext::new(
+        "test".to_string(),
+        0,
+        0,
+        [0; 32],
+        AccountAddress::ZERO,
+    ));
     exts.add(NativeCodeContext::default());
     exts.add(NativeStakingContext::new(&MOCK_API.staking_api));
-    exts.add(NativeCosmosContext::default());
-    exts.add(NativeTransactionContext::new([0; 32], [0; 32], None));
+    exts.add(NativeCosmosContext::new(&MOCK_API.cosmos_api, true, &[]));
+    exts.add(NativeTransactionContext::new(
+        [0; 32],
+        [0; 32],
+        0,
+        0,
+        "0".to_string(),
+        None,
+    ));
     exts.add(NativeEventContext::default());
+    exts.add(NativeRandomnessContext::new(None));
     exts.add(NativeOracleContext::new(&MOCK_API.oracle_api));
-    exts.add(NativeQueryContext::new(&MOCK_API.query_api));
+    exts.add(NativeQueryContext::new(&MOCK_API.query_api, &[]));
 }
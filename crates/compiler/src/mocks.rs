@@ -1,9 +1,9 @@
 use anyhow::{anyhow, Error};
 use initia_move_natives::{
-    account::AccountAPI, oracle::OracleAPI, query::QueryAPI, staking::StakingAPI,
+    account::AccountAPI, cosmos::CosmosAPI, oracle::OracleAPI, query::QueryAPI, staking::StakingAPI,
 };
 use initia_move_storage::table_resolver::TableResolver;
-use initia_move_types::table::TableHandle;
+use initia_move_types::{staking_change_set::UnbondingEntry, table::TableHandle};
 use move_core_types::{account_address::AccountAddress, u256::U256};
 
 /// A dummy storage containing no modules or resources.
@@ -22,6 +22,7 @@ impl TableResolver for BlankTableViewImpl {
     fn create_iterator(
         &mut self,
         _handle: &TableHandle,
+        _prefix: Option<&[u8]>,
         _start: Option<&[u8]>,
         _end: Option<&[u8]>,
         _order: initia_move_types::iterator::Order,
@@ -39,6 +40,7 @@ pub struct BlankAPIImpl {
     pub staking_api: BlankStakingAPIImpl,
     pub oracle_api: BlankOracleAPIImpl,
     pub query_api: BlankQueryAPIImpl,
+    pub cosmos_api: BlankCosmosAPIImpl,
 }
 
 impl BlankAPIImpl {
@@ -48,6 +50,7 @@ impl BlankAPIImpl {
             staking_api: BlankStakingAPIImpl,
             oracle_api: BlankOracleAPIImpl,
             query_api: BlankQueryAPIImpl,
+            cosmos_api: BlankCosmosAPIImpl,
         }
     }
 }
@@ -93,6 +96,14 @@ impl StakingAPI for BlankStakingAPIImpl {
     fn unbond_timestamp(&self) -> anyhow::Result<u64> {
         Ok(60 * 60 * 24 * 7)
     }
+
+    fn unbonding_entries(
+        &self,
+        _validator: &[u8],
+        _metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>> {
+        Err(anyhow!("validator not found"))
+    }
 }
 
 pub struct BlankOracleAPIImpl;
@@ -108,6 +119,30 @@ impl OracleAPI for BlankOracleAPIImpl {
     )> {
         Err(anyhow!("pair not found"))
     }
+
+    fn get_price_at(
+        &self,
+        _pair_id: &[u8],
+        _height: u64,
+    ) -> anyhow::Result<(
+        U256, /* price */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        Err(anyhow!("historical price not found"))
+    }
+
+    fn get_twap(
+        &self,
+        _pair_id: &[u8],
+        _window: u64,
+    ) -> anyhow::Result<(
+        U256, /* twap */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        Err(anyhow!("twap not found"))
+    }
 }
 
 pub struct BlankQueryAPIImpl;
@@ -117,3 +152,16 @@ impl QueryAPI for BlankQueryAPIImpl {
         (Err(anyhow!("not registered query")), 0)
     }
 }
+
+pub struct BlankCosmosAPIImpl;
+
+impl CosmosAPI for BlankCosmosAPIImpl {
+    fn dispatch_message(
+        &self,
+        _sender: AccountAddress,
+        _message: &[u8],
+        _gas_balance: u64,
+    ) -> (anyhow::Result<Vec<u8>>, u64) {
+        (Err(anyhow!("not registered message")), 0)
+    }
+}
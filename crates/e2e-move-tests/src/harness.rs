@@ -44,6 +44,9 @@ pub struct MoveHarness {
     pub api: MockAPI,
     /// Optional fee payer injected into every execution Env (not the initialize Env).
     pub fee_payer: Option<AccountAddress>,
+    /// Number of the messages in the transaction injected into every execution Env,
+    /// where the executed message is always the first one.
+    pub num_msgs: u64,
 }
 
 pub fn path_in_crate<S>(relative: S) -> PathBuf
@@ -73,6 +76,7 @@ impl MoveHarness {
             vm,
             api,
             fee_payer: None,
+            num_msgs: 1,
         }
     }
 
@@ -81,6 +85,11 @@ impl MoveHarness {
         self.fee_payer = fee_payer;
     }
 
+    /// Sets the number of the messages in the transaction for all subsequent execution calls.
+    pub fn set_num_msgs(&mut self, num_msgs: u64) {
+        self.num_msgs = num_msgs;
+    }
+
     pub fn initialize(&mut self) {
         let state = self.chain.create_state();
         let mut table_resolver = MockTableState::new(&state);
@@ -93,6 +102,13 @@ impl MoveHarness {
             Self::generate_random_hash().try_into().unwrap(),
             Self::generate_random_hash().try_into().unwrap(),
             None,
+            None,
+            Self::generate_random_hash().try_into().unwrap(),
+            AccountAddress::ZERO,
+            0,
+            0,
+            1,
+            "0".to_string(),
         );
 
         let output = self
@@ -196,6 +212,13 @@ impl MoveHarness {
             Self::generate_random_hash().try_into().unwrap(),
             Self::generate_random_hash().try_into().unwrap(),
             self.fee_payer,
+            Some(Self::generate_random_hash().try_into().unwrap()),
+            Self::generate_random_hash().try_into().unwrap(),
+            AccountAddress::ZERO,
+            0,
+            0,
+            self.num_msgs,
+            "0".to_string(),
         );
 
         self.vm.execute_view_function(
@@ -350,6 +373,13 @@ impl MoveHarness {
             Self::generate_random_hash().try_into().unwrap(),
             Self::generate_random_hash().try_into().unwrap(),
             self.fee_payer,
+            Some(Self::generate_random_hash().try_into().unwrap()),
+            Self::generate_random_hash().try_into().unwrap(),
+            AccountAddress::ZERO,
+            0,
+            0,
+            self.num_msgs,
+            "0".to_string(),
         );
 
         let state = self.chain.create_state();
@@ -369,6 +399,14 @@ impl MoveHarness {
     }
 
     pub fn run_message(&mut self, message: Message) -> Result<MessageOutput, VMStatus> {
+        self.run_message_with_gas_limit(message, 100_000_000u64)
+    }
+
+    pub fn run_message_with_gas_limit(
+        &mut self,
+        message: Message,
+        gas_limit: u64,
+    ) -> Result<MessageOutput, VMStatus> {
         let env = Env::new(
             "test".to_string(),
             0,
@@ -377,13 +415,19 @@ impl MoveHarness {
             Self::generate_random_hash().try_into().unwrap(),
             Self::generate_random_hash().try_into().unwrap(),
             self.fee_payer,
+            Some(Self::generate_random_hash().try_into().unwrap()),
+            Self::generate_random_hash().try_into().unwrap(),
+            AccountAddress::ZERO,
+            0,
+            0,
+            self.num_msgs,
+            "0".to_string(),
         );
 
         let state = self.chain.create_state();
         let mut table_resolver = MockTableState::new(&state);
 
-        let gas_limit: initia_move_gas::GasQuantity<initia_move_gas::GasUnit> =
-            Gas::new(100_000_000u64);
+        let gas_limit = Gas::new(gas_limit);
         let mut gas_meter = self.vm.create_gas_meter(gas_limit);
         self.vm.execute_message(
             &mut gas_meter,
@@ -408,6 +452,13 @@ impl MoveHarness {
             Self::generate_random_hash().try_into().unwrap(),
             Self::generate_random_hash().try_into().unwrap(),
             self.fee_payer,
+            Some(Self::generate_random_hash().try_into().unwrap()),
+            Self::generate_random_hash().try_into().unwrap(),
+            AccountAddress::ZERO,
+            0,
+            0,
+            self.num_msgs,
+            "0".to_string(),
         );
 
         let mut table_resolver = MockTableState::new(state);
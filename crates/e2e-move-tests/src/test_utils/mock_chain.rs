@@ -1,17 +1,22 @@
 use bigdecimal::{num_bigint::ToBigInt, BigDecimal};
 use bytes::Bytes;
-use initia_move_storage::{state_view::StateView, table_resolver::TableResolver};
+use initia_move_storage::{
+    state_view::{IterableStateView, StateView},
+    table_resolver::TableResolver,
+};
 use std::{
+    cell::Cell,
     collections::BTreeMap,
     ops::{Bound, RangeBounds},
     str::FromStr,
 };
 
 use initia_move_natives::{
-    account::AccountAPI, oracle::OracleAPI, query::QueryAPI, staking::StakingAPI,
+    account::AccountAPI, cosmos::CosmosAPI, oracle::OracleAPI, query::QueryAPI, staking::StakingAPI,
 };
 use initia_move_types::{
-    access_path::AccessPath, iterator::Order, table::TableHandle, write_set::WriteSet,
+    access_path::AccessPath, errors::BackendError, iterator::Order,
+    staking_change_set::UnbondingEntry, table::TableHandle, write_set::WriteSet,
 };
 use move_core_types::{account_address::AccountAddress, effects::Op, u256::U256};
 
@@ -87,6 +92,21 @@ impl StateView for MockState {
     }
 }
 
+impl IterableStateView for MockState {
+    fn iter_entries(
+        &self,
+        address: Option<AccountAddress>,
+    ) -> Box<dyn Iterator<Item = anyhow::Result<(AccessPath, Bytes)>> + '_> {
+        let prefix = address.map(|addr| addr.to_vec()).unwrap_or_default();
+        Box::new(
+            self.map
+                .range(prefix.clone()..)
+                .take_while(move |(key, _)| key.starts_with(&prefix))
+                .map(|(key, value)| Ok((AccessPath::from_bytes(key)?, value.clone().into()))),
+        )
+    }
+}
+
 pub struct MockTableState<'r> {
     inner: &'r MockState,
     iterators: Vec<Vec<Vec<u8>>>,
@@ -114,11 +134,13 @@ impl TableResolver for MockTableState<'_> {
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
+        key_prefix: Option<&[u8]>,
         start: Option<&[u8]>,
         end: Option<&[u8]>,
         order: Order,
     ) -> anyhow::Result<u32> {
-        let prefix = AccessPath::table_item_access_path(handle.0, vec![]).to_bytes()?;
+        let table_prefix = AccessPath::table_item_access_path(handle.0, vec![]).to_bytes()?;
+        let prefix = [table_prefix.as_slice(), key_prefix.unwrap_or_default()].concat();
 
         let start = start.map_or(prefix.clone(), |v| {
             let mut prefix = prefix.clone();
@@ -146,7 +168,8 @@ impl TableResolver for MockTableState<'_> {
 
         let iterator_id = self.iterators.len();
 
-        let prefix_len = prefix.len();
+        // only strip the table prefix, so the returned keys include the key prefix
+        let prefix_len = table_prefix.len();
         let iter = self.inner.map.range(bounds);
         self.iterators.push(match order {
             Order::Ascending => iter
@@ -215,6 +238,7 @@ pub struct MockAPI {
     pub staking_api: MockStakingAPI,
     pub query_api: MockQueryAPI,
     pub oracle_api: MockOracleAPI,
+    pub cosmos_api: MockCosmosAPI,
     pub block_time: u64,
 }
 
@@ -224,12 +248,14 @@ impl MockAPI {
         staking_api: MockStakingAPI,
         query_api: MockQueryAPI,
         oracle_api: MockOracleAPI,
+        cosmos_api: MockCosmosAPI,
     ) -> Self {
         Self {
             account_api,
             staking_api,
             query_api,
             oracle_api,
+            cosmos_api,
             block_time: 0,
         }
     }
@@ -239,7 +265,8 @@ impl MockAPI {
         let staking_api = MockStakingAPI::new();
         let query_api = MockQueryAPI::new();
         let oracle_api = MockOracleAPI::new();
-        MockAPI::new(account_api, staking_api, query_api, oracle_api)
+        let cosmos_api = MockCosmosAPI::new();
+        MockAPI::new(account_api, staking_api, query_api, oracle_api, cosmos_api)
     }
 
     pub fn set_block_time(&mut self, block_time: u64) {
@@ -285,6 +312,14 @@ impl StakingAPI for MockAPI {
     fn unbond_timestamp(&self) -> anyhow::Result<u64> {
         Ok(self.block_time + 60 * 60 * 24 * 7)
     }
+
+    fn unbonding_entries(
+        &self,
+        validator: &[u8],
+        metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>> {
+        self.staking_api.unbonding_entries(validator, metadata)
+    }
 }
 
 impl QueryAPI for MockAPI {
@@ -304,6 +339,55 @@ impl OracleAPI for MockAPI {
     )> {
         self.oracle_api.get_price(pair_id)
     }
+
+    fn get_prices(
+        &self,
+        pair_ids: &[Vec<u8>],
+    ) -> anyhow::Result<
+        Vec<(
+            U256, /* price */
+            u64,  /* updated_at */
+            u64,  /* decimals */
+        )>,
+    > {
+        self.oracle_api.get_prices(pair_ids)
+    }
+
+    fn get_price_at(
+        &self,
+        pair_id: &[u8],
+        height: u64,
+    ) -> anyhow::Result<(
+        U256, /* price */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        self.oracle_api.get_price_at(pair_id, height)
+    }
+
+    fn get_twap(
+        &self,
+        pair_id: &[u8],
+        window: u64,
+    ) -> anyhow::Result<(
+        U256, /* twap */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        self.oracle_api.get_twap(pair_id, window)
+    }
+}
+
+impl CosmosAPI for MockAPI {
+    fn dispatch_message(
+        &self,
+        sender: AccountAddress,
+        message: &[u8],
+        gas_balance: u64,
+    ) -> (anyhow::Result<Vec<u8>>, u64) {
+        self.cosmos_api
+            .dispatch_message(sender, message, gas_balance)
+    }
 }
 
 pub struct MockAccountAPI {
@@ -348,15 +432,27 @@ impl Default for MockAccountAPI {
 
 pub struct MockStakingAPI {
     pub validators: BTreeMap<Vec<u8>, BTreeMap<AccountAddress, (u64, u64)>>,
+    pub unbonding_entries: BTreeMap<(Vec<u8>, AccountAddress), Vec<UnbondingEntry>>,
 }
 
 impl MockStakingAPI {
     pub fn new() -> Self {
         MockStakingAPI {
             validators: BTreeMap::default(),
+            unbonding_entries: BTreeMap::default(),
         }
     }
 
+    pub fn set_unbonding_entries(
+        &mut self,
+        validator: Vec<u8>,
+        metadata: AccountAddress,
+        entries: Vec<UnbondingEntry>,
+    ) {
+        self.unbonding_entries
+            .insert((validator, metadata), entries);
+    }
+
     pub fn set_share_ratio(
         &mut self,
         validator: Vec<u8>,
@@ -422,13 +518,44 @@ impl MockStakingAPI {
             None => Err(anyhow!("validator not found")),
         }
     }
+
+    fn unbonding_entries(
+        &self,
+        validator: &[u8],
+        metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>> {
+        if !self.validators.contains_key(validator) {
+            return Err(anyhow!("validator not found"));
+        }
+
+        Ok(self
+            .unbonding_entries
+            .get(&(validator.to_vec(), metadata))
+            .cloned()
+            .unwrap_or_default())
+    }
 }
 
-pub struct MockQueryAPI {}
+pub struct MockQueryAPI {
+    pub responses: BTreeMap<Vec<u8>, Vec<u8>>,
+    // number of queries sent to the api
+    calls: Cell<u64>,
+}
 
 impl MockQueryAPI {
     pub fn new() -> Self {
-        MockQueryAPI {}
+        MockQueryAPI {
+            responses: BTreeMap::default(),
+            calls: Cell::new(0),
+        }
+    }
+
+    pub fn set_response(&mut self, request: Vec<u8>, response: Vec<u8>) {
+        self.responses.insert(request, response);
+    }
+
+    pub fn calls(&self) -> u64 {
+        self.calls.get()
     }
 }
 
@@ -439,19 +566,27 @@ impl Default for MockQueryAPI {
 }
 
 impl MockQueryAPI {
-    fn query(&self, _request: &[u8], _gas_balance: u64) -> (anyhow::Result<Vec<u8>>, u64) {
-        (Err(anyhow!("not registered query")), 0)
+    fn query(&self, request: &[u8], _gas_balance: u64) -> (anyhow::Result<Vec<u8>>, u64) {
+        self.calls.set(self.calls.get() + 1);
+        match self.responses.get(request) {
+            Some(response) => (Ok(response.clone()), 0),
+            None => (Err(anyhow!("not registered query")), 0),
+        }
     }
 }
 
 pub struct MockOracleAPI {
     pub prices: BTreeMap<Vec<u8>, (U256, u64, u64)>,
+    pub historical_prices: BTreeMap<(Vec<u8>, u64), (U256, u64, u64)>,
+    pub twaps: BTreeMap<(Vec<u8>, u64), (U256, u64, u64)>,
 }
 
 impl MockOracleAPI {
     pub fn new() -> Self {
         MockOracleAPI {
             prices: BTreeMap::default(),
+            historical_prices: BTreeMap::default(),
+            twaps: BTreeMap::default(),
         }
     }
 
@@ -464,6 +599,30 @@ impl MockOracleAPI {
     ) {
         self.prices.insert(pair_id, (price, updated_at, decimals));
     }
+
+    pub fn set_oracle_price_at(
+        &mut self,
+        pair_id: Vec<u8>,
+        height: u64,
+        price: U256,
+        updated_at: u64,
+        decimals: u64,
+    ) {
+        self.historical_prices
+            .insert((pair_id, height), (price, updated_at, decimals));
+    }
+
+    pub fn set_oracle_twap(
+        &mut self,
+        pair_id: Vec<u8>,
+        window: u64,
+        twap: U256,
+        updated_at: u64,
+        decimals: u64,
+    ) {
+        self.twaps
+            .insert((pair_id, window), (twap, updated_at, decimals));
+    }
 }
 
 impl Default for MockOracleAPI {
@@ -479,6 +638,81 @@ impl MockOracleAPI {
             None => Err(anyhow!("pair not found")),
         }
     }
+
+    fn get_prices(&self, pair_ids: &[Vec<u8>]) -> anyhow::Result<Vec<(U256, u64, u64)>> {
+        pair_ids
+            .iter()
+            .map(|pair_id| self.get_price(pair_id))
+            .collect()
+    }
+
+    fn get_price_at(&self, pair_id: &[u8], height: u64) -> anyhow::Result<(U256, u64, u64)> {
+        match self.historical_prices.get(&(pair_id.to_vec(), height)) {
+            Some(res) => Ok(*res),
+            None => Err(anyhow!("historical price not found")),
+        }
+    }
+
+    fn get_twap(&self, pair_id: &[u8], window: u64) -> anyhow::Result<(U256, u64, u64)> {
+        match self.twaps.get(&(pair_id.to_vec(), window)) {
+            Some(res) => Ok(*res),
+            None => Err(anyhow!("twap not found")),
+        }
+    }
+}
+
+type DispatchHandler = Box<dyn Fn(&[u8]) -> anyhow::Result<Vec<u8>>>;
+
+pub struct MockCosmosAPI {
+    pub responses: BTreeMap<Vec<u8>, Result<Vec<u8>, String>>,
+    // executes the messages without a registered response, like the host does
+    handler: Option<DispatchHandler>,
+}
+
+impl MockCosmosAPI {
+    pub fn new() -> Self {
+        MockCosmosAPI {
+            responses: BTreeMap::default(),
+            handler: None,
+        }
+    }
+
+    pub fn set_handler(&mut self, handler: impl Fn(&[u8]) -> anyhow::Result<Vec<u8>> + 'static) {
+        self.handler = Some(Box::new(handler));
+    }
+
+    pub fn set_response(&mut self, message: Vec<u8>, response: Vec<u8>) {
+        self.responses.insert(message, Ok(response));
+    }
+
+    pub fn set_error(&mut self, message: Vec<u8>, error: String) {
+        self.responses.insert(message, Err(error));
+    }
+}
+
+impl Default for MockCosmosAPI {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockCosmosAPI {
+    fn dispatch_message(
+        &self,
+        _sender: AccountAddress,
+        message: &[u8],
+        _gas_balance: u64,
+    ) -> (anyhow::Result<Vec<u8>>, u64) {
+        match self.responses.get(message) {
+            Some(Ok(response)) => (Ok(response.clone()), 0),
+            // message failures are reported back to move, like the go api does
+            Some(Err(err)) => (Err(anyhow!(BackendError::user_err(err.clone()))), 0),
+            None => match &self.handler {
+                Some(handler) => (handler(message), 0),
+                None => (Err(anyhow!("not registered message")), 0),
+            },
+        }
+    }
 }
 
 ///////////////////////////////////////////////
@@ -507,6 +741,7 @@ impl TableResolver for BlankTableViewImpl {
     fn create_iterator(
         &mut self,
         _handle: &TableHandle,
+        _prefix: Option<&[u8]>,
         _start: Option<&[u8]>,
         _end: Option<&[u8]>,
         _order: Order,
@@ -524,6 +759,7 @@ pub struct BlankAPIImpl {
     pub staking_api: BlankStakingAPIImpl,
     pub oracle_api: BlankOracleAPIImpl,
     pub query_api: BlankQueryAPIImpl,
+    pub cosmos_api: BlankCosmosAPIImpl,
 }
 
 impl BlankAPIImpl {
@@ -533,6 +769,7 @@ impl BlankAPIImpl {
             staking_api: BlankStakingAPIImpl,
             oracle_api: BlankOracleAPIImpl,
             query_api: BlankQueryAPIImpl,
+            cosmos_api: BlankCosmosAPIImpl,
         }
     }
 }
@@ -590,6 +827,14 @@ impl StakingAPI for BlankStakingAPIImpl {
     fn unbond_timestamp(&self) -> anyhow::Result<u64> {
         Ok(60 * 60 * 24 * 7)
     }
+
+    fn unbonding_entries(
+        &self,
+        _validator: &[u8],
+        _metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>> {
+        Err(anyhow!("validator not found"))
+    }
 }
 
 pub struct BlankOracleAPIImpl;
@@ -605,6 +850,30 @@ impl OracleAPI for BlankOracleAPIImpl {
     )> {
         Err(anyhow!("pair not found"))
     }
+
+    fn get_price_at(
+        &self,
+        _pair_id: &[u8],
+        _height: u64,
+    ) -> anyhow::Result<(
+        U256, /* price */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        Err(anyhow!("historical price not found"))
+    }
+
+    fn get_twap(
+        &self,
+        _pair_id: &[u8],
+        _window: u64,
+    ) -> anyhow::Result<(
+        U256, /* twap */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )> {
+        Err(anyhow!("twap not found"))
+    }
 }
 pub struct BlankQueryAPIImpl;
 
@@ -613,3 +882,16 @@ impl QueryAPI for BlankQueryAPIImpl {
         (Err(anyhow!("not registered query")), 0)
     }
 }
+
+pub struct BlankCosmosAPIImpl;
+
+impl CosmosAPI for BlankCosmosAPIImpl {
+    fn dispatch_message(
+        &self,
+        _sender: AccountAddress,
+        _message: &[u8],
+        _gas_balance: u64,
+    ) -> (anyhow::Result<Vec<u8>>, u64) {
+        (Err(anyhow!("not registered message")), 0)
+    }
+}
@@ -166,6 +166,21 @@ module 0xCAFE::test {
         assert!(bigdecimal::eq(bd, bd2), 1);
     }
 
+    enum Shape has drop {
+        Circle { radius: u64 },
+        Named { name: String },
+    }
+
+    public entry fun enum_test(shapes: vector<Shape>, radius: u64, name: String) {
+        assert!(vector::length(&shapes) == 2, 1);
+        vector::for_each(shapes, |shape| {
+            match (shape) {
+                Shape::Circle { radius: r } => assert!(r == radius, 2),
+                Shape::Named { name: n } => assert!(n == name, 3),
+            }
+        });
+    }
+
     fun find_hello_in_msgs_of_msgs(msgs: &vector<vector<String>>) {
         let outer_len = vector::length(msgs);
         while (outer_len > 0) {
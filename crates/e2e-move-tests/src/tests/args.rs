@@ -858,6 +858,70 @@ fn biguint_bigdecimal() {
     .unwrap();
 }
 
+#[test]
+fn json_enum_args() {
+    let acc = AccountAddress::from_hex_literal("0xcafe").expect("0xcafe account should be created");
+    let path = "src/tests/args.data/pack";
+    let mut h = MoveHarness::new();
+
+    h.initialize();
+
+    // publish package
+    let output = h
+        .publish_package(&acc, path, UpgradePolicy::Compatible)
+        .expect("should success");
+    h.commit(output, true);
+
+    let entry = "0xcafe::test::enum_test";
+    h.run_entry_function_with_json(
+        vec![acc],
+        str::parse(entry).unwrap(),
+        vec![],
+        vec![
+            r#"[{"Circle": {"radius": "10"}}, {"1": ["hello"]}]"#.to_string(),
+            r#""10""#.to_string(),
+            r#""hello""#.to_string(),
+        ],
+    )
+    .unwrap();
+
+    // unknown variant
+    let err = h
+        .run_entry_function_with_json(
+            vec![acc],
+            str::parse(entry).unwrap(),
+            vec![],
+            vec![
+                r#"[{"Square": {"side": "10"}}]"#.to_string(),
+                r#""10""#.to_string(),
+                r#""hello""#.to_string(),
+            ],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.status_code(),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+
+    // enums are not accepted as bcs arguments
+    let err = h
+        .run_entry_function(
+            vec![acc],
+            str::parse(entry).unwrap(),
+            vec![],
+            vec![
+                vec![1, 0, 10, 0, 0, 0, 0, 0, 0, 0],
+                bcs::to_bytes(&10u64).unwrap(),
+                bcs::to_bytes("hello").unwrap(),
+            ],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.status_code(),
+        StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE
+    );
+}
+
 #[derive(Deserialize)]
 struct CreateEvent {
     object: AccountAddress,
@@ -0,0 +1,43 @@
+module 0xcafe::dispatcher {
+    use std::auth_data::AbstractionAuthData;
+    use initia_std::cosmos;
+    use initia_std::object;
+    use initia_std::query;
+
+    const MSG: vector<u8> = b"{\"@type\":\"/cosmos.gov.v1.MsgVote\"}";
+    const QUERY_PATH: vector<u8> = b"/cosmos.bank.v1beta1.Query/Balance";
+
+    public entry fun dispatch(sender: &signer) {
+        cosmos::dispatch(sender, MSG, false);
+    }
+
+    public entry fun dispatch_allow_failure(sender: &signer, success: bool) {
+        let result = cosmos::dispatch(sender, MSG, true);
+        assert!(cosmos::is_dispatch_success(&result) == success, 1);
+    }
+
+    public entry fun dispatch_data(sender: &signer, data: vector<u8>) {
+        cosmos::dispatch(sender, data, false);
+    }
+
+    public entry fun query_dispatch_query(sender: &signer) {
+        query::query_stargate(QUERY_PATH, b"{}");
+        query::query_stargate(QUERY_PATH, b"{}");
+        cosmos::dispatch(sender, MSG, false);
+        query::query_stargate(QUERY_PATH, b"{}");
+    }
+
+    #[view]
+    public fun view_dispatch(): bool {
+        let constructor_ref = object::create_object(@0xcafe, true);
+        let sender = object::generate_signer(&constructor_ref);
+        cosmos::is_dispatch_success(&cosmos::dispatch(&sender, MSG, true))
+    }
+
+    public fun authenticate(
+        account: signer, _auth_data: AbstractionAuthData
+    ): signer {
+        cosmos::dispatch(&account, MSG, true);
+        account
+    }
+}
@@ -0,0 +1,3 @@
+module 0xCAFE::other {
+    public fun callback(_id: u64, _success: bool) {}
+}
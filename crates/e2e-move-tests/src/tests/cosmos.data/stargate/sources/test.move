@@ -11,4 +11,16 @@ module 0xCAFE::test {
 
         cosmos::stargate_with_options(sender, data, options);
     }
-}
\ No newline at end of file
+
+    public fun callback(_id: u64, _success: bool) {}
+
+    fun private_callback(_id: u64, _success: bool) {}
+
+    public fun result_callback(
+        _sender: &signer,
+        _id: u64,
+        _success: bool,
+        _response: vector<u8>,
+        _error: String
+    ) {}
+}
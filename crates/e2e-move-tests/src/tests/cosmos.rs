@@ -3,10 +3,16 @@ use core::str;
 use crate::tests::common::ExpectedOutput;
 use crate::MoveHarness;
 use initia_move_natives::code::UpgradePolicy;
+use initia_move_types::authenticator::{AbstractionAuthData, AbstractionData};
 use initia_move_types::cosmos::{CosmosCallback, CosmosMessage};
+use initia_move_types::function_info::FunctionInfo;
+use initia_move_types::query::{QueryRequest, StargateQuery};
+use initia_move_types::vm_config::InitiaVMConfig;
+use initia_move_vm::InitiaVM;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::language_storage::TypeTag;
-use move_core_types::vm_status::VMStatus;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
 
 use base64::{self, Engine};
 use bech32::{Bech32, Hrp};
@@ -658,11 +664,295 @@ fn test_cosmos_stargate() {
                     module_address: AccountAddress::from_hex_literal("0xcafe").unwrap(),
                     module_name: "test".to_string(),
                     function_name: "callback".to_string(),
+                    with_signer: false,
+                    with_result: false,
                 }),
             }]),
         ),
     );
     tests.push(test_stargate);
 
+    let test_stargate = (
+        sender,
+        "0xcafe::test::stargate",
+        vec![],
+        vec![
+            bcs::to_bytes(data.as_bytes()).unwrap(),
+            bcs::to_bytes(&true).unwrap(),
+            bcs::to_bytes(&124u64).unwrap(),
+            bcs::to_bytes("0xcafe::test::result_callback").unwrap(),
+        ],
+        ExpectedOutput::new(
+            VMStatus::Executed,
+            None,
+            None,
+            Some(vec![CosmosMessage {
+                sender,
+                data: data.as_bytes().to_vec(),
+                allow_failure: true,
+                callback: Some(CosmosCallback {
+                    id: 124,
+                    module_address: AccountAddress::from_hex_literal("0xcafe").unwrap(),
+                    module_name: "test".to_string(),
+                    function_name: "result_callback".to_string(),
+                    with_signer: true,
+                    with_result: true,
+                }),
+            }]),
+        ),
+    );
+    tests.push(test_stargate);
+
+    let test_stargate = (
+        sender,
+        "0xcafe::test::stargate",
+        vec![],
+        vec![
+            bcs::to_bytes(data.as_bytes()).unwrap(),
+            bcs::to_bytes(&false).unwrap(),
+            bcs::to_bytes(&125u64).unwrap(),
+            bcs::to_bytes("0xcafe::test::stargate").unwrap(),
+        ],
+        ExpectedOutput::new(
+            VMStatus::MoveAbort(
+                AbortLocation::Module(ModuleId::new(
+                    AccountAddress::ONE,
+                    Identifier::new("cosmos").unwrap(),
+                )),
+                0x10065,
+            ),
+            None,
+            None,
+            None,
+        ),
+    );
+    tests.push(test_stargate);
+
+    // private callbacks are rejected
+    let test_stargate = (
+        sender,
+        "0xcafe::test::stargate",
+        vec![],
+        vec![
+            bcs::to_bytes(data.as_bytes()).unwrap(),
+            bcs::to_bytes(&false).unwrap(),
+            bcs::to_bytes(&126u64).unwrap(),
+            bcs::to_bytes("0xcafe::test::private_callback").unwrap(),
+        ],
+        ExpectedOutput::new(
+            VMStatus::MoveAbort(
+                AbortLocation::Module(ModuleId::new(
+                    AccountAddress::ONE,
+                    Identifier::new("cosmos").unwrap(),
+                )),
+                0x10065,
+            ),
+            None,
+            None,
+            None,
+        ),
+    );
+    tests.push(test_stargate);
+
+    // callbacks of modules not loaded by the transaction are rejected
+    let test_stargate = (
+        sender,
+        "0xcafe::test::stargate",
+        vec![],
+        vec![
+            bcs::to_bytes(data.as_bytes()).unwrap(),
+            bcs::to_bytes(&false).unwrap(),
+            bcs::to_bytes(&127u64).unwrap(),
+            bcs::to_bytes("0xcafe::other::callback").unwrap(),
+        ],
+        ExpectedOutput::new(
+            VMStatus::MoveAbort(
+                AbortLocation::Module(ModuleId::new(
+                    AccountAddress::ONE,
+                    Identifier::new("cosmos").unwrap(),
+                )),
+                0x10065,
+            ),
+            None,
+            None,
+            None,
+        ),
+    );
+    tests.push(test_stargate);
+
     run_tests(tests);
 }
+
+const DISPATCH_MSG: &[u8] = br#"{"@type":"/cosmos.gov.v1.MsgVote"}"#;
+
+fn setup_dispatch_harness() -> (MoveHarness, AccountAddress) {
+    let acc = AccountAddress::from_hex_literal("0xcafe").expect("0xcafe account should be created");
+    let mut h = MoveHarness::new();
+    h.vm = InitiaVM::new(InitiaVMConfig {
+        allowed_dispatch_messages: vec!["/cosmos.gov.v1.MsgVote".to_string()],
+        ..Default::default()
+    });
+    h.initialize();
+
+    let output = h
+        .publish_package(
+            &acc,
+            "src/tests/cosmos.data/dispatch",
+            UpgradePolicy::Compatible,
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    (h, acc)
+}
+
+#[test]
+fn test_cosmos_dispatch_only_in_message_execution() {
+    let (mut h, acc) = setup_dispatch_harness();
+    h.api
+        .cosmos_api
+        .set_response(DISPATCH_MSG.to_vec(), b"{}".to_vec());
+
+    let dispatch_not_allowed = VMStatus::MoveAbort(
+        AbortLocation::Module(ModuleId::new(
+            AccountAddress::ONE,
+            Identifier::new("cosmos").unwrap(),
+        )),
+        0x30066,
+    );
+
+    // entry functions can dispatch messages
+    let output = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0xcafe::dispatcher::dispatch").unwrap(),
+            vec![],
+            vec![],
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    // view functions cannot
+    let view_fn = h.create_view_function(
+        str::parse("0xcafe::dispatcher::view_dispatch").unwrap(),
+        vec![],
+        vec![],
+    );
+    let err = h.run_view_function(view_fn).unwrap_err();
+    assert_eq!(err, dispatch_not_allowed);
+
+    // nor account authentication functions
+    let output = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0x1::account_abstraction::add_authentication_function").unwrap(),
+            vec![],
+            vec![
+                acc.to_vec(),
+                bcs::to_bytes(b"dispatcher".as_slice()).unwrap(),
+                bcs::to_bytes(b"authenticate".as_slice()).unwrap(),
+            ],
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    let abstraction_data = AbstractionData {
+        function_info: FunctionInfo {
+            module_address: acc,
+            module_name: "dispatcher".to_string(),
+            function_name: "authenticate".to_string(),
+        },
+        auth_data: AbstractionAuthData::V1 {
+            signing_message_digest: vec![],
+            authenticator: vec![],
+        },
+    };
+    let err = h.authenticate(acc, abstraction_data).unwrap_err();
+    assert_eq!(err, dispatch_not_allowed);
+}
+
+#[test]
+fn test_cosmos_dispatch_invalidates_query_cache() {
+    let (mut h, acc) = setup_dispatch_harness();
+    h.api
+        .cosmos_api
+        .set_response(DISPATCH_MSG.to_vec(), b"{}".to_vec());
+
+    let request = serde_json::to_vec(&QueryRequest::Stargate(StargateQuery {
+        path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+        data: b"{}".to_vec(),
+    }))
+    .unwrap();
+    h.api
+        .query_api
+        .set_response(request, br#"{"balance":null}"#.to_vec());
+
+    h.run_entry_function(
+        vec![acc],
+        str::parse("0xcafe::dispatcher::query_dispatch_query").unwrap(),
+        vec![],
+        vec![],
+    )
+    .expect("should success");
+
+    // the repeated query is served from the cache, and the query after the
+    // dispatch is sent to the host again
+    assert_eq!(h.api.query_api.calls(), 2);
+}
+
+#[test]
+fn test_cosmos_dispatch_message_not_allowed() {
+    let (mut h, acc) = setup_dispatch_harness();
+
+    // messages which are not in the allowed dispatch messages never reach the host
+    let msg = br#"{"@type":"/cosmos.bank.v1beta1.MsgSend"}"#.to_vec();
+    h.api.cosmos_api.set_response(msg.clone(), b"{}".to_vec());
+
+    let err = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0xcafe::dispatcher::dispatch_data").unwrap(),
+            vec![],
+            vec![bcs::to_bytes(&msg).unwrap()],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        VMStatus::MoveAbort(
+            AbortLocation::Module(ModuleId::new(
+                AccountAddress::ONE,
+                Identifier::new("cosmos").unwrap(),
+            )),
+            0x50068,
+        )
+    );
+}
+
+#[test]
+fn test_cosmos_dispatch_host_error() {
+    let (mut h, acc) = setup_dispatch_harness();
+
+    // message failures are returned to move when failures are allowed
+    h.api
+        .cosmos_api
+        .set_error(DISPATCH_MSG.to_vec(), "insufficient funds".to_string());
+    h.run_entry_function(
+        vec![acc],
+        str::parse("0xcafe::dispatcher::dispatch_allow_failure").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&false).unwrap()],
+    )
+    .expect("should success");
+
+    // host errors abort the move execution even when failures are allowed
+    h.api.cosmos_api.responses.clear();
+    let err = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0xcafe::dispatcher::dispatch_allow_failure").unwrap(),
+            vec![],
+            vec![bcs::to_bytes(&false).unwrap()],
+        )
+        .unwrap_err();
+    assert_eq!(err.status_code(), StatusCode::VM_EXTENSION_ERROR);
+}
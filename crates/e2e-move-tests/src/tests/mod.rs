@@ -10,6 +10,7 @@ mod max_loop_depth;
 mod memory_quota;
 mod oracle;
 mod output;
+mod randomness;
 mod solana_derivable_account_abstraction;
 mod staking;
 mod std_coin;
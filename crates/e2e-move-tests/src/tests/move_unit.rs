@@ -11,13 +11,13 @@ use initia_move_gas::{
 use initia_move_natives::{
     account::NativeAccountContext, all_natives, block::NativeBlockContext, code::NativeCodeContext,
     cosmos::NativeCosmosContext, event::NativeEventContext, oracle::NativeOracleContext,
-    query::NativeQueryContext, staking::NativeStakingContext, table::NativeTableContext,
-    transaction_context::NativeTransactionContext,
+    query::NativeQueryContext, randomness::NativeRandomnessContext, staking::NativeStakingContext,
+    table::NativeTableContext, transaction_context::NativeTransactionContext,
 };
 use initia_move_types::metadata;
 
 use move_cli::base::test::{run_move_unit_tests_with_factory, UnitTestResult};
-use move_core_types::effects::ChangeSet;
+use move_core_types::{account_address::AccountAddress, effects::ChangeSet};
 use move_model::metadata::{CompilerVersion, LanguageVersion};
 use move_unit_test::UnitTestingConfig;
 use move_vm_runtime::native_extensions::NativeContextExtensions;
@@ -39,14 +39,28 @@ fn unit_test_extensions_hook(exts: &mut NativeContextExtensions) {
     exts.add(NativeTableContext::new([0; 32], unsafe {
         addr_of_mut!(BLANK_TABLE_RESOLVER).as_mut().unwrap()
     }));
-    exts.add(NativeBlockContext::new("test".to_string(), 0, 0));
+    exts.add(NativeBlockContext::new(
+        "test".to_string(),
+        0,
+        0,
+        [0; 32],
+        AccountAddress::ZERO,
+    ));
     exts.add(NativeCodeContext::default());
     exts.add(NativeStakingContext::new(&BLANK_API.staking_api));
-    exts.add(NativeCosmosContext::default());
-    exts.add(NativeTransactionContext::new([0; 32], [0; 32], None));
+    exts.add(NativeCosmosContext::new(&BLANK_API.cosmos_api, true, &[]));
+    exts.add(NativeTransactionContext::new(
+        [0; 32],
+        [0; 32],
+        0,
+        0,
+        "0".to_string(),
+        None,
+    ));
     exts.add(NativeEventContext::default());
+    exts.add(NativeRandomnessContext::new(None));
     exts.add(NativeOracleContext::new(&BLANK_API.oracle_api));
-    exts.add(NativeQueryContext::new(&BLANK_API.query_api));
+    exts.add(NativeQueryContext::new(&BLANK_API.query_api, &[]));
 }
 
 fn run_tests_for_pkg(path_to_pkg: impl Into<String>) {
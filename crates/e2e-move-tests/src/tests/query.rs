@@ -1,5 +1,8 @@
 use crate::MoveHarness;
+use initia_move_types::vm_config::InitiaVMConfig;
+use initia_move_vm::InitiaVM;
 use move_core_types::u256::U256;
+use move_core_types::vm_status::{AbortLocation, VMStatus};
 
 #[test]
 fn test_query() {
@@ -20,3 +23,31 @@ fn test_query() {
         .expect("get_proposal should success");
     assert_eq!(view_output, "{\"proposal\":{\"id\":0,\"title\":\"test_proposal\",\"summary\":\"test_proposal_summary\"}}");
 }
+
+#[test]
+fn test_query_path_not_allowed() {
+    let mut h = MoveHarness::new();
+    h.vm = InitiaVM::new(InitiaVMConfig {
+        allowed_query_paths: vec!["/cosmos.bank.v1beta1.Query/Balance".to_string()],
+        ..Default::default()
+    });
+
+    h.initialize();
+
+    let proposal_id = 1;
+
+    let view_fn = h.create_view_function(
+        str::parse("0x1::query::get_proposal").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&proposal_id).unwrap()],
+    );
+
+    let status = h
+        .run_view_function(view_fn)
+        .expect_err("get_proposal should fail");
+    assert!(matches!(
+        status,
+        VMStatus::MoveAbort(AbortLocation::Module(module_id), 0x50065)
+            if module_id.name().as_str() == "query"
+    ));
+}
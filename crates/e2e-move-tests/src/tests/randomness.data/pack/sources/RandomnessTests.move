@@ -0,0 +1,25 @@
+module test::RandomnessTests {
+    use std::signer;
+
+    use initia_std::cosmos;
+    use initia_std::randomness;
+
+    struct Roll has key {
+        value: u64
+    }
+
+    #[randomness]
+    entry fun roll(account: &signer) acquires Roll {
+        let value = randomness::u64_range(1, 7);
+        let addr = signer::address_of(account);
+        if (exists<Roll>(addr)) {
+            borrow_global_mut<Roll>(addr).value = value;
+        } else {
+            move_to(account, Roll { value });
+        }
+    }
+
+    entry fun dispatch(account: &signer, msg: vector<u8>) {
+        cosmos::dispatch(account, msg, true);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::MoveHarness;
+use initia_move_natives::code::UpgradePolicy;
+use initia_move_types::errors::BackendError;
+use initia_move_types::vm_config::InitiaVMConfig;
+use initia_move_vm::InitiaVM;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+/// `Roll` mirrors the Move struct `test::RandomnessTests::Roll`.
+#[derive(serde::Deserialize, Debug, PartialEq)]
+struct Roll {
+    value: u64,
+}
+
+const MSG_EXECUTE_TYPE_URL: &str = "/initia.move.v1.MsgExecute";
+
+fn setup_harness() -> MoveHarness {
+    let deployer_addr =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be parseable");
+    let path = "src/tests/randomness.data/pack";
+    let mut h = MoveHarness::new();
+    h.vm = InitiaVM::new(InitiaVMConfig {
+        allowed_dispatch_messages: vec![MSG_EXECUTE_TYPE_URL.to_string()],
+        ..Default::default()
+    });
+
+    h.initialize();
+
+    let output = h
+        .publish_package(&deployer_addr, path, UpgradePolicy::Compatible)
+        .expect("publish should succeed");
+    h.commit(output, true);
+
+    h
+}
+
+fn randomness_not_unbiasable() -> VMStatus {
+    VMStatus::MoveAbort(
+        AbortLocation::Module(ModuleId::new(
+            AccountAddress::ONE,
+            Identifier::new("randomness").unwrap(),
+        )),
+        0x30065,
+    )
+}
+
+#[test]
+fn test_randomness_entry_function() {
+    let deployer_addr =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be parseable");
+    let mut h = setup_harness();
+
+    let sender = AccountAddress::from_hex_literal("0x42").unwrap();
+    let entry_function = MoveHarness::create_entry_function(
+        str::parse("0x2::RandomnessTests::roll").unwrap(),
+        vec![],
+        vec![],
+    );
+
+    // gas limit below `randomness_min_gas_limit` is rejected before execution
+    let msg = h.create_entry_function_message(vec![sender], entry_function.clone());
+    let err = h
+        .run_message_with_gas_limit(msg, 100_000)
+        .expect_err("low gas limit should be rejected");
+    assert_eq!(
+        err.status_code(),
+        StatusCode::MAX_GAS_UNITS_BELOW_MIN_TRANSACTION_GAS_UNITS
+    );
+
+    // enough gas limit executes the entry function with randomness
+    let msg = h.create_entry_function_message(vec![sender], entry_function);
+    let output = h
+        .run_message_with_gas_limit(msg, 1_000_000)
+        .expect("randomness entry function should succeed");
+    h.commit(output, true);
+
+    let struct_tag = StructTag {
+        address: deployer_addr,
+        module: Identifier::from_str("RandomnessTests").unwrap(),
+        name: Identifier::from_str("Roll").unwrap(),
+        type_args: vec![],
+    };
+    let roll: Roll = h
+        .read_resource(&sender, struct_tag)
+        .expect("Roll resource should exist");
+    assert!((1..7).contains(&roll.value));
+}
+
+#[test]
+fn test_randomness_not_last_message() {
+    let mut h = setup_harness();
+
+    // a later message of the transaction could observe the outcome and abort
+    h.set_num_msgs(2);
+
+    let sender = AccountAddress::from_hex_literal("0x42").unwrap();
+    let err = h
+        .run_entry_function(
+            vec![sender],
+            str::parse("0x2::RandomnessTests::roll").unwrap(),
+            vec![],
+            vec![],
+        )
+        .expect_err("randomness should not be allowed");
+    assert_eq!(err, randomness_not_unbiasable());
+}
+
+#[test]
+fn test_randomness_in_dispatched_message() {
+    let sender = AccountAddress::from_hex_literal("0x42").unwrap();
+    let mut h = setup_harness();
+
+    // the host executes the dispatched `MsgExecute` with another vm instance
+    let inner = Rc::new(RefCell::new(setup_harness()));
+    let inner_result = Rc::new(RefCell::new(None));
+    {
+        let inner = inner.clone();
+        let inner_result = inner_result.clone();
+        h.api.cosmos_api.set_handler(move |_| {
+            let res = inner.borrow_mut().run_entry_function(
+                vec![sender],
+                str::parse("0x2::RandomnessTests::roll").unwrap(),
+                vec![],
+                vec![],
+            );
+            let success = res.is_ok();
+            *inner_result.borrow_mut() = Some(res);
+
+            if success {
+                Ok(b"{}".to_vec())
+            } else {
+                Err(anyhow!(BackendError::user_err("failed to execute")))
+            }
+        });
+    }
+
+    // the dispatching module could observe the outcome and abort
+    let msg = format!(r#"{{"@type":"{MSG_EXECUTE_TYPE_URL}"}}"#).into_bytes();
+    h.run_entry_function(
+        vec![sender],
+        str::parse("0x2::RandomnessTests::dispatch").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&msg).unwrap()],
+    )
+    .expect("dispatch should succeed");
+
+    let err = inner_result
+        .borrow_mut()
+        .take()
+        .expect("message should be dispatched")
+        .expect_err("randomness should not be allowed");
+    assert_eq!(err, randomness_not_unbiasable());
+}
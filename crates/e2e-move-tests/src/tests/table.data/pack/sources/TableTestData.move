@@ -77,6 +77,13 @@ module 0x2::TableTestData {
         move_to(&s, S { t });
     }
 
+    public entry fun remove_range(
+        acc: signer, start: u64, end: u64, limit: u64
+    ) acquires S {
+        let s = borrow_global_mut<S<u64, u64>>(signer::address_of(&acc));
+        T::remove_range(&mut s.t, option::some(start), option::some(end), limit);
+    }
+
     public entry fun iterate_ascending(acc: address) acquires S {
         let t_ref = &borrow_global<S<u64, u64>>(acc).t;
 
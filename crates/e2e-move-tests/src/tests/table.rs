@@ -1,8 +1,14 @@
 use crate::test_utils::generate_account;
 use crate::tests::common::{ExpectedOutput, ExpectedOutputItem};
 use crate::MoveHarness;
+use initia_move_api::convert::MoveConverter;
+use initia_move_api::state_dump::{export_state, import_state, StateFile};
 use initia_move_natives::code::UpgradePolicy;
+use initia_move_storage::state_view::{IterableStateView, StateView};
+use initia_move_types::access_path::DataPath;
+use initia_move_types::json_options::JsonOptions;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
 use move_core_types::language_storage::TypeTag;
 use move_core_types::vm_status::VMStatus;
 
@@ -156,3 +162,188 @@ fn test_tables() {
 
     run_tests(tests);
 }
+
+#[test]
+fn test_decode_table_item() {
+    let test_addr = AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let mut h = MoveHarness::new();
+    h.initialize();
+
+    let output = h
+        .publish_package(
+            &test_addr,
+            "src/tests/table.data/pack",
+            UpgradePolicy::Compatible,
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    let output = h
+        .run_entry_function(
+            vec![test_addr],
+            str::parse("0x2::TableTestData::simple_write").unwrap(),
+            vec![],
+            vec![u64::to_le_bytes(1).to_vec(), u64::to_le_bytes(2).to_vec()],
+        )
+        .expect("should success");
+    let (handle, key, value) = output
+        .write_set()
+        .into_iter()
+        .find_map(|(ap, op)| match (&ap.path, op) {
+            (DataPath::TableItem(key), Op::New(value)) => {
+                Some((ap.address, key.clone(), value.clone()))
+            }
+            _ => None,
+        })
+        .expect("table item should be written");
+    h.commit(output, true);
+
+    let state = h.chain.create_state();
+    let converter = MoveConverter::new(&state);
+    let item = converter
+        .try_into_table_item(&handle, &key, Some(&value), &JsonOptions::default())
+        .expect("table item should be decoded");
+    assert_eq!(
+        serde_json::to_value(&item).unwrap(),
+        serde_json::json!({
+            "key_type": "u64",
+            "value_type": "u64",
+            "key": "1",
+            "value": "2",
+        })
+    );
+
+    // unknown tables have no recorded types
+    assert!(converter
+        .try_into_table_item(&test_addr, &key, None, &JsonOptions::default())
+        .is_err());
+}
+
+#[test]
+fn test_export_import_state() {
+    let test_addr = AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let mut h = MoveHarness::new();
+    h.initialize();
+
+    let output = h
+        .publish_package(
+            &test_addr,
+            "src/tests/table.data/pack",
+            UpgradePolicy::Compatible,
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    let output = h
+        .run_entry_function(
+            vec![test_addr],
+            str::parse("0x2::TableTestData::simple_write").unwrap(),
+            vec![],
+            vec![u64::to_le_bytes(1).to_vec(), u64::to_le_bytes(2).to_vec()],
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    let state = h.chain.create_state();
+    let dump = export_state(&state, Some(test_addr)).expect("state should be exported");
+    assert_eq!(dump.accounts.len(), 1);
+    assert!(dump.accounts[0]
+        .modules
+        .iter()
+        .any(|module| module.abi.name.as_str() == "TableTestData"));
+
+    // the table lives under its own handle but is reachable from the resources of 0x2
+    assert_eq!(dump.tables.len(), 1);
+    let table = &dump.tables[0];
+    assert_eq!(table.key_type, "u64");
+    assert_eq!(table.value_type, "u64");
+    assert_eq!(table.items.len(), 1);
+    assert_eq!(table.items[0].key, serde_json::json!("1"));
+    assert_eq!(table.items[0].value, serde_json::json!("2"));
+
+    // the dump survives a json round trip and recreates the exported entries
+    let dump = serde_json::from_slice(&serde_json::to_vec(&dump).unwrap()).unwrap();
+    let mut imported = StateFile::new();
+    imported
+        .apply(import_state(&dump).expect("state should be imported"))
+        .unwrap();
+
+    let account_entries = state.iter_entries(Some(test_addr)).count();
+    let table_entries = state.iter_entries(Some(*table.handle.inner())).count();
+    assert_eq!(
+        imported.iter_entries(None).count(),
+        account_entries + table_entries
+    );
+    for entry in imported.iter_entries(None) {
+        let (access_path, value) = entry.unwrap();
+        assert_eq!(state.get(&access_path).unwrap(), Some(value));
+    }
+}
+
+#[test]
+fn test_remove_range_change_set() {
+    let test_addr = AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let acc = generate_account("0x8");
+    let mut h = MoveHarness::new();
+    h.initialize();
+
+    let output = h
+        .publish_package(
+            &test_addr,
+            "src/tests/table.data/pack",
+            UpgradePolicy::Compatible,
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    let output = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0x2::TableTestData::prepare_table_for_iterator").unwrap(),
+            vec![],
+            vec![],
+        )
+        .expect("should success");
+    h.commit(output, true);
+
+    // at most 3 of the keys in [2, 8) are removed
+    let output = h
+        .run_entry_function(
+            vec![acc],
+            str::parse("0x2::TableTestData::remove_range").unwrap(),
+            vec![],
+            vec![
+                u64::to_le_bytes(2).to_vec(),
+                u64::to_le_bytes(8).to_vec(),
+                u64::to_le_bytes(3).to_vec(),
+            ],
+        )
+        .expect("should success");
+
+    let mut removed_keys = vec![];
+    for (access_path, op) in output.write_set() {
+        match &access_path.path {
+            DataPath::TableItem(key) => {
+                assert!(matches!(op, Op::Delete));
+                removed_keys.push(bcs::from_bytes::<u64>(key).unwrap());
+            }
+            // the table length kept in the resource is updated
+            DataPath::Resource(_) => assert_eq!(access_path.address, acc),
+            _ => panic!("unexpected write to {}", access_path),
+        }
+    }
+    assert_eq!(removed_keys, vec![2, 3, 4]);
+
+    // the natively tracked entry count is reported to the host
+    let length_changes = output.table_length_changes();
+    assert_eq!(length_changes.len(), 1);
+    assert_eq!(length_changes[0].delta(), -3);
+    h.commit(output, true);
+
+    let view_fn = h.create_view_function(
+        str::parse("0x2::TableTestData::table_len").unwrap(),
+        vec![],
+        vec![acc.to_vec()],
+    );
+    assert_eq!(h.run_view_function(view_fn).unwrap(), "\"7\"");
+}
@@ -16,4 +16,9 @@ module test::ViewOutputTests {
 
         arg
     }
+
+    #[view]
+    public fun view_numbers(): (u64, u128, address) {
+        (42, 340282366920938463463374607431768211455, @0x1)
+    }
 }
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use crate::MoveHarness;
 use initia_move_natives::code::UpgradePolicy;
 use initia_move_types::json_event::JsonEvents;
+use initia_move_types::json_options::JsonOptions;
 use initia_move_types::view_function::ViewFunction;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
@@ -54,3 +55,50 @@ fn test_view_output() {
         .into_inner(),
     );
 }
+
+#[test]
+fn test_view_output_with_json_options() {
+    let deployer_addr =
+        AccountAddress::from_hex_literal("0x2").expect("0x2 account should be created");
+    let path = "src/tests/view_output.data/pack";
+    let mut h = MoveHarness::new();
+
+    h.initialize();
+
+    let output = h
+        .publish_package(&deployer_addr, path, UpgradePolicy::Compatible)
+        .expect("should success");
+    h.commit(output, true);
+
+    let module_id = ModuleId::new(
+        deployer_addr,
+        Identifier::from_str("ViewOutputTests").unwrap(),
+    );
+    let view_fn = ViewFunction::new(
+        module_id,
+        Identifier::from_str("view_numbers").unwrap(),
+        vec![],
+        vec![],
+        true,
+    );
+
+    let ret = h
+        .run_view_function(view_fn.clone())
+        .expect("should success");
+    assert_eq!(
+        ret,
+        "[\"42\",\"340282366920938463463374607431768211455\",\"0x1\"]"
+    );
+
+    let ret = h
+        .run_view_function(view_fn.with_json_options(JsonOptions {
+            numbers_as_json: true,
+            address_hrp: Some("init".to_string()),
+            ..Default::default()
+        }))
+        .expect("should success");
+    assert_eq!(
+        ret,
+        "[42,\"340282366920938463463374607431768211455\",\"init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d\"]"
+    );
+}
@@ -35,9 +35,21 @@ crate::macros::define_gas_parameters!(
     [json_unmarshal_base: InternalGas, "json.unmarshal.base", 1102],
     [json_unmarshal_per_byte: InternalGasPerByte, "json.unmarshal.per_byte", 18],
 
+    [protobuf_encode_base: InternalGas, "protobuf.encode.base", 1102],
+    [protobuf_encode_per_byte: InternalGasPerByte, "protobuf.encode.per_byte", 18],
+    [protobuf_decode_base: InternalGas, "protobuf.decode.base", 1102],
+    [protobuf_decode_per_byte: InternalGasPerByte, "protobuf.decode.per_byte", 18],
+
     [from_bcs_from_bytes_base: InternalGas, "from_bcs.from_bytes.base", 1102],
     [from_bcs_from_bytes_unit: InternalGasPerByte, "from_bcs.from_bytes.unit", 18],
 
+    [base58_encode_base: InternalGas, "base58.encode.base", 1102],
+    [base58_encode_unit: InternalGasPerByte, "base58.encode.unit", 18],
+    [base58_encode_squared_unit: InternalGasPerByte, "base58.encode.squared_unit", 1],
+    [base58_decode_base: InternalGas, "base58.decode.base", 1102],
+    [base58_decode_unit: InternalGasPerByte, "base58.decode.unit", 18],
+    [base58_decode_squared_unit: InternalGasPerByte, "base58.decode.squared_unit", 1],
+
     [base64_encode_base: InternalGas, "base64.encode.base", 1102],
     [base64_encode_unit: InternalGasPerByte, "base64.encode.unit", 18],
     [base64_decode_base: InternalGas, "base64.decode.base", 1102],
@@ -55,11 +67,15 @@ crate::macros::define_gas_parameters!(
     [crypto_ed25519_per_msg_hashing_base: InternalGasPerArg, "crypto.ed25519.per_msg_hashing_base", 11910],
     [crypto_ed25519_per_msg_byte_hashing: InternalGasPerByte, "crypto.ed25519.per_msg_byte_hashing", 220],
 
+    [crypto_multi_ed25519_base: InternalGas, "crypto.multi_ed25519.base", 551],
+
     [crypto_secp256k1_base: InternalGas, "crypto.secp256k1.base", 551],
     [crypto_secp256k1_per_sig_verify: InternalGasPerArg, "crypto.secp256k1.per_sig_verify", 981492],
     [crypto_secp256k1_per_ecdsa_recover: InternalGasPerArg, "crypto.secp256k1.per_ecdsa_recover", 5918360],
     [crypto_secp256k1_per_pubkey_deserialize: InternalGasPerArg, "crypto.secp256k1.per_pubkey_deserialize", 139688],
     [crypto_secp256k1_per_sig_deserialize: InternalGasPerArg, "crypto.secp256k1.per_sig_deserialize", 1378],
+    [crypto_secp256k1_per_xonly_pubkey_deserialize: InternalGasPerArg, "crypto.secp256k1.per_xonly_pubkey_deserialize", 139688],
+    [crypto_secp256k1_per_schnorr_verify: InternalGasPerArg, "crypto.secp256k1.per_schnorr_verify", 981492],
 
     // Note(Gas): These are storage operations so the values should not be multiplied.
     [event_emit_base: InternalGas, "event.emit.base", 20006],
@@ -68,6 +84,9 @@ crate::macros::define_gas_parameters!(
     [keccak_keccak256_base: InternalGas, "keccak.keccak256.base", 14704],
     [keccak_keccak256_per_byte: InternalGasPerByte, "keccak.keccak256.per_byte", 165],
 
+    [eip712_hash_struct_base: InternalGas, "eip712.hash_struct.base", 1102],
+    [eip712_hash_struct_per_byte: InternalGasPerByte, "eip712.hash_struct.per_byte", 18],
+
     [object_exists_at_base: InternalGas, "object.exists_at.base", 919],
     [object_exists_at_per_byte_loaded: InternalGasPerByte, "object.exists_at.per_byte_loaded", 183],
     [object_exists_at_per_item_loaded: InternalGas, "object.exists_at.per_item_loaded", 1470],
@@ -79,6 +98,13 @@ crate::macros::define_gas_parameters!(
     [transaction_context_senders_base: InternalGas, "transaction_context.senders.base", 735],
     [transaction_context_senders_per_address: InternalGasPerArg, "transaction_context.senders.per_address", 18],
     [transaction_context_fee_payer_base: InternalGas, "transaction_context.fee_payer.base", 735],
+    [transaction_context_get_transaction_index_base: InternalGas, "transaction_context.get_transaction_index.base", 735],
+    [transaction_context_get_message_index_base: InternalGas, "transaction_context.get_message_index.base", 735],
+    [transaction_context_gas_price_base: InternalGas, "transaction_context.gas_price.base", 735],
+    [transaction_context_remaining_gas_base: InternalGas, "transaction_context.remaining_gas.base", 735],
+
+    [randomness_next_32_bytes_base: InternalGas, "randomness.next_32_bytes.base", 1102],
+    [randomness_is_unbiasable_base: InternalGas, "randomness.is_unbiasable.base", 735],
 
     // Note(Gas): These are SDK gas cost, so use `SCALING` factor
     [staking_delegate_base: InternalGas, "staking.delegate.base", 50_000 * SCALING],
@@ -89,12 +115,19 @@ crate::macros::define_gas_parameters!(
     [staking_share_to_amount_per_byte: InternalGasPerByte, "staking.share_to_amount.per_byte", 18],
     [staking_amount_to_share_base: InternalGas, "staking.amount_to_share.base", 100 * SCALING],
     [staking_amount_to_share_per_byte: InternalGasPerByte, "staking.amount_to_share.per_byte", 18],
+    [staking_redelegate_base: InternalGas, "staking.redelegate.base", 80_000 * SCALING],
+    [staking_redelegate_per_byte: InternalGasPerByte, "staking.redelegate.per_byte", 18],
+    [staking_unbonding_entries_base: InternalGas, "staking.unbonding_entries.base", 200 * SCALING],
+    [staking_unbonding_entries_per_entry: InternalGasPerArg, "staking.unbonding_entries.per_entry", 10 * SCALING],
+    [staking_unbonding_entries_per_byte: InternalGasPerByte, "staking.unbonding_entries.per_byte", 18],
 
     // Note(Gas): These are SDK gas cost, so use `SCALING` factor
     // These functions will consume gas after move execution finished,
     // so don't need to charge a lot here.
     [cosmos_stargate_base: InternalGas, "cosmos.stargate.base", 1000 * SCALING],
     [cosmos_stargate_per_byte: InternalGasPerByte, "cosmos.stargate.per_byte", 18],
+    [cosmos_dispatch_base: InternalGas, "cosmos.dispatch.base", 1000 * SCALING],
+    [cosmos_dispatch_per_byte: InternalGasPerByte, "cosmos.dispatch.per_byte", 18],
 
     [query_custom_base: InternalGas, "query.custom.base", 100 * SCALING],
     [query_custom_per_byte: InternalGasPerByte, "query.custom.per_byte", 18],
@@ -104,8 +137,12 @@ crate::macros::define_gas_parameters!(
     // Note(Gas): These are SDK gas cost, so use `SCALING` factor
     [block_get_block_info_base_cost: InternalGas, "block.get_block_info.base", 100 * SCALING],
     [block_get_chain_id_base_cost: InternalGas, "block.get_chain_id.base", 100 * SCALING],
+    [block_get_block_hash_base_cost: InternalGas, "block.get_block_hash.base", 100 * SCALING],
+    [block_get_proposer_base_cost: InternalGas, "block.get_proposer.base", 100 * SCALING],
     [oracle_get_price_base_cost: InternalGas, "oracle.get_prices.base_cost", 1500 * SCALING],
     [oracle_get_price_per_byte: InternalGasPerByte, "oracle.get_prices.per_byte", 18],
+    [oracle_get_prices_per_pair: InternalGasPerArg, "oracle.get_prices.per_pair", 1000 * SCALING],
+    [oracle_get_historical_price_base_cost: InternalGas, "oracle.get_historical_price.base_cost", 2000 * SCALING],
 
     [string_utils_format_base: InternalGas, "string_utils.format.base", 1102],
     [string_utils_format_per_byte: InternalGasPerByte, "string_utils.format.per_byte", 3],
@@ -126,6 +163,27 @@ crate::macros::define_gas_parameters!(
     [biguint_mul_per_byte: InternalGasPerByte, "biguint.mul.per_byte", 3],
     [biguint_div_base: InternalGas, "biguint.div.base", 588],
     [biguint_div_per_byte: InternalGasPerByte, "biguint.div.per_byte", 3],
+    [biguint_mod_base: InternalGas, "biguint.mod.base", 588],
+    [biguint_mod_per_byte: InternalGasPerByte, "biguint.mod.per_byte", 3],
+    [biguint_pow_base: InternalGas, "biguint.pow.base", 882],
+    [biguint_pow_per_byte: InternalGasPerByte, "biguint.pow.per_byte", 5],
+    [biguint_pow_per_byte_squared: InternalGasPerByte, "biguint.pow.per_byte_squared", 1],
+    [biguint_modpow_base: InternalGas, "biguint.modpow.base", 1470],
+    [biguint_modpow_per_byte: InternalGasPerByte, "biguint.modpow.per_byte", 5],
+    [biguint_modpow_per_byte_squared: InternalGasPerByte, "biguint.modpow.per_byte_squared", 2],
+    [biguint_sqrt_base: InternalGas, "biguint.sqrt.base", 588],
+    [biguint_sqrt_per_byte: InternalGasPerByte, "biguint.sqrt.per_byte", 3],
+    [biguint_sqrt_per_byte_squared: InternalGasPerByte, "biguint.sqrt.per_byte_squared", 1],
+    [biguint_shl_base: InternalGas, "biguint.shl.base", 588],
+    [biguint_shl_per_byte: InternalGasPerByte, "biguint.shl.per_byte", 3],
+    [biguint_shr_base: InternalGas, "biguint.shr.base", 588],
+    [biguint_shr_per_byte: InternalGasPerByte, "biguint.shr.per_byte", 3],
+    [biguint_and_base: InternalGas, "biguint.and.base", 588],
+    [biguint_and_per_byte: InternalGasPerByte, "biguint.and.per_byte", 3],
+    [biguint_or_base: InternalGas, "biguint.or.base", 588],
+    [biguint_or_per_byte: InternalGasPerByte, "biguint.or.per_byte", 3],
+    [biguint_xor_base: InternalGas, "biguint.xor.base", 588],
+    [biguint_xor_per_byte: InternalGasPerByte, "biguint.xor.per_byte", 3],
     [biguint_new_base: InternalGas, "biguint.new.base", 441],
     [biguint_cast_base: InternalGas, "biguint.cast.base", 441],
     [biguint_cast_per_byte: InternalGasPerByte, "biguint.cast.per_byte", 3],
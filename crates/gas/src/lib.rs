@@ -24,6 +24,7 @@ mod instr;
 mod meter;
 mod misc;
 mod move_stdlib;
+pub mod query;
 mod storage;
 pub mod table;
 mod traits;
@@ -43,6 +43,7 @@ pub struct NativeGasParameters {
     pub move_stdlib: crate::move_stdlib::MoveStdlibGasParameters,
     pub initia_stdlib: crate::initia_stdlib::InitiaStdlibGasParameters,
     pub table: crate::table::TableGasParameters,
+    pub query: crate::query::QueryGasParameters,
 }
 
 impl FromOnChainGasSchedule for NativeGasParameters {
@@ -51,6 +52,7 @@ impl FromOnChainGasSchedule for NativeGasParameters {
             move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
             initia_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
             table: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            query: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
         })
     }
 }
@@ -60,6 +62,7 @@ impl ToOnChainGasSchedule for NativeGasParameters {
         let mut entries = self.move_stdlib.to_on_chain_gas_schedule();
         entries.extend(self.initia_stdlib.to_on_chain_gas_schedule());
         entries.extend(self.table.to_on_chain_gas_schedule());
+        entries.extend(self.query.to_on_chain_gas_schedule());
         entries
     }
 }
@@ -70,6 +73,7 @@ impl NativeGasParameters {
             move_stdlib: crate::move_stdlib::MoveStdlibGasParameters::zeros(),
             initia_stdlib: crate::initia_stdlib::InitiaStdlibGasParameters::zeros(),
             table: crate::table::TableGasParameters::zeros(),
+            query: crate::query::QueryGasParameters::zeros(),
         }
     }
 }
@@ -80,6 +84,7 @@ impl InitialGasSchedule for NativeGasParameters {
             move_stdlib: InitialGasSchedule::initial(),
             initia_stdlib: InitialGasSchedule::initial(),
             table: InitialGasSchedule::initial(),
+            query: InitialGasSchedule::initial(),
         }
     }
 }
@@ -201,6 +206,12 @@ impl InitiaGasMeter {
             .to_unit_round_down_with_params(&self.gas_params.txn)
     }
 
+    /// Return whether the gas limit is large enough to execute an entry function
+    /// annotated with `#[randomness]`.
+    pub fn allows_randomness(&self) -> bool {
+        self.gas_limit >= self.gas_params.txn.randomness_min_gas_limit
+    }
+
     #[inline]
     fn charge(&mut self, amount: InternalGas) -> PartialVMResult<()> {
         // copy the value for error message
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use move_core_types::gas_algebra::InternalGas;
+
+use crate::traits::{FromOnChainGasSchedule, InitialGasSchedule, ToOnChainGasSchedule};
+
+const QUERY_GAS_OVERRIDE_PREFIX: &str = "initia.query.stargate.override.";
+
+/// Fixed gas costs of stargate query paths, charged instead of the gas reported by the host.
+///
+/// Unlike the other gas parameters, the entries are not known in advance, so every on-chain
+/// gas schedule entry named `initia.query.stargate.override.<path>` is collected, e.g.
+/// `initia.query.stargate.override./cosmos.bank.v1beta1.Query/Balance`.
+#[derive(Debug, Clone, Default)]
+pub struct QueryGasParameters {
+    pub stargate_overrides: BTreeMap<String, InternalGas>,
+}
+
+impl QueryGasParameters {
+    pub fn zeros() -> Self {
+        Self::default()
+    }
+
+    /// Returns the fixed gas cost of the given stargate query path, if any.
+    pub fn stargate_override(&self, path: &str) -> Option<InternalGas> {
+        self.stargate_overrides.get(path).cloned()
+    }
+}
+
+impl FromOnChainGasSchedule for QueryGasParameters {
+    fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Result<Self, String> {
+        let stargate_overrides = gas_schedule
+            .range(QUERY_GAS_OVERRIDE_PREFIX.to_string()..)
+            .take_while(|(name, _)| name.starts_with(QUERY_GAS_OVERRIDE_PREFIX))
+            .map(|(name, gas)| {
+                (
+                    name[QUERY_GAS_OVERRIDE_PREFIX.len()..].to_string(),
+                    InternalGas::new(*gas),
+                )
+            })
+            .collect();
+
+        Ok(Self { stargate_overrides })
+    }
+}
+
+impl ToOnChainGasSchedule for QueryGasParameters {
+    fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
+        self.stargate_overrides
+            .iter()
+            .map(|(path, gas)| {
+                (
+                    format!("{}{}", QUERY_GAS_OVERRIDE_PREFIX, path),
+                    (*gas).into(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl InitialGasSchedule for QueryGasParameters {
+    fn initial() -> Self {
+        Self::default()
+    }
+}
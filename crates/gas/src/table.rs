@@ -33,4 +33,15 @@ crate::macros::define_gas_parameters!(TableGasParameters,
     [prepare_box_per_byte_serialized: InternalGasPerByte, "prepare_box.per_byte_serialized", 36],
 
     [next_box_base: InternalGas, "next_box.base", 4411],
+
+    // for keys only iterators
+    [prepare_key_box_base: InternalGas, "prepare_key_box.base", 4411],
+    [prepare_key_box_per_byte_serialized: InternalGasPerByte, "prepare_key_box.per_byte_serialized", 36],
+
+    [next_key_box_base: InternalGas, "next_key_box.base", 367],
+
+    // for range removal
+    [remove_range_box_base: InternalGas, "remove_range_box.base", 4411],
+    [remove_range_box_per_item_sorted: InternalGasPerArg, "remove_range_box.per_item_sorted", 367],
+    [remove_range_box_per_item_removed: InternalGasPerArg, "remove_range_box.per_item_removed", 4411],
 ]);
@@ -59,6 +59,14 @@ crate::macros::define_gas_parameters!(
             max_total_dependency_size: NumBytes,
             "max_total_dependency_size",
             1024 * 1024 * 12 / 10, // 1.2 MB
+        ],
+        // The minimum gas limit required to execute an entry function annotated with `#[randomness]`.
+        // Prevents undergasing attacks, where the gas limit is set so that only the unfavorable
+        // outcomes run out of gas.
+        [
+            randomness_min_gas_limit: InternalGas,
+            "randomness_min_gas_limit",
+            250_000 * SCALING // 250_000 SDK gas
         ]
     ]
 );
@@ -0,0 +1,226 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    value::{MoveFieldLayout, MoveStructLayout, MoveTypeLayout, MoveVariantLayout},
+};
+
+use initia_move_json::deserialize_json_to_value;
+
+#[derive(Arbitrary, Debug)]
+enum Layout {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Vector(Box<Layout>),
+    String,
+    Option(Box<Layout>),
+    FixedPoint32,
+    FixedPoint64,
+    BigUint,
+    BigDecimal,
+    JSONValue,
+    JSONObject,
+    Struct(Vec<Layout>),
+    Enum(Vec<Vec<Layout>>),
+    // special struct names with arbitrary fields to exercise malformed layouts.
+    Malformed(u8, Vec<Layout>),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    layout: Layout,
+    json: Vec<u8>,
+}
+
+const SPECIAL_STRUCTS: &[(&str, &str)] = &[
+    ("string", "String"),
+    ("option", "Option"),
+    ("fixed_point32", "FixedPoint32"),
+    ("fixed_point64", "FixedPoint64"),
+    ("biguint", "BigUint"),
+    ("bigdecimal", "BigDecimal"),
+    ("json", "JSONValue"),
+    ("json", "JSONObject"),
+];
+
+fn module_id() -> ModuleId {
+    ModuleId::new(AccountAddress::TWO, Identifier::new("test").unwrap())
+}
+
+fn ident(s: &str) -> Identifier {
+    Identifier::new(s).unwrap()
+}
+
+fn field(name: &str, layout: MoveTypeLayout) -> MoveFieldLayout {
+    MoveFieldLayout {
+        name: ident(name),
+        layout,
+    }
+}
+
+fn struct_layout(
+    address: AccountAddress,
+    module: &str,
+    name: &str,
+    type_args: Vec<TypeTag>,
+    fields: Vec<MoveFieldLayout>,
+) -> (MoveTypeLayout, TypeTag) {
+    let tag = StructTag {
+        address,
+        module: ident(module),
+        name: ident(name),
+        type_args,
+    };
+    (
+        MoveTypeLayout::Struct(MoveStructLayout::with_types(tag.clone(), fields)),
+        TypeTag::Struct(Box::new(tag)),
+    )
+}
+
+fn fields(layouts: &[Layout]) -> (Vec<MoveFieldLayout>, Vec<TypeTag>) {
+    layouts
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| {
+            let (layout, tag) = convert(l);
+            (field(&format!("f{}", idx), layout), tag)
+        })
+        .unzip()
+}
+
+fn convert(layout: &Layout) -> (MoveTypeLayout, TypeTag) {
+    let bytes = || MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
+    match layout {
+        Layout::Bool => (MoveTypeLayout::Bool, TypeTag::Bool),
+        Layout::U8 => (MoveTypeLayout::U8, TypeTag::U8),
+        Layout::U16 => (MoveTypeLayout::U16, TypeTag::U16),
+        Layout::U32 => (MoveTypeLayout::U32, TypeTag::U32),
+        Layout::U64 => (MoveTypeLayout::U64, TypeTag::U64),
+        Layout::U128 => (MoveTypeLayout::U128, TypeTag::U128),
+        Layout::U256 => (MoveTypeLayout::U256, TypeTag::U256),
+        Layout::Address => (MoveTypeLayout::Address, TypeTag::Address),
+        Layout::Vector(elem) => {
+            let (layout, tag) = convert(elem);
+            (
+                MoveTypeLayout::Vector(Box::new(layout)),
+                TypeTag::Vector(Box::new(tag)),
+            )
+        }
+        Layout::String => struct_layout(
+            AccountAddress::ONE,
+            "string",
+            "String",
+            vec![],
+            vec![field("bytes", bytes())],
+        ),
+        Layout::Option(elem) => {
+            let (layout, tag) = convert(elem);
+            struct_layout(
+                AccountAddress::ONE,
+                "option",
+                "Option",
+                vec![tag],
+                vec![field("vec", MoveTypeLayout::Vector(Box::new(layout)))],
+            )
+        }
+        Layout::FixedPoint32 => struct_layout(
+            AccountAddress::ONE,
+            "fixed_point32",
+            "FixedPoint32",
+            vec![],
+            vec![field("value", MoveTypeLayout::U64)],
+        ),
+        Layout::FixedPoint64 => struct_layout(
+            AccountAddress::ONE,
+            "fixed_point64",
+            "FixedPoint64",
+            vec![],
+            vec![field("value", MoveTypeLayout::U128)],
+        ),
+        Layout::BigUint => struct_layout(
+            AccountAddress::ONE,
+            "biguint",
+            "BigUint",
+            vec![],
+            vec![field("value", bytes())],
+        ),
+        Layout::BigDecimal => {
+            let (inner, _) = convert(&Layout::BigUint);
+            struct_layout(
+                AccountAddress::ONE,
+                "bigdecimal",
+                "BigDecimal",
+                vec![],
+                vec![field("scaled", inner)],
+            )
+        }
+        Layout::JSONValue => struct_layout(
+            AccountAddress::ONE,
+            "json",
+            "JSONValue",
+            vec![],
+            vec![field("value", bytes())],
+        ),
+        Layout::JSONObject => {
+            let (elem, _) = struct_layout(
+                AccountAddress::ONE,
+                "json",
+                "Element",
+                vec![],
+                vec![field("key", bytes()), field("value", bytes())],
+            );
+            struct_layout(
+                AccountAddress::ONE,
+                "json",
+                "JSONObject",
+                vec![],
+                vec![field("elems", MoveTypeLayout::Vector(Box::new(elem)))],
+            )
+        }
+        Layout::Struct(layouts) => {
+            let (fields, _) = fields(layouts);
+            struct_layout(AccountAddress::TWO, "test", "S", vec![], fields)
+        }
+        Layout::Enum(variants) => {
+            let variants = variants
+                .iter()
+                .enumerate()
+                .map(|(idx, layouts)| MoveVariantLayout {
+                    name: ident(&format!("V{}", idx)),
+                    fields: fields(layouts).0,
+                })
+                .collect();
+            (
+                MoveTypeLayout::Struct(MoveStructLayout::WithVariants(variants)),
+                TypeTag::Struct(Box::new(StructTag {
+                    address: AccountAddress::TWO,
+                    module: ident("test"),
+                    name: ident("E"),
+                    type_args: vec![],
+                })),
+            )
+        }
+        Layout::Malformed(idx, layouts) => {
+            let (module, name) = SPECIAL_STRUCTS[*idx as usize % SPECIAL_STRUCTS.len()];
+            let (fields, type_args) = fields(layouts);
+            struct_layout(AccountAddress::ONE, module, name, type_args, fields)
+        }
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let (layout, ty_tag) = convert(&input.layout);
+
+    // any input must be rejected with an error rather than a panic.
+    let _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, &input.json);
+});
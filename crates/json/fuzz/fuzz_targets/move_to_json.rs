@@ -0,0 +1,109 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::StructTag,
+    u256::U256,
+    value::{MoveStruct, MoveValue},
+};
+
+use initia_move_json::serialize_move_value_to_json_value;
+
+#[derive(Arbitrary, Debug)]
+enum Value {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    U256(u128),
+    Address([u8; AccountAddress::LENGTH]),
+    Signer([u8; AccountAddress::LENGTH]),
+    Vector(Vec<Value>),
+    Runtime(Vec<Value>),
+    RuntimeVariant(u16, Vec<Value>),
+    WithFields(Vec<Value>),
+    WithVariantFields(u16, Vec<Value>),
+    // struct types that get a special json representation, with arbitrary fields
+    // to exercise malformed values.
+    WithTypes(u8, Vec<Value>),
+}
+
+const STRUCTS: &[(&str, &str)] = &[
+    ("string", "String"),
+    ("option", "Option"),
+    ("object", "Object"),
+    ("fixed_point32", "FixedPoint32"),
+    ("fixed_point64", "FixedPoint64"),
+    ("biguint", "BigUint"),
+    ("bigdecimal", "BigDecimal"),
+    ("json", "JSONValue"),
+    ("json", "JSONObject"),
+    ("test", "S"),
+];
+
+fn ident(s: &str) -> Identifier {
+    Identifier::new(s).unwrap()
+}
+
+fn fields(values: &[Value]) -> Vec<(Identifier, MoveValue)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, v)| (ident(&format!("f{}", idx)), convert(v)))
+        .collect()
+}
+
+fn convert(value: &Value) -> MoveValue {
+    match value {
+        Value::Bool(v) => MoveValue::Bool(*v),
+        Value::U8(v) => MoveValue::U8(*v),
+        Value::U16(v) => MoveValue::U16(*v),
+        Value::U32(v) => MoveValue::U32(*v),
+        Value::U64(v) => MoveValue::U64(*v),
+        Value::U128(v) => MoveValue::U128(*v),
+        Value::U256(v) => MoveValue::U256(U256::from(*v)),
+        Value::Address(v) => MoveValue::Address(AccountAddress::new(*v)),
+        Value::Signer(v) => MoveValue::Signer(AccountAddress::new(*v)),
+        Value::Vector(vs) => MoveValue::Vector(vs.iter().map(convert).collect()),
+        Value::Runtime(vs) => {
+            MoveValue::Struct(MoveStruct::Runtime(vs.iter().map(convert).collect()))
+        }
+        Value::RuntimeVariant(tag, vs) => MoveValue::Struct(MoveStruct::RuntimeVariant(
+            *tag,
+            vs.iter().map(convert).collect(),
+        )),
+        Value::WithFields(vs) => MoveValue::Struct(MoveStruct::WithFields(fields(vs))),
+        Value::WithVariantFields(tag, vs) => MoveValue::Struct(MoveStruct::WithVariantFields(
+            ident(&format!("V{}", tag)),
+            *tag,
+            fields(vs),
+        )),
+        Value::WithTypes(idx, vs) => {
+            let (module, name) = STRUCTS[*idx as usize % STRUCTS.len()];
+            let address = if module == "test" {
+                AccountAddress::TWO
+            } else {
+                AccountAddress::ONE
+            };
+            MoveValue::Struct(MoveStruct::WithTypes {
+                _type_: StructTag {
+                    address,
+                    module: ident(module),
+                    name: ident(name),
+                    type_args: vec![],
+                },
+                _fields: fields(vs),
+            })
+        }
+    }
+}
+
+fuzz_target!(|value: Value| {
+    // any value must be rejected with an error rather than a panic.
+    let _ = serialize_move_value_to_json_value(&convert(&value));
+});
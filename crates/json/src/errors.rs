@@ -1,6 +1,10 @@
-use move_binary_format::errors::{Location, PartialVMError, VMError};
+use std::fmt;
+
+use move_binary_format::errors::{Location, PartialVMError, VMError, VMResult};
 use move_core_types::vm_status::StatusCode;
+use serde_json::{Map, Value as JSONValue};
 
+#[cfg(test)]
 pub(crate) fn deserialization_error() -> VMError {
     PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT).finish(Location::Undefined)
 }
@@ -10,3 +14,105 @@ pub(crate) fn deserialization_error_with_msg<T: ToString>(msg: T) -> VMError {
         .with_message(msg.to_string())
         .finish(Location::Undefined)
 }
+
+pub(crate) fn serialization_error_with_msg<T: ToString>(msg: T) -> VMError {
+    PartialVMError::new(StatusCode::VALUE_SERIALIZATION_ERROR)
+        .with_message(msg.to_string())
+        .finish(Location::Undefined)
+}
+
+/// Location of the value being converted, rendered as `args[2].inner.amount`.
+///
+/// Paths are built on the stack while descending into a value, so no allocation
+/// happens unless an error is reported.
+#[derive(Clone, Copy)]
+pub(crate) enum JsonPath<'a> {
+    Root(&'a str),
+    Field(&'a JsonPath<'a>, &'a str),
+    Index(&'a JsonPath<'a>, usize),
+}
+
+impl<'a> JsonPath<'a> {
+    pub(crate) fn field(&'a self, name: &'a str) -> JsonPath<'a> {
+        JsonPath::Field(self, name)
+    }
+
+    pub(crate) fn index(&'a self, idx: usize) -> JsonPath<'a> {
+        JsonPath::Index(self, idx)
+    }
+
+    /// Deserialization error reported at this path.
+    pub(crate) fn error<T: ToString>(&self, msg: T) -> VMError {
+        deserialization_error_with_msg(format!("{}: {}", self, msg.to_string()))
+    }
+
+    /// Serialization error reported at this path.
+    pub(crate) fn serialization_error<T: ToString>(&self, msg: T) -> VMError {
+        serialization_error_with_msg(format!("{}: {}", self, msg.to_string()))
+    }
+
+    pub(crate) fn expect_str<'v>(&self, json_val: &'v JSONValue) -> VMResult<&'v str> {
+        json_val
+            .as_str()
+            .ok_or_else(|| self.error(format!("expected string, got {}", json_kind(json_val))))
+    }
+
+    pub(crate) fn expect_array(&self, json_val: JSONValue) -> VMResult<Vec<JSONValue>> {
+        match json_val {
+            JSONValue::Array(json_vals) => Ok(json_vals),
+            v => Err(self.error(format!("expected array, got {}", json_kind(&v)))),
+        }
+    }
+
+    pub(crate) fn expect_object(&self, json_val: JSONValue) -> VMResult<Map<String, JSONValue>> {
+        match json_val {
+            JSONValue::Object(json_obj) => Ok(json_obj),
+            v => Err(self.error(format!("expected object, got {}", json_kind(&v)))),
+        }
+    }
+}
+
+fn json_kind(json_val: &JSONValue) -> &'static str {
+    match json_val {
+        JSONValue::Null => "null",
+        JSONValue::Bool(_) => "bool",
+        JSONValue::Number(_) => "number",
+        JSONValue::String(_) => "string",
+        JSONValue::Array(_) => "array",
+        JSONValue::Object(_) => "object",
+    }
+}
+
+impl fmt::Display for JsonPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonPath::Root(name) => write!(f, "{}", name),
+            JsonPath::Field(parent, name) => write!(f, "{}.{}", parent, name),
+            JsonPath::Index(parent, idx) => write!(f, "{}[{}]", parent, idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_path_display() {
+        let root = JsonPath::Root("args[2]");
+        let inner = root.field("inner");
+        let amounts = inner.field("amounts");
+        let amount = amounts.index(1);
+        assert_eq!(amount.to_string(), "args[2].inner.amounts[1]");
+
+        let err = amount.error("expected string");
+        assert_eq!(
+            err.major_status(),
+            StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+        );
+        assert_eq!(
+            err.message().unwrap(),
+            "args[2].inner.amounts[1]: expected string"
+        );
+    }
+}
@@ -12,9 +12,9 @@ use move_binary_format::errors::{Location, VMResult};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
-    language_storage::{StructTag, TypeTag},
+    language_storage::{ModuleId, StructTag, TypeTag},
     u256::U256,
-    value::MoveValue,
+    value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue},
 };
 use move_vm_types::{
     loaded_data::runtime_types::Type::{self, *},
@@ -22,28 +22,42 @@ use move_vm_types::{
 };
 use serde_json::Value as JSONValue;
 
-use crate::errors::{deserialization_error, deserialization_error_with_msg};
+use crate::{
+    errors::{deserialization_error_with_msg, JsonPath},
+    variant::split_variant_json,
+};
 
 // deserialize json argument to JSONValue and convert to MoveValue,
 // and then do bcs serialization.
+//
+// `callee` is the module of the function receiving the argument; only enums
+// declared in that module can be constructed. `path` names the argument in
+// error messages, e.g. `args[2]`.
 pub fn deserialize_json_args<S: StateView>(
     code_storage: &InitiaStorage<S>,
     struct_resolver: &impl StructResolver,
+    callee: Option<&ModuleId>,
+    path: &str,
     ty: &Type,
     arg: &[u8],
 ) -> VMResult<Vec<u8>> {
+    let path = JsonPath::Root(path);
+
     const MAX_NUM_BYTES: usize = 1_000_000;
     if arg.len() > MAX_NUM_BYTES {
-        return Err(deserialization_error_with_msg(format!(
-            "maximum limit of {} bytes exceeded",
-            MAX_NUM_BYTES
-        )));
+        return Err(path.error(format!("maximum limit of {} bytes exceeded", MAX_NUM_BYTES)));
     }
-    let json_val: JSONValue =
-        serde_json::from_slice(arg).map_err(deserialization_error_with_msg)?;
-
-    let move_val =
-        convert_json_value_to_move_value(code_storage, struct_resolver, ty, json_val, 1)?;
+    let json_val: JSONValue = serde_json::from_slice(arg).map_err(|e| path.error(e))?;
+
+    let move_val = convert_json_value_to_move_value(
+        code_storage,
+        struct_resolver,
+        callee,
+        &path,
+        ty,
+        json_val,
+        1,
+    )?;
     bcs::to_bytes(&move_val).map_err(deserialization_error_with_msg)
 }
 
@@ -51,70 +65,55 @@ pub fn deserialize_json_args<S: StateView>(
 fn convert_json_value_to_move_value<S: StateView>(
     code_storage: &InitiaStorage<S>,
     struct_resolver: &impl StructResolver,
+    callee: Option<&ModuleId>,
+    path: &JsonPath,
     ty: &Type,
     json_val: JSONValue,
     depth: usize,
 ) -> VMResult<MoveValue> {
     const MAX_RECURSIVE_DEPTH: usize = 10;
     if depth > MAX_RECURSIVE_DEPTH {
-        return Err(deserialization_error_with_msg(format!(
+        return Err(path.error(format!(
             "maximum recursive depth of {} exceeded",
             MAX_RECURSIVE_DEPTH
         )));
     }
 
     Ok(match ty {
-        Address => MoveValue::Address(
-            serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?,
-        ),
-        Bool => MoveValue::Bool(
-            serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?,
-        ),
-        U8 => {
-            MoveValue::U8(serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?)
-        }
-        U16 => MoveValue::U16(
-            serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?,
-        ),
-        U32 => MoveValue::U32(
-            serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?,
-        ),
+        Address => MoveValue::Address(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        Bool => MoveValue::Bool(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U8 => MoveValue::U8(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U16 => MoveValue::U16(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U32 => MoveValue::U32(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
         U64 => MoveValue::U64(
-            json_val
-                .as_str()
-                .ok_or_else(deserialization_error)?
+            path.expect_str(&json_val)?
                 .parse()
-                .map_err(deserialization_error_with_msg)?,
+                .map_err(|e| path.error(e))?,
         ),
         U128 => MoveValue::U128(
-            json_val
-                .as_str()
-                .ok_or_else(deserialization_error)?
+            path.expect_str(&json_val)?
                 .parse()
-                .map_err(deserialization_error_with_msg)?,
-        ),
-        U256 => MoveValue::U256(
-            U256::from_str(json_val.as_str().ok_or_else(deserialization_error)?)
-                .map_err(deserialization_error_with_msg)?,
+                .map_err(|e| path.error(e))?,
         ),
+        U256 => {
+            MoveValue::U256(U256::from_str(path.expect_str(&json_val)?).map_err(|e| path.error(e))?)
+        }
         Vector(ty) => {
-            if &Type::U8 == ty.as_ref() && json_val.is_string() {
+            if let (U8, JSONValue::String(s)) = (ty.as_ref(), &json_val) {
                 return Ok(MoveValue::vector_u8(
-                    hex::decode(json_val.as_str().unwrap())
-                        .map_err(deserialization_error_with_msg)?,
+                    hex::decode(s).map_err(|e| path.error(e))?,
                 ));
             }
 
-            let json_vals = json_val
-                .as_array()
-                .ok_or_else(deserialization_error)?
-                .to_owned();
+            let json_vals = path.expect_array(json_val)?;
 
             let mut vec = Vec::new();
-            for json_val in json_vals {
+            for (idx, json_val) in json_vals.into_iter().enumerate() {
                 vec.push(convert_json_value_to_move_value(
                     code_storage,
                     struct_resolver,
+                    callee,
+                    &path.index(idx),
                     ty,
                     json_val,
                     depth + 1,
@@ -126,94 +125,48 @@ fn convert_json_value_to_move_value<S: StateView>(
             let st = struct_resolver
                 .get_struct_name(ty)
                 .map_err(|e| e.finish(Location::Undefined))?
-                .ok_or_else(deserialization_error)?;
-
-            let full_name = format!("{}::{}", st.0.short_str_lossless(), st.1);
-            match full_name.as_str() {
-                // JSONValue and JSONObject are not supported as entry function arguments
-                //
-                // "0x1::json::JSONValue" => MoveValue::vector_u8(
-                //     serde_json::to_vec(&json_val).map_err(deserialization_error_with_msg)?,
-                // ),
-                // "0x1::json::JSONObject" => {
-                //         let json_obj = json_val.as_object().ok_or_else(deserialization_error)?.to_owned();
-                //         let elems = json_obj.into_iter().map(|(k, v)| {
-                //             let key = k.into_bytes();
-                //             let value = serde_json::to_vec(&v).map_err(deserialization_error_with_msg)?;
-                //             Ok(MoveValue::Struct(MoveStruct::new(vec![MoveValue::vector_u8(key), MoveValue::vector_u8(value)])))
-                //         }).collect::<VMResult<Vec<_>>>()?;
-                //         MoveValue::Vector(elems)
-                //     },
-                "0x1::string::String" => MoveValue::vector_u8(
-                    json_val.as_str().ok_or_else(deserialization_error)?.into(),
-                ),
-                "0x1::fixed_point32::FixedPoint32" => {
-                    let s = json_val.as_str().ok_or_else(deserialization_error)?;
-                    let bigint = bigdecimal::BigDecimal::from_str(s)
-                        .map(|v| v * (1u64 << 32))
-                        .map_err(deserialization_error_with_msg)?
-                        .to_bigint()
-                        .ok_or_else(deserialization_error)?;
-
-                    MoveValue::U64(bigint.try_into().map_err(deserialization_error_with_msg)?)
-                }
-                "0x1::fixed_point64::FixedPoint64" => {
-                    let s = json_val.as_str().ok_or_else(deserialization_error)?;
-                    let bigint = BigDecimal::from_str(s)
-                        .map(|v| v * (1u128 << 64))
-                        .map_err(deserialization_error_with_msg)?
-                        .to_bigint()
-                        .ok_or_else(deserialization_error)?;
-
-                    MoveValue::U128(bigint.try_into().map_err(deserialization_error_with_msg)?)
-                }
-                "0x1::biguint::BigUint" => {
-                    let s = json_val.as_str().ok_or_else(deserialization_error)?;
-                    let biguint = BigUint::from_str(s).map_err(deserialization_error_with_msg)?;
-
-                    MoveValue::vector_u8(biguint.to_bytes_le())
-                }
-                "0x1::bigdecimal::BigDecimal" => {
-                    const DECIMAL_SCALE: u128 = 1_000_000_000_000_000_000;
-                    let s = json_val.as_str().ok_or_else(deserialization_error)?;
-                    let bigint = BigDecimal::from_str(s)
-                        .map(|v| v * DECIMAL_SCALE)
-                        .map_err(deserialization_error_with_msg)?
-                        .to_bigint()
-                        .ok_or_else(deserialization_error)?;
-                    if bigint.is_negative() {
-                        return Err(deserialization_error_with_msg(
-                            format!(
-                                "BigDecimal conversion error: negative values are not supported, received: {}",
-                                bigint
+                .ok_or_else(|| path.error("unknown struct type"))?;
+
+            // Enums can only be constructed as arguments of a function declared
+            // in the same module.
+            if callee.is_some_and(|callee| callee == &st.0) {
+                if let MoveTypeLayout::Struct(MoveStructLayout::WithVariants(variants)) =
+                    struct_resolver.type_to_fully_annotated_layout(ty)?
+                {
+                    let (tag, variant_name, fields) =
+                        split_variant_json(path, &variants, json_val)?;
+                    let variant_path = path.field(variant_name);
+                    let values = fields
+                        .into_iter()
+                        .map(|(field_name, layout, json_val)| {
+                            convert_json_value_to_move_value_with_layout(
+                                code_storage,
+                                struct_resolver,
+                                &variant_path.field(field_name),
+                                layout,
+                                json_val,
+                                depth + 1,
                             )
-                            .as_str(),
-                        ));
-                    }
-
-                    let (_, bytes) = bigint.to_bytes_le();
-                    MoveValue::vector_u8(bytes)
-                }
-                _ => {
-                    return Err(deserialization_error_with_msg(
-                        format!("unsupported type: {}", full_name).as_str(),
-                    ))
+                        })
+                        .collect::<VMResult<Vec<_>>>()?;
+                    return Ok(MoveValue::Struct(MoveStruct::RuntimeVariant(tag, values)));
                 }
             }
+
+            let full_name = format!("{}::{}", st.0.short_str_lossless(), st.1);
+            convert_json_value_to_builtin_struct(path, &full_name, json_val)?
         }
         StructInstantiation { ty_args, .. } => {
             if ty_args.len() != 1 {
-                return Err(deserialization_error_with_msg(
-                    "invalid type arguments length",
-                ));
+                return Err(path.error("invalid type arguments length"));
             }
 
             let st = struct_resolver
                 .get_struct_name(ty)
                 .map_err(|e| e.finish(Location::Undefined))?
-                .ok_or_else(deserialization_error)?;
+                .ok_or_else(|| path.error("unknown struct type"))?;
 
-            let ty = ty_args.first().ok_or_else(deserialization_error)?;
+            let ty = &ty_args[0];
             let full_name = format!("{}::{}", st.0.short_str_lossless(), st.1);
             match full_name.as_str() {
                 "0x1::option::Option" => {
@@ -224,39 +177,196 @@ fn convert_json_value_to_move_value<S: StateView>(
                     return Ok(MoveValue::Vector(vec![convert_json_value_to_move_value(
                         code_storage,
                         struct_resolver,
+                        callee,
+                        path,
                         ty,
                         json_val,
                         depth + 1,
                     )?]));
                 }
                 "0x1::object::Object" => {
-                    let addr = AccountAddress::from_hex_literal(
-                        json_val.as_str().ok_or_else(deserialization_error)?,
-                    )
-                    .map_err(deserialization_error_with_msg)?;
+                    let addr = AccountAddress::from_hex_literal(path.expect_str(&json_val)?)
+                        .map_err(|e| path.error(e))?;
 
                     // verify a object
                     // 1) address is holding object core resource
                     // 2) object is holding inner type resource
-                    verify_object(code_storage, struct_resolver, addr, ty)?;
+                    verify_object(code_storage, struct_resolver, path, addr, ty)?;
 
                     MoveValue::Address(addr)
                 }
-                _ => {
-                    return Err(deserialization_error_with_msg(
-                        format!("unsupported type: {}", full_name).as_str(),
-                    ))
-                }
+                _ => return Err(path.error(format!("unsupported type: {}", full_name))),
             }
         }
-        _ => unimplemented!("Deserialization for type {:?} not implemented", ty),
+        _ => return Err(path.error(format!("unsupported type: {:?}", ty))),
     })
 }
 
+// convert JSONValue to MoveValue for the non-generic framework structs which are
+// accepted as arguments.
+fn convert_json_value_to_builtin_struct(
+    path: &JsonPath,
+    full_name: &str,
+    json_val: JSONValue,
+) -> VMResult<MoveValue> {
+    Ok(match full_name {
+        // JSONValue and JSONObject are not supported as entry function arguments
+        //
+        // "0x1::json::JSONValue" => MoveValue::vector_u8(
+        //     serde_json::to_vec(&json_val).map_err(deserialization_error_with_msg)?,
+        // ),
+        // "0x1::json::JSONObject" => {
+        //         let json_obj = json_val.as_object().ok_or_else(deserialization_error)?.to_owned();
+        //         let elems = json_obj.into_iter().map(|(k, v)| {
+        //             let key = k.into_bytes();
+        //             let value = serde_json::to_vec(&v).map_err(deserialization_error_with_msg)?;
+        //             Ok(MoveValue::Struct(MoveStruct::new(vec![MoveValue::vector_u8(key), MoveValue::vector_u8(value)])))
+        //         }).collect::<VMResult<Vec<_>>>()?;
+        //         MoveValue::Vector(elems)
+        //     },
+        "0x1::string::String" => MoveValue::vector_u8(path.expect_str(&json_val)?.into()),
+        "0x1::fixed_point32::FixedPoint32" => {
+            let s = path.expect_str(&json_val)?;
+            let bigint = bigdecimal::BigDecimal::from_str(s)
+                .map(|v| v * (1u64 << 32))
+                .map_err(|e| path.error(e))?
+                .to_bigint()
+                .ok_or_else(|| path.error("invalid fixed point value"))?;
+
+            MoveValue::U64(bigint.try_into().map_err(|e| path.error(e))?)
+        }
+        "0x1::fixed_point64::FixedPoint64" => {
+            let s = path.expect_str(&json_val)?;
+            let bigint = BigDecimal::from_str(s)
+                .map(|v| v * (1u128 << 64))
+                .map_err(|e| path.error(e))?
+                .to_bigint()
+                .ok_or_else(|| path.error("invalid fixed point value"))?;
+
+            MoveValue::U128(bigint.try_into().map_err(|e| path.error(e))?)
+        }
+        "0x1::biguint::BigUint" => {
+            let s = path.expect_str(&json_val)?;
+            let biguint = BigUint::from_str(s).map_err(|e| path.error(e))?;
+
+            MoveValue::vector_u8(biguint.to_bytes_le())
+        }
+        "0x1::bigdecimal::BigDecimal" => {
+            const DECIMAL_SCALE: u128 = 1_000_000_000_000_000_000;
+            let s = path.expect_str(&json_val)?;
+            let bigint = BigDecimal::from_str(s)
+                .map(|v| v * DECIMAL_SCALE)
+                .map_err(|e| path.error(e))?
+                .to_bigint()
+                .ok_or_else(|| path.error("invalid decimal value"))?;
+            if bigint.is_negative() {
+                return Err(path.error(format!(
+                    "BigDecimal conversion error: negative values are not supported, received: {}",
+                    bigint
+                )));
+            }
+
+            let (_, bytes) = bigint.to_bytes_le();
+            MoveValue::vector_u8(bytes)
+        }
+        _ => return Err(path.error(format!("unsupported type: {}", full_name))),
+    })
+}
+
+// convert JSONValue to MoveValue following a fully annotated layout. This is used
+// for enum variant fields, whose types are only known through the enum layout.
+fn convert_json_value_to_move_value_with_layout<S: StateView>(
+    code_storage: &InitiaStorage<S>,
+    struct_resolver: &impl StructResolver,
+    path: &JsonPath,
+    layout: &MoveTypeLayout,
+    json_val: JSONValue,
+    depth: usize,
+) -> VMResult<MoveValue> {
+    const MAX_RECURSIVE_DEPTH: usize = 10;
+    if depth > MAX_RECURSIVE_DEPTH {
+        return Err(path.error(format!(
+            "maximum recursive depth of {} exceeded",
+            MAX_RECURSIVE_DEPTH
+        )));
+    }
+
+    let ty = match layout {
+        MoveTypeLayout::Bool => Bool,
+        MoveTypeLayout::U8 => U8,
+        MoveTypeLayout::U16 => U16,
+        MoveTypeLayout::U32 => U32,
+        MoveTypeLayout::U64 => U64,
+        MoveTypeLayout::U128 => U128,
+        MoveTypeLayout::U256 => U256,
+        MoveTypeLayout::Address => Address,
+        MoveTypeLayout::Vector(layout) => {
+            if let (MoveTypeLayout::U8, JSONValue::String(s)) = (layout.as_ref(), &json_val) {
+                return Ok(MoveValue::vector_u8(
+                    hex::decode(s).map_err(|e| path.error(e))?,
+                ));
+            }
+
+            let json_vals = path.expect_array(json_val)?;
+
+            let mut vec = Vec::new();
+            for (idx, json_val) in json_vals.into_iter().enumerate() {
+                vec.push(convert_json_value_to_move_value_with_layout(
+                    code_storage,
+                    struct_resolver,
+                    &path.index(idx),
+                    layout,
+                    json_val,
+                    depth + 1,
+                )?);
+            }
+            return Ok(MoveValue::Vector(vec));
+        }
+        MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, fields }) => {
+            let full_name = format!("{}::{}", type_.module_id().short_str_lossless(), type_.name);
+            if full_name == "0x1::option::Option" {
+                if json_val.is_null() {
+                    return Ok(MoveValue::Vector(vec![]));
+                }
+
+                let layout = match fields.first().map(|f| &f.layout) {
+                    Some(MoveTypeLayout::Vector(layout)) => layout,
+                    _ => return Err(path.error("invalid option layout")),
+                };
+                return Ok(MoveValue::Vector(vec![
+                    convert_json_value_to_move_value_with_layout(
+                        code_storage,
+                        struct_resolver,
+                        path,
+                        layout,
+                        json_val,
+                        depth + 1,
+                    )?,
+                ]));
+            }
+
+            return convert_json_value_to_builtin_struct(path, &full_name, json_val);
+        }
+        _ => return Err(path.error(format!("unsupported type: {:?}", layout))),
+    };
+
+    // primitive values are converted the same way as the runtime types.
+    convert_json_value_to_move_value(
+        code_storage,
+        struct_resolver,
+        None,
+        path,
+        &ty,
+        json_val,
+        depth,
+    )
+}
+
 // verify object address is holding object core and inner type resources.
 fn verify_object<S: StateView>(
     code_storage: &InitiaStorage<S>,
     struct_resolver: &impl StructResolver,
+    path: &JsonPath,
     addr: AccountAddress,
     inner_type: &Type,
 ) -> VMResult<()> {
@@ -274,33 +384,31 @@ fn verify_object<S: StateView>(
             &[],
             None,
         )
-        .map_err(deserialization_error_with_msg)?
+        .map_err(|e| path.error(e))?
         .0
         .is_none()
     {
-        return Err(deserialization_error_with_msg("invalid object address"));
+        return Err(path.error("invalid object address"));
     }
 
     // verify a object hold inner type
     let inner_type_tag = struct_resolver
         .type_to_type_tag(inner_type)
-        .map_err(deserialization_error_with_msg)?;
+        .map_err(|e| path.error(e))?;
 
     let inner_type_st = if let TypeTag::Struct(inner_type_st) = inner_type_tag {
         inner_type_st
     } else {
-        return Err(deserialization_error_with_msg("invalid object inner type"));
+        return Err(path.error("invalid object inner type"));
     };
 
     if resource_resolver
         .get_resource_bytes_with_metadata_and_layout(&addr, &inner_type_st, &[], None)
-        .map_err(deserialization_error_with_msg)?
+        .map_err(|e| path.error(e))?
         .0
         .is_none()
     {
-        return Err(deserialization_error_with_msg(
-            "object does not hold the type",
-        ));
+        return Err(path.error("object does not hold the type"));
     }
     Ok(())
 }
@@ -325,6 +433,7 @@ mod json_arg_testing {
         ident_str,
         identifier::Identifier,
         language_storage::{ModuleId, StructTag},
+        value::{MoveFieldLayout, MoveVariantLayout},
         vm_status::StatusCode,
     };
     use move_vm_runtime::RuntimeEnvironment;
@@ -334,12 +443,14 @@ mod json_arg_testing {
     };
 
     use super::*;
+    use crate::errors::deserialization_error;
 
     const TEST_CACHE_CAPACITY: usize = 100;
 
     struct MockState {
         pub map: BTreeMap<Vec<u8>, Vec<u8>>,
         pub structs: BTreeMap<StructNameIndex, (ModuleId, Identifier)>,
+        pub layouts: BTreeMap<StructNameIndex, MoveTypeLayout>,
     }
 
     impl StateView for MockState {
@@ -381,12 +492,26 @@ mod json_arg_testing {
                 }
             }
         }
+
+        fn type_to_fully_annotated_layout(&self, ty: &Type) -> VMResult<MoveTypeLayout> {
+            match ty {
+                Type::Struct { idx, .. } => self
+                    .layouts
+                    .get(idx)
+                    .cloned()
+                    .ok_or_else(deserialization_error),
+                _ => {
+                    Err(PartialVMError::new(StatusCode::TYPE_MISMATCH).finish(Location::Undefined))
+                }
+            }
+        }
     }
 
     fn mock_state() -> MockState {
         MockState {
             map: BTreeMap::new(),
             structs: BTreeMap::new(),
+            layouts: BTreeMap::new(),
         }
     }
 
@@ -405,17 +530,18 @@ mod json_arg_testing {
 
         let ty = Type::U8;
         let arg = b"123";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(result, bcs::to_bytes(&123u8).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -433,17 +559,18 @@ mod json_arg_testing {
 
         let ty = Type::U16;
         let arg = b"123";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(result, bcs::to_bytes(&123u16).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -461,16 +588,17 @@ mod json_arg_testing {
 
         let ty = Type::U32;
         let arg = b"123";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&123u32).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -488,16 +616,17 @@ mod json_arg_testing {
 
         let ty = Type::U64;
         let arg = b"\"123\"";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&123u64).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -515,16 +644,17 @@ mod json_arg_testing {
 
         let ty = Type::U128;
         let arg = b"\"123\"";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&123u128).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -542,16 +672,17 @@ mod json_arg_testing {
 
         let ty = Type::U256;
         let arg = b"\"123\"";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&U256::from(123u128)).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -569,7 +700,8 @@ mod json_arg_testing {
 
         let ty = Type::Bool;
         let arg = b"true";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&true).unwrap());
     }
 
@@ -588,7 +720,8 @@ mod json_arg_testing {
 
         let ty = Type::Address;
         let arg = b"\"0x1\"";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(
             result,
             bcs::to_bytes(&"0x1".parse::<AccountAddress>().unwrap()).unwrap()
@@ -610,12 +743,14 @@ mod json_arg_testing {
 
         let ty = Type::Vector(triomphe::Arc::new(Type::U8));
         let arg = b"[0, 1, 2, 3]";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&vec![0u8, 1u8, 2u8, 3u8]).unwrap());
 
         // hex string to vector<u8>
         let arg = b"\"00010203\"";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes(&vec![0u8, 1u8, 2u8, 3u8]).unwrap());
     }
 
@@ -634,7 +769,8 @@ mod json_arg_testing {
 
         let ty = Type::Vector(triomphe::Arc::new(Type::Address));
         let arg = b"[\"0x1\", \"0x2\"]";
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(
             result,
             bcs::to_bytes(&vec![
@@ -645,8 +781,14 @@ mod json_arg_testing {
         );
 
         // invalid inner address
-        let arg = b"[\"0xgg\"]";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        let arg = b"[\"0x1\", \"0xgg\"]";
+        let err = deserialize_json_args(&code_storage, &mock_state, None, "args[2]", &ty, arg)
+            .unwrap_err();
+        assert_eq!(
+            err.major_status(),
+            StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+        );
+        assert!(err.message().unwrap().starts_with("args[2][1]: "));
     }
 
     pub fn for_test(
@@ -695,7 +837,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(result, bcs::to_bytes("hello").unwrap());
     }
 
@@ -774,13 +917,20 @@ mod json_arg_testing {
         );
 
         // valid object address
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg);
+        let result = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg);
         assert_eq!(result.unwrap(), bcs::to_bytes(&obj_addr).unwrap());
 
         // invalid object address
         let wrong_object_addr_arg = b"\"0x1\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, wrong_object_addr_arg)
-            .unwrap_err();
+        _ = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            None,
+            "arg",
+            &ty,
+            wrong_object_addr_arg,
+        )
+        .unwrap_err();
 
         // invalid inner type
         let wrong_inner_ty = Type::StructInstantiation {
@@ -795,11 +945,19 @@ mod json_arg_testing {
                 ability: AbilityInfo::struct_(AbilitySet::singleton(Ability::Key)),
             }]),
         };
-        _ = deserialize_json_args(&code_storage, &mock_state, &wrong_inner_ty, arg).unwrap_err();
+        _ = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            None,
+            "arg",
+            &wrong_inner_ty,
+            arg,
+        )
+        .unwrap_err();
 
         // invalid address
         let arg = b"\"0xgg\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -828,7 +986,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(
             result,
             bcs::to_bytes(&vec!["0x1".parse::<AccountAddress>().unwrap()]).unwrap()
@@ -836,7 +995,7 @@ mod json_arg_testing {
 
         // invalid inner value
         let arg = b"\"0xgg\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -865,7 +1024,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
         assert_eq!(
             result,
             bcs::to_bytes::<Vec<AccountAddress>>(&vec![]).unwrap()
@@ -897,7 +1057,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(
             result,
@@ -906,7 +1067,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -929,7 +1090,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(
             result,
@@ -938,7 +1100,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -959,7 +1121,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(
             result,
@@ -968,7 +1131,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-1234567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 
     #[test]
@@ -990,7 +1153,8 @@ mod json_arg_testing {
             module_cache,
         );
 
-        let result = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap();
+        let result =
+            deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap();
 
         assert_eq!(
             result,
@@ -1004,6 +1168,157 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_args(&code_storage, &mock_state, &ty, arg).unwrap_err();
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
+    }
+
+    #[test]
+    fn test_deserialize_json_args_enum() {
+        let mut mock_state = mock_state();
+        let struct_index_map = StructNameIndexMap::empty();
+        let (ty, idx, (module_id, identifier)) = for_test(&struct_index_map, "shape", "Shape");
+        mock_state
+            .structs
+            .insert(idx, (module_id.clone(), identifier));
+        mock_state.layouts.insert(
+            idx,
+            MoveTypeLayout::Struct(MoveStructLayout::WithVariants(vec![
+                MoveVariantLayout {
+                    name: ident_str!("Circle").into(),
+                    fields: vec![MoveFieldLayout {
+                        name: ident_str!("radius").into(),
+                        layout: MoveTypeLayout::U64,
+                    }],
+                },
+                MoveVariantLayout {
+                    name: ident_str!("Named").into(),
+                    fields: vec![MoveFieldLayout {
+                        name: ident_str!("name").into(),
+                        layout: MoveTypeLayout::Struct(MoveStructLayout::with_types(
+                            StructTag {
+                                address: AccountAddress::ONE,
+                                module: ident_str!("string").into(),
+                                name: ident_str!("String").into(),
+                                type_args: vec![],
+                            },
+                            vec![MoveFieldLayout {
+                                name: ident_str!("bytes").into(),
+                                layout: MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+                            }],
+                        )),
+                    }],
+                },
+            ])),
+        );
+
+        let runtime_environment = RuntimeEnvironment::new(vec![]);
+        let script_cache = InitiaScriptCache::new(TEST_CACHE_CAPACITY);
+        let module_cache = InitiaModuleCache::new(TEST_CACHE_CAPACITY);
+        let code_storage = InitiaStorage::new(
+            &mock_state,
+            &runtime_environment,
+            script_cache,
+            module_cache,
+        );
+
+        // variant name with named fields
+        let arg = b"{\"Circle\": {\"radius\": \"42\"}}";
+        let result = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap();
+        let mut expected = vec![0u8];
+        expected.extend(bcs::to_bytes(&42u64).unwrap());
+        assert_eq!(result, expected);
+
+        // variant index with positional fields
+        let arg = b"{\"1\": [\"hello\"]}";
+        let result = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap();
+        let mut expected = vec![1u8];
+        expected.extend(bcs::to_bytes("hello").unwrap());
+        assert_eq!(result, expected);
+
+        // vector of enums
+        let vec_ty = Type::Vector(triomphe::Arc::new(ty.clone()));
+        let arg = b"[{\"Circle\": {\"radius\": \"1\"}}, {\"Named\": {\"name\": \"a\"}}]";
+        let result = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &vec_ty,
+            arg,
+        )
+        .unwrap();
+        let mut expected = vec![2u8, 0u8];
+        expected.extend(bcs::to_bytes(&1u64).unwrap());
+        expected.push(1u8);
+        expected.extend(bcs::to_bytes("a").unwrap());
+        assert_eq!(result, expected);
+
+        // unknown variant
+        let arg = b"{\"Square\": {\"side\": \"1\"}}";
+        _ = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap_err();
+
+        // missing field
+        let arg = b"{\"Circle\": {}}";
+        _ = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap_err();
+
+        // invalid field value
+        let arg = b"{\"Circle\": {\"radius\": \"-1\"}}";
+        let err = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap_err();
+        assert!(err.message().unwrap().starts_with("arg.Circle.radius: "));
+
+        // enum declared in another module than the callee
+        let arg = b"{\"Circle\": {\"radius\": \"42\"}}";
+        let other_module_id = ModuleId::new(AccountAddress::ONE, ident_str!("other").into());
+        _ = deserialize_json_args(
+            &code_storage,
+            &mock_state,
+            Some(&other_module_id),
+            "arg",
+            &ty,
+            arg,
+        )
+        .unwrap_err();
+
+        // no callee module
+        _ = deserialize_json_args(&code_storage, &mock_state, None, "arg", &ty, arg).unwrap_err();
     }
 }
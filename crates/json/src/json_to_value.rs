@@ -6,7 +6,7 @@ use bigdecimal::{
 };
 use move_binary_format::errors::VMResult;
 use move_core_types::{
-    language_storage::ModuleId,
+    language_storage::{ModuleId, TypeTag},
     u256::U256,
     value::{
         MoveStructLayout::*,
@@ -17,93 +17,83 @@ use move_vm_types::values::{Struct, Value};
 
 use serde_json::Value as JSONValue;
 
-use crate::errors::{deserialization_error, deserialization_error_with_msg};
+use crate::{errors::JsonPath, variant::split_variant_json};
 
-// deserialize json argument to JSONValue and convert to MoveValue,
-// and then do bcs serialization.
+// deserialize json argument to JSONValue and convert to Value.
+//
+// `ty_tag` is the type tag of `layout`; it is required to check the declaring
+// module of enums, as their annotated layout does not carry the type.
 pub fn deserialize_json_to_value(
     caller: &ModuleId,
+    ty_tag: &TypeTag,
     layout: &MoveTypeLayout,
     arg: &[u8],
 ) -> VMResult<Value> {
+    let path = JsonPath::Root("value");
+
     const MAX_NUM_BYTES: usize = 1_000_000;
     if arg.len() > MAX_NUM_BYTES {
-        return Err(deserialization_error_with_msg(format!(
-            "maximum limit of {} bytes exceeded",
-            MAX_NUM_BYTES
-        )));
+        return Err(path.error(format!("maximum limit of {} bytes exceeded", MAX_NUM_BYTES)));
     }
 
-    let json_val: JSONValue =
-        serde_json::from_slice(arg).map_err(deserialization_error_with_msg)?;
+    let json_val: JSONValue = serde_json::from_slice(arg).map_err(|e| path.error(e))?;
 
-    convert_json_value_to_value(caller, layout, json_val, 1)
+    convert_json_value_to_value(caller, &path, Some(ty_tag), layout, json_val, 1)
 }
 
 // convert JSONValue to Value.
-pub fn convert_json_value_to_value(
+fn convert_json_value_to_value(
     caller: &ModuleId,
+    path: &JsonPath,
+    ty_tag: Option<&TypeTag>,
     layout: &MoveTypeLayout,
     json_val: JSONValue,
     depth: usize,
 ) -> VMResult<Value> {
     const MAX_RECURSIVE_DEPTH: usize = 10;
     if depth > MAX_RECURSIVE_DEPTH {
-        return Err(deserialization_error_with_msg(format!(
+        return Err(path.error(format!(
             "maximum recursive depth of {} exceeded",
             MAX_RECURSIVE_DEPTH
         )));
     }
 
     Ok(match layout {
-        Address => Value::address(
-            serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?,
-        ),
-        Bool => {
-            Value::bool(serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?)
-        }
-        U8 => Value::u8(serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?),
-        U16 => {
-            Value::u16(serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?)
-        }
-        U32 => {
-            Value::u32(serde_json::from_value(json_val).map_err(deserialization_error_with_msg)?)
-        }
+        Address => Value::address(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        Bool => Value::bool(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U8 => Value::u8(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U16 => Value::u16(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
+        U32 => Value::u32(serde_json::from_value(json_val).map_err(|e| path.error(e))?),
         U64 => Value::u64(
-            json_val
-                .as_str()
-                .ok_or_else(deserialization_error)?
+            path.expect_str(&json_val)?
                 .parse()
-                .map_err(deserialization_error_with_msg)?,
+                .map_err(|e| path.error(e))?,
         ),
         U128 => Value::u128(
-            json_val
-                .as_str()
-                .ok_or_else(deserialization_error)?
+            path.expect_str(&json_val)?
                 .parse()
-                .map_err(deserialization_error_with_msg)?,
-        ),
-        U256 => Value::u256(
-            U256::from_str(json_val.as_str().ok_or_else(deserialization_error)?)
-                .map_err(deserialization_error_with_msg)?,
+                .map_err(|e| path.error(e))?,
         ),
+        U256 => {
+            Value::u256(U256::from_str(path.expect_str(&json_val)?).map_err(|e| path.error(e))?)
+        }
         Vector(layout) => {
-            if &MoveTypeLayout::U8 == layout.as_ref() && json_val.is_string() {
-                return Ok(Value::vector_u8(
-                    hex::decode(json_val.as_str().unwrap())
-                        .map_err(deserialization_error_with_msg)?,
-                ));
+            if let (U8, JSONValue::String(s)) = (layout.as_ref(), &json_val) {
+                return Ok(Value::vector_u8(hex::decode(s).map_err(|e| path.error(e))?));
             }
 
-            let json_vals = json_val
-                .as_array()
-                .ok_or_else(deserialization_error)?
-                .to_owned();
+            let json_vals = path.expect_array(json_val)?;
+            let elem_ty_tag = match ty_tag {
+                Some(TypeTag::Vector(elem_ty_tag)) => Some(elem_ty_tag.as_ref()),
+                _ => None,
+            };
 
             let mut vec = Vec::new();
-            for json_val in json_vals {
+            for (idx, json_val) in json_vals.into_iter().enumerate() {
                 vec.push(convert_json_value_to_value(
                     caller,
+                    &path.index(idx),
+                    elem_ty_tag,
                     layout,
                     json_val,
                     depth + 1,
@@ -124,19 +114,16 @@ pub fn convert_json_value_to_value(
                     format!("{}::{}", type_.module_id().short_str_lossless(), type_.name);
                 match full_name.as_str() {
                     "0x1::json::JSONValue" => Value::struct_(Struct::pack(vec![Value::vector_u8(
-                        serde_json::to_vec(&json_val).map_err(deserialization_error_with_msg)?,
+                        serde_json::to_vec(&json_val).map_err(|e| path.error(e))?,
                     )])),
                     "0x1::json::JSONObject" => {
-                        let json_obj = json_val
-                            .as_object()
-                            .ok_or_else(deserialization_error)?
-                            .to_owned();
+                        let json_obj = path.expect_object(json_val)?;
                         let elems = json_obj
                             .into_iter()
                             .map(|(k, v)| {
+                                let value =
+                                    serde_json::to_vec(&v).map_err(|e| path.field(&k).error(e))?;
                                 let key = k.into_bytes();
-                                let value = serde_json::to_vec(&v)
-                                    .map_err(deserialization_error_with_msg)?;
                                 Ok(Value::struct_(Struct::pack(vec![
                                     Value::vector_u8(key),
                                     Value::vector_u8(value),
@@ -146,59 +133,51 @@ pub fn convert_json_value_to_value(
                         Value::struct_(Struct::pack(vec![Value::vector_for_testing_only(elems)]))
                     }
                     "0x1::string::String" => Value::struct_(Struct::pack(vec![Value::vector_u8(
-                        json_val
-                            .as_str()
-                            .ok_or_else(deserialization_error)?
-                            .as_bytes()
-                            .to_vec(),
+                        path.expect_str(&json_val)?.as_bytes().to_vec(),
                     )])),
                     "0x1::fixed_point32::FixedPoint32" => {
-                        let s = json_val.as_str().ok_or_else(deserialization_error)?;
+                        let s = path.expect_str(&json_val)?;
                         let bigint = bigdecimal::BigDecimal::from_str(s)
                             .map(|v| v * (1u64 << 32))
-                            .map_err(deserialization_error_with_msg)?
+                            .map_err(|e| path.error(e))?
                             .to_bigint()
-                            .ok_or_else(deserialization_error)?;
+                            .ok_or_else(|| path.error("invalid fixed point value"))?;
 
                         Value::struct_(Struct::pack(vec![Value::u64(
-                            bigint.try_into().map_err(deserialization_error_with_msg)?,
+                            bigint.try_into().map_err(|e| path.error(e))?,
                         )]))
                     }
                     "0x1::fixed_point64::FixedPoint64" => {
-                        let s = json_val.as_str().ok_or_else(deserialization_error)?;
+                        let s = path.expect_str(&json_val)?;
                         let bigint = BigDecimal::from_str(s)
                             .map(|v| v * (1u128 << 64))
-                            .map_err(deserialization_error_with_msg)?
+                            .map_err(|e| path.error(e))?
                             .to_bigint()
-                            .ok_or_else(deserialization_error)?;
+                            .ok_or_else(|| path.error("invalid fixed point value"))?;
 
                         Value::struct_(Struct::pack(vec![Value::u128(
-                            bigint.try_into().map_err(deserialization_error_with_msg)?,
+                            bigint.try_into().map_err(|e| path.error(e))?,
                         )]))
                     }
                     "0x1::biguint::BigUint" => {
-                        let s = json_val.as_str().ok_or_else(deserialization_error)?;
-                        let biguint =
-                            BigUint::from_str(s).map_err(deserialization_error_with_msg)?;
+                        let s = path.expect_str(&json_val)?;
+                        let biguint = BigUint::from_str(s).map_err(|e| path.error(e))?;
 
                         Value::struct_(Struct::pack(vec![Value::vector_u8(biguint.to_bytes_le())]))
                     }
                     "0x1::bigdecimal::BigDecimal" => {
                         const DECIMAL_SCALE: u128 = 1_000_000_000_000_000_000;
-                        let s = json_val.as_str().ok_or_else(deserialization_error)?;
+                        let s = path.expect_str(&json_val)?;
                         let bigint = BigDecimal::from_str(s)
                             .map(|v| v * DECIMAL_SCALE)
-                            .map_err(deserialization_error_with_msg)?
+                            .map_err(|e| path.error(e))?
                             .to_bigint()
-                            .ok_or_else(deserialization_error)?;
+                            .ok_or_else(|| path.error("invalid decimal value"))?;
                         if bigint.is_negative() {
-                            return Err(deserialization_error_with_msg(
-                                format!(
-                                    "failed to convert negative value {} to BigDecimal",
-                                    bigint
-                                )
-                                .as_str(),
-                            ));
+                            return Err(path.error(format!(
+                                "failed to convert negative value {} to BigDecimal",
+                                bigint
+                            )));
                         }
 
                         let (_, bytes) = bigint.to_bytes_le();
@@ -213,15 +192,16 @@ pub fn convert_json_value_to_value(
                             ])));
                         }
 
-                        let elem = fields.first().ok_or_else(deserialization_error)?;
-                        let elem_value = match &elem.layout {
-                            Vector(layout) => {
-                                convert_json_value_to_value(caller, layout, json_val, depth + 1)
-                            }
-                            _ => unimplemented!(
-                                "Deserialization for type {:?} not implemented",
-                                layout
+                        let elem_value = match fields.first().map(|f| &f.layout) {
+                            Some(Vector(layout)) => convert_json_value_to_value(
+                                caller,
+                                path,
+                                type_.type_args.first(),
+                                layout,
+                                json_val,
+                                depth + 1,
                             ),
+                            _ => Err(path.error("invalid option layout")),
                         }?;
 
                         Value::struct_(Struct::pack(vec![Value::vector_for_testing_only(vec![
@@ -230,22 +210,19 @@ pub fn convert_json_value_to_value(
                     }
                     _ => {
                         if !type_.type_args.is_empty() {
-                            return Err(deserialization_error_with_msg(
-                                "generic type not supported in json deserialization",
-                            ));
+                            return Err(
+                                path.error("generic type not supported in json deserialization")
+                            );
                         }
 
                         // Enforce that the struct creation is in the same module
                         if !type_.module_id().eq(caller) {
-                            return Err(deserialization_error_with_msg(
-                                "struct creation only allowed in the same module",
-                            ));
+                            return Err(
+                                path.error("struct creation only allowed in the same module")
+                            );
                         }
 
-                        let mut json_obj = json_val
-                            .as_object()
-                            .ok_or_else(deserialization_error)?
-                            .to_owned();
+                        let mut json_obj = path.expect_object(json_val)?;
                         let values = fields
                             .iter()
                             .map(|f| {
@@ -261,6 +238,8 @@ pub fn convert_json_value_to_value(
                                     .unwrap_or_else(|| JSONValue::Array(vec![]));
                                 convert_json_value_to_value(
                                     caller,
+                                    &path.field(field_name),
+                                    None,
                                     &f.layout,
                                     json_field_val,
                                     depth + 1,
@@ -271,11 +250,43 @@ pub fn convert_json_value_to_value(
                     }
                 }
             }
-            _ => {
-                unimplemented!("Deserialization for type {:?} not implemented", layout)
+            WithVariants(variants) => {
+                // The annotated layout of an enum does not carry its type, so the
+                // declaring module is taken from the type tag. Enums at positions
+                // where the type tag is unknown (e.g. struct fields) are rejected.
+                let st = match ty_tag {
+                    Some(TypeTag::Struct(st)) => st,
+                    _ => return Err(path.error("enum type is unknown at this position")),
+                };
+                if !st.type_args.is_empty() {
+                    return Err(path.error("generic type not supported in json deserialization"));
+                }
+
+                // Enforce that the enum creation is in the same module
+                if !st.module_id().eq(caller) {
+                    return Err(path.error("enum creation only allowed in the same module"));
+                }
+
+                let (tag, variant_name, fields) = split_variant_json(path, variants, json_val)?;
+                let variant_path = path.field(variant_name);
+                let values = fields
+                    .into_iter()
+                    .map(|(field_name, layout, json_val)| {
+                        convert_json_value_to_value(
+                            caller,
+                            &variant_path.field(field_name),
+                            None,
+                            layout,
+                            json_val,
+                            depth + 1,
+                        )
+                    })
+                    .collect::<VMResult<Vec<_>>>()?;
+                Value::struct_(Struct::pack_variant(tag, values))
             }
+            _ => return Err(path.error(format!("unsupported type: {:?}", layout))),
         },
-        _ => unimplemented!("Deserialization for type {:?} not implemented", layout),
+        _ => return Err(path.error(format!("unsupported type: {:?}", layout))),
     })
 }
 
@@ -290,7 +301,7 @@ mod json_arg_testing {
         account_address::AccountAddress,
         ident_str,
         language_storage::StructTag,
-        value::{MoveFieldLayout, MoveStructLayout},
+        value::{MoveFieldLayout, MoveStructLayout, MoveVariantLayout},
     };
     use move_vm_types::value_serde::ValueSerDeContext;
 
@@ -300,21 +311,40 @@ mod json_arg_testing {
         ModuleId::new(AccountAddress::TWO, ident_str!("test").into())
     }
 
+    fn type_tag(layout: &MoveTypeLayout) -> TypeTag {
+        match layout {
+            MoveTypeLayout::Bool => TypeTag::Bool,
+            MoveTypeLayout::U8 => TypeTag::U8,
+            MoveTypeLayout::U16 => TypeTag::U16,
+            MoveTypeLayout::U32 => TypeTag::U32,
+            MoveTypeLayout::U64 => TypeTag::U64,
+            MoveTypeLayout::U128 => TypeTag::U128,
+            MoveTypeLayout::U256 => TypeTag::U256,
+            MoveTypeLayout::Address => TypeTag::Address,
+            MoveTypeLayout::Vector(layout) => TypeTag::Vector(Box::new(type_tag(layout))),
+            MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, .. }) => {
+                TypeTag::Struct(Box::new(type_.clone()))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_deserialize_json_to_value_u8() {
         let layout = MoveTypeLayout::U8;
         let arg = b"123";
 
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u8(123)).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -322,16 +352,17 @@ mod json_arg_testing {
         let layout = MoveTypeLayout::U16;
         let arg = b"123";
 
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u16(123)).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -339,16 +370,17 @@ mod json_arg_testing {
         let layout = MoveTypeLayout::U32;
         let arg = b"123";
 
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u32(123)).unwrap());
 
         // invalid negative
         let arg = b"-123";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"123.4567";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -356,16 +388,17 @@ mod json_arg_testing {
         let layout = MoveTypeLayout::U64;
         let arg = b"\"123\"";
 
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u64(123)).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -373,39 +406,42 @@ mod json_arg_testing {
         let layout = MoveTypeLayout::U128;
         let arg = b"\"123\"";
 
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u128(123)).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
     fn test_deserialize_json_to_value_u256() {
         let layout = MoveTypeLayout::U256;
         let arg = b"\"123\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::u256(U256::from(123u128))).unwrap());
 
         // invalid negative
         let arg = b"\"-123\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // invalid decimal
         let arg = b"\"123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
     fn test_deserialize_json_to_value_bool() {
         let layout = MoveTypeLayout::Bool;
         let arg = b"true";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result.equals(&Value::bool(true)).unwrap());
     }
 
@@ -413,7 +449,8 @@ mod json_arg_testing {
     fn test_deserialize_json_to_value_address() {
         let layout = MoveTypeLayout::Address;
         let arg = b"\"0x1\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::address("0x1".parse::<AccountAddress>().unwrap()))
             .unwrap());
@@ -423,7 +460,8 @@ mod json_arg_testing {
     fn test_deserialize_json_to_value_vec_u8() {
         let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
         let arg = b"[0, 1, 2, 3]";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
 
         assert_eq!(
             ValueSerDeContext::new()
@@ -436,7 +474,8 @@ mod json_arg_testing {
 
         // hex string to vector<u8>
         let arg = b"\"00010203\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::vector_u8(vec![0u8, 1u8, 2u8, 3u8]))
             .unwrap());
@@ -446,7 +485,8 @@ mod json_arg_testing {
     fn test_deserialize_json_to_value_vec_address() {
         let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Address));
         let arg = b"[\"0x1\", \"0x2\"]";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
 
         assert_eq!(
             ValueSerDeContext::new()
@@ -465,7 +505,7 @@ mod json_arg_testing {
 
         // invalid inner address
         let arg = b"[\"0xgg\"]";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -484,7 +524,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"hello\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
 
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::vector_u8(
@@ -509,7 +550,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"0x1\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![
                 Value::vector_for_testing_only(vec![Value::address(
@@ -520,7 +562,7 @@ mod json_arg_testing {
 
         // invalid inner value
         let arg = b"\"0xgg\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -539,7 +581,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"null";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![
                 Value::vector_for_testing_only(vec![])
@@ -563,7 +606,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"123.4567\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::u64(
                 (1234567u64 << 32) / 10_000
@@ -572,7 +616,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -591,7 +635,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"123.4567\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::u128(
                 (1234567u128 << 64) / 10_000
@@ -600,7 +645,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -619,7 +664,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"1234567\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::vector_u8(
                 BigUint::from_u128(1234567u128).unwrap().to_bytes_le()
@@ -628,7 +674,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -647,7 +693,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"123.4567\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::struct_(
                 Struct::pack(vec![Value::vector_u8(
@@ -660,7 +707,7 @@ mod json_arg_testing {
 
         // invalid negative
         let arg = b"\"-123.4567\"";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
     }
 
     #[test]
@@ -679,7 +726,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"\"123\"";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::vector_u8(
                 b"\"123\"".to_vec()
@@ -722,7 +770,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"{\"key1\": \"value1\", \"key2\": \"value2\"}";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![
                 Value::vector_for_testing_only(vec![
@@ -755,7 +804,8 @@ mod json_arg_testing {
         ));
 
         let arg = b"{\"value\": \"0x1\"}";
-        let result = deserialize_json_to_value(&module_id(), &layout, arg).unwrap();
+        let result =
+            deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap();
         assert!(result
             .equals(&Value::struct_(Struct::pack(vec![Value::address(
                 AccountAddress::ONE,
@@ -777,7 +827,7 @@ mod json_arg_testing {
         ));
 
         let arg = b"{\"value\": \"0x1\"}";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
 
         // module permission violation
         let layout = MoveTypeLayout::Struct(MoveStructLayout::with_types(
@@ -794,6 +844,125 @@ mod json_arg_testing {
         ));
 
         let arg = b"{\"value\": \"0x1\"}";
-        _ = deserialize_json_to_value(&module_id(), &layout, arg).unwrap_err();
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&layout), &layout, arg).unwrap_err();
+    }
+
+    #[test]
+    fn test_deserialize_json_to_value_enum() {
+        let layout = MoveTypeLayout::Struct(MoveStructLayout::WithVariants(vec![
+            MoveVariantLayout {
+                name: ident_str!("Circle").into(),
+                fields: vec![MoveFieldLayout {
+                    name: ident_str!("radius").into(),
+                    layout: MoveTypeLayout::U64,
+                }],
+            },
+            MoveVariantLayout {
+                name: ident_str!("Rectangle").into(),
+                fields: vec![
+                    MoveFieldLayout {
+                        name: ident_str!("width").into(),
+                        layout: MoveTypeLayout::U64,
+                    },
+                    MoveFieldLayout {
+                        name: ident_str!("height").into(),
+                        layout: MoveTypeLayout::U64,
+                    },
+                ],
+            },
+        ]));
+        let shape_tag = |address| {
+            TypeTag::Struct(Box::new(StructTag {
+                address,
+                module: ident_str!("test").into(),
+                name: ident_str!("Shape").into(),
+                type_args: vec![],
+            }))
+        };
+        let ty_tag = shape_tag(AccountAddress::TWO);
+
+        // variant name with named fields
+        let arg = b"{\"Circle\": {\"radius\": \"42\"}}";
+        let result = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap();
+        assert!(result
+            .equals(&Value::struct_(Struct::pack_variant(
+                0,
+                vec![Value::u64(42)]
+            )))
+            .unwrap());
+
+        // variant index with positional fields
+        let arg = b"{\"1\": [\"3\", \"4\"]}";
+        let result = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap();
+        assert!(result
+            .equals(&Value::struct_(Struct::pack_variant(
+                1,
+                vec![Value::u64(3), Value::u64(4)]
+            )))
+            .unwrap());
+
+        // vector of enums
+        let vec_layout = MoveTypeLayout::Vector(Box::new(layout.clone()));
+        let vec_ty_tag = TypeTag::Vector(Box::new(ty_tag.clone()));
+        let arg = b"[{\"Circle\": {\"radius\": \"1\"}}, {\"Rectangle\": {\"width\": \"2\", \"height\": \"3\"}}]";
+        let result =
+            deserialize_json_to_value(&module_id(), &vec_ty_tag, &vec_layout, arg).unwrap();
+        assert!(result
+            .equals(&Value::vector_for_testing_only(vec![
+                Value::struct_(Struct::pack_variant(0, vec![Value::u64(1)])),
+                Value::struct_(Struct::pack_variant(1, vec![Value::u64(2), Value::u64(3)])),
+            ]))
+            .unwrap());
+
+        // missing field
+        let arg = b"{\"Rectangle\": {\"width\": \"2\"}}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // unknown field
+        let arg = b"{\"Circle\": {\"radius\": \"42\", \"width\": \"2\"}}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // unknown variant
+        let arg = b"{\"Triangle\": {}}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // invalid variant index
+        let arg = b"{\"2\": []}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // invalid number of positional fields
+        let arg = b"{\"1\": [\"3\"]}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // multiple variants
+        let arg = b"{\"Circle\": {\"radius\": \"42\"}, \"1\": [\"3\", \"4\"]}";
+        _ = deserialize_json_to_value(&module_id(), &ty_tag, &layout, arg).unwrap_err();
+
+        // module permission violation
+        let arg = b"{\"Circle\": {\"radius\": \"42\"}}";
+        _ = deserialize_json_to_value(
+            &module_id(),
+            &shape_tag(AccountAddress::THREE),
+            &layout,
+            arg,
+        )
+        .unwrap_err();
+
+        // enum type is unknown inside a struct field
+        let struct_layout = MoveTypeLayout::Struct(MoveStructLayout::with_types(
+            StructTag {
+                address: AccountAddress::TWO,
+                module: ident_str!("test").into(),
+                name: ident_str!("Holder").into(),
+                type_args: vec![],
+            },
+            vec![MoveFieldLayout {
+                name: ident_str!("shape").into(),
+                layout: layout.clone(),
+            }],
+        ));
+        let arg = b"{\"shape\": {\"Circle\": {\"radius\": \"42\"}}}";
+        _ = deserialize_json_to_value(&module_id(), &type_tag(&struct_layout), &struct_layout, arg)
+            .unwrap_err();
     }
 }
@@ -2,7 +2,12 @@ mod errors;
 mod json_to_move;
 mod json_to_value;
 mod move_to_json;
+mod variant;
 
+pub use initia_move_types::json_options::JsonOptions;
 pub use json_to_move::deserialize_json_args;
 pub use json_to_value::deserialize_json_to_value;
-pub use move_to_json::serialize_move_value_to_json_value;
+pub use move_to_json::{
+    serialize_move_value_to_json_value, serialize_move_value_to_json_value_with_options,
+    validate_json_options, MAX_DECIMAL_PRECISION,
+};
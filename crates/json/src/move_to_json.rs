@@ -1,22 +1,64 @@
+use bech32::{Bech32, Hrp};
 use bigdecimal::{num_bigint::BigUint, BigDecimal, FromPrimitive};
+use initia_move_types::json_options::JsonOptions;
 use move_binary_format::errors::{Location, PartialVMError, VMResult};
 use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
     language_storage::{StructTag, CORE_CODE_ADDRESS},
     value::{MoveStruct, MoveValue},
     vm_status::StatusCode,
 };
 use serde_json::{Map, Value as JSONValue};
 
-use crate::errors::deserialization_error_with_msg;
+use crate::errors::{serialization_error_with_msg, JsonPath};
+
+// largest integer a double can represent exactly; bigger numbers are kept as strings.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+// decimals have 18 fractional digits and fixed point numbers at most 20 meaningful ones;
+// larger precisions would only pad the output with zeros.
+pub const MAX_DECIMAL_PRECISION: u32 = 20;
 
 pub fn serialize_move_value_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
-    convert_move_value_to_json_value(val, 1)
+    serialize_move_value_to_json_value_with_options(val, &JsonOptions::default())
+}
+
+pub fn serialize_move_value_to_json_value_with_options(
+    val: &MoveValue,
+    options: &JsonOptions,
+) -> VMResult<JSONValue> {
+    validate_json_options(options)?;
+    convert_move_value_to_json_value(&JsonPath::Root("value"), val, options, 1)
 }
 
-fn convert_move_value_to_json_value(val: &MoveValue, depth: usize) -> VMResult<JSONValue> {
+/// Reject options whose decimal precision is above `MAX_DECIMAL_PRECISION` or whose
+/// address prefix is not a valid bech32 human readable part.
+pub fn validate_json_options(options: &JsonOptions) -> VMResult<()> {
+    if let Some(precision) = options.decimal_precision {
+        if precision > MAX_DECIMAL_PRECISION {
+            return Err(serialization_error_with_msg(format!(
+                "decimal precision {} exceeds the maximum of {}",
+                precision, MAX_DECIMAL_PRECISION
+            )));
+        }
+    }
+    if let Some(hrp) = &options.address_hrp {
+        Hrp::parse(hrp).map_err(serialization_error_with_msg)?;
+    }
+
+    Ok(())
+}
+
+fn convert_move_value_to_json_value(
+    path: &JsonPath,
+    val: &MoveValue,
+    options: &JsonOptions,
+    depth: usize,
+) -> VMResult<JSONValue> {
     const MAX_RECURSIVE_DEPTH: usize = 10;
     if depth > MAX_RECURSIVE_DEPTH {
-        return Err(deserialization_error_with_msg(format!(
+        return Err(path.error(format!(
             "maximum recursive depth of {} exceeded",
             MAX_RECURSIVE_DEPTH
         )));
@@ -24,45 +66,49 @@ fn convert_move_value_to_json_value(val: &MoveValue, depth: usize) -> VMResult<J
 
     match val {
         MoveValue::Vector(elems) => {
-            if !elems.is_empty() {
-                if let MoveValue::U8(_) = elems.first().unwrap() {
-                    let bytes = elems
-                        .iter()
-                        .map(|e| {
-                            if let MoveValue::U8(byte) = e {
-                                *byte
-                            } else {
-                                panic!("should not enter here");
-                            }
-                        })
-                        .collect::<Vec<u8>>();
-
-                    return Ok(JSONValue::String(hex::encode(bytes)));
-                }
+            if let Some(MoveValue::U8(_)) = elems.first() {
+                return Ok(JSONValue::String(hex::encode(bytes_from_move_value(
+                    path, val,
+                )?)));
             }
 
             Ok(JSONValue::Array(
                 elems
                     .iter()
-                    .map(|v| convert_move_value_to_json_value(v, depth + 1))
+                    .enumerate()
+                    .map(|(idx, v)| {
+                        convert_move_value_to_json_value(&path.index(idx), v, options, depth + 1)
+                    })
                     .collect::<VMResult<Vec<JSONValue>>>()?,
             ))
         }
         MoveValue::Struct(s) => match s {
             MoveStruct::Runtime(values) => {
                 let mut fields_array: Vec<JSONValue> = vec![];
-                for mv in values.iter() {
-                    fields_array.push(convert_move_value_to_json_value(mv, depth + 1)?);
+                for (idx, mv) in values.iter().enumerate() {
+                    fields_array.push(convert_move_value_to_json_value(
+                        &path.index(idx),
+                        mv,
+                        options,
+                        depth + 1,
+                    )?);
                 }
                 Ok(JSONValue::Array(fields_array))
             }
             MoveStruct::RuntimeVariant(idx, values) => {
+                let tag = idx.to_string();
+                let variant_path = path.field(&tag);
                 let mut fields_array: Vec<JSONValue> = vec![];
-                for mv in values.iter() {
-                    fields_array.push(convert_move_value_to_json_value(mv, depth + 1)?);
+                for (idx, mv) in values.iter().enumerate() {
+                    fields_array.push(convert_move_value_to_json_value(
+                        &variant_path.index(idx),
+                        mv,
+                        options,
+                        depth + 1,
+                    )?);
                 }
                 Ok(JSONValue::Object(
-                    std::iter::once((idx.to_string(), JSONValue::Array(fields_array))).collect(),
+                    std::iter::once((tag, JSONValue::Array(fields_array))).collect(),
                 ))
             }
             MoveStruct::WithFields(fields) => {
@@ -74,7 +120,12 @@ fn convert_move_value_to_json_value(val: &MoveValue, depth: usize) -> VMResult<J
 
                 let mut fields_map: Map<String, JSONValue> = Map::new();
                 for (id, mv) in fields.iter() {
-                    let value = convert_move_value_to_json_value(mv, depth + 1)?;
+                    let value = convert_move_value_to_json_value(
+                        &path.field(id.as_str()),
+                        mv,
+                        options,
+                        depth + 1,
+                    )?;
                     let _ = fields_map.insert(id.to_string(), value);
                 }
 
@@ -87,9 +138,15 @@ fn convert_move_value_to_json_value(val: &MoveValue, depth: usize) -> VMResult<J
                     return Ok(JSONValue::Object(Map::new()));
                 }
 
+                let variant_path = path.field(_id.as_str());
                 let mut fields_map: Map<String, JSONValue> = Map::new();
                 for (id, mv) in fields.iter() {
-                    let value = convert_move_value_to_json_value(mv, depth + 1)?;
+                    let value = convert_move_value_to_json_value(
+                        &variant_path.field(id.as_str()),
+                        mv,
+                        options,
+                        depth + 1,
+                    )?;
                     let _ = fields_map.insert(id.to_string(), value);
                 }
 
@@ -98,215 +155,312 @@ fn convert_move_value_to_json_value(val: &MoveValue, depth: usize) -> VMResult<J
                 ))
             }
             MoveStruct::WithTypes { _type_, _fields } => {
-                // The move compiler inserts a dummy field with the value of false
-                // for structs with no fields.
-                if _fields.len() == 1 && _fields[0].0.as_str() == "dummy_field" {
-                    return Ok(JSONValue::Object(Map::new()));
+                let value =
+                    convert_typed_struct_to_json_value(path, _type_, _fields, options, depth)?;
+                if !options.type_annotations {
+                    return Ok(value);
                 }
 
-                // check the struct type is string
-                // if yes, then convert move value to json string
-                // else, execute convert function recursively
-                if is_json_value(_type_) {
-                    convert_json_value_to_json_value(&_fields[0].1)
-                } else if is_json_object(_type_) {
-                    convert_json_object_to_json_value(&_fields[0].1)
-                } else if is_utf8_string(_type_) {
-                    convert_string_to_json_value(&_fields[0].1)
-                } else if is_biguint(_type_) {
-                    convert_biguint_to_json_value(&_fields[0].1)
-                } else if is_decimal(_type_) {
-                    convert_decimal_to_json_value(&_fields[0].1)
-                } else if is_option(_type_) {
-                    convert_option_to_json_value(&_fields[0].1, depth)
-                } else if is_object(_type_) {
-                    convert_object_to_json_value(&_fields[0].1)
-                } else if is_fixed_point(_type_) {
-                    convert_fixed_point_to_json_value(&_fields[0].1)
-                } else {
-                    let mut fields_map: Map<String, JSONValue> = Map::new();
-                    for (id, mv) in _fields.iter() {
-                        let field_name = match id.as_str() {
-                            "_type_" => "@type",
-                            "_move_" => "move",
-                            "_signer_" => "signer",
-                            v => v,
-                        };
-
-                        let value = convert_move_value_to_json_value(mv, depth + 1)?;
-                        let _ = fields_map.insert(field_name.to_string(), value);
-                    }
-
-                    Ok(JSONValue::Object(fields_map))
-                }
+                let mut annotated = Map::new();
+                annotated.insert(
+                    "type".to_string(),
+                    JSONValue::String(_type_.to_canonical_string()),
+                );
+                annotated.insert("value".to_string(), value);
+                Ok(JSONValue::Object(annotated))
             }
         },
         // convert huge numbers to string
-        MoveValue::U64(num) => Ok(JSONValue::String(num.to_string())),
-        MoveValue::U128(num) => Ok(JSONValue::String(num.to_string())),
-        MoveValue::U256(num) => Ok(JSONValue::String(num.to_string())),
-        MoveValue::Address(addr) => Ok(JSONValue::String(addr.to_hex_literal())),
+        MoveValue::U64(num) => Ok(convert_integer_to_json_value(
+            &num.to_le_bytes(),
+            num,
+            options,
+        )),
+        MoveValue::U128(num) => Ok(convert_integer_to_json_value(
+            &num.to_le_bytes(),
+            num,
+            options,
+        )),
+        MoveValue::U256(num) => Ok(convert_integer_to_json_value(
+            &num.to_le_bytes(),
+            num,
+            options,
+        )),
+        MoveValue::Address(addr) => convert_address_to_json_value(path, addr, options),
         MoveValue::Signer(_) => {
             Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).finish(Location::Undefined))
         }
-        _ => serde_json::to_value(val).map_err(|_| {
-            PartialVMError::new(StatusCode::VALUE_SERIALIZATION_ERROR).finish(Location::Undefined)
-        }),
+        _ => serde_json::to_value(val).map_err(|e| path.serialization_error(e)),
     }
 }
 
-fn bytes_from_move_value(val: &MoveValue) -> VMResult<Vec<u8>> {
+fn convert_typed_struct_to_json_value(
+    path: &JsonPath,
+    type_: &StructTag,
+    fields: &[(Identifier, MoveValue)],
+    options: &JsonOptions,
+    depth: usize,
+) -> VMResult<JSONValue> {
+    // The move compiler inserts a dummy field with the value of false
+    // for structs with no fields.
+    if fields.len() == 1 && fields[0].0.as_str() == "dummy_field" {
+        return Ok(JSONValue::Object(Map::new()));
+    }
+
+    // check the struct type is string
+    // if yes, then convert move value to json string
+    // else, execute convert function recursively
+    if is_json_value(type_) {
+        convert_json_value_to_json_value(path, first_field(path, fields)?)
+    } else if is_json_object(type_) {
+        convert_json_object_to_json_value(path, first_field(path, fields)?)
+    } else if is_utf8_string(type_) {
+        convert_string_to_json_value(path, first_field(path, fields)?)
+    } else if is_biguint(type_) {
+        convert_biguint_to_json_value(path, first_field(path, fields)?)
+    } else if is_decimal(type_) {
+        convert_decimal_to_json_value(path, first_field(path, fields)?, options)
+    } else if is_option(type_) {
+        convert_option_to_json_value(path, first_field(path, fields)?, options, depth)
+    } else if is_object(type_) {
+        convert_object_to_json_value(path, type_, first_field(path, fields)?, options)
+    } else if is_fixed_point(type_) {
+        convert_fixed_point_to_json_value(path, first_field(path, fields)?, options)
+    } else {
+        let mut fields_map: Map<String, JSONValue> = Map::new();
+        for (id, mv) in fields.iter() {
+            let field_name = match id.as_str() {
+                "_type_" => "@type",
+                "_move_" => "move",
+                "_signer_" => "signer",
+                v => v,
+            };
+
+            let value =
+                convert_move_value_to_json_value(&path.field(field_name), mv, options, depth + 1)?;
+            let _ = fields_map.insert(field_name.to_string(), value);
+        }
+
+        Ok(JSONValue::Object(fields_map))
+    }
+}
+
+fn first_field<'v>(
+    path: &JsonPath,
+    fields: &'v [(Identifier, MoveValue)],
+) -> VMResult<&'v MoveValue> {
+    fields
+        .first()
+        .map(|(_, v)| v)
+        .ok_or_else(|| path.serialization_error("missing struct field"))
+}
+
+fn bytes_from_move_value(path: &JsonPath, val: &MoveValue) -> VMResult<Vec<u8>> {
     match val {
         MoveValue::Vector(bytes_val) => bytes_val
             .iter()
             .map(|byte_val| match byte_val {
                 MoveValue::U8(byte) => Ok(*byte),
-                _ => Err(deserialization_error_with_msg("Expected U8 in vector")),
+                _ => Err(path.error("Expected U8 in vector")),
             })
             .collect::<VMResult<Vec<u8>>>(),
-        _ => Err(deserialization_error_with_msg("Expected vector of U8s")),
+        _ => Err(path.error("Expected vector of U8s")),
+    }
+}
+
+// `le_bytes` is the little endian encoding of `num`, which is at least 8 bytes long.
+fn convert_integer_to_json_value(
+    le_bytes: &[u8],
+    num: &impl ToString,
+    options: &JsonOptions,
+) -> JSONValue {
+    if options.numbers_as_json && le_bytes[8..].iter().all(|b| *b == 0) {
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&le_bytes[..8]);
+        let low = u64::from_le_bytes(low);
+        if low <= MAX_SAFE_INTEGER {
+            return JSONValue::from(low);
+        }
     }
+
+    JSONValue::String(num.to_string())
+}
+
+fn convert_address_to_json_value(
+    path: &JsonPath,
+    addr: &AccountAddress,
+    options: &JsonOptions,
+) -> VMResult<JSONValue> {
+    let hrp = match &options.address_hrp {
+        Some(hrp) => Hrp::parse(hrp).map_err(|e| path.serialization_error(e))?,
+        None => return Ok(JSONValue::String(addr.to_hex_literal())),
+    };
+
+    // cosmos accounts are 20 bytes long and stored left padded with zeros.
+    let bytes = addr.as_ref();
+    let data = if bytes[..12].iter().all(|b| *b == 0) {
+        &bytes[12..]
+    } else {
+        bytes
+    };
+    bech32::encode::<Bech32>(hrp, data)
+        .map(JSONValue::String)
+        .map_err(|e| path.serialization_error(e))
 }
 
-fn convert_json_value_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
-    let bz = bytes_from_move_value(val)?;
-    serde_json::from_slice(&bz).map_err(deserialization_error_with_msg)
+fn format_decimal(num: BigDecimal, options: &JsonOptions) -> String {
+    match options.decimal_precision {
+        Some(precision) => num.with_scale(i64::from(precision)).to_string(),
+        None => num.normalized().to_string(),
+    }
 }
 
-fn convert_json_object_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
+fn convert_json_value_to_json_value(path: &JsonPath, val: &MoveValue) -> VMResult<JSONValue> {
+    let bz = bytes_from_move_value(path, val)?;
+    serde_json::from_slice(&bz).map_err(|e| path.error(e))
+}
+
+fn convert_json_object_to_json_value(path: &JsonPath, val: &MoveValue) -> VMResult<JSONValue> {
     let elems = match val {
         MoveValue::Vector(elems) => elems
             .iter()
-            .map(|elem| match elem {
-                MoveValue::Struct(
-                    MoveStruct::WithTypes { _type_: _, _fields }
-                    | MoveStruct::WithFields(_fields)
-                    | MoveStruct::WithVariantFields(_, _, _fields),
-                ) => {
-                    let key =
-                        std::str::from_utf8(&bytes_from_move_value(&_fields.first().unwrap().1)?)
-                            .map_err(deserialization_error_with_msg)?
-                            .to_string();
-                    let val = convert_json_value_to_json_value(&_fields.get(1).unwrap().1)?;
-
-                    Ok((key, val))
+            .enumerate()
+            .map(|(idx, elem)| {
+                let elem_path = path.index(idx);
+                match elem {
+                    MoveValue::Struct(
+                        MoveStruct::WithTypes { _type_: _, _fields }
+                        | MoveStruct::WithFields(_fields)
+                        | MoveStruct::WithVariantFields(_, _, _fields),
+                    ) if _fields.len() == 2 => {
+                        let key =
+                            std::str::from_utf8(&bytes_from_move_value(&elem_path, &_fields[0].1)?)
+                                .map_err(|e| elem_path.error(e))?
+                                .to_string();
+                        let val = convert_json_value_to_json_value(&elem_path, &_fields[1].1)?;
+
+                        Ok((key, val))
+                    }
+                    _ => Err(elem_path.serialization_error("expected json object element")),
                 }
-                _ => unreachable!(),
             })
             .collect::<VMResult<Map<_, _>>>()?,
-        _ => unreachable!(),
+        _ => return Err(path.serialization_error("expected vector of json object elements")),
     };
 
     Ok(JSONValue::Object(elems))
 }
 
-fn convert_string_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
-    let bz: Vec<u8> = match val {
-        MoveValue::Vector(bytes_val) => bytes_val
-            .iter()
-            .map(|byte_val| match byte_val {
-                MoveValue::U8(byte) => *byte,
-                _ => unreachable!(),
-            })
-            .collect::<Vec<u8>>(),
-        _ => unreachable!(),
-    };
-
+fn convert_string_to_json_value(path: &JsonPath, val: &MoveValue) -> VMResult<JSONValue> {
+    let bz = bytes_from_move_value(path, val)?;
     let json_val = std::str::from_utf8(&bz).map_err(|_| {
-        PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).finish(Location::Undefined)
+        PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+            .with_message(format!("{}: invalid utf8 string", path))
+            .finish(Location::Undefined)
     })?;
     Ok(JSONValue::String(json_val.to_string()))
 }
 
-fn convert_biguint_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
-    Ok(JSONValue::String(match val {
-        MoveValue::Vector(bytes_val) => {
-            let bytes_le = bytes_val
-                .iter()
-                .map(|byte_val| match byte_val {
-                    MoveValue::U8(byte) => *byte,
-                    _ => unreachable!(),
-                })
-                .collect::<Vec<u8>>();
-
-            BigUint::from_bytes_le(&bytes_le).to_string()
-        }
-        _ => unreachable!(),
-    }))
-}
-
-fn convert_decimal_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
+fn convert_biguint_to_json_value(path: &JsonPath, val: &MoveValue) -> VMResult<JSONValue> {
+    let bytes_le = bytes_from_move_value(path, val)?;
     Ok(JSONValue::String(
-        match val {
-            MoveValue::U128(num) => {
-                let num = BigUint::from_bytes_le(&num.to_le_bytes());
-                BigDecimal::new(num.into(), 18)
-            }
-            MoveValue::U256(num) => {
-                let num = BigUint::from_bytes_le(&num.to_le_bytes());
-                BigDecimal::new(num.into(), 18)
-            }
-            MoveValue::Struct(
-                MoveStruct::WithTypes { _type_: _, _fields }
-                | MoveStruct::WithFields(_fields)
-                | MoveStruct::WithVariantFields(_, _, _fields),
-            ) => {
-                let (_, bytes_val) = _fields.first().unwrap();
-                match bytes_val {
-                    MoveValue::Vector(bytes_val) => {
-                        let bytes_le = bytes_val
-                            .iter()
-                            .map(|byte_val| match byte_val {
-                                MoveValue::U8(byte) => *byte,
-                                _ => unreachable!(),
-                            })
-                            .collect::<Vec<u8>>();
-
-                        let num = BigUint::from_bytes_le(&bytes_le);
-                        BigDecimal::new(num.into(), 18)
-                    }
-                    _ => unreachable!(),
-                }
-            }
-            _ => unreachable!(),
-        }
-        .normalized()
-        .to_string(),
+        BigUint::from_bytes_le(&bytes_le).to_string(),
     ))
 }
 
-fn convert_fixed_point_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
-    Ok(JSONValue::String(match val {
-        MoveValue::U64(num) => (BigDecimal::from_u64(*num).unwrap() / (1u64 << 32))
-            .normalized()
-            .to_string(),
-        MoveValue::U128(num) => (BigDecimal::from_u128(*num).unwrap() / (1u128 << 64))
-            .normalized()
-            .to_string(),
-        _ => unreachable!(),
-    }))
+fn convert_decimal_to_json_value(
+    path: &JsonPath,
+    val: &MoveValue,
+    options: &JsonOptions,
+) -> VMResult<JSONValue> {
+    let num = match val {
+        MoveValue::U128(num) => {
+            let num = BigUint::from_bytes_le(&num.to_le_bytes());
+            BigDecimal::new(num.into(), 18)
+        }
+        MoveValue::U256(num) => {
+            let num = BigUint::from_bytes_le(&num.to_le_bytes());
+            BigDecimal::new(num.into(), 18)
+        }
+        MoveValue::Struct(
+            MoveStruct::WithTypes { _type_: _, _fields }
+            | MoveStruct::WithFields(_fields)
+            | MoveStruct::WithVariantFields(_, _, _fields),
+        ) => {
+            let bytes_le = bytes_from_move_value(path, first_field(path, _fields)?)?;
+            let num = BigUint::from_bytes_le(&bytes_le);
+            BigDecimal::new(num.into(), 18)
+        }
+        _ => return Err(path.serialization_error("expected decimal value")),
+    };
+
+    Ok(JSONValue::String(format_decimal(num, options)))
 }
 
-fn convert_option_to_json_value(val: &MoveValue, depth: usize) -> VMResult<JSONValue> {
-    Ok(match val {
-        MoveValue::Vector(elem) => {
-            if elem.is_empty() {
-                JSONValue::Null
-            } else {
-                convert_move_value_to_json_value(elem.first().unwrap(), depth + 1)?
-            }
+fn convert_fixed_point_to_json_value(
+    path: &JsonPath,
+    val: &MoveValue,
+    options: &JsonOptions,
+) -> VMResult<JSONValue> {
+    let num = match val {
+        MoveValue::U64(num) => {
+            BigDecimal::from_u64(*num)
+                .ok_or_else(|| path.serialization_error("invalid fixed point value"))?
+                / (1u64 << 32)
         }
-        _ => unreachable!(),
-    })
+        MoveValue::U128(num) => {
+            BigDecimal::from_u128(*num)
+                .ok_or_else(|| path.serialization_error("invalid fixed point value"))?
+                / (1u128 << 64)
+        }
+        _ => return Err(path.serialization_error("expected fixed point value")),
+    };
+
+    Ok(JSONValue::String(format_decimal(num, options)))
 }
 
-fn convert_object_to_json_value(val: &MoveValue) -> VMResult<JSONValue> {
+fn convert_option_to_json_value(
+    path: &JsonPath,
+    val: &MoveValue,
+    options: &JsonOptions,
+    depth: usize,
+) -> VMResult<JSONValue> {
     Ok(match val {
-        MoveValue::Address(addr) => JSONValue::String(addr.to_hex_literal()),
-        _ => unreachable!(),
+        MoveValue::Vector(elem) => match elem.first() {
+            None => JSONValue::Null,
+            Some(elem) => convert_move_value_to_json_value(path, elem, options, depth + 1)?,
+        },
+        _ => return Err(path.serialization_error("expected option value")),
     })
 }
 
+fn convert_object_to_json_value(
+    path: &JsonPath,
+    type_: &StructTag,
+    val: &MoveValue,
+    options: &JsonOptions,
+) -> VMResult<JSONValue> {
+    let addr = match val {
+        MoveValue::Address(addr) => convert_address_to_json_value(path, addr, options)?,
+        _ => return Err(path.serialization_error("expected object address")),
+    };
+    if !options.object_inner_types {
+        return Ok(addr);
+    }
+
+    let inner_type = type_
+        .type_args
+        .first()
+        .ok_or_else(|| path.serialization_error("missing object type argument"))?;
+    let mut object = Map::new();
+    object.insert("inner".to_string(), addr);
+    object.insert(
+        "type".to_string(),
+        JSONValue::String(inner_type.to_canonical_string()),
+    );
+    Ok(JSONValue::Object(object))
+}
+
 // check functions
 fn is_json_value(type_: &StructTag) -> bool {
     type_.address == CORE_CODE_ADDRESS
@@ -358,7 +512,7 @@ fn is_object(type_: &StructTag) -> bool {
 
 #[cfg(test)]
 mod move_to_json_tests {
-    use move_core_types::{account_address::AccountAddress, ident_str, u256::U256};
+    use move_core_types::{ident_str, language_storage::TypeTag, u256::U256};
     use serde_json::json;
 
     use super::*;
@@ -367,32 +521,32 @@ mod move_to_json_tests {
     fn test_convert_move_value_to_json_value() {
         // u8
         let mv = MoveValue::U8(123);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(123u8));
 
         // u16
         let mv = MoveValue::U16(123);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(123u16));
 
         // u32
         let mv = MoveValue::U32(123);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(123u32));
 
         // u64
         let mv = MoveValue::U64(123);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("123"));
 
         // u128
         let mv = MoveValue::U128(123);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("123"));
 
         // u256
         let mv = MoveValue::U256(U256::from(123u64));
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("123"));
 
         // biguint
@@ -413,19 +567,19 @@ mod move_to_json_tests {
                 ]),
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("1077952576"));
 
         // address
         let addr = AccountAddress::random();
         let mv = MoveValue::Address(addr);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(addr.to_hex_literal()));
 
         // vector
         let addr2 = AccountAddress::random();
         let mv = MoveValue::Vector(vec![MoveValue::Address(addr), MoveValue::Address(addr2)]);
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(
             val,
             json!(vec![addr.to_hex_literal(), addr2.to_hex_literal()])
@@ -444,7 +598,7 @@ mod move_to_json_tests {
                 MoveValue::Vector(vec![MoveValue::U8(123)]),
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(123u8));
 
         // option none
@@ -457,7 +611,7 @@ mod move_to_json_tests {
             },
             _fields: vec![(ident_str!("vec").into(), MoveValue::Vector(vec![]))],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(null));
 
         // fixed_point32
@@ -473,7 +627,7 @@ mod move_to_json_tests {
                 MoveValue::U64((123 << 32) / 2), // 61.5
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("61.5"));
 
         // fixed_point64
@@ -489,7 +643,7 @@ mod move_to_json_tests {
                 MoveValue::U128((123 << 64) / 2), // 61.5
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("61.5"));
 
         // bigdecimal
@@ -522,7 +676,7 @@ mod move_to_json_tests {
                 }),
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("0.00000027595585952"));
 
         // object
@@ -539,7 +693,7 @@ mod move_to_json_tests {
                 MoveValue::Address(addr), // 61.5
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!(addr.to_hex_literal()));
 
         // json value
@@ -562,7 +716,7 @@ mod move_to_json_tests {
                 ]),
             )],
         });
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!("move"));
 
         // json object
@@ -633,7 +787,7 @@ mod move_to_json_tests {
             )],
         });
 
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(
             val,
             json!({
@@ -647,7 +801,7 @@ mod move_to_json_tests {
     fn test_convert_move_value_to_json_value_enum() {
         // enum struct
         let mv = MoveValue::Struct(MoveStruct::RuntimeVariant(0, vec![(MoveValue::U64(42))]));
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!({"0": json!(vec!["42"]) }));
 
         // enum struct
@@ -656,7 +810,160 @@ mod move_to_json_tests {
             0,
             vec![(ident_str!("radius").into(), MoveValue::U64(42))],
         ));
-        let val = convert_move_value_to_json_value(&mv, 1).unwrap();
+        let val = serialize_move_value_to_json_value(&mv).unwrap();
         assert_eq!(val, json!({"Circle": json!({"radius": "42"})}));
     }
+
+    #[test]
+    fn test_convert_move_value_to_json_value_with_options() {
+        let options = JsonOptions {
+            numbers_as_json: true,
+            address_hrp: Some("init".to_string()),
+            type_annotations: false,
+            object_inner_types: true,
+            decimal_precision: Some(2),
+        };
+
+        // numbers are json numbers while they are safe
+        let mv = MoveValue::U64(MAX_SAFE_INTEGER);
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!(MAX_SAFE_INTEGER));
+
+        let mv = MoveValue::U64(MAX_SAFE_INTEGER + 1);
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!((MAX_SAFE_INTEGER + 1).to_string()));
+
+        let mv = MoveValue::U256(U256::from(123u64));
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!(123u64));
+
+        let mv = MoveValue::U128(u128::MAX);
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!(u128::MAX.to_string()));
+
+        // bech32 addresses; 20 bytes accounts drop the zero padding
+        let mv = MoveValue::Address(AccountAddress::ONE);
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!("init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d"));
+
+        let mut bytes = [0u8; AccountAddress::LENGTH];
+        bytes[0] = 1;
+        let mv = MoveValue::Address(AccountAddress::new(bytes));
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(
+            val,
+            json!("init1qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6v0qcm")
+        );
+
+        // object with its inner type
+        let metadata = StructTag {
+            address: CORE_CODE_ADDRESS,
+            module: ident_str!("fungible_asset").into(),
+            name: ident_str!("Metadata").into(),
+            type_args: vec![],
+        };
+        let mv = MoveValue::Struct(MoveStruct::WithTypes {
+            _type_: StructTag {
+                address: CORE_CODE_ADDRESS,
+                module: ident_str!("object").into(),
+                name: ident_str!("Object").into(),
+                type_args: vec![TypeTag::Struct(Box::new(metadata.clone()))],
+            },
+            _fields: vec![(
+                ident_str!("inner").into(),
+                MoveValue::Address(AccountAddress::ONE),
+            )],
+        });
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(
+            val,
+            json!({
+                "inner": "init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d",
+                "type": metadata.to_canonical_string(),
+            })
+        );
+
+        // decimals are truncated to the precision
+        let mv = MoveValue::Struct(MoveStruct::WithTypes {
+            _type_: StructTag {
+                address: CORE_CODE_ADDRESS,
+                module: ident_str!("fixed_point32").into(),
+                name: ident_str!("FixedPoint32").into(),
+                type_args: vec![],
+            },
+            _fields: vec![(ident_str!("value").into(), MoveValue::U64((1 << 32) / 3))],
+        });
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!("0.33"));
+
+        // type annotations
+        let options = JsonOptions {
+            type_annotations: true,
+            ..Default::default()
+        };
+        let type_ = StructTag {
+            address: AccountAddress::TWO,
+            module: ident_str!("test").into(),
+            name: ident_str!("Test").into(),
+            type_args: vec![],
+        };
+        let mv = MoveValue::Struct(MoveStruct::WithTypes {
+            _type_: type_.clone(),
+            _fields: vec![(ident_str!("amount").into(), MoveValue::U64(1))],
+        });
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(
+            val,
+            json!({
+                "type": type_.to_canonical_string(),
+                "value": { "amount": "1" },
+            })
+        );
+
+        // invalid hrp
+        let options = JsonOptions {
+            address_hrp: Some("".to_string()),
+            ..Default::default()
+        };
+        let mv = MoveValue::Address(AccountAddress::ONE);
+        let err = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::VALUE_SERIALIZATION_ERROR);
+
+        // the hrp is validated even when no address is converted
+        let mv = MoveValue::U64(1);
+        let err = validate_json_options(&options).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::VALUE_SERIALIZATION_ERROR);
+        assert!(serialize_move_value_to_json_value_with_options(&mv, &options).is_err());
+
+        // precision above the maximum
+        let options = JsonOptions {
+            decimal_precision: Some(MAX_DECIMAL_PRECISION + 1),
+            ..Default::default()
+        };
+        let err = validate_json_options(&options).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::VALUE_SERIALIZATION_ERROR);
+
+        let options = JsonOptions {
+            decimal_precision: Some(u32::MAX),
+            ..Default::default()
+        };
+        let mv = MoveValue::Struct(MoveStruct::WithTypes {
+            _type_: StructTag {
+                address: CORE_CODE_ADDRESS,
+                module: ident_str!("fixed_point32").into(),
+                name: ident_str!("FixedPoint32").into(),
+                type_args: vec![],
+            },
+            _fields: vec![(ident_str!("value").into(), MoveValue::U64(1 << 32))],
+        });
+        let err = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::VALUE_SERIALIZATION_ERROR);
+
+        let options = JsonOptions {
+            decimal_precision: Some(MAX_DECIMAL_PRECISION),
+            ..Default::default()
+        };
+        let val = serialize_move_value_to_json_value_with_options(&mv, &options).unwrap();
+        assert_eq!(val, json!("1.00000000000000000000"));
+    }
 }
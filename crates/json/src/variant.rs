@@ -0,0 +1,80 @@
+use move_binary_format::errors::VMResult;
+use move_core_types::value::{MoveTypeLayout, MoveVariantLayout};
+use serde_json::Value as JSONValue;
+
+use crate::errors::JsonPath;
+
+// split the json representation of an enum value into the variant index, the
+// variant name and the json values of the variant fields, paired with the field
+// names and layouts.
+//
+// an enum value is an object with a single entry keyed by the variant, in the same
+// shape `move_to_json` produces:
+// - `{"<variant_name>": {"<field_name>": <value>, ...}}`
+// - `{"<variant_index>": [<value>, ...]}`
+#[allow(clippy::type_complexity)]
+pub(crate) fn split_variant_json<'l>(
+    path: &JsonPath,
+    variants: &'l [MoveVariantLayout],
+    json_val: JSONValue,
+) -> VMResult<(u16, &'l str, Vec<(&'l str, &'l MoveTypeLayout, JSONValue)>)> {
+    let json_obj = path.expect_object(json_val)?;
+    if json_obj.len() != 1 {
+        return Err(path.error("enum value must have exactly one variant"));
+    }
+    let (key, json_fields) = json_obj
+        .into_iter()
+        .next()
+        .ok_or_else(|| path.error("enum value must have exactly one variant"))?;
+
+    // variant names are identifiers, so they never collide with an index.
+    let (tag, variant, fields) = if let Some((tag, variant)) = variants
+        .iter()
+        .enumerate()
+        .find(|(_, v)| v.name.as_str() == key)
+    {
+        let variant_path = path.field(variant.name.as_str());
+        let mut json_fields = variant_path.expect_object(json_fields)?;
+        let fields = variant
+            .fields
+            .iter()
+            .map(|f| {
+                json_fields
+                    .remove(f.name.as_str())
+                    .map(|v| (f.name.as_str(), &f.layout, v))
+                    .ok_or_else(|| variant_path.field(f.name.as_str()).error("missing field"))
+            })
+            .collect::<VMResult<Vec<_>>>()?;
+        if let Some(name) = json_fields.keys().next() {
+            return Err(variant_path.field(name).error("unknown field"));
+        }
+
+        (tag, variant, fields)
+    } else if let Ok(tag) = key.parse::<usize>() {
+        let variant = variants
+            .get(tag)
+            .ok_or_else(|| path.error(format!("invalid variant index {}", tag)))?;
+        let variant_path = path.field(variant.name.as_str());
+        let json_fields = variant_path.expect_array(json_fields)?;
+        if json_fields.len() != variant.fields.len() {
+            return Err(variant_path.error(format!(
+                "expected {} fields, got {}",
+                variant.fields.len(),
+                json_fields.len()
+            )));
+        }
+
+        let fields = variant
+            .fields
+            .iter()
+            .zip(json_fields)
+            .map(|(f, v)| (f.name.as_str(), &f.layout, v))
+            .collect();
+        (tag, variant, fields)
+    } else {
+        return Err(path.error(format!("unknown variant {}", key)));
+    };
+
+    let tag = u16::try_from(tag).map_err(|e| path.error(e))?;
+    Ok((tag, variant.name.as_str(), fields))
+}
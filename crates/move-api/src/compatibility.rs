@@ -0,0 +1,595 @@
+//! Differences between two versions of a module and whether publishing the new version
+//! as an upgrade of the old one would be accepted.
+
+use std::collections::BTreeMap;
+
+use move_binary_format::{compatibility::Compatibility, CompiledModule};
+use serde::Serialize;
+
+use crate::{
+    address::Address,
+    move_types::{
+        MoveAbility, MoveFunction, MoveFunctionVisibility, MoveModule, MoveModuleId, MoveStruct,
+        MoveType,
+    },
+    wrappers::IdentifierWrapper,
+};
+
+/// The differences between a module and its upgrade
+#[derive(Clone, Debug, Serialize)]
+pub struct ModuleDiff {
+    pub address: Address,
+    pub name: IdentifierWrapper,
+    pub functions: Vec<ItemChange<MoveFunction>>,
+    pub structs: Vec<ItemChange<MoveStruct>>,
+    pub friends: Vec<ItemChange<MoveModuleId>>,
+    pub compatibility: CompatibilityReport,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ItemChange<T> {
+    Added {
+        new: T,
+    },
+    Removed {
+        old: T,
+    },
+    Changed {
+        old: T,
+        new: T,
+        details: Vec<String>,
+    },
+}
+
+/// Whether the upgrade passes the compatibility check run on module publish, along with
+/// the changes that make it fail
+#[derive(Clone, Debug, Serialize)]
+pub struct CompatibilityReport {
+    pub compatible: bool,
+    pub reasons: Vec<String>,
+}
+
+/// Diff two versions of a module and check them with the compatibility rules applied when
+/// publishing, which check struct layouts but neither friend nor private entry functions.
+pub fn diff_modules(old: &CompiledModule, new: &CompiledModule) -> ModuleDiff {
+    let old_abi = MoveModule::from(old.clone());
+    let new_abi = MoveModule::from(new.clone());
+    let (functions, structs, friends, mut reasons) = diff_abis(&old_abi, &new_abi);
+
+    let compatible = if old_abi.address != new_abi.address || old_abi.name != new_abi.name {
+        reasons.insert(
+            0,
+            format!(
+                "module id changed from {}::{} to {}::{}",
+                old_abi.address, old_abi.name, new_abi.address, new_abi.name
+            ),
+        );
+        false
+    } else {
+        match Compatibility::new(true, false, false).check(old, new) {
+            Ok(()) => true,
+            Err(e) => {
+                // the abi does not cover every rule, e.g. enum variants
+                if reasons.is_empty() {
+                    reasons.push(e.to_string());
+                }
+                false
+            }
+        }
+    };
+    if compatible {
+        reasons.clear();
+    }
+
+    ModuleDiff {
+        address: new_abi.address,
+        name: new_abi.name,
+        functions,
+        structs,
+        friends,
+        compatibility: CompatibilityReport {
+            compatible,
+            reasons,
+        },
+    }
+}
+
+type AbiDiff = (
+    Vec<ItemChange<MoveFunction>>,
+    Vec<ItemChange<MoveStruct>>,
+    Vec<ItemChange<MoveModuleId>>,
+    Vec<String>,
+);
+
+/// Diff the abis, returning the changes of each kind and the changes that break
+/// compatibility.
+fn diff_abis(old: &MoveModule, new: &MoveModule) -> AbiDiff {
+    let mut reasons = vec![];
+
+    let functions = diff_items(
+        &old.exposed_functions,
+        &new.exposed_functions,
+        |function| function.name.to_string(),
+        function_changes,
+    );
+    for change in &functions {
+        match change {
+            ItemChange::Removed { old } if old.visibility == MoveFunctionVisibility::Public => {
+                reasons.push(format!("public function `{}` was removed", old.name))
+            }
+            ItemChange::Changed { old, new, .. }
+                if old.visibility == MoveFunctionVisibility::Public =>
+            {
+                reasons.extend(public_function_breaks(old, new))
+            }
+            ItemChange::Added { .. } | ItemChange::Removed { .. } | ItemChange::Changed { .. } => {}
+        }
+    }
+
+    let structs = diff_items(
+        &old.structs,
+        &new.structs,
+        |struct_| struct_.name.to_string(),
+        struct_changes,
+    );
+    for change in &structs {
+        match change {
+            ItemChange::Removed { old } => {
+                reasons.push(format!("struct `{}` was removed", old.name))
+            }
+            ItemChange::Changed { old, new, .. } => reasons.extend(struct_breaks(old, new)),
+            ItemChange::Added { .. } => {}
+        }
+    }
+
+    let friends = diff_items(
+        &old.friends,
+        &new.friends,
+        |friend| friend.to_string(),
+        |_, _| vec![],
+    );
+
+    (functions, structs, friends, reasons)
+}
+
+/// Match the items of both versions by key, keeping the order of the old version followed
+/// by the additions. Items without any change are left out.
+fn diff_items<T: Clone, K: Ord>(
+    old: &[T],
+    new: &[T],
+    key: impl Fn(&T) -> K,
+    changes: impl Fn(&T, &T) -> Vec<String>,
+) -> Vec<ItemChange<T>> {
+    let mut new_items = new
+        .iter()
+        .map(|item| (key(item), item))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut diff = vec![];
+    for old_item in old {
+        match new_items.remove(&key(old_item)) {
+            None => diff.push(ItemChange::Removed {
+                old: old_item.clone(),
+            }),
+            Some(new_item) => {
+                let details = changes(old_item, new_item);
+                if !details.is_empty() {
+                    diff.push(ItemChange::Changed {
+                        old: old_item.clone(),
+                        new: new_item.clone(),
+                        details,
+                    });
+                }
+            }
+        }
+    }
+    for new_item in new {
+        if new_items.contains_key(&key(new_item)) {
+            diff.push(ItemChange::Added {
+                new: new_item.clone(),
+            });
+        }
+    }
+
+    diff
+}
+
+fn function_changes(old: &MoveFunction, new: &MoveFunction) -> Vec<String> {
+    let mut details = vec![];
+    if old.visibility != new.visibility {
+        details.push(format!(
+            "visibility changed from {} to {}",
+            visibility(&old.visibility),
+            visibility(&new.visibility)
+        ));
+    }
+    if old.is_entry != new.is_entry {
+        details.push(attribute_change("entry", new.is_entry));
+    }
+    if old.is_view != new.is_view {
+        details.push(attribute_change("view", new.is_view));
+    }
+
+    let old_constraints = old
+        .generic_type_params
+        .iter()
+        .map(|param| param.constraints.as_slice())
+        .collect::<Vec<_>>();
+    let new_constraints = new
+        .generic_type_params
+        .iter()
+        .map(|param| param.constraints.as_slice())
+        .collect::<Vec<_>>();
+    details.extend(type_param_changes(&old_constraints, &new_constraints));
+
+    if old.params != new.params {
+        details.push(format!(
+            "parameters changed from ({}) to ({})",
+            type_list(&old.params),
+            type_list(&new.params)
+        ));
+    }
+    if old.return_ != new.return_ {
+        details.push(format!(
+            "return types changed from ({}) to ({})",
+            type_list(&old.return_),
+            type_list(&new.return_)
+        ));
+    }
+
+    details
+}
+
+fn struct_changes(old: &MoveStruct, new: &MoveStruct) -> Vec<String> {
+    let mut details = vec![];
+    if old.abilities != new.abilities {
+        details.push(format!(
+            "abilities changed from `{}` to `{}`",
+            abilities(&old.abilities),
+            abilities(&new.abilities)
+        ));
+    }
+    if old.is_event != new.is_event {
+        details.push(attribute_change("event", new.is_event));
+    }
+    if old.is_native != new.is_native {
+        details.push(attribute_change("native", new.is_native));
+    }
+
+    let old_constraints = old
+        .generic_type_params
+        .iter()
+        .map(|param| param.constraints.as_slice())
+        .collect::<Vec<_>>();
+    let new_constraints = new
+        .generic_type_params
+        .iter()
+        .map(|param| param.constraints.as_slice())
+        .collect::<Vec<_>>();
+    details.extend(type_param_changes(&old_constraints, &new_constraints));
+    for (idx, (old_param, new_param)) in old
+        .generic_type_params
+        .iter()
+        .zip(&new.generic_type_params)
+        .enumerate()
+    {
+        if old_param.is_phantom != new_param.is_phantom {
+            details.push(format!(
+                "type parameter T{} {}",
+                idx,
+                if new_param.is_phantom {
+                    "became phantom"
+                } else {
+                    "is no longer phantom"
+                }
+            ));
+        }
+    }
+
+    for old_field in &old.fields {
+        match new.fields.iter().find(|field| field.name == old_field.name) {
+            None => details.push(format!("field `{}` was removed", old_field.name)),
+            Some(new_field) if new_field.typ != old_field.typ => details.push(format!(
+                "type of field `{}` changed from {} to {}",
+                old_field.name, old_field.typ, new_field.typ
+            )),
+            Some(_) => {}
+        }
+    }
+    for new_field in &new.fields {
+        if !old.fields.iter().any(|field| field.name == new_field.name) {
+            details.push(format!("field `{}` was added", new_field.name));
+        }
+    }
+    let old_names = old
+        .fields
+        .iter()
+        .map(|field| &field.name)
+        .collect::<Vec<_>>();
+    let new_names = new
+        .fields
+        .iter()
+        .map(|field| &field.name)
+        .collect::<Vec<_>>();
+    if old_names.len() == new_names.len()
+        && old_names != new_names
+        && old_names.iter().all(|name| new_names.contains(name))
+    {
+        details.push("fields were reordered".to_string());
+    }
+
+    details
+}
+
+fn type_param_changes(old: &[&[MoveAbility]], new: &[&[MoveAbility]]) -> Vec<String> {
+    if old.len() != new.len() {
+        return vec![format!(
+            "number of type parameters changed from {} to {}",
+            old.len(),
+            new.len()
+        )];
+    }
+
+    old.iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(idx, (old, new))| {
+            format!(
+                "constraints of type parameter T{} changed from `{}` to `{}`",
+                idx,
+                abilities(old),
+                abilities(new)
+            )
+        })
+        .collect()
+}
+
+/// The changes of a public function that break the modules calling it
+fn public_function_breaks(old: &MoveFunction, new: &MoveFunction) -> Vec<String> {
+    let mut reasons = vec![];
+    if new.visibility != MoveFunctionVisibility::Public {
+        reasons.push(format!(
+            "public function `{}` became {}",
+            old.name,
+            visibility(&new.visibility)
+        ));
+    }
+    if old.params != new.params || old.return_ != new.return_ {
+        reasons.push(format!(
+            "signature of public function `{}` changed",
+            old.name
+        ));
+    }
+    if old.generic_type_params.len() != new.generic_type_params.len() {
+        reasons.push(format!(
+            "type parameters of public function `{}` changed",
+            old.name
+        ));
+    } else if old
+        .generic_type_params
+        .iter()
+        .zip(&new.generic_type_params)
+        .any(|(old, new)| !is_subset(&new.constraints, &old.constraints))
+    {
+        reasons.push(format!(
+            "type parameter constraints of public function `{}` were tightened",
+            old.name
+        ));
+    }
+
+    reasons
+}
+
+/// The changes of a struct that break existing values or the modules using it
+fn struct_breaks(old: &MoveStruct, new: &MoveStruct) -> Vec<String> {
+    let mut reasons = vec![];
+    if old.abilities != new.abilities {
+        reasons.push(format!("abilities of struct `{}` changed", old.name));
+    }
+    if old.generic_type_params.len() != new.generic_type_params.len() {
+        reasons.push(format!("type parameters of struct `{}` changed", old.name));
+    } else if old
+        .generic_type_params
+        .iter()
+        .zip(&new.generic_type_params)
+        .any(|(old, new)| {
+            (old.is_phantom && !new.is_phantom) || !is_subset(&new.constraints, &old.constraints)
+        })
+    {
+        reasons.push(format!(
+            "type parameters of struct `{}` were tightened",
+            old.name
+        ));
+    }
+    if old.fields != new.fields {
+        reasons.push(format!("layout of struct `{}` changed", old.name));
+    }
+
+    reasons
+}
+
+fn is_subset(abilities: &[MoveAbility], of: &[MoveAbility]) -> bool {
+    abilities.iter().all(|ability| of.contains(ability))
+}
+
+fn attribute_change(attribute: &str, added: bool) -> String {
+    if added {
+        format!("{} attribute was added", attribute)
+    } else {
+        format!("{} attribute was removed", attribute)
+    }
+}
+
+fn visibility(visibility: &MoveFunctionVisibility) -> &'static str {
+    match visibility {
+        MoveFunctionVisibility::Private => "private",
+        MoveFunctionVisibility::Public => "public",
+        MoveFunctionVisibility::Friend => "friend",
+    }
+}
+
+fn abilities(abilities: &[MoveAbility]) -> String {
+    abilities
+        .iter()
+        .map(|ability| ability.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn type_list(types: &[MoveType]) -> String {
+    types
+        .iter()
+        .map(|typ| typ.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use move_core_types::ability::Ability;
+
+    use super::*;
+    use crate::move_types::{MoveStructField, MoveStructGenericTypeParam};
+
+    fn ident(s: &str) -> IdentifierWrapper {
+        IdentifierWrapper::from_str(s).unwrap()
+    }
+
+    fn function(
+        name: &str,
+        visibility: MoveFunctionVisibility,
+        params: Vec<MoveType>,
+    ) -> MoveFunction {
+        MoveFunction {
+            name: ident(name),
+            visibility,
+            is_entry: false,
+            is_view: false,
+            generic_type_params: vec![],
+            params,
+            return_: vec![],
+        }
+    }
+
+    fn coin(fields: Vec<(&str, MoveType)>) -> MoveStruct {
+        MoveStruct {
+            name: ident("Coin"),
+            is_native: false,
+            is_event: false,
+            abilities: vec![MoveAbility(Ability::Store)],
+            generic_type_params: vec![MoveStructGenericTypeParam {
+                constraints: vec![],
+                is_phantom: true,
+            }],
+            fields: fields
+                .into_iter()
+                .map(|(name, typ)| MoveStructField {
+                    name: ident(name),
+                    typ,
+                })
+                .collect(),
+        }
+    }
+
+    fn module(functions: Vec<MoveFunction>, structs: Vec<MoveStruct>) -> MoveModule {
+        MoveModule {
+            address: Address::from_str("0x2").unwrap(),
+            name: ident("coin"),
+            friends: vec![],
+            exposed_functions: functions,
+            structs,
+        }
+    }
+
+    #[test]
+    fn test_compatible_changes() {
+        let old = module(
+            vec![
+                function("value", MoveFunctionVisibility::Public, vec![MoveType::U64]),
+                function("burn", MoveFunctionVisibility::Friend, vec![MoveType::U64]),
+            ],
+            vec![coin(vec![("value", MoveType::U64)])],
+        );
+        let mut new = module(
+            vec![
+                function("value", MoveFunctionVisibility::Public, vec![MoveType::U64]),
+                function("burn", MoveFunctionVisibility::Friend, vec![MoveType::U128]),
+                function("mint", MoveFunctionVisibility::Public, vec![]),
+            ],
+            vec![coin(vec![("value", MoveType::U64)])],
+        );
+        new.friends = vec![MoveModuleId::from_str("0x2::market").unwrap()];
+
+        let (functions, structs, friends, reasons) = diff_abis(&old, &new);
+        assert!(reasons.is_empty());
+        assert!(structs.is_empty());
+        assert_eq!(friends.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&functions)
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|change| (
+                    change["change"].clone(),
+                    change.pointer("/new/name").cloned()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    serde_json::json!("changed"),
+                    Some(serde_json::json!("burn"))
+                ),
+                (serde_json::json!("added"), Some(serde_json::json!("mint"))),
+            ]
+        );
+        match &functions[0] {
+            ItemChange::Changed { details, .. } => {
+                assert_eq!(details, &vec!["parameters changed from (u64) to (u128)"])
+            }
+            _ => panic!("burn should be changed"),
+        }
+    }
+
+    #[test]
+    fn test_incompatible_changes() {
+        let old = module(
+            vec![
+                function("value", MoveFunctionVisibility::Public, vec![MoveType::U64]),
+                function("split", MoveFunctionVisibility::Public, vec![]),
+            ],
+            vec![coin(vec![("value", MoveType::U64)])],
+        );
+        let new = module(
+            vec![function(
+                "value",
+                MoveFunctionVisibility::Friend,
+                vec![MoveType::U64],
+            )],
+            vec![coin(vec![
+                ("value", MoveType::U64),
+                ("frozen", MoveType::Bool),
+            ])],
+        );
+
+        let (_, structs, _, reasons) = diff_abis(&old, &new);
+        assert_eq!(
+            reasons,
+            vec![
+                "public function `value` became friend",
+                "public function `split` was removed",
+                "layout of struct `Coin` changed",
+            ]
+        );
+        match &structs[0] {
+            ItemChange::Changed { details, .. } => {
+                assert_eq!(details, &vec!["field `frozen` was added"])
+            }
+            _ => panic!("Coin should be changed"),
+        }
+    }
+}
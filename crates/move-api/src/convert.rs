@@ -1,11 +1,18 @@
-use crate::move_types::{MoveResource, MoveValue};
+use crate::move_types::{MoveResource, MoveTableItem, MoveValue};
 
-use anyhow::Result;
+use anyhow::{format_err, Result};
+use std::collections::{hash_map::Entry, HashMap};
 
+use initia_move_json::{serialize_move_value_to_json_value_with_options, JsonOptions};
 use initia_move_resource_viewer::InitiaValueAnnotator;
 use initia_move_storage::state_view::StateView;
+use initia_move_types::{access_path::AccessPath, event::RawEvent, table::TableInfo};
 
-use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{StructTag, TypeTag},
+    value::MoveValue as DecoratedMoveValue,
+};
 
 /// The Move converter for converting Move types to JSON
 ///
@@ -13,12 +20,14 @@ use move_core_types::language_storage::{StructTag, TypeTag};
 /// JSON outputs
 pub struct MoveConverter<'a, S> {
     inner: InitiaValueAnnotator<'a, S>,
+    state_view: &'a S,
 }
 
 impl<'a, S: StateView> MoveConverter<'a, S> {
     pub fn new(state_view: &'a S) -> Self {
         Self {
             inner: InitiaValueAnnotator::new(state_view),
+            state_view,
         }
     }
 
@@ -29,4 +38,93 @@ impl<'a, S: StateView> MoveConverter<'a, S> {
     pub fn try_into_value(&self, type_tag: &TypeTag, blob: &[u8]) -> Result<MoveValue> {
         self.inner.view_value(type_tag, blob)?.try_into()
     }
+
+    /// Convert the value to the json shape produced by view functions and
+    /// `json::marshal`, customized by `options`.
+    pub fn try_into_json_value(
+        &self,
+        type_tag: &TypeTag,
+        blob: &[u8],
+        options: &JsonOptions,
+    ) -> Result<serde_json::Value> {
+        let layout = self.inner.view_fully_decorated_ty_layout(type_tag)?;
+        let value = DecoratedMoveValue::simple_deserialize(blob, &layout)?;
+        serialize_move_value_to_json_value_with_options(&value, options).map_err(anyhow::Error::msg)
+    }
+
+    /// Convert a bcs encoded event to the json emitted for it during execution,
+    /// customized by `options`.
+    pub fn try_into_event(
+        &self,
+        type_tag: &TypeTag,
+        blob: &[u8],
+        options: &JsonOptions,
+    ) -> Result<serde_json::Value> {
+        let value = self.inner.view_event(type_tag, blob)?;
+        serialize_move_value_to_json_value_with_options(&value, options).map_err(anyhow::Error::msg)
+    }
+
+    /// Convert a batch of bcs encoded events, resolving the layout of each event
+    /// type once. The results are in the order of `events`.
+    pub fn try_into_events(
+        &self,
+        events: &[RawEvent],
+        options: &JsonOptions,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut layouts = HashMap::new();
+        let mut values = Vec::with_capacity(events.len());
+        for (idx, event) in events.iter().enumerate() {
+            let layout = match layouts.entry(&event.type_tag) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(
+                    self.inner
+                        .view_event_layout(&event.type_tag)
+                        .map_err(|e| format_err!("events[{}]: {}", idx, e))?,
+                ),
+            };
+            let value = DecoratedMoveValue::simple_deserialize(&event.event_data, layout)
+                .map_err(|e| format_err!("events[{}]: {}", idx, e))?;
+            values.push(
+                serialize_move_value_to_json_value_with_options(&value, options)
+                    .map_err(|e| format_err!("events[{}]: {}", idx, e))?,
+            );
+        }
+
+        Ok(values)
+    }
+
+    /// Read the key and value types recorded when the table was created.
+    pub fn table_info(&self, handle: &AccountAddress) -> Result<TableInfo> {
+        let blob = self
+            .state_view
+            .get(&AccessPath::table_info_access_path(*handle))?
+            .ok_or_else(|| format_err!("table info not found: {}", handle.to_hex_literal()))?;
+        Ok(bcs::from_bytes(&blob)?)
+    }
+
+    /// Convert a bcs encoded table key, and optionally its value, to json using
+    /// the types recorded for the table.
+    pub fn try_into_table_item(
+        &self,
+        handle: &AccountAddress,
+        key: &[u8],
+        value: Option<&[u8]>,
+        options: &JsonOptions,
+    ) -> Result<MoveTableItem> {
+        let info = self.table_info(handle)?;
+        let key = self
+            .try_into_json_value(&info.key_type, key, options)
+            .map_err(|e| format_err!("key: {}", e))?;
+        let value = value
+            .map(|value| self.try_into_json_value(&info.value_type, value, options))
+            .transpose()
+            .map_err(|e| format_err!("value: {}", e))?;
+
+        Ok(MoveTableItem {
+            key_type: (&info.key_type).into(),
+            value_type: (&info.value_type).into(),
+            key,
+            value,
+        })
+    }
 }
@@ -0,0 +1,783 @@
+//! Human readable disassembly of compiled modules and scripts.
+//!
+//! The output lists imports, structs, functions with their locals and bytecode, the
+//! constant pool and the decoded metadata section. Branch targets are replaced by jump
+//! labels. When a source map is given, parameters and locals take their source names and
+//! instructions are annotated with their source location.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use anyhow::Result;
+use initia_move_types::metadata::{
+    KnownAttribute, RuntimeModuleMetadataV0, INITIA_METADATA_KEY_V0,
+};
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{
+        Bytecode as Instruction, CodeOffset, CodeUnit, CompiledScript, Constant, FieldDefinition,
+        FieldHandleIndex, FunctionDefinitionIndex, FunctionHandleIndex, FunctionInstantiation,
+        ModuleHandle, Signature, SignatureIndex, SignatureToken, StructDefinition,
+        StructDefinitionIndex, StructFieldInformation, StructHandleIndex, Visibility,
+    },
+    CompiledModule,
+};
+use move_bytecode_source_map::source_map::{FunctionSourceMap, SourceMap};
+use move_core_types::{ability::AbilitySet, metadata::Metadata};
+
+use crate::{bytecode::Bytecode, move_types::MoveAbility};
+
+/// Disassemble a module, optionally annotated with its source map and the source file
+/// the map refers to.
+pub fn disassemble_module(
+    module: &CompiledModule,
+    source_map: Option<&SourceMap>,
+    source: Option<&str>,
+) -> Result<String> {
+    let mut disassembler = Disassembler {
+        bytecode: module,
+        module: Some(module),
+        constants: &module.constant_pool,
+        function_instantiations: &module.function_instantiations,
+        source_map,
+        source,
+        out: String::new(),
+    };
+    disassembler.module(module)?;
+    Ok(disassembler.out)
+}
+
+/// Disassemble a script, optionally annotated with its source map and the source file
+/// the map refers to.
+pub fn disassemble_script(
+    script: &CompiledScript,
+    source_map: Option<&SourceMap>,
+    source: Option<&str>,
+) -> Result<String> {
+    let mut disassembler = Disassembler {
+        bytecode: script,
+        module: None,
+        constants: &script.constant_pool,
+        function_instantiations: &script.function_instantiations,
+        source_map,
+        source,
+        out: String::new(),
+    };
+    disassembler.script(script)?;
+    Ok(disassembler.out)
+}
+
+/// A function declaration along with its code, if it is not native
+struct FunctionDecl<'b> {
+    header: String,
+    type_parameters: &'b [AbilitySet],
+    parameters: &'b Signature,
+    return_: &'b Signature,
+    code: Option<&'b CodeUnit>,
+}
+
+/// The names and types used while printing the body of a function
+struct FunctionContext<'b> {
+    type_names: Vec<String>,
+    local_names: Vec<String>,
+    local_types: Vec<&'b SignatureToken>,
+    labels: BTreeMap<CodeOffset, usize>,
+}
+
+struct Disassembler<'a, B> {
+    bytecode: &'a B,
+    module: Option<&'a CompiledModule>,
+    constants: &'a [Constant],
+    function_instantiations: &'a [FunctionInstantiation],
+    source_map: Option<&'a SourceMap>,
+    source: Option<&'a str>,
+    out: String,
+}
+
+impl<'a, B: Bytecode> Disassembler<'a, B> {
+    fn module(&mut self, module: &'a CompiledModule) -> Result<()> {
+        let bytecode = self.bytecode;
+        let self_id = module.self_id();
+        writeln!(self.out, "// Move bytecode v{}", module.version)?;
+        writeln!(
+            self.out,
+            "module {}::{} {{",
+            self_id.address().to_hex_literal(),
+            self_id.name()
+        )?;
+
+        let self_handle = module.self_handle_idx().0 as usize;
+        for (idx, handle) in module.module_handles.iter().enumerate() {
+            if idx != self_handle {
+                writeln!(self.out, "use {};", self.module_name(handle))?;
+            }
+        }
+        for handle in &module.friend_decls {
+            writeln!(self.out, "friend {};", self.module_name(handle))?;
+        }
+
+        for def in &module.struct_defs {
+            writeln!(self.out)?;
+            self.struct_def(def)?;
+        }
+
+        for (idx, def) in module.function_defs.iter().enumerate() {
+            let handle = bytecode.function_handle_at(def.function);
+            let mut header = String::new();
+            if def.code.is_none() {
+                header.push_str("native ");
+            }
+            match def.visibility {
+                Visibility::Private => {}
+                Visibility::Public => header.push_str("public "),
+                Visibility::Friend => header.push_str("public(friend) "),
+            }
+            if def.is_entry {
+                header.push_str("entry ");
+            }
+            header.push_str("fun ");
+            header.push_str(bytecode.identifier_at(handle.name).as_str());
+
+            writeln!(self.out)?;
+            self.function(
+                FunctionDecl {
+                    header,
+                    type_parameters: &handle.type_parameters,
+                    parameters: bytecode.signature_at(handle.parameters),
+                    return_: bytecode.signature_at(handle.return_),
+                    code: def.code.as_ref(),
+                },
+                FunctionDefinitionIndex(idx as u16),
+            )?;
+        }
+
+        self.constant_pool()?;
+        self.metadata(&module.metadata)?;
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn script(&mut self, script: &'a CompiledScript) -> Result<()> {
+        let bytecode = self.bytecode;
+        writeln!(self.out, "// Move bytecode v{}", script.version)?;
+        writeln!(self.out, "script {{")?;
+        for handle in &script.module_handles {
+            writeln!(self.out, "use {};", self.module_name(handle))?;
+        }
+
+        writeln!(self.out)?;
+        self.function(
+            FunctionDecl {
+                header: "fun main".to_string(),
+                type_parameters: &script.type_parameters,
+                parameters: bytecode.signature_at(script.parameters),
+                return_: &Signature(vec![]),
+                code: Some(&script.code),
+            },
+            FunctionDefinitionIndex(0),
+        )?;
+
+        self.constant_pool()?;
+        self.metadata(&script.metadata)?;
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn struct_def(&mut self, def: &StructDefinition) -> Result<()> {
+        let handle = self.bytecode.struct_handle_at(def.struct_handle);
+        let type_params = handle
+            .type_parameters
+            .iter()
+            .enumerate()
+            .map(|(idx, param)| {
+                let phantom = if param.is_phantom { "phantom " } else { "" };
+                format!("{}T{}{}", phantom, idx, constraints(param.constraints))
+            })
+            .collect::<Vec<_>>();
+        let type_names = (0..type_params.len())
+            .map(|idx| format!("T{}", idx))
+            .collect::<Vec<_>>();
+
+        let native = match def.field_information {
+            StructFieldInformation::Native => "native ",
+            _ => "",
+        };
+        write!(
+            self.out,
+            "{}struct {}{}",
+            native,
+            self.bytecode.identifier_at(handle.name),
+            type_list(&type_params)
+        )?;
+        if handle.abilities != AbilitySet::EMPTY {
+            write!(self.out, " has {}", abilities(handle.abilities, ", "))?;
+        }
+
+        match &def.field_information {
+            StructFieldInformation::Native => writeln!(self.out, ";")?,
+            StructFieldInformation::Declared(fields) => {
+                writeln!(self.out, " {{")?;
+                for field in fields {
+                    writeln!(self.out, "    {},", self.field(field, &type_names))?;
+                }
+                writeln!(self.out, "}}")?;
+            }
+            StructFieldInformation::DeclaredVariants(variants) => {
+                writeln!(self.out, " {{")?;
+                for variant in variants {
+                    let fields = variant
+                        .fields
+                        .iter()
+                        .map(|field| self.field(field, &type_names))
+                        .collect::<Vec<_>>();
+                    writeln!(
+                        self.out,
+                        "    {} {{ {} }},",
+                        self.bytecode.identifier_at(variant.name),
+                        fields.join(", ")
+                    )?;
+                }
+                writeln!(self.out, "}}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn field(&self, field: &FieldDefinition, type_names: &[String]) -> String {
+        format!(
+            "{}: {}",
+            self.bytecode.identifier_at(field.name),
+            self.type_name(&field.signature.0, type_names)
+        )
+    }
+
+    fn function<'b>(&mut self, decl: FunctionDecl<'b>, idx: FunctionDefinitionIndex) -> Result<()>
+    where
+        'a: 'b,
+    {
+        let source_map = self
+            .source_map
+            .map(|source_map| source_map.get_function_source_map(idx))
+            .transpose()?;
+        let context = self.function_context(&decl, source_map);
+
+        let type_params = decl
+            .type_parameters
+            .iter()
+            .zip(&context.type_names)
+            .map(|(constraint, name)| format!("{}{}", name, constraints(*constraint)))
+            .collect::<Vec<_>>();
+        let params = decl
+            .parameters
+            .0
+            .iter()
+            .enumerate()
+            .map(|(idx, token)| {
+                format!(
+                    "{}: {}",
+                    context.local_names[idx],
+                    self.type_name(token, &context.type_names)
+                )
+            })
+            .collect::<Vec<_>>();
+        write!(
+            self.out,
+            "{}{}({})",
+            decl.header,
+            type_list(&type_params),
+            params.join(", ")
+        )?;
+        match decl.return_.0.as_slice() {
+            [] => {}
+            [token] => write!(self.out, ": {}", self.type_name(token, &context.type_names))?,
+            tokens => write!(
+                self.out,
+                ": ({})",
+                tokens
+                    .iter()
+                    .map(|token| self.type_name(token, &context.type_names))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?,
+        }
+
+        let Some(code) = decl.code else {
+            writeln!(self.out, ";")?;
+            return Ok(());
+        };
+
+        writeln!(self.out, " {{")?;
+        for idx in decl.parameters.len()..context.local_types.len() {
+            writeln!(
+                self.out,
+                "    local {}: {}",
+                context.local_names[idx],
+                self.type_name(context.local_types[idx], &context.type_names)
+            )?;
+        }
+
+        let mut last_location = None;
+        for (offset, instruction) in code.code.iter().enumerate() {
+            let offset = offset as CodeOffset;
+            if let Some(label) = context.labels.get(&offset) {
+                writeln!(self.out, "  L{}:", label)?;
+            }
+
+            write!(
+                self.out,
+                "    {:>3}: {}",
+                offset,
+                self.instruction(instruction, &context)
+            )?;
+            let location = source_map
+                .and_then(|source_map| source_map.get_code_location(offset))
+                .map(|loc| (loc.start(), loc.end()));
+            if location != last_location {
+                if let Some((start, end)) = location {
+                    match self.source {
+                        Some(source) => {
+                            let (line, column) = line_column(source, start as usize);
+                            write!(self.out, "  // {}:{}", line, column)?;
+                        }
+                        None => write!(self.out, "  // {}..{}", start, end)?,
+                    }
+                }
+                last_location = location;
+            }
+            writeln!(self.out)?;
+        }
+
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn function_context<'b>(
+        &self,
+        decl: &FunctionDecl<'b>,
+        source_map: Option<&FunctionSourceMap>,
+    ) -> FunctionContext<'b>
+    where
+        'a: 'b,
+    {
+        let bytecode: &'a B = self.bytecode;
+        let type_names = (0..decl.type_parameters.len())
+            .map(|idx| {
+                source_map
+                    .and_then(|source_map| source_map.type_parameters.get(idx))
+                    .map(|(name, _)| name.clone())
+                    .unwrap_or_else(|| format!("T{}", idx))
+            })
+            .collect();
+
+        let mut local_types = decl.parameters.0.iter().collect::<Vec<_>>();
+        if let Some(code) = decl.code {
+            local_types.extend(bytecode.signature_at(code.locals).0.iter());
+        }
+        let local_names = (0..local_types.len())
+            .map(|idx| {
+                source_map
+                    .and_then(|source_map| source_map.get_parameter_or_local_name(idx as u64))
+                    .map(|(name, _)| name)
+                    .unwrap_or_else(|| {
+                        if idx < decl.parameters.len() {
+                            format!("arg{}", idx)
+                        } else {
+                            format!("loc{}", idx)
+                        }
+                    })
+            })
+            .collect();
+
+        let mut labels = BTreeMap::new();
+        for instruction in decl
+            .code
+            .map(|code| code.code.as_slice())
+            .unwrap_or_default()
+        {
+            if let Instruction::Branch(offset)
+            | Instruction::BrTrue(offset)
+            | Instruction::BrFalse(offset) = instruction
+            {
+                labels.insert(*offset, 0);
+            }
+        }
+        for (idx, label) in labels.values_mut().enumerate() {
+            *label = idx;
+        }
+
+        FunctionContext {
+            type_names,
+            local_names,
+            local_types,
+            labels,
+        }
+    }
+
+    fn instruction(&self, instruction: &Instruction, context: &FunctionContext) -> String {
+        let opcode = opcode(instruction);
+        match instruction {
+            Instruction::Branch(offset)
+            | Instruction::BrTrue(offset)
+            | Instruction::BrFalse(offset) => format!("{} L{}", opcode, context.labels[offset]),
+            Instruction::CopyLoc(idx)
+            | Instruction::MoveLoc(idx)
+            | Instruction::StLoc(idx)
+            | Instruction::MutBorrowLoc(idx)
+            | Instruction::ImmBorrowLoc(idx) => {
+                let idx = *idx as usize;
+                format!(
+                    "{}[{}]({}: {})",
+                    opcode,
+                    idx,
+                    context.local_names[idx],
+                    self.type_name(context.local_types[idx], &context.type_names)
+                )
+            }
+            Instruction::LdConst(idx) => {
+                let idx = idx.0 as usize;
+                format!(
+                    "{}[{}]({})",
+                    opcode,
+                    idx,
+                    constant_value(&self.constants[idx])
+                )
+            }
+            Instruction::Call(idx) => format!("{} {}", opcode, self.function_name(*idx)),
+            Instruction::CallGeneric(idx) => {
+                let instantiation = &self.function_instantiations[idx.0 as usize];
+                format!(
+                    "{} {}{}",
+                    opcode,
+                    self.function_name(instantiation.handle),
+                    self.type_args(instantiation.type_parameters, &context.type_names)
+                )
+            }
+            _ => match self.module {
+                Some(module) => self.module_instruction(module, instruction, context),
+                None => format!("{:?}", instruction),
+            },
+        }
+    }
+
+    /// Render the instructions referring to struct definitions, which only modules have.
+    fn module_instruction(
+        &self,
+        module: &CompiledModule,
+        instruction: &Instruction,
+        context: &FunctionContext,
+    ) -> String {
+        let opcode = opcode(instruction);
+        match instruction {
+            Instruction::Pack(idx)
+            | Instruction::Unpack(idx)
+            | Instruction::MutBorrowGlobal(idx)
+            | Instruction::ImmBorrowGlobal(idx)
+            | Instruction::Exists(idx)
+            | Instruction::MoveFrom(idx)
+            | Instruction::MoveTo(idx) => {
+                format!("{} {}", opcode, self.struct_def_name(module, *idx))
+            }
+            Instruction::PackGeneric(idx)
+            | Instruction::UnpackGeneric(idx)
+            | Instruction::MutBorrowGlobalGeneric(idx)
+            | Instruction::ImmBorrowGlobalGeneric(idx)
+            | Instruction::ExistsGeneric(idx)
+            | Instruction::MoveFromGeneric(idx)
+            | Instruction::MoveToGeneric(idx) => {
+                let instantiation = module.struct_instantiation_at(*idx);
+                format!(
+                    "{} {}{}",
+                    opcode,
+                    self.struct_def_name(module, instantiation.def),
+                    self.type_args(instantiation.type_parameters, &context.type_names)
+                )
+            }
+            Instruction::MutBorrowField(idx) | Instruction::ImmBorrowField(idx) => {
+                format!("{} {}", opcode, self.field_name(module, *idx))
+            }
+            Instruction::MutBorrowFieldGeneric(idx) | Instruction::ImmBorrowFieldGeneric(idx) => {
+                let instantiation = module.field_instantiation_at(*idx);
+                format!(
+                    "{} {}{}",
+                    opcode,
+                    self.field_name(module, instantiation.handle),
+                    self.type_args(instantiation.type_parameters, &context.type_names)
+                )
+            }
+            _ => format!("{:?}", instruction),
+        }
+    }
+
+    fn constant_pool(&mut self) -> Result<()> {
+        if self.constants.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(self.out)?;
+        writeln!(self.out, "constants {{")?;
+        for (idx, constant) in self.constants.iter().enumerate() {
+            writeln!(
+                self.out,
+                "    {}: {} = {}",
+                idx,
+                self.type_name(&constant.type_, &[]),
+                constant_value(constant)
+            )?;
+        }
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn metadata(&mut self, metadata: &[Metadata]) -> Result<()> {
+        if metadata.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(self.out)?;
+        writeln!(self.out, "metadata {{")?;
+        for entry in metadata {
+            let key = String::from_utf8_lossy(&entry.key);
+            let decoded = (entry.key == INITIA_METADATA_KEY_V0)
+                .then(|| bcs::from_bytes::<RuntimeModuleMetadataV0>(&entry.value).ok())
+                .flatten();
+            let Some(decoded) = decoded else {
+                writeln!(self.out, "    {}: 0x{}", key, hex::encode(&entry.value))?;
+                continue;
+            };
+
+            writeln!(self.out, "    {} {{", key)?;
+            for (code, description) in &decoded.error_map {
+                writeln!(
+                    self.out,
+                    "        error {}: {} {:?}",
+                    code, description.code_name, description.code_description
+                )?;
+            }
+            for (name, attributes) in &decoded.struct_attributes {
+                writeln!(
+                    self.out,
+                    "        struct {}: {}",
+                    name,
+                    attribute_list(attributes)
+                )?;
+            }
+            for (name, attributes) in &decoded.fun_attributes {
+                writeln!(
+                    self.out,
+                    "        fun {}: {}",
+                    name,
+                    attribute_list(attributes)
+                )?;
+            }
+            writeln!(self.out, "    }}")?;
+        }
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    fn module_name(&self, handle: &ModuleHandle) -> String {
+        format!(
+            "{}::{}",
+            self.bytecode
+                .address_identifier_at(handle.address)
+                .to_hex_literal(),
+            self.bytecode.identifier_at(handle.name)
+        )
+    }
+
+    fn function_name(&self, idx: FunctionHandleIndex) -> String {
+        let handle = self.bytecode.function_handle_at(idx);
+        let module = self.bytecode.module_handle_at(handle.module);
+        format!(
+            "{}::{}",
+            self.bytecode.identifier_at(module.name),
+            self.bytecode.identifier_at(handle.name)
+        )
+    }
+
+    fn struct_def_name(&self, module: &CompiledModule, idx: StructDefinitionIndex) -> String {
+        let handle = self
+            .bytecode
+            .struct_handle_at(module.struct_def_at(idx).struct_handle);
+        self.bytecode.identifier_at(handle.name).to_string()
+    }
+
+    fn field_name(&self, module: &CompiledModule, idx: FieldHandleIndex) -> String {
+        let handle = module.field_handle_at(idx);
+        let def = module.struct_def_at(handle.owner);
+        let field = match &def.field_information {
+            StructFieldInformation::Declared(fields) => fields
+                .get(handle.field as usize)
+                .map(|field| self.bytecode.identifier_at(field.name).to_string()),
+            StructFieldInformation::Native | StructFieldInformation::DeclaredVariants(_) => None,
+        };
+        format!(
+            "{}.{}",
+            self.struct_def_name(module, handle.owner),
+            field.unwrap_or_else(|| handle.field.to_string())
+        )
+    }
+
+    fn type_args(&self, idx: SignatureIndex, type_names: &[String]) -> String {
+        let types = self
+            .bytecode
+            .signature_at(idx)
+            .0
+            .iter()
+            .map(|token| self.type_name(token, type_names))
+            .collect::<Vec<_>>();
+        type_list(&types)
+    }
+
+    fn type_name(&self, token: &SignatureToken, type_names: &[String]) -> String {
+        match token {
+            SignatureToken::Bool => "bool".to_string(),
+            SignatureToken::U8 => "u8".to_string(),
+            SignatureToken::U16 => "u16".to_string(),
+            SignatureToken::U32 => "u32".to_string(),
+            SignatureToken::U64 => "u64".to_string(),
+            SignatureToken::U128 => "u128".to_string(),
+            SignatureToken::U256 => "u256".to_string(),
+            SignatureToken::Address => "address".to_string(),
+            SignatureToken::Signer => "signer".to_string(),
+            SignatureToken::Vector(token) => {
+                format!("vector<{}>", self.type_name(token, type_names))
+            }
+            SignatureToken::Struct(idx) => self.struct_name(*idx),
+            SignatureToken::StructInstantiation(idx, tokens) => {
+                let types = tokens
+                    .iter()
+                    .map(|token| self.type_name(token, type_names))
+                    .collect::<Vec<_>>();
+                format!("{}{}", self.struct_name(*idx), type_list(&types))
+            }
+            SignatureToken::TypeParameter(idx) => type_names
+                .get(*idx as usize)
+                .cloned()
+                .unwrap_or_else(|| format!("T{}", idx)),
+            SignatureToken::Reference(token) => format!("&{}", self.type_name(token, type_names)),
+            SignatureToken::MutableReference(token) => {
+                format!("&mut {}", self.type_name(token, type_names))
+            }
+            SignatureToken::Function(..) => format!("{:?}", token),
+        }
+    }
+
+    fn struct_name(&self, idx: StructHandleIndex) -> String {
+        let handle = self.bytecode.struct_handle_at(idx);
+        let module = self.bytecode.module_handle_at(handle.module);
+        format!(
+            "{}::{}",
+            self.bytecode.identifier_at(module.name),
+            self.bytecode.identifier_at(handle.name)
+        )
+    }
+}
+
+/// The name of the instruction without its operands
+fn opcode(instruction: &Instruction) -> String {
+    let debug = format!("{:?}", instruction);
+    match debug.find('(') {
+        Some(idx) => debug[..idx].to_string(),
+        None => debug,
+    }
+}
+
+fn constant_value(constant: &Constant) -> String {
+    if constant.type_ == SignatureToken::Vector(Box::new(SignatureToken::U8)) {
+        if let Ok(bytes) = bcs::from_bytes::<Vec<u8>>(&constant.data) {
+            return format!("x\"{}\"", hex::encode(bytes));
+        }
+    }
+
+    constant
+        .deserialize_constant()
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| format!("0x{}", hex::encode(&constant.data)))
+}
+
+fn abilities(set: AbilitySet, separator: &str) -> String {
+    set.into_iter()
+        .map(|ability| MoveAbility::from(ability).to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn constraints(set: AbilitySet) -> String {
+    if set == AbilitySet::EMPTY {
+        String::new()
+    } else {
+        format!(": {}", abilities(set, " + "))
+    }
+}
+
+fn type_list(types: &[String]) -> String {
+    if types.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", types.join(", "))
+    }
+}
+
+fn attribute_list(attributes: &[KnownAttribute]) -> String {
+    attributes
+        .iter()
+        .map(|attribute| {
+            let name = if attribute.is_view_function() {
+                "view".to_string()
+            } else if attribute.is_event() {
+                "event".to_string()
+            } else if attribute.is_randomness() {
+                "randomness".to_string()
+            } else {
+                format!("attribute({})", attribute.kind)
+            };
+            if attribute.args.is_empty() {
+                name
+            } else {
+                format!("{}({})", name, attribute.args.join(", "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The 1-based line and column of a byte offset in `source`
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let prefix = &source.as_bytes()[..offset.min(source.len())];
+    let line_start = prefix
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    let line = prefix.iter().filter(|b| **b == b'\n').count() + 1;
+    (line, prefix.len() - line_start + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        let source = "module 0x1::m {\n    fun f() {}\n}\n";
+        assert_eq!(line_column(source, 0), (1, 1));
+        assert_eq!(line_column(source, 20), (2, 5));
+        assert_eq!(line_column(source, 1000), (4, 1));
+    }
+
+    #[test]
+    fn test_constant_value() {
+        let bytes = Constant {
+            type_: SignatureToken::Vector(Box::new(SignatureToken::U8)),
+            data: bcs::to_bytes(&b"abc".to_vec()).unwrap(),
+        };
+        assert_eq!(constant_value(&bytes), "x\"616263\"");
+
+        let flag = Constant {
+            type_: SignatureToken::Bool,
+            data: bcs::to_bytes(&true).unwrap(),
+        };
+        assert_eq!(constant_value(&flag), "true");
+    }
+}
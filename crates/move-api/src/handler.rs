@@ -1,11 +1,21 @@
 use initia_move_types::module::ModuleBundle;
 use move_binary_format::{
-    access::ModuleAccess as _, deserializer::DeserializerConfig, CompiledModule,
+    access::ModuleAccess as _, deserializer::DeserializerConfig, file_format::CompiledScript,
+    CompiledModule,
+};
+use move_bytecode_source_map::source_map::SourceMap;
+use move_core_types::{
+    account_address::AccountAddress, language_storage::StructTag, parser::parse_struct_tag,
 };
-use move_core_types::{language_storage::StructTag, parser::parse_struct_tag};
 use serde::Serialize;
 
-use crate::move_types::{MoveModuleBytecode, MoveScriptBytecode};
+use crate::{
+    compatibility, disassembler,
+    move_types::{MoveModuleBytecode, MoveScriptBytecode},
+    schema::MoveModuleSchema,
+    state_dump::{self, StateDump, StateFile},
+    typescript,
+};
 
 pub fn decode_script_bytes(script_bytes: Vec<u8>) -> Result<Vec<u8>, anyhow::Error> {
     let script: MoveScriptBytecode = MoveScriptBytecode::new(script_bytes);
@@ -23,6 +33,95 @@ pub fn decode_module_bytes(module_bytes: Vec<u8>) -> Result<Vec<u8>, anyhow::Err
     serde_json::to_vec(&abi).map_err(anyhow::Error::msg)
 }
 
+/// Diff two versions of a module and report whether the new one can be published as an
+/// upgrade of the old one.
+pub fn diff_module_bytes(
+    old_module_bytes: Vec<u8>,
+    new_module_bytes: Vec<u8>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let old = CompiledModule::deserialize(&old_module_bytes)?;
+    let new = CompiledModule::deserialize(&new_module_bytes)?;
+    let diff = compatibility::diff_modules(&old, &new);
+
+    // serialize response as json
+    serde_json::to_vec(&diff).map_err(anyhow::Error::msg)
+}
+
+/// Disassemble module bytes to text. `source_map` is a bcs encoded source map of the
+/// module, and `source` the source file it refers to.
+pub fn disassemble_module_bytes(
+    module_bytes: Vec<u8>,
+    source_map: Option<&[u8]>,
+    source: Option<&str>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let module = CompiledModule::deserialize(&module_bytes)?;
+    let source_map = read_source_map(source_map)?;
+    let text = disassembler::disassemble_module(&module, source_map.as_ref(), source)?;
+    Ok(text.into_bytes())
+}
+
+/// Disassemble script bytes to text. `source_map` is a bcs encoded source map of the
+/// script, and `source` the source file it refers to.
+pub fn disassemble_script_bytes(
+    script_bytes: Vec<u8>,
+    source_map: Option<&[u8]>,
+    source: Option<&str>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let script = CompiledScript::deserialize(&script_bytes)?;
+    let source_map = read_source_map(source_map)?;
+    let text = disassembler::disassemble_script(&script, source_map.as_ref(), source)?;
+    Ok(text.into_bytes())
+}
+
+fn read_source_map(source_map: Option<&[u8]>) -> Result<Option<SourceMap>, anyhow::Error> {
+    source_map
+        .map(bcs::from_bytes)
+        .transpose()
+        .map_err(|e| anyhow::Error::msg(format!("invalid source map: {}", e)))
+}
+
+pub fn generate_json_schema(module_bytes: Vec<u8>) -> Result<Vec<u8>, anyhow::Error> {
+    let module: MoveModuleBytecode = MoveModuleBytecode::new(module_bytes);
+    let abi = module.try_parse_abi()?;
+    let schema = MoveModuleSchema::from(&abi);
+
+    // serialize response as json
+    serde_json::to_vec(&schema).map_err(anyhow::Error::msg)
+}
+
+pub fn generate_typescript(modules_bytes: Vec<Vec<u8>>) -> Result<Vec<u8>, anyhow::Error> {
+    let modules = modules_bytes
+        .into_iter()
+        .map(|bytes| MoveModuleBytecode::new(bytes).try_parse_abi())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(typescript::generate_typescript(&modules).into_bytes())
+}
+
+pub fn export_state(state_file: &[u8], address: Option<&str>) -> Result<Vec<u8>, anyhow::Error> {
+    let state = StateFile::from_json(state_file)?;
+    let address = address
+        .map(AccountAddress::from_hex_literal)
+        .transpose()
+        .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+    let dump = state_dump::export_state(&state, address)?;
+
+    // serialize response as json
+    serde_json::to_vec_pretty(&dump).map_err(anyhow::Error::msg)
+}
+
+/// Import a state dump on top of `state_file`, or of an empty state, and return the
+/// resulting state file.
+pub fn import_state(dump: &[u8], state_file: Option<&[u8]>) -> Result<Vec<u8>, anyhow::Error> {
+    let dump: StateDump = serde_json::from_slice(dump)?;
+    let mut state = state_file
+        .map(StateFile::from_json)
+        .transpose()?
+        .unwrap_or_default();
+    state.apply(state_dump::import_state(&dump)?)?;
+    state.to_json()
+}
+
 #[derive(Serialize)]
 struct ModuleInfoResponse {
     #[serde(with = "serde_bytes")]
@@ -1,11 +1,16 @@
 mod address;
 mod bytecode;
+mod compatibility;
+mod disassembler;
 mod metadata;
 mod move_types;
+mod schema;
+mod typescript;
 mod wrappers;
 
 pub mod convert;
 pub mod handler;
+pub mod state_dump;
 
 /// For verifying a given struct
 pub trait VerifyInput {
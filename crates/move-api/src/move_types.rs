@@ -42,6 +42,17 @@ pub struct MoveResource {
     pub data: MoveStructValue,
 }
 
+/// A table item with the key and value types recorded for its table
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveTableItem {
+    pub key_type: MoveType,
+    pub value_type: MoveType,
+    pub key: serde_json::Value,
+    /// Omitted when only the key was decoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+}
+
 impl TryFrom<AnnotatedMoveStruct> for MoveResource {
     type Error = anyhow::Error;
 
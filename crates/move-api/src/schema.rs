@@ -0,0 +1,480 @@
+//! JSON Schema (draft 2020-12) generation for the json arguments and return
+//! values of entry and view functions.
+//!
+//! Argument schemas follow the encoding accepted by the vm for json arguments
+//! (`initia_move_json::deserialize_json_args`), and return schemas follow the
+//! default output of `initia_move_json::serialize_move_value_to_json_value`.
+
+use move_core_types::account_address::AccountAddress;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{
+    address::Address,
+    move_types::{MoveFunction, MoveModule, MoveStruct, MoveStructTag, MoveType},
+    wrappers::IdentifierWrapper,
+};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const UINT_PATTERN: &str = "^[0-9]+$";
+const DECIMAL_PATTERN: &str = "^[0-9]+(\\.[0-9]+)?$";
+const HEX_PATTERN: &str = "^([0-9a-fA-F]{2})*$";
+const ADDRESS_PATTERN: &str = "^(0x[0-9a-fA-F]{1,64}|[0-9a-fA-F]{64})$";
+const ADDRESS_OUTPUT_PATTERN: &str = "^0x[0-9a-f]{1,64}$";
+
+/// Maximum depth of nested struct schemas; deeper values are left unconstrained.
+const MAX_SCHEMA_DEPTH: usize = 16;
+
+/// JSON Schemas of the entry and view functions of a module.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MoveModuleSchema {
+    pub address: Address,
+    pub name: IdentifierWrapper,
+    pub functions: Vec<MoveFunctionSchema>,
+}
+
+/// JSON Schemas of a single entry or view function.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MoveFunctionSchema {
+    pub name: IdentifierWrapper,
+    pub is_entry: bool,
+    pub is_view: bool,
+    /// Schema of the json arguments array, without the leading signer parameters
+    pub arguments: Value,
+    /// Schema of the json result; only set for view functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returns: Option<Value>,
+}
+
+impl From<&MoveModule> for MoveModuleSchema {
+    fn from(module: &MoveModule) -> Self {
+        let generator = SchemaGenerator { module };
+        Self {
+            address: module.address,
+            name: module.name.clone(),
+            functions: module
+                .exposed_functions
+                .iter()
+                .filter(|f| f.is_entry || f.is_view)
+                .map(|f| generator.function_schema(f))
+                .collect(),
+        }
+    }
+}
+
+struct SchemaGenerator<'a> {
+    module: &'a MoveModule,
+}
+
+impl SchemaGenerator<'_> {
+    fn function_schema(&self, function: &MoveFunction) -> MoveFunctionSchema {
+        let params: Vec<Value> = function
+            .params
+            .iter()
+            .skip_while(|ty| ty.is_signer())
+            .map(|ty| self.argument_schema(ty, 0))
+            .collect();
+
+        let mut arguments = json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "type": "array",
+            "minItems": params.len(),
+            "items": false,
+        });
+        if !params.is_empty() {
+            arguments["prefixItems"] = Value::Array(params);
+        }
+
+        let returns = function.is_view.then(|| {
+            let mut schema = match function.return_.as_slice() {
+                [ty] => self.return_schema(ty, 0),
+                types => json!({
+                    "type": "array",
+                    "prefixItems": types
+                        .iter()
+                        .map(|ty| self.return_schema(ty, 0))
+                        .collect::<Vec<_>>(),
+                    "minItems": types.len(),
+                    "items": false,
+                }),
+            };
+            // `true` and `false` are valid schemas, but cannot carry keywords.
+            if let Value::Object(obj) = &mut schema {
+                obj.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+            }
+            schema
+        });
+
+        MoveFunctionSchema {
+            name: function.name.clone(),
+            is_entry: function.is_entry,
+            is_view: function.is_view,
+            arguments,
+            returns,
+        }
+    }
+
+    fn argument_schema(&self, ty: &MoveType, depth: usize) -> Value {
+        match ty {
+            MoveType::Bool => json!({ "type": "boolean" }),
+            MoveType::U8 => integer_schema(u8::MAX.into()),
+            MoveType::U16 => integer_schema(u16::MAX.into()),
+            MoveType::U32 => integer_schema(u32::MAX.into()),
+            MoveType::U64 | MoveType::U128 | MoveType::U256 => pattern_schema(UINT_PATTERN),
+            MoveType::Address => pattern_schema(ADDRESS_PATTERN),
+            MoveType::Vector { items } if **items == MoveType::U8 => json!({
+                "anyOf": [
+                    pattern_schema(HEX_PATTERN),
+                    { "type": "array", "items": integer_schema(u8::MAX.into()) },
+                ]
+            }),
+            MoveType::Vector { items } => json!({
+                "type": "array",
+                "items": self.argument_schema(items, depth + 1),
+            }),
+            MoveType::Struct(tag) => self.argument_struct_schema(tag, depth),
+            MoveType::Reference { to, .. } => self.argument_schema(to, depth),
+            // generic parameters are bound by the type arguments of the call
+            MoveType::GenericTypeParam { .. } => Value::Bool(true),
+            MoveType::Signer | MoveType::Function { .. } | MoveType::Unparsable(_) => {
+                Value::Bool(false)
+            }
+        }
+    }
+
+    fn argument_struct_schema(&self, tag: &MoveStructTag, depth: usize) -> Value {
+        if let Some(schema) = self.special_struct_schema(tag, depth, Self::argument_schema) {
+            return schema;
+        }
+
+        match self.local_struct(tag) {
+            // enums are encoded as a single variant name keyed object
+            Some(s) if s.fields.is_empty() && !s.is_native => json!({
+                "type": "object",
+                "minProperties": 1,
+                "maxProperties": 1,
+            }),
+            // other structs are not allowed as arguments
+            _ => Value::Bool(false),
+        }
+    }
+
+    fn return_schema(&self, ty: &MoveType, depth: usize) -> Value {
+        if depth > MAX_SCHEMA_DEPTH {
+            return Value::Bool(true);
+        }
+
+        match ty {
+            MoveType::Bool => json!({ "type": "boolean" }),
+            MoveType::U8 => integer_schema(u8::MAX.into()),
+            MoveType::U16 => integer_schema(u16::MAX.into()),
+            MoveType::U32 => integer_schema(u32::MAX.into()),
+            MoveType::U64 | MoveType::U128 | MoveType::U256 => pattern_schema(UINT_PATTERN),
+            MoveType::Address | MoveType::Signer => pattern_schema(ADDRESS_OUTPUT_PATTERN),
+            // non-empty byte vectors are emitted as hex strings
+            MoveType::Vector { items } if **items == MoveType::U8 => json!({
+                "anyOf": [
+                    pattern_schema(HEX_PATTERN),
+                    { "type": "array", "maxItems": 0 },
+                ]
+            }),
+            MoveType::Vector { items } => json!({
+                "type": "array",
+                "items": self.return_schema(items, depth + 1),
+            }),
+            MoveType::Struct(tag) => self.return_struct_schema(tag, depth),
+            MoveType::Reference { to, .. } => self.return_schema(to, depth),
+            MoveType::GenericTypeParam { .. }
+            | MoveType::Function { .. }
+            | MoveType::Unparsable(_) => Value::Bool(true),
+        }
+    }
+
+    fn return_struct_schema(&self, tag: &MoveStructTag, depth: usize) -> Value {
+        if let Some(schema) = self.special_struct_schema(tag, depth, Self::return_schema) {
+            return schema;
+        }
+
+        if *tag.address.inner() == AccountAddress::ONE && tag.module.as_str() == "json" {
+            match tag.name.as_str() {
+                "JSONValue" => return Value::Bool(true),
+                "JSONObject" => return json!({ "type": "object" }),
+                _ => {}
+            }
+        }
+
+        let s = match self.local_struct(tag) {
+            Some(s) if !s.fields.is_empty() => s,
+            // structs of other modules and enums are only known to be objects
+            _ => return json!({ "type": "object" }),
+        };
+
+        let mut properties = serde_json::Map::new();
+        for field in &s.fields {
+            let ty = substitute(&field.typ, &tag.generic_type_params);
+            properties.insert(
+                json_field_name(field.name.as_str()).to_string(),
+                self.return_schema(&ty, depth + 1),
+            );
+        }
+        let required: Vec<String> = properties.keys().cloned().collect();
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Schemas of the structs with a dedicated json representation, which are
+    /// the same for arguments and return values.
+    fn special_struct_schema(
+        &self,
+        tag: &MoveStructTag,
+        depth: usize,
+        inner: fn(&Self, &MoveType, usize) -> Value,
+    ) -> Option<Value> {
+        if *tag.address.inner() != AccountAddress::ONE {
+            return None;
+        }
+
+        let schema = match (tag.module.as_str(), tag.name.as_str()) {
+            ("string", "String") => json!({ "type": "string" }),
+            ("object", "Object") => pattern_schema(ADDRESS_PATTERN),
+            ("option", "Option") => {
+                let inner = match tag.generic_type_params.first() {
+                    Some(ty) => inner(self, ty, depth + 1),
+                    None => Value::Bool(true),
+                };
+                json!({ "anyOf": [{ "type": "null" }, inner] })
+            }
+            ("fixed_point32", "FixedPoint32")
+            | ("fixed_point64", "FixedPoint64")
+            | ("bigdecimal", "BigDecimal") => pattern_schema(DECIMAL_PATTERN),
+            ("biguint", "BigUint") => pattern_schema(UINT_PATTERN),
+            _ => return None,
+        };
+
+        Some(schema)
+    }
+
+    fn local_struct(&self, tag: &MoveStructTag) -> Option<&MoveStruct> {
+        if tag.address != self.module.address || tag.module != self.module.name {
+            return None;
+        }
+
+        self.module.structs.iter().find(|s| s.name == tag.name)
+    }
+}
+
+fn integer_schema(max: u64) -> Value {
+    json!({ "type": "integer", "minimum": 0, "maximum": max })
+}
+
+fn pattern_schema(pattern: &str) -> Value {
+    json!({ "type": "string", "pattern": pattern })
+}
+
+/// Field names that collide with the keys of the serializer are renamed.
+fn json_field_name(name: &str) -> &str {
+    match name {
+        "_type_" => "@type",
+        "_move_" => "move",
+        "_signer_" => "signer",
+        name => name,
+    }
+}
+
+/// Replace the generic type params of a struct field with the struct's type arguments.
+fn substitute(ty: &MoveType, type_args: &[MoveType]) -> MoveType {
+    match ty {
+        MoveType::GenericTypeParam { index } => type_args
+            .get(*index as usize)
+            .cloned()
+            .unwrap_or_else(|| ty.clone()),
+        MoveType::Vector { items } => MoveType::Vector {
+            items: Box::new(substitute(items, type_args)),
+        },
+        MoveType::Reference { mutable, to } => MoveType::Reference {
+            mutable: *mutable,
+            to: Box::new(substitute(to, type_args)),
+        },
+        MoveType::Struct(tag) => MoveType::Struct(MoveStructTag {
+            address: tag.address,
+            module: tag.module.clone(),
+            name: tag.name.clone(),
+            generic_type_params: tag
+                .generic_type_params
+                .iter()
+                .map(|ty| substitute(ty, type_args))
+                .collect(),
+        }),
+        ty => ty.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::move_types::{MoveFunctionVisibility, MoveStructField};
+
+    fn ident(s: &str) -> IdentifierWrapper {
+        IdentifierWrapper::from_str(s).unwrap()
+    }
+
+    fn struct_type(address: Address, module: &str, name: &str, params: Vec<MoveType>) -> MoveType {
+        MoveType::Struct(MoveStructTag {
+            address,
+            module: ident(module),
+            name: ident(name),
+            generic_type_params: params,
+        })
+    }
+
+    fn function(
+        name: &str,
+        is_entry: bool,
+        is_view: bool,
+        params: Vec<MoveType>,
+        return_: Vec<MoveType>,
+    ) -> MoveFunction {
+        MoveFunction {
+            name: ident(name),
+            visibility: MoveFunctionVisibility::Public,
+            is_entry,
+            is_view,
+            generic_type_params: vec![],
+            params,
+            return_,
+        }
+    }
+
+    fn test_module() -> MoveModule {
+        let address = Address::from_str("0x2").unwrap();
+        MoveModule {
+            address,
+            name: ident("test"),
+            friends: vec![],
+            exposed_functions: vec![
+                function(
+                    "transfer",
+                    true,
+                    false,
+                    vec![
+                        MoveType::Reference {
+                            mutable: false,
+                            to: Box::new(MoveType::Signer),
+                        },
+                        MoveType::Address,
+                        MoveType::U64,
+                        struct_type(
+                            AccountAddress::ONE.into(),
+                            "option",
+                            "Option",
+                            vec![struct_type(
+                                AccountAddress::ONE.into(),
+                                "string",
+                                "String",
+                                vec![],
+                            )],
+                        ),
+                    ],
+                    vec![],
+                ),
+                function(
+                    "info",
+                    false,
+                    true,
+                    vec![MoveType::Vector {
+                        items: Box::new(MoveType::U8),
+                    }],
+                    vec![struct_type(address, "test", "Info", vec![MoveType::U8])],
+                ),
+                function("internal", false, false, vec![MoveType::Bool], vec![]),
+            ],
+            structs: vec![MoveStruct {
+                name: ident("Info"),
+                is_native: false,
+                is_event: false,
+                abilities: vec![],
+                generic_type_params: vec![],
+                fields: vec![
+                    MoveStructField {
+                        name: ident("_type_"),
+                        typ: MoveType::GenericTypeParam { index: 0 },
+                    },
+                    MoveStructField {
+                        name: ident("ratio"),
+                        typ: struct_type(
+                            AccountAddress::ONE.into(),
+                            "bigdecimal",
+                            "BigDecimal",
+                            vec![],
+                        ),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_module_schema() {
+        let schema = MoveModuleSchema::from(&test_module());
+        assert_eq!(schema.functions.len(), 2);
+
+        let transfer = &schema.functions[0];
+        assert_eq!(transfer.name.as_str(), "transfer");
+        assert_eq!(transfer.returns, None);
+        assert_eq!(
+            transfer.arguments,
+            json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "type": "array",
+                "minItems": 3,
+                "items": false,
+                "prefixItems": [
+                    { "type": "string", "pattern": ADDRESS_PATTERN },
+                    { "type": "string", "pattern": UINT_PATTERN },
+                    { "anyOf": [{ "type": "null" }, { "type": "string" }] },
+                ],
+            })
+        );
+
+        let info = &schema.functions[1];
+        assert_eq!(
+            info.arguments["prefixItems"][0]["anyOf"][0],
+            json!({ "type": "string", "pattern": HEX_PATTERN })
+        );
+        assert_eq!(
+            info.returns,
+            Some(json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "type": "object",
+                "properties": {
+                    "@type": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "ratio": { "type": "string", "pattern": DECIMAL_PATTERN },
+                },
+                "required": ["@type", "ratio"],
+                "additionalProperties": false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_disallowed_argument_schema() {
+        let module = test_module();
+        let generator = SchemaGenerator { module: &module };
+
+        // structs without a json representation cannot be passed as arguments
+        let other = struct_type(AccountAddress::ONE.into(), "coin", "Coin", vec![]);
+        assert_eq!(generator.argument_schema(&other, 0), Value::Bool(false));
+        assert_eq!(
+            generator.argument_schema(&MoveType::Signer, 0),
+            Value::Bool(false)
+        );
+    }
+}
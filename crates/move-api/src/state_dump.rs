@@ -0,0 +1,511 @@
+//! Export and import of the Move state stored under accounts.
+//!
+//! An export walks the entries of an [`IterableStateView`] and renders every module,
+//! resource and table as annotated JSON next to its raw bcs bytes. The bytes are what
+//! [`import_state`] writes back, so a dump reproduces the exported entries exactly.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, format_err, Result};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use initia_move_json::serialize_move_value_to_json_value;
+use initia_move_resource_viewer::InitiaValueAnnotator;
+use initia_move_storage::state_view::{IterableStateView, StateView};
+use initia_move_types::{
+    access_path::{AccessPath, DataPath},
+    table::TableInfo,
+    write_set::WriteSet,
+};
+
+use move_binary_format::{
+    access::ModuleAccess as _, deserializer::DeserializerConfig, CompiledModule,
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::Op,
+    language_storage::{StructTag, TypeTag},
+    parser::{parse_struct_tag, parse_type_tag},
+    value::{MoveStruct, MoveValue},
+};
+use move_vm_types::sha3_256;
+
+use crate::{
+    address::Address,
+    move_types::{HexEncodedBytes, MoveModule, MoveModuleBytecode},
+};
+
+/// The exported state of a set of accounts and of the tables they own
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateDump {
+    pub accounts: Vec<AccountDump>,
+    pub tables: Vec<TableDump>,
+}
+
+/// The modules and resources stored under an account
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccountDump {
+    pub address: Address,
+    pub modules: Vec<ModuleDump>,
+    pub resources: Vec<ResourceDump>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModuleDump {
+    pub bytecode: HexEncodedBytes,
+    pub abi: MoveModule,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResourceDump {
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub bcs: HexEncodedBytes,
+    pub data: serde_json::Value,
+}
+
+/// The items of a table along with the key and value types recorded at its creation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableDump {
+    pub handle: Address,
+    pub key_type: String,
+    pub value_type: String,
+    pub items: Vec<TableItemDump>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TableItemDump {
+    pub key_bcs: HexEncodedBytes,
+    pub value_bcs: HexEncodedBytes,
+    pub key: serde_json::Value,
+    pub value: serde_json::Value,
+}
+
+/// Export the state stored under `address`, or the whole store when `address` is `None`.
+///
+/// Tables reachable from the exported resources are exported as well, even though their
+/// items are stored under the table handle rather than under `address`.
+pub fn export_state<S: IterableStateView>(
+    state_view: &S,
+    address: Option<AccountAddress>,
+) -> Result<StateDump> {
+    let mut exporter = StateExporter::new(state_view);
+    exporter.load(address)?;
+    exporter.export()
+}
+
+/// Rebuild the write set recreating every entry of `dump`.
+///
+/// Only the bcs bytes are written; the annotated JSON is informational. Module checksums
+/// are recomputed from the bytecode.
+pub fn import_state(dump: &StateDump) -> Result<WriteSet> {
+    let mut write_set = BTreeMap::new();
+    for account in &dump.accounts {
+        let address = *account.address.inner();
+        for module in &account.modules {
+            let bytecode = &module.bytecode.0;
+            let compiled =
+                CompiledModule::deserialize_with_config(bytecode, &DeserializerConfig::default())?;
+            if *compiled.address() != address {
+                bail!(
+                    "module {} cannot be imported under {}",
+                    compiled.self_id(),
+                    address.to_hex_literal()
+                );
+            }
+
+            let name = compiled.name().to_owned();
+            write_set.insert(
+                AccessPath::code_access_path(address, name.clone()),
+                Op::New(bytecode.clone()),
+            );
+            write_set.insert(
+                AccessPath::checksum_access_path(address, name),
+                Op::New(sha3_256(bytecode).to_vec()),
+            );
+        }
+
+        for resource in &account.resources {
+            let struct_tag = parse_struct_tag(&resource.typ)?;
+            write_set.insert(
+                AccessPath::resource_access_path(address, struct_tag),
+                Op::New(resource.bcs.0.clone()),
+            );
+        }
+    }
+
+    for table in &dump.tables {
+        let handle = *table.handle.inner();
+        let info = TableInfo::new(
+            parse_type_tag(&table.key_type)?,
+            parse_type_tag(&table.value_type)?,
+        );
+        write_set.insert(
+            AccessPath::table_info_access_path(handle),
+            Op::New(bcs::to_bytes(&info)?),
+        );
+
+        for item in &table.items {
+            write_set.insert(
+                AccessPath::table_item_access_path(handle, item.key_bcs.0.clone()),
+                Op::New(item.value_bcs.0.clone()),
+            );
+        }
+    }
+
+    Ok(WriteSet::new_with_write_set(write_set))
+}
+
+/// The raw entries stored under a single address
+#[derive(Default)]
+struct RawEntries {
+    modules: Vec<Bytes>,
+    resources: Vec<(StructTag, Bytes)>,
+    table_info: Option<Bytes>,
+    table_items: Vec<(Vec<u8>, Bytes)>,
+}
+
+struct StateExporter<'a, S> {
+    state_view: &'a S,
+    annotator: InitiaValueAnnotator<'a, S>,
+    entries: BTreeMap<AccountAddress, RawEntries>,
+}
+
+impl<'a, S: IterableStateView> StateExporter<'a, S> {
+    fn new(state_view: &'a S) -> Self {
+        Self {
+            state_view,
+            annotator: InitiaValueAnnotator::new(state_view),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    fn load(&mut self, address: Option<AccountAddress>) -> Result<()> {
+        for entry in self.state_view.iter_entries(address) {
+            let (access_path, blob) = entry?;
+            let raw = self.entries.entry(access_path.address).or_default();
+            match access_path.path {
+                DataPath::Code(_) => raw.modules.push(blob),
+                // checksums are derived from the bytecode on import
+                DataPath::Checksum(_) => {}
+                DataPath::Resource(struct_tag) => raw.resources.push((struct_tag, blob)),
+                DataPath::TableItem(key) => raw.table_items.push((key, blob)),
+                DataPath::TableInfo => raw.table_info = Some(blob),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn export(mut self) -> Result<StateDump> {
+        let mut pending_tables = vec![];
+
+        let mut accounts = vec![];
+        for (address, raw) in &self.entries {
+            if raw.modules.is_empty() && raw.resources.is_empty() {
+                continue;
+            }
+
+            let modules = raw
+                .modules
+                .iter()
+                .map(|bytecode| {
+                    let abi = MoveModuleBytecode::new(bytecode.to_vec()).try_parse_abi()?;
+                    Ok(ModuleDump {
+                        bytecode: bytecode.to_vec().into(),
+                        abi,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut resources = vec![];
+            for (struct_tag, blob) in &raw.resources {
+                let type_tag = TypeTag::Struct(Box::new(struct_tag.clone()));
+                let data = self
+                    .decode(&type_tag, blob, &mut pending_tables)
+                    .map_err(|e| {
+                        format_err!(
+                            "{}/{}: {}",
+                            address.to_hex_literal(),
+                            struct_tag.to_canonical_string(),
+                            e
+                        )
+                    })?;
+                resources.push(ResourceDump {
+                    typ: struct_tag.to_canonical_string(),
+                    bcs: blob.to_vec().into(),
+                    data,
+                });
+            }
+
+            accounts.push(AccountDump {
+                address: (*address).into(),
+                modules,
+                resources,
+            });
+        }
+
+        pending_tables.extend(
+            self.entries
+                .iter()
+                .filter(|(_, raw)| raw.table_info.is_some())
+                .map(|(handle, _)| *handle),
+        );
+
+        let mut tables = BTreeMap::new();
+        while let Some(handle) = pending_tables.pop() {
+            if tables.contains_key(&handle) {
+                continue;
+            }
+            if !self.entries.contains_key(&handle) {
+                self.load(Some(handle))?;
+            }
+
+            let table = self
+                .export_table(&handle, &mut pending_tables)
+                .map_err(|e| format_err!("table {}: {}", handle.to_hex_literal(), e))?;
+            tables.insert(handle, table);
+        }
+
+        Ok(StateDump {
+            accounts,
+            tables: tables.into_values().collect(),
+        })
+    }
+
+    fn export_table(
+        &self,
+        handle: &AccountAddress,
+        pending_tables: &mut Vec<AccountAddress>,
+    ) -> Result<TableDump> {
+        let Some((raw, blob)) = self
+            .entries
+            .get(handle)
+            .and_then(|raw| raw.table_info.as_ref().map(|blob| (raw, blob)))
+        else {
+            bail!("table info not found");
+        };
+        let info: TableInfo = bcs::from_bytes(blob)?;
+
+        let mut items = vec![];
+        for (key, value) in &raw.table_items {
+            let key_data = self
+                .decode(&info.key_type, key, pending_tables)
+                .map_err(|e| format_err!("key {}: {}", hex::encode(key), e))?;
+            let value_data = self
+                .decode(&info.value_type, value, pending_tables)
+                .map_err(|e| format_err!("value of key {}: {}", hex::encode(key), e))?;
+            items.push(TableItemDump {
+                key_bcs: key.clone().into(),
+                value_bcs: value.to_vec().into(),
+                key: key_data,
+                value: value_data,
+            });
+        }
+
+        Ok(TableDump {
+            handle: (*handle).into(),
+            key_type: info.key_type.to_canonical_string(),
+            value_type: info.value_type.to_canonical_string(),
+            items,
+        })
+    }
+
+    /// Decode `blob` to json, collecting the handles of the tables it holds.
+    fn decode(
+        &self,
+        type_tag: &TypeTag,
+        blob: &[u8],
+        pending_tables: &mut Vec<AccountAddress>,
+    ) -> Result<serde_json::Value> {
+        let layout = self.annotator.view_fully_decorated_ty_layout(type_tag)?;
+        let value = MoveValue::simple_deserialize(blob, &layout)?;
+        collect_table_handles(&value, pending_tables);
+        serialize_move_value_to_json_value(&value).map_err(anyhow::Error::msg)
+    }
+}
+
+fn is_table(struct_tag: &StructTag) -> bool {
+    struct_tag.address == AccountAddress::ONE
+        && struct_tag.module.as_str() == "table"
+        && struct_tag.name.as_str() == "Table"
+}
+
+fn collect_table_handles(value: &MoveValue, handles: &mut Vec<AccountAddress>) {
+    match value {
+        MoveValue::Vector(values) => values
+            .iter()
+            .for_each(|value| collect_table_handles(value, handles)),
+        MoveValue::Struct(MoveStruct::WithTypes { _type_, _fields }) => {
+            if is_table(_type_) {
+                handles.extend(_fields.iter().find_map(|(name, value)| match value {
+                    MoveValue::Address(handle) if name.as_str() == "handle" => Some(*handle),
+                    _ => None,
+                }));
+            } else {
+                _fields
+                    .iter()
+                    .for_each(|(_, value)| collect_table_handles(value, handles));
+            }
+        }
+        MoveValue::Struct(MoveStruct::WithFields(fields))
+        | MoveValue::Struct(MoveStruct::WithVariantFields(_, _, fields)) => fields
+            .iter()
+            .for_each(|(_, value)| collect_table_handles(value, handles)),
+        MoveValue::Struct(MoveStruct::Runtime(values))
+        | MoveValue::Struct(MoveStruct::RuntimeVariant(_, values)) => values
+            .iter()
+            .for_each(|value| collect_table_handles(value, handles)),
+        _ => {}
+    }
+}
+
+/// A state snapshot stored as a JSON object mapping hex encoded storage keys to hex
+/// encoded values, as used by the CLI to work against a local state file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateFile(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl StateFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_json(bytes: &[u8]) -> Result<Self> {
+        let entries: BTreeMap<String, String> = serde_json::from_slice(bytes)?;
+        entries
+            .into_iter()
+            .map(|(key, value)| Ok((decode_hex(&key)?, decode_hex(&value)?)))
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>> {
+        let entries = self
+            .0
+            .iter()
+            .map(|(key, value)| {
+                (
+                    format!("0x{}", hex::encode(key)),
+                    format!("0x{}", hex::encode(value)),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        Ok(serde_json::to_vec_pretty(&entries)?)
+    }
+
+    pub fn apply(&mut self, write_set: WriteSet) -> Result<()> {
+        for (access_path, op) in write_set {
+            let key = access_path.to_bytes()?;
+            match op {
+                Op::New(value) | Op::Modify(value) => {
+                    self.0.insert(key, value);
+                }
+                Op::Delete => {
+                    self.0.remove(&key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl StateView for StateFile {
+    fn get(&self, access_path: &AccessPath) -> Result<Option<Bytes>> {
+        Ok(self
+            .0
+            .get(&access_path.to_bytes()?)
+            .map(|value| Bytes::copy_from_slice(value)))
+    }
+}
+
+impl IterableStateView for StateFile {
+    fn iter_entries(
+        &self,
+        address: Option<AccountAddress>,
+    ) -> Box<dyn Iterator<Item = Result<(AccessPath, Bytes)>> + '_> {
+        let prefix = address.map(|address| address.to_vec()).unwrap_or_default();
+        Box::new(
+            self.0
+                .range(prefix.clone()..)
+                .take_while(move |(key, _)| key.starts_with(&prefix))
+                .map(|(key, value)| {
+                    Ok((AccessPath::from_bytes(key)?, Bytes::copy_from_slice(value)))
+                }),
+        )
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    hex::decode(s).map_err(|e| format_err!("invalid hex string {}: {}", s, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_file_round_trip() {
+        let address = AccountAddress::from_hex_literal("0x2").unwrap();
+        let other = AccountAddress::from_hex_literal("0x3").unwrap();
+        let info = TableInfo::new(TypeTag::U64, TypeTag::Bool);
+
+        let mut write_set = BTreeMap::new();
+        write_set.insert(
+            AccessPath::table_info_access_path(address),
+            Op::New(bcs::to_bytes(&info).unwrap()),
+        );
+        write_set.insert(
+            AccessPath::table_item_access_path(address, bcs::to_bytes(&1u64).unwrap()),
+            Op::New(bcs::to_bytes(&true).unwrap()),
+        );
+        write_set.insert(
+            AccessPath::table_info_access_path(other),
+            Op::New(bcs::to_bytes(&info).unwrap()),
+        );
+
+        let mut state = StateFile::new();
+        state
+            .apply(WriteSet::new_with_write_set(write_set))
+            .unwrap();
+
+        let state = StateFile::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(state.iter_entries(None).count(), 3);
+        assert_eq!(state.iter_entries(Some(other)).count(), 1);
+
+        // tables need no modules to be decoded
+        let dump = export_state(&state, Some(address)).unwrap();
+        assert!(dump.accounts.is_empty());
+        assert_eq!(
+            serde_json::to_value(&dump.tables).unwrap(),
+            serde_json::json!([{
+                "handle": "0x2",
+                "key_type": "u64",
+                "value_type": "bool",
+                "items": [{
+                    "key_bcs": "0x0100000000000000",
+                    "value_bcs": "0x01",
+                    "key": "1",
+                    "value": true,
+                }],
+            }])
+        );
+
+        let mut imported = StateFile::new();
+        imported.apply(import_state(&dump).unwrap()).unwrap();
+        assert_eq!(
+            imported.iter_entries(None).count(),
+            state.iter_entries(Some(address)).count()
+        );
+        assert_eq!(
+            imported
+                .get(&AccessPath::table_info_access_path(address))
+                .unwrap(),
+            state
+                .get(&AccessPath::table_info_access_path(address))
+                .unwrap()
+        );
+    }
+}
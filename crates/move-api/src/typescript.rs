@@ -0,0 +1,853 @@
+//! TypeScript client generation from module ABIs.
+//!
+//! Every module becomes a namespace with interfaces for its structs, payload
+//! builders for its entry functions and callers for its view functions. The
+//! generated code only depends on the runtime helpers emitted once at the top
+//! of the output, so it can be used with any client that submits json or bcs
+//! encoded arguments.
+
+use std::fmt::Write as _;
+
+use move_core_types::{ability::Ability, account_address::AccountAddress};
+
+use crate::move_types::{MoveFunction, MoveModule, MoveStruct, MoveStructTag, MoveType};
+
+const RUNTIME: &str = r#"// Code generated by initia-move-cli. DO NOT EDIT.
+/* eslint-disable */
+
+/** u64, u128, u256 and BigUint values */
+export type IntegerInput = bigint | number | string;
+/** FixedPoint32, FixedPoint64 and BigDecimal values */
+export type DecimalInput = number | string;
+/** vector<u8> values, either raw or hex encoded */
+export type BytesInput = Uint8Array | string;
+
+export interface JsonEntryPayload {
+  module_address: string;
+  module_name: string;
+  function_name: string;
+  type_args: string[];
+  args: string[];
+}
+
+export interface BcsEntryPayload {
+  module_address: string;
+  module_name: string;
+  function_name: string;
+  type_args: string[];
+  args: Uint8Array[];
+}
+
+/** Executes a view function with json arguments and resolves to its json encoded result. */
+export type ViewJson = (payload: JsonEntryPayload) => Promise<string>;
+
+function stripHexPrefix(hex: string): string {
+  return hex.startsWith("0x") ? hex.slice(2) : hex;
+}
+
+function hexToBytes(hex: string): Uint8Array {
+  const s = stripHexPrefix(hex);
+  if (s.length % 2 !== 0 || !/^[0-9a-fA-F]*$/.test(s)) {
+    throw new Error(`invalid hex string: ${hex}`);
+  }
+  const out = new Uint8Array(s.length / 2);
+  for (let i = 0; i < out.length; i++) {
+    out[i] = parseInt(s.slice(i * 2, i * 2 + 2), 16);
+  }
+  return out;
+}
+
+function bytesToHex(bytes: Uint8Array): string {
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("");
+}
+
+/** Parses a decimal and returns it multiplied by `scale`, truncated. */
+function scaleDecimal(value: DecimalInput, scale: bigint): bigint {
+  const [int, frac = ""] = String(value).split(".");
+  if (!/^[0-9]+$/.test(int) || !/^[0-9]*$/.test(frac)) {
+    throw new Error(`invalid decimal: ${value}`);
+  }
+  return (BigInt(int + frac) * scale) / 10n ** BigInt(frac.length);
+}
+
+function concatBytes(parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((len, p) => len + p.length, 0));
+  let offset = 0;
+  for (const p of parts) {
+    out.set(p, offset);
+    offset += p.length;
+  }
+  return out;
+}
+
+function uleb128(value: number): Uint8Array {
+  const out: number[] = [];
+  do {
+    let byte = value & 0x7f;
+    value >>>= 7;
+    if (value !== 0) byte |= 0x80;
+    out.push(byte);
+  } while (value !== 0);
+  return Uint8Array.from(out);
+}
+
+function uintBytes(value: IntegerInput, size: number): Uint8Array {
+  let n = BigInt(value);
+  if (n < 0n || n >= 1n << BigInt(size * 8)) {
+    throw new Error(`integer out of range: ${value}`);
+  }
+  const out = new Uint8Array(size);
+  for (let i = 0; i < size; i++) {
+    out[i] = Number(n & 0xffn);
+    n >>= 8n;
+  }
+  return out;
+}
+
+function biguintBytes(value: bigint): Uint8Array {
+  if (value < 0n) throw new Error(`negative biguint: ${value}`);
+  const out: number[] = [];
+  do {
+    out.push(Number(value & 0xffn));
+    value >>= 8n;
+  } while (value !== 0n);
+  return Uint8Array.from(out);
+}
+
+function vectorBytes(bytes: Uint8Array): Uint8Array {
+  return concatBytes([uleb128(bytes.length), bytes]);
+}
+
+/** Encoders of the json argument format. */
+export const JsonArg = {
+  bool: (v: boolean): unknown => v,
+  u8: (v: number): unknown => v,
+  u16: (v: number): unknown => v,
+  u32: (v: number): unknown => v,
+  u64: (v: IntegerInput): unknown => BigInt(v).toString(),
+  u128: (v: IntegerInput): unknown => BigInt(v).toString(),
+  u256: (v: IntegerInput): unknown => BigInt(v).toString(),
+  address: (v: string): unknown => v,
+  bytes: (v: BytesInput): unknown => (typeof v === "string" ? stripHexPrefix(v) : bytesToHex(v)),
+  string: (v: string): unknown => v,
+  decimal: (v: DecimalInput): unknown => String(v),
+  biguint: (v: IntegerInput): unknown => BigInt(v).toString(),
+  vector: <T>(v: T[], f: (e: T) => unknown): unknown => v.map(f),
+  option: <T>(v: T | null | undefined, f: (e: T) => unknown): unknown =>
+    v === null || v === undefined ? null : f(v),
+  raw: (v: unknown): unknown => v,
+};
+
+/** Encoders of the bcs argument format. */
+export const BcsArg = {
+  bool: (v: boolean): Uint8Array => Uint8Array.of(v ? 1 : 0),
+  u8: (v: number): Uint8Array => uintBytes(v, 1),
+  u16: (v: number): Uint8Array => uintBytes(v, 2),
+  u32: (v: number): Uint8Array => uintBytes(v, 4),
+  u64: (v: IntegerInput): Uint8Array => uintBytes(v, 8),
+  u128: (v: IntegerInput): Uint8Array => uintBytes(v, 16),
+  u256: (v: IntegerInput): Uint8Array => uintBytes(v, 32),
+  address: (v: string): Uint8Array => {
+    const s = stripHexPrefix(v);
+    if (s.length > 64) throw new Error(`invalid address: ${v}`);
+    return hexToBytes(s.padStart(64, "0"));
+  },
+  bytes: (v: BytesInput): Uint8Array => vectorBytes(typeof v === "string" ? hexToBytes(v) : v),
+  string: (v: string): Uint8Array => vectorBytes(new TextEncoder().encode(v)),
+  fixedPoint32: (v: DecimalInput): Uint8Array => uintBytes(scaleDecimal(v, 1n << 32n), 8),
+  fixedPoint64: (v: DecimalInput): Uint8Array => uintBytes(scaleDecimal(v, 1n << 64n), 16),
+  biguint: (v: IntegerInput): Uint8Array => vectorBytes(biguintBytes(BigInt(v))),
+  bigdecimal: (v: DecimalInput): Uint8Array =>
+    vectorBytes(biguintBytes(scaleDecimal(v, 10n ** 18n))),
+  vector: <T>(v: T[], f: (e: T) => Uint8Array): Uint8Array =>
+    concatBytes([uleb128(v.length), ...v.map(f)]),
+  option: <T>(v: T | null | undefined, f: (e: T) => Uint8Array): Uint8Array =>
+    v === null || v === undefined ? Uint8Array.of(0) : concatBytes([Uint8Array.of(1), f(v)]),
+};
+"#;
+
+/// Words that cannot be used as TypeScript identifiers.
+const RESERVED_WORDS: &[&str] = &[
+    "any",
+    "await",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "never",
+    "new",
+    "null",
+    "number",
+    "object",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "symbol",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// The structs with a dedicated json representation.
+#[derive(Clone, Copy)]
+enum SpecialStruct {
+    String,
+    Object,
+    Option,
+    FixedPoint32,
+    FixedPoint64,
+    BigUint,
+    BigDecimal,
+    JSONValue,
+    JSONObject,
+}
+
+impl SpecialStruct {
+    fn from_tag(tag: &MoveStructTag) -> Option<Self> {
+        if *tag.address.inner() != AccountAddress::ONE {
+            return None;
+        }
+
+        Some(match (tag.module.as_str(), tag.name.as_str()) {
+            ("string", "String") => Self::String,
+            ("object", "Object") => Self::Object,
+            ("option", "Option") => Self::Option,
+            ("fixed_point32", "FixedPoint32") => Self::FixedPoint32,
+            ("fixed_point64", "FixedPoint64") => Self::FixedPoint64,
+            ("biguint", "BigUint") => Self::BigUint,
+            ("bigdecimal", "BigDecimal") => Self::BigDecimal,
+            ("json", "JSONValue") => Self::JSONValue,
+            ("json", "JSONObject") => Self::JSONObject,
+            _ => return None,
+        })
+    }
+}
+
+/// Generates a TypeScript client for the given modules.
+pub fn generate_typescript(modules: &[MoveModule]) -> String {
+    let mut out = String::from(RUNTIME);
+    for module in modules {
+        out.push('\n');
+        ModuleGenerator { module }.generate(&mut out);
+    }
+    out
+}
+
+struct ModuleGenerator<'a> {
+    module: &'a MoveModule,
+}
+
+impl ModuleGenerator<'_> {
+    fn generate(&self, out: &mut String) {
+        let module = self.module;
+        writeln!(
+            out,
+            "export namespace {} {{",
+            identifier(module.name.as_str())
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "  export const MODULE_ADDRESS = \"{}\";",
+            module.address
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "  export const MODULE_NAME = \"{}\";",
+            module.name.as_str()
+        )
+        .unwrap();
+
+        for s in module.structs.iter().filter(|s| !s.is_native) {
+            out.push('\n');
+            self.generate_struct(out, s);
+        }
+
+        for function in &module.exposed_functions {
+            if function.is_entry || function.is_view {
+                out.push('\n');
+                self.generate_function(out, function);
+            }
+        }
+
+        writeln!(out, "}}").unwrap();
+    }
+
+    fn generate_struct(&self, out: &mut String, s: &MoveStruct) {
+        let name = s.name.as_str();
+        let type_params = (0..s.generic_type_params.len())
+            .map(|idx| format!("T{}", idx))
+            .collect::<Vec<_>>();
+        let type_params = if type_params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", type_params.join(", "))
+        };
+
+        if s.fields.is_empty() {
+            // enum variants are not part of the abi, so only the variant keyed
+            // object shape is known.
+            writeln!(
+                out,
+                "  export type {}{} = Record<string, unknown>;",
+                name, type_params
+            )
+            .unwrap();
+        } else {
+            writeln!(out, "  export interface {}{} {{", name, type_params).unwrap();
+            for field in &s.fields {
+                writeln!(
+                    out,
+                    "    {}: {};",
+                    property(json_field_name(field.name.as_str())),
+                    self.output_type(&field.typ, true)
+                )
+                .unwrap();
+            }
+            writeln!(out, "  }}").unwrap();
+        }
+
+        let is_resource = s.abilities.iter().any(|a| a.0 == Ability::Key);
+        if is_resource || s.is_event {
+            writeln!(
+                out,
+                "  export const {}Type = \"{}::{}::{}\";",
+                name,
+                self.module.address,
+                self.module.name.as_str(),
+                name
+            )
+            .unwrap();
+        }
+    }
+
+    fn generate_function(&self, out: &mut String, function: &MoveFunction) {
+        let fn_name = camel_case(function.name.as_str());
+        let params: Vec<&MoveType> = function
+            .params
+            .iter()
+            .skip_while(|ty| ty.is_signer())
+            .collect();
+
+        let mut signature = Vec::new();
+        if !function.generic_type_params.is_empty() {
+            signature.push(format!(
+                "typeArgs: [{}]",
+                vec!["string"; function.generic_type_params.len()].join(", ")
+            ));
+        }
+        for (idx, ty) in params.iter().enumerate() {
+            signature.push(format!("arg{}: {}", idx, self.input_type(ty)));
+        }
+        let signature = signature.join(", ");
+
+        let json_args = params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                format!(
+                    "JSON.stringify({})",
+                    self.json_expr(ty, &format!("arg{}", idx), 0)
+                )
+            })
+            .collect::<Vec<_>>();
+        self.generate_payload(
+            out,
+            function,
+            &format!("{}Json", fn_name),
+            "JsonEntryPayload",
+            &signature,
+            &json_args,
+        );
+
+        // bcs payloads are only generated when every argument can be encoded.
+        let bcs_args = params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| self.bcs_expr(ty, &format!("arg{}", idx), 0))
+            .collect::<Option<Vec<_>>>();
+        if function.is_entry {
+            if let Some(bcs_args) = bcs_args {
+                self.generate_payload(
+                    out,
+                    function,
+                    &format!("{}Bcs", fn_name),
+                    "BcsEntryPayload",
+                    &signature,
+                    &bcs_args,
+                );
+            }
+        }
+
+        if function.is_view {
+            let return_type = match function.return_.as_slice() {
+                [ty] => self.output_type(ty, false),
+                types => format!(
+                    "[{}]",
+                    types
+                        .iter()
+                        .map(|ty| self.output_type(ty, false))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            let mut call_args = (0..params.len())
+                .map(|idx| format!("arg{}", idx))
+                .collect::<Vec<_>>();
+            if !function.generic_type_params.is_empty() {
+                call_args.insert(0, "typeArgs".to_string());
+            }
+            let call_args = call_args.join(", ");
+            let view_signature = if signature.is_empty() {
+                "viewJson: ViewJson".to_string()
+            } else {
+                format!("viewJson: ViewJson, {}", signature)
+            };
+
+            writeln!(
+                out,
+                "  export async function {}({}): Promise<{}> {{",
+                identifier(&fn_name),
+                view_signature,
+                return_type
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "    return JSON.parse(await viewJson({}Json({})));",
+                fn_name, call_args
+            )
+            .unwrap();
+            writeln!(out, "  }}").unwrap();
+        }
+    }
+
+    fn generate_payload(
+        &self,
+        out: &mut String,
+        function: &MoveFunction,
+        name: &str,
+        payload_type: &str,
+        signature: &str,
+        args: &[String],
+    ) {
+        writeln!(
+            out,
+            "  export function {}({}): {} {{",
+            name, signature, payload_type
+        )
+        .unwrap();
+        writeln!(out, "    return {{").unwrap();
+        writeln!(out, "      module_address: MODULE_ADDRESS,").unwrap();
+        writeln!(out, "      module_name: MODULE_NAME,").unwrap();
+        writeln!(out, "      function_name: \"{}\",", function.name.as_str()).unwrap();
+        if function.generic_type_params.is_empty() {
+            writeln!(out, "      type_args: [],").unwrap();
+        } else {
+            writeln!(out, "      type_args: typeArgs,").unwrap();
+        }
+        writeln!(out, "      args: [{}],", args.join(", ")).unwrap();
+        writeln!(out, "    }};").unwrap();
+        writeln!(out, "  }}").unwrap();
+    }
+
+    /// TypeScript type of a function argument.
+    fn input_type(&self, ty: &MoveType) -> String {
+        match ty {
+            MoveType::Bool => "boolean".to_string(),
+            MoveType::U8 | MoveType::U16 | MoveType::U32 => "number".to_string(),
+            MoveType::U64 | MoveType::U128 | MoveType::U256 => "IntegerInput".to_string(),
+            MoveType::Address => "string".to_string(),
+            MoveType::Vector { items } if **items == MoveType::U8 => "BytesInput".to_string(),
+            MoveType::Vector { items } => format!("{}[]", wrap(self.input_type(items))),
+            MoveType::Struct(tag) => match SpecialStruct::from_tag(tag) {
+                Some(SpecialStruct::String) | Some(SpecialStruct::Object) => "string".to_string(),
+                Some(SpecialStruct::Option) => match tag.generic_type_params.first() {
+                    Some(ty) => format!("{} | null", self.input_type(ty)),
+                    None => "unknown".to_string(),
+                },
+                Some(SpecialStruct::FixedPoint32)
+                | Some(SpecialStruct::FixedPoint64)
+                | Some(SpecialStruct::BigDecimal) => "DecimalInput".to_string(),
+                Some(SpecialStruct::BigUint) => "IntegerInput".to_string(),
+                _ => "unknown".to_string(),
+            },
+            MoveType::Reference { to, .. } => self.input_type(to),
+            MoveType::Signer
+            | MoveType::Function { .. }
+            | MoveType::GenericTypeParam { .. }
+            | MoveType::Unparsable(_) => "unknown".to_string(),
+        }
+    }
+
+    /// TypeScript type of a json encoded value. Generic type params are named
+    /// only within struct definitions.
+    fn output_type(&self, ty: &MoveType, in_struct: bool) -> String {
+        match ty {
+            MoveType::Bool => "boolean".to_string(),
+            MoveType::U8 | MoveType::U16 | MoveType::U32 => "number".to_string(),
+            MoveType::U64 | MoveType::U128 | MoveType::U256 => "string".to_string(),
+            MoveType::Address | MoveType::Signer => "string".to_string(),
+            MoveType::Vector { items } if **items == MoveType::U8 => "string".to_string(),
+            MoveType::Vector { items } => {
+                format!("{}[]", wrap(self.output_type(items, in_struct)))
+            }
+            MoveType::Struct(tag) => match SpecialStruct::from_tag(tag) {
+                Some(SpecialStruct::Option) => match tag.generic_type_params.first() {
+                    Some(ty) => format!("{} | null", self.output_type(ty, in_struct)),
+                    None => "unknown".to_string(),
+                },
+                Some(SpecialStruct::JSONValue) => "unknown".to_string(),
+                Some(SpecialStruct::JSONObject) => "Record<string, unknown>".to_string(),
+                Some(_) => "string".to_string(),
+                None if self.is_local(tag) => {
+                    let mut name = tag.name.as_str().to_string();
+                    if !tag.generic_type_params.is_empty() {
+                        let args = tag
+                            .generic_type_params
+                            .iter()
+                            .map(|ty| self.output_type(ty, in_struct))
+                            .collect::<Vec<_>>();
+                        write!(name, "<{}>", args.join(", ")).unwrap();
+                    }
+                    name
+                }
+                None => "Record<string, unknown>".to_string(),
+            },
+            MoveType::Reference { to, .. } => self.output_type(to, in_struct),
+            MoveType::GenericTypeParam { index } if in_struct => format!("T{}", index),
+            MoveType::GenericTypeParam { .. }
+            | MoveType::Function { .. }
+            | MoveType::Unparsable(_) => "unknown".to_string(),
+        }
+    }
+
+    /// Expression converting `var` into its json argument value.
+    fn json_expr(&self, ty: &MoveType, var: &str, depth: usize) -> String {
+        let encoder = match ty {
+            MoveType::Bool => "bool",
+            MoveType::U8 => "u8",
+            MoveType::U16 => "u16",
+            MoveType::U32 => "u32",
+            MoveType::U64 => "u64",
+            MoveType::U128 => "u128",
+            MoveType::U256 => "u256",
+            MoveType::Address => "address",
+            MoveType::Vector { items } if **items == MoveType::U8 => "bytes",
+            MoveType::Vector { items } => {
+                let elem = format!("v{}", depth);
+                return format!(
+                    "JsonArg.vector({}, ({}) => {})",
+                    var,
+                    elem,
+                    self.json_expr(items, &elem, depth + 1)
+                );
+            }
+            MoveType::Struct(tag) => match SpecialStruct::from_tag(tag) {
+                Some(SpecialStruct::String) => "string",
+                Some(SpecialStruct::Object) => "address",
+                Some(SpecialStruct::Option) => match tag.generic_type_params.first() {
+                    Some(ty) => {
+                        let elem = format!("v{}", depth);
+                        return format!(
+                            "JsonArg.option({}, ({}) => {})",
+                            var,
+                            elem,
+                            self.json_expr(ty, &elem, depth + 1)
+                        );
+                    }
+                    None => "raw",
+                },
+                Some(SpecialStruct::FixedPoint32)
+                | Some(SpecialStruct::FixedPoint64)
+                | Some(SpecialStruct::BigDecimal) => "decimal",
+                Some(SpecialStruct::BigUint) => "biguint",
+                _ => "raw",
+            },
+            MoveType::Reference { to, .. } => return self.json_expr(to, var, depth),
+            _ => "raw",
+        };
+        format!("JsonArg.{}({})", encoder, var)
+    }
+
+    /// Expression converting `var` into its bcs argument bytes, if the type
+    /// has a known bcs encoding.
+    fn bcs_expr(&self, ty: &MoveType, var: &str, depth: usize) -> Option<String> {
+        let encoder = match ty {
+            MoveType::Bool => "bool",
+            MoveType::U8 => "u8",
+            MoveType::U16 => "u16",
+            MoveType::U32 => "u32",
+            MoveType::U64 => "u64",
+            MoveType::U128 => "u128",
+            MoveType::U256 => "u256",
+            MoveType::Address => "address",
+            MoveType::Vector { items } if **items == MoveType::U8 => "bytes",
+            MoveType::Vector { items } => {
+                let elem = format!("v{}", depth);
+                return Some(format!(
+                    "BcsArg.vector({}, ({}) => {})",
+                    var,
+                    elem,
+                    self.bcs_expr(items, &elem, depth + 1)?
+                ));
+            }
+            MoveType::Struct(tag) => match SpecialStruct::from_tag(tag)? {
+                SpecialStruct::String => "string",
+                SpecialStruct::Object => "address",
+                SpecialStruct::Option => {
+                    let elem = format!("v{}", depth);
+                    return Some(format!(
+                        "BcsArg.option({}, ({}) => {})",
+                        var,
+                        elem,
+                        self.bcs_expr(tag.generic_type_params.first()?, &elem, depth + 1)?
+                    ));
+                }
+                SpecialStruct::FixedPoint32 => "fixedPoint32",
+                SpecialStruct::FixedPoint64 => "fixedPoint64",
+                SpecialStruct::BigUint => "biguint",
+                SpecialStruct::BigDecimal => "bigdecimal",
+                SpecialStruct::JSONValue | SpecialStruct::JSONObject => return None,
+            },
+            MoveType::Reference { to, .. } => return self.bcs_expr(to, var, depth),
+            _ => return None,
+        };
+        Some(format!("BcsArg.{}({})", encoder, var))
+    }
+
+    fn is_local(&self, tag: &MoveStructTag) -> bool {
+        tag.address == self.module.address
+            && tag.module == self.module.name
+            && self.module.structs.iter().any(|s| s.name == tag.name)
+    }
+}
+
+/// Field names that collide with the keys of the json serializer are renamed.
+fn json_field_name(name: &str) -> &str {
+    match name {
+        "_type_" => "@type",
+        "_move_" => "move",
+        "_signer_" => "signer",
+        name => name,
+    }
+}
+
+fn identifier(name: &str) -> String {
+    if RESERVED_WORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn property(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        && !name.starts_with(|c: char| c.is_ascii_digit());
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Parenthesizes union types used as array elements.
+fn wrap(ty: String) -> String {
+    if ty.contains(" | ") {
+        format!("({})", ty)
+    } else {
+        ty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        address::Address,
+        move_types::{
+            MoveAbility, MoveFunctionVisibility, MoveStructField, MoveStructGenericTypeParam,
+        },
+        wrappers::IdentifierWrapper,
+    };
+
+    fn ident(s: &str) -> IdentifierWrapper {
+        IdentifierWrapper::from_str(s).unwrap()
+    }
+
+    fn std_struct(module: &str, name: &str, params: Vec<MoveType>) -> MoveType {
+        MoveType::Struct(MoveStructTag {
+            address: AccountAddress::ONE.into(),
+            module: ident(module),
+            name: ident(name),
+            generic_type_params: params,
+        })
+    }
+
+    fn test_module() -> MoveModule {
+        let address = Address::from_str("0x2").unwrap();
+        MoveModule {
+            address,
+            name: ident("vault"),
+            friends: vec![],
+            exposed_functions: vec![
+                MoveFunction {
+                    name: ident("deposit_all"),
+                    visibility: MoveFunctionVisibility::Public,
+                    is_entry: true,
+                    is_view: false,
+                    generic_type_params: vec![],
+                    params: vec![
+                        MoveType::Reference {
+                            mutable: false,
+                            to: Box::new(MoveType::Signer),
+                        },
+                        MoveType::Vector {
+                            items: Box::new(MoveType::U64),
+                        },
+                        std_struct(
+                            "option",
+                            "Option",
+                            vec![std_struct("string", "String", vec![])],
+                        ),
+                    ],
+                    return_: vec![],
+                },
+                MoveFunction {
+                    name: ident("get_vault"),
+                    visibility: MoveFunctionVisibility::Public,
+                    is_entry: false,
+                    is_view: true,
+                    generic_type_params: vec![],
+                    params: vec![MoveType::Address],
+                    return_: vec![MoveType::Struct(MoveStructTag {
+                        address,
+                        module: ident("vault"),
+                        name: ident("Vault"),
+                        generic_type_params: vec![MoveType::U8],
+                    })],
+                },
+            ],
+            structs: vec![MoveStruct {
+                name: ident("Vault"),
+                is_native: false,
+                is_event: false,
+                abilities: vec![MoveAbility(Ability::Key)],
+                generic_type_params: vec![MoveStructGenericTypeParam {
+                    constraints: vec![],
+                    is_phantom: false,
+                }],
+                fields: vec![
+                    MoveStructField {
+                        name: ident("_type_"),
+                        typ: MoveType::GenericTypeParam { index: 0 },
+                    },
+                    MoveStructField {
+                        name: ident("amounts"),
+                        typ: MoveType::Vector {
+                            items: Box::new(std_struct("option", "Option", vec![MoveType::U128])),
+                        },
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_generate_typescript() {
+        let ts = generate_typescript(&[test_module()]);
+        assert!(ts.starts_with(RUNTIME));
+
+        let generated = &ts[RUNTIME.len()..];
+        assert!(generated.contains("export namespace vault {"));
+        assert!(generated.contains("  export const MODULE_ADDRESS = \"0x2\";"));
+        assert!(generated.contains(
+            "  export interface Vault<T0> {\n    \"@type\": T0;\n    amounts: (string | null)[];"
+        ));
+        assert!(generated.contains("  export const VaultType = \"0x2::vault::Vault\";"));
+        assert!(generated.contains(
+            "  export function depositAllJson(arg0: IntegerInput[], arg1: string | null): JsonEntryPayload {"
+        ));
+        assert!(generated.contains(
+            "      args: [JSON.stringify(JsonArg.vector(arg0, (v0) => JsonArg.u64(v0))), \
+             JSON.stringify(JsonArg.option(arg1, (v0) => JsonArg.string(v0)))],"
+        ));
+        assert!(generated.contains(
+            "      args: [BcsArg.vector(arg0, (v0) => BcsArg.u64(v0)), \
+             BcsArg.option(arg1, (v0) => BcsArg.string(v0))],"
+        ));
+        assert!(generated.contains(
+            "  export async function getVault(viewJson: ViewJson, arg0: string): Promise<Vault<number>> {"
+        ));
+        assert!(generated.contains("    return JSON.parse(await viewJson(getVaultJson(arg0)));"));
+        // view functions do not get bcs payload builders
+        assert!(!generated.contains("getVaultBcs"));
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(camel_case("get_balance_of"), "getBalanceOf");
+        assert_eq!(camel_case("_private"), "_private");
+        assert_eq!(identifier("object"), "object_");
+        assert_eq!(property("@type"), "\"@type\"");
+        assert_eq!(property("value"), "value");
+    }
+}
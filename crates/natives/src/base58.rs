@@ -0,0 +1,172 @@
+use move_core_types::gas_algebra::NumBytes;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+use crate::{
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
+    safely_pop_arg,
+};
+
+// See stdlib/error.move
+const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+
+// native errors always start from 100
+const UNABLE_TO_DECODE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
+
+/// Base58 is a base conversion, so the work grows with the square of the input length.
+fn squared_len(len: usize) -> NumBytes {
+    NumBytes::new((len as u64).saturating_mul(len as u64))
+}
+
+/***************************************************************************************************
+ * native fun encode
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len + squared_unit_cost * bytes_len^2
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_encode(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.base58_encode_base
+            + gas_params.base58_encode_unit * NumBytes::new(bytes.len() as u64)
+            + gas_params.base58_encode_squared_unit * squared_len(bytes.len()),
+    )?;
+
+    let val = bs58::encode(bytes).into_string();
+    Ok(smallvec![Value::vector_u8(val.into_bytes())])
+}
+
+/***************************************************************************************************
+ * native fun decode
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len + squared_unit_cost * bytes_len^2
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_decode(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.base58_decode_base
+            + gas_params.base58_decode_unit * NumBytes::new(bytes.len() as u64)
+            + gas_params.base58_decode_squared_unit * squared_len(bytes.len()),
+    )?;
+
+    let val = match bs58::decode(bytes).into_vec() {
+        Ok(val) => val,
+        Err(_err) => {
+            return Err(SafeNativeError::Abort {
+                abort_code: UNABLE_TO_DECODE,
+            })
+        }
+    };
+
+    Ok(smallvec![Value::vector_u8(val)])
+}
+
+/***************************************************************************************************
+ * native fun encode_check
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len + squared_unit_cost * bytes_len^2
+ *
+ *   Appends the first four bytes of sha256(sha256(bytes)) before encoding.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_encode_check(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.base58_encode_base
+            + gas_params.base58_encode_unit * NumBytes::new(bytes.len() as u64)
+            + gas_params.base58_encode_squared_unit * squared_len(bytes.len()),
+    )?;
+
+    let val = bs58::encode(bytes).with_check().into_string();
+    Ok(smallvec![Value::vector_u8(val.into_bytes())])
+}
+
+/***************************************************************************************************
+ * native fun decode_check
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len + squared_unit_cost * bytes_len^2
+ *
+ *   Verifies and strips the trailing four byte checksum after decoding.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_decode_check(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.base58_decode_base
+            + gas_params.base58_decode_unit * NumBytes::new(bytes.len() as u64)
+            + gas_params.base58_decode_squared_unit * squared_len(bytes.len()),
+    )?;
+
+    let val = match bs58::decode(bytes).with_check(None).into_vec() {
+        Ok(val) => val,
+        Err(_err) => {
+            return Err(SafeNativeError::Abort {
+                abort_code: UNABLE_TO_DECODE,
+            })
+        }
+    };
+
+    Ok(smallvec![Value::vector_u8(val)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        ("encode", native_encode as RawSafeNative),
+        ("decode", native_decode),
+        ("encode_check", native_encode_check),
+        ("decode_check", native_decode_check),
+    ];
+
+    builder.make_named_natives(natives)
+}
@@ -1,4 +1,4 @@
-use bech32::{Bech32, Hrp};
+use bech32::{primitives::decode::CheckedHrpstring, segwit, Bech32, Bech32m, Fe32, Hrp};
 use move_core_types::gas_algebra::NumBytes;
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
@@ -24,10 +24,15 @@ const EUNABLE_TO_ENCODE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
 const EUNABLE_TO_DECODE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 101;
 const EINVALID_PREFIX: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 102;
 const EINVALID_ADDRESS: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 103;
+const EINVALID_WITNESS_VERSION: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 104;
 
 /*
     native public fun encode(prefix: String, data: vector<u8>): String;
     native public fun decode(addr: String): (String, vector<u8>);
+    native public fun encode_bech32m(prefix: String, data: vector<u8>): String;
+    native public fun decode_bech32m(addr: String): (String, vector<u8>);
+    native public fun encode_segwit(prefix: String, version: u8, program: vector<u8>): String;
+    native public fun decode_segwit(addr: String): (String, u8, vector<u8>);
 */
 
 /***************************************************************************************************
@@ -111,6 +116,181 @@ fn native_decode(
     ])
 }
 
+/***************************************************************************************************
+ * native fun encode_bech32m
+ *
+ *   gas cost: base_cost + unit_cost * (prefix_len + data_len)
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_encode_bech32m(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let data = safely_pop_arg!(arguments, Vec<u8>);
+    let raw_prefix = get_string(safely_pop_arg!(arguments, Struct))?;
+    let prefix = String::from_utf8(raw_prefix).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_PREFIX,
+    })?;
+    context.charge(
+        gas_params.bech32_encode_base
+            + gas_params.bech32_encode_unit * NumBytes::new((prefix.len() + data.len()) as u64),
+    )?;
+
+    let encoded_string = bech32::encode::<Bech32m>(
+        Hrp::parse(prefix.as_str()).map_err(|_| SafeNativeError::Abort {
+            abort_code: EINVALID_PREFIX,
+        })?,
+        data.as_slice(),
+    )
+    .map_err(|_| SafeNativeError::Abort {
+        abort_code: EUNABLE_TO_ENCODE,
+    })?;
+
+    Ok(smallvec![Value::struct_(Struct::pack(vec![
+        Value::vector_u8(encoded_string.as_bytes().to_vec()),
+    ]))])
+}
+
+/***************************************************************************************************
+ * native fun decode_bech32m
+ *
+ *   gas cost: base_cost + unit_cost * address_len
+ *
+ *   Unlike `decode`, only accepts addresses with a bech32m checksum.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_decode_bech32m(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let raw_addr = get_string(safely_pop_arg!(arguments, Struct))?;
+    let addr = String::from_utf8(raw_addr).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_ADDRESS,
+    })?;
+
+    context.charge(
+        gas_params.bech32_decode_base
+            + gas_params.bech32_decode_unit * NumBytes::new(addr.len() as u64),
+    )?;
+
+    let checked =
+        CheckedHrpstring::new::<Bech32m>(addr.as_str()).map_err(|_| SafeNativeError::Abort {
+            abort_code: EUNABLE_TO_DECODE,
+        })?;
+    let words: Vec<u8> = checked.byte_iter().collect();
+
+    Ok(smallvec![
+        Value::struct_(Struct::pack(vec![Value::vector_u8(
+            checked.hrp().as_bytes().to_vec()
+        )])),
+        Value::vector_u8(words)
+    ])
+}
+
+/***************************************************************************************************
+ * native fun encode_segwit
+ *
+ *   gas cost: base_cost + unit_cost * (prefix_len + program_len)
+ *
+ *   Encodes a segwit address; version 0 uses bech32 and versions 1..=16 use bech32m.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_encode_segwit(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 3);
+
+    let program = safely_pop_arg!(arguments, Vec<u8>);
+    let version = safely_pop_arg!(arguments, u8);
+    let raw_prefix = get_string(safely_pop_arg!(arguments, Struct))?;
+    let prefix = String::from_utf8(raw_prefix).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_PREFIX,
+    })?;
+    context.charge(
+        gas_params.bech32_encode_base
+            + gas_params.bech32_encode_unit * NumBytes::new((prefix.len() + program.len()) as u64),
+    )?;
+
+    let version = Fe32::try_from(version).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_WITNESS_VERSION,
+    })?;
+    let encoded_string = segwit::encode(
+        Hrp::parse(prefix.as_str()).map_err(|_| SafeNativeError::Abort {
+            abort_code: EINVALID_PREFIX,
+        })?,
+        version,
+        program.as_slice(),
+    )
+    .map_err(|_| SafeNativeError::Abort {
+        abort_code: EUNABLE_TO_ENCODE,
+    })?;
+
+    Ok(smallvec![Value::struct_(Struct::pack(vec![
+        Value::vector_u8(encoded_string.as_bytes().to_vec()),
+    ]))])
+}
+
+/***************************************************************************************************
+ * native fun decode_segwit
+ *
+ *   gas cost: base_cost + unit_cost * address_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_decode_segwit(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let raw_addr = get_string(safely_pop_arg!(arguments, Struct))?;
+    let addr = String::from_utf8(raw_addr).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_ADDRESS,
+    })?;
+
+    context.charge(
+        gas_params.bech32_decode_base
+            + gas_params.bech32_decode_unit * NumBytes::new(addr.len() as u64),
+    )?;
+
+    let (prefix, version, program) =
+        segwit::decode(addr.as_str()).map_err(|_| SafeNativeError::Abort {
+            abort_code: EUNABLE_TO_DECODE,
+        })?;
+
+    Ok(smallvec![
+        Value::struct_(Struct::pack(vec![Value::vector_u8(
+            prefix.as_bytes().to_vec()
+        )])),
+        Value::u8(version.to_u8()),
+        Value::vector_u8(program)
+    ])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -121,6 +301,10 @@ pub fn make_all(
     let natives = [
         ("encode", native_encode as RawSafeNative),
         ("decode", native_decode),
+        ("encode_bech32m", native_encode_bech32m),
+        ("decode_bech32m", native_decode_bech32m),
+        ("encode_segwit", native_encode_segwit),
+        ("decode_segwit", native_decode_segwit),
     ];
 
     builder.make_named_natives(natives)
@@ -21,6 +21,7 @@ const NEGATIVE_RESULT: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
 const DIVISION_BY_ZERO: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 101;
 const CAST_OVERFLOW: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 102;
 const INVALID_NUMERIC_TYPE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 103;
+const EXPONENT_TOO_LARGE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 104;
 
 /***************************************************************************************************
  * native fun add
@@ -162,6 +163,358 @@ fn native_div(
     Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
 }
 
+/***************************************************************************************************
+ * native fun mod
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_mod(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let num2_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num1_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.biguint_mod_base
+            + gas_params.biguint_mod_per_byte
+                * NumBytes::new((num1_bytes.len() + num2_bytes.len()) as u64),
+    )?;
+
+    let num1 = BigUint::from_bytes_le(&num1_bytes);
+    let num2 = BigUint::from_bytes_le(&num2_bytes);
+
+    if num2.is_zero() {
+        return Err(SafeNativeError::Abort {
+            abort_code: DIVISION_BY_ZERO,
+        });
+    }
+
+    let result = num1 % num2;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun pow
+ *
+ *   gas cost: base_cost + unit_cost * (bytes_len + result_bytes_len)
+ *              + squared_unit_cost * result_bytes_len^2
+ *
+ *   The result length is estimated from the bit length of the base before the
+ *   exponentiation is performed, so oversized results run out of gas up front.
+ *   Repeated squaring is dominated by the last multiplication, which grows with
+ *   the square of the result length.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_pow(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let exp = safely_pop_arg!(arguments, u64);
+    let num_bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    let num = BigUint::from_bytes_le(&num_bytes);
+
+    // 0^exp and 1^exp never grow, so only charge for reading the input
+    let result_bytes_len = if num.bits() <= 1 {
+        0
+    } else {
+        num.bits().saturating_mul(exp).div_ceil(8)
+    };
+    context.charge(
+        gas_params.biguint_pow_base
+            + gas_params.biguint_pow_per_byte
+                * NumBytes::new((num_bytes.len() as u64).saturating_add(result_bytes_len))
+            + gas_params.biguint_pow_per_byte_squared
+                * NumBytes::new(result_bytes_len.saturating_mul(result_bytes_len)),
+    )?;
+
+    let result = if exp == 0 {
+        BigUint::from(1u8)
+    } else if num.bits() <= 1 {
+        num
+    } else {
+        let exp: u32 = match exp.try_into() {
+            Ok(exp) => exp,
+            Err(_) => {
+                return Err(SafeNativeError::Abort {
+                    abort_code: EXPONENT_TOO_LARGE,
+                })
+            }
+        };
+
+        num.pow(exp)
+    };
+
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun modpow
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *              + squared_unit_cost * modulus_bytes_len^2 * exp_bits
+ *
+ *   Each bit of the exponent costs at most one modular squaring and one modular
+ *   multiplication over numbers of the modulus size, and each of them grows with
+ *   the square of the modulus length.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_modpow(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 3);
+
+    let modulus_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let exp_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num_bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    let num = BigUint::from_bytes_le(&num_bytes);
+    let exp = BigUint::from_bytes_le(&exp_bytes);
+    let modulus = BigUint::from_bytes_le(&modulus_bytes);
+
+    let modulus_bytes_len = modulus_bytes.len() as u64;
+    context.charge(
+        gas_params.biguint_modpow_base
+            + gas_params.biguint_modpow_per_byte
+                * NumBytes::new((num_bytes.len() + exp_bytes.len() + modulus_bytes.len()) as u64)
+            + gas_params.biguint_modpow_per_byte_squared
+                * NumBytes::new(
+                    modulus_bytes_len
+                        .saturating_mul(modulus_bytes_len)
+                        .saturating_mul(exp.bits()),
+                ),
+    )?;
+
+    if modulus.is_zero() {
+        return Err(SafeNativeError::Abort {
+            abort_code: DIVISION_BY_ZERO,
+        });
+    }
+
+    let result = num.modpow(&exp, &modulus);
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun sqrt
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len + squared_unit_cost * bytes_len^2
+ *
+ *   Returns the integer square root, rounded down. Each Newton iteration divides
+ *   numbers of the operand size, so the cost grows quadratically with its length.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_sqrt(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 1);
+
+    let num_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num_bytes_len = num_bytes.len() as u64;
+    context.charge(
+        gas_params.biguint_sqrt_base
+            + gas_params.biguint_sqrt_per_byte * NumBytes::new(num_bytes_len)
+            + gas_params.biguint_sqrt_per_byte_squared
+                * NumBytes::new(num_bytes_len.saturating_mul(num_bytes_len)),
+    )?;
+
+    let num = BigUint::from_bytes_le(&num_bytes);
+
+    let result = num.sqrt();
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun shl
+ *
+ *   gas cost: base_cost + unit_cost * (bytes_len + shift / 8)
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_shl(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let shift = safely_pop_arg!(arguments, u64);
+    let num_bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    let num = BigUint::from_bytes_le(&num_bytes);
+
+    // shifting zero never grows the number
+    let result_bytes = if num.is_zero() { 0 } else { shift / 8 };
+    context.charge(
+        gas_params.biguint_shl_base
+            + gas_params.biguint_shl_per_byte
+                * NumBytes::new((num_bytes.len() as u64).saturating_add(result_bytes)),
+    )?;
+
+    if num.is_zero() {
+        return Ok(smallvec![Value::vector_u8(num.to_bytes_le())]);
+    }
+
+    let result = num << shift;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun shr
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_shr(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let shift = safely_pop_arg!(arguments, u64);
+    let num_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.biguint_shr_base
+            + gas_params.biguint_shr_per_byte * NumBytes::new(num_bytes.len() as u64),
+    )?;
+
+    let num = BigUint::from_bytes_le(&num_bytes);
+
+    let result = num >> shift;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun and
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_and(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let num2_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num1_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.biguint_and_base
+            + gas_params.biguint_and_per_byte
+                * NumBytes::new((num1_bytes.len() + num2_bytes.len()) as u64),
+    )?;
+
+    let num1 = BigUint::from_bytes_le(&num1_bytes);
+    let num2 = BigUint::from_bytes_le(&num2_bytes);
+
+    let result = num1 & num2;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun or
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_or(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let num2_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num1_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.biguint_or_base
+            + gas_params.biguint_or_per_byte
+                * NumBytes::new((num1_bytes.len() + num2_bytes.len()) as u64),
+    )?;
+
+    let num1 = BigUint::from_bytes_le(&num1_bytes);
+    let num2 = BigUint::from_bytes_le(&num2_bytes);
+
+    let result = num1 | num2;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
+/***************************************************************************************************
+ * native fun xor
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_xor(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 2);
+
+    let num2_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let num1_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.biguint_xor_base
+            + gas_params.biguint_xor_per_byte
+                * NumBytes::new((num1_bytes.len() + num2_bytes.len()) as u64),
+    )?;
+
+    let num1 = BigUint::from_bytes_le(&num1_bytes);
+    let num2 = BigUint::from_bytes_le(&num2_bytes);
+
+    let result = num1 ^ num2;
+    Ok(smallvec![Value::vector_u8(result.to_bytes_le())])
+}
+
 #[allow(clippy::result_large_err)]
 fn native_new(
     context: &mut SafeNativeContext,
@@ -456,6 +809,15 @@ pub fn make_all(
         ("sub_internal", native_sub),
         ("mul_internal", native_mul),
         ("div_internal", native_div),
+        ("mod_internal", native_mod),
+        ("pow_internal", native_pow),
+        ("modpow_internal", native_modpow),
+        ("sqrt_internal", native_sqrt),
+        ("shl_internal", native_shl),
+        ("shr_internal", native_shr),
+        ("and_internal", native_and),
+        ("or_internal", native_or),
+        ("xor_internal", native_xor),
         ("new_internal", native_new),
         ("cast_internal", native_cast),
         ("eq_internal", native_eq),
@@ -1,4 +1,5 @@
 use better_any::{Tid, TidAble};
+use move_core_types::account_address::AccountAddress;
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -16,14 +17,24 @@ pub struct NativeBlockContext {
     pub height: u64,
     /// nanoseconds since UNIX_EPOCH
     pub timestamp_nanos: u64,
+    pub block_hash: [u8; 32],
+    pub proposer: AccountAddress,
 }
 
 impl NativeBlockContext {
-    pub fn new(chain_id: String, height: u64, timestamp_nanos: u64) -> Self {
+    pub fn new(
+        chain_id: String,
+        height: u64,
+        timestamp_nanos: u64,
+        block_hash: [u8; 32],
+        proposer: AccountAddress,
+    ) -> Self {
         Self {
             chain_id,
             height,
             timestamp_nanos,
+            block_hash,
+            proposer,
         }
     }
 
@@ -65,6 +76,24 @@ impl NativeBlockContext {
     pub fn get_block_timestamp_nanos(&self) -> u64 {
         self.timestamp_nanos
     }
+
+    #[cfg(feature = "testing")]
+    pub fn set_block_hash(&mut self, block_hash: [u8; 32]) {
+        self.block_hash = block_hash;
+    }
+
+    pub fn get_block_hash(&self) -> [u8; 32] {
+        self.block_hash
+    }
+
+    #[cfg(feature = "testing")]
+    pub fn set_proposer(&mut self, proposer: AccountAddress) {
+        self.proposer = proposer;
+    }
+
+    pub fn get_proposer(&self) -> AccountAddress {
+        self.proposer
+    }
 }
 
 #[allow(clippy::result_large_err)]
@@ -114,6 +143,34 @@ fn native_get_block_info_nanos(
     ])
 }
 
+#[allow(clippy::result_large_err)]
+fn native_get_block_hash(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.block_get_block_hash_base_cost)?;
+
+    let block_context = context.extensions().get::<NativeBlockContext>();
+    Ok(smallvec![Value::vector_u8(
+        block_context.get_block_hash().to_vec()
+    )])
+}
+
+#[allow(clippy::result_large_err)]
+fn native_get_proposer(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.block_get_proposer_base_cost)?;
+
+    let block_context = context.extensions().get::<NativeBlockContext>();
+    Ok(smallvec![Value::address(block_context.get_proposer())])
+}
+
 #[cfg(feature = "testing")]
 #[allow(clippy::result_large_err)]
 fn native_test_only_set_block_info(
@@ -178,6 +235,49 @@ fn native_test_only_set_chain_id(
     Ok(smallvec![])
 }
 
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_block_hash(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use crate::{helpers::partial_extension_error, safely_pop_arg};
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let block_hash = safely_pop_arg!(arguments, Vec<u8>);
+    let block_hash: [u8; 32] = block_hash
+        .try_into()
+        .map_err(|_| partial_extension_error("block hash must be 32 bytes"))?;
+
+    let block_context = context.extensions_mut().get_mut::<NativeBlockContext>();
+    block_context.set_block_hash(block_hash);
+
+    Ok(smallvec![])
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_proposer(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use crate::safely_pop_arg;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let proposer = safely_pop_arg!(arguments, AccountAddress);
+
+    let block_context = context.extensions_mut().get_mut::<NativeBlockContext>();
+    block_context.set_proposer(proposer);
+
+    Ok(smallvec![])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -192,6 +292,8 @@ pub fn make_all(
         native_get_block_info_nanos as RawSafeNative,
     )]);
     natives.extend([("get_chain_id", native_get_chain_id as RawSafeNative)]);
+    natives.extend([("get_block_hash", native_get_block_hash as RawSafeNative)]);
+    natives.extend([("get_proposer", native_get_proposer as RawSafeNative)]);
 
     #[cfg(feature = "testing")]
     natives.extend([
@@ -207,6 +309,14 @@ pub fn make_all(
             "set_chain_id_for_test",
             native_test_only_set_chain_id as RawSafeNative,
         ),
+        (
+            "set_block_hash_for_test",
+            native_test_only_set_block_hash as RawSafeNative,
+        ),
+        (
+            "set_proposer_for_test",
+            native_test_only_set_proposer as RawSafeNative,
+        ),
     ]);
 
     builder.make_named_natives(natives)
@@ -1,20 +1,252 @@
 use better_any::{Tid, TidAble};
-use initia_move_types::cosmos::{CosmosMessage, CosmosMessages};
-use move_core_types::{account_address::AccountAddress, gas_algebra::NumBytes};
+use initia_move_gas::{InternalGas, GAS_UNIT_SCALING_FACTOR};
+use initia_move_types::{
+    cosmos::{CosmosCallback, CosmosMessage, CosmosMessages},
+    errors::BackendError,
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::NumBytes,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+};
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     values::{Struct, Value, Vector},
 };
+use serde::Deserialize;
 use smallvec::{smallvec, SmallVec};
-use std::{cell::RefCell, collections::VecDeque};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, VecDeque},
+};
 
 use crate::{
-    helpers::get_stargate_options,
-    interface::{RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult},
+    helpers::{get_stargate_options, partial_extension_error},
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
+    query::NativeQueryContext,
     safely_pop_arg,
 };
 
+/***************************************************************************************************
+ * native fun dispatch_internal
+ *
+ *   gas cost: base_cost + unit_cost * data_len + gas used by the host
+ *
+ *   Executes the message synchronously and returns (success, response, error).
+ *   When `allow_failure` is false, a failed message aborts the move execution,
+ *   which rolls back every move state change made so far.
+ *
+ *   Only allowed in sessions executing an entry function or a script, and not
+ *   from a move execution the host started while dispatching another message.
+ *   The message type must be in the allowed dispatch messages of the vm config.
+ *
+ *   Only message failures are returned to the caller; host failures such as a
+ *   panic abort the move execution.
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_dispatch(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let allow_failure = safely_pop_arg!(arguments, bool);
+    let data = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    let sender: AccountAddress = safely_pop_arg!(arguments, AccountAddress);
+
+    if !context
+        .extensions()
+        .get::<NativeCosmosContext>()
+        .dispatch_allowed
+    {
+        return Err(SafeNativeError::Abort {
+            abort_code: EDISPATCH_NOT_ALLOWED,
+        });
+    }
+
+    // charge gas before execution
+    context.charge(
+        gas_params.cosmos_dispatch_base
+            + gas_params.cosmos_dispatch_per_byte * NumBytes::new(data.len() as u64),
+    )?;
+
+    let cosmos_context = context.extensions().get::<NativeCosmosContext>();
+
+    #[cfg(feature = "testing")]
+    let mocked = cosmos_context.dispatch_responses.get(&data).cloned();
+    #[cfg(not(feature = "testing"))]
+    let mocked: Option<(bool, Vec<u8>)> = None;
+
+    let res = match mocked {
+        Some((true, response)) => Ok(response),
+        Some((false, error)) => Err(String::from_utf8_lossy(&error).to_string()),
+        None => {
+            if !cosmos_context.is_allowed_message(&data) {
+                return Err(SafeNativeError::Abort {
+                    abort_code: EDISPATCH_MESSAGE_NOT_ALLOWED,
+                });
+            }
+
+            let _guard = DispatchGuard::enter().ok_or(SafeNativeError::Abort {
+                abort_code: ENESTED_DISPATCH,
+            })?;
+
+            let gas_balance: u64 = context.gas_balance().into();
+            let (res, used_gas) = cosmos_context.api.dispatch_message(
+                sender,
+                &data,
+                gas_balance / GAS_UNIT_SCALING_FACTOR,
+            );
+            let used_gas = InternalGas::from(used_gas.saturating_mul(GAS_UNIT_SCALING_FACTOR));
+            context.charge(used_gas)?;
+
+            match res {
+                Ok(response) => Ok(response),
+                Err(err) => match err.downcast_ref::<BackendError>() {
+                    Some(BackendError::UserErr { msg }) => Err(msg.clone()),
+                    _ => {
+                        return Err(SafeNativeError::InvariantViolation(
+                            partial_extension_error(err),
+                        ))
+                    }
+                },
+            }
+        }
+    };
+
+    // the message changed the host state, so cached query responses are stale
+    if res.is_ok() {
+        context
+            .extensions_mut()
+            .get_mut::<NativeQueryContext>()
+            .clear_cache();
+    }
+
+    match res {
+        Ok(response) => Ok(smallvec![
+            Value::bool(true),
+            Value::vector_u8(response),
+            Value::vector_u8(vec![])
+        ]),
+        Err(_) if !allow_failure => Err(SafeNativeError::Abort {
+            abort_code: EDISPATCH_FAILED,
+        }),
+        Err(err) => Ok(smallvec![
+            Value::bool(false),
+            Value::vector_u8(vec![]),
+            Value::vector_u8(err.into_bytes())
+        ]),
+    }
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_dispatch_response(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let output = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    let success = safely_pop_arg!(arguments, bool);
+    let data = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    let cosmos_context = context.extensions_mut().get_mut::<NativeCosmosContext>();
+    cosmos_context
+        .dispatch_responses
+        .insert(data, (success, output));
+
+    Ok(smallvec![])
+}
+
+#[cfg(feature = "testing")]
+use std::collections::BTreeMap;
+
+// See stdlib/error.move
+const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+const ECATEGORY_INVALID_STATE: u64 = 0x3;
+const ECATEGORY_PERMISSION_DENIED: u64 = 0x5;
+const ECATEGORY_ABORTED: u64 = 0x7;
+
+// native errors always start from 100
+const EDISPATCH_FAILED: u64 = (ECATEGORY_ABORTED << 16) + 100;
+const EINVALID_CALLBACK: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 101;
+const EDISPATCH_NOT_ALLOWED: u64 = (ECATEGORY_INVALID_STATE << 16) + 102;
+const ENESTED_DISPATCH: u64 = (ECATEGORY_INVALID_STATE << 16) + 103;
+const EDISPATCH_MESSAGE_NOT_ALLOWED: u64 = (ECATEGORY_PERMISSION_DENIED << 16) + 104;
+
+thread_local! {
+    // Set while the host executes a dispatched message. The host calls back into the
+    // vm on the same thread, so a move execution started by the host sees it set.
+    static DISPATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Return whether the current thread is executing a dispatched message, i.e. the
+/// move execution was started by the host while dispatching a message.
+pub fn is_dispatching() -> bool {
+    DISPATCHING.with(|dispatching| dispatching.get())
+}
+
+/// Marks the current thread as dispatching a message until dropped.
+struct DispatchGuard;
+
+impl DispatchGuard {
+    fn enter() -> Option<Self> {
+        if DISPATCHING.with(|dispatching| dispatching.replace(true)) {
+            return None;
+        }
+
+        Some(DispatchGuard)
+    }
+}
+
+impl Drop for DispatchGuard {
+    fn drop(&mut self) {
+        DISPATCHING.with(|dispatching| dispatching.set(false));
+    }
+}
+
+/// API to allow move modules to execute a cosmos message synchronously,
+/// in the middle of the move execution.
+///
+/// The host must execute the message on a branch of its state and discard the
+/// branch when the message fails, so a failed dispatch leaves no host side effects.
+/// Successful dispatches are committed together with the move execution, and
+/// discarded with it when the move execution aborts later on.
+///
+/// The move state changes made before the dispatch are not written to the host
+/// state yet, and the host state changes made by the message are overwritten when
+/// the move execution commits. So only the message types listed in the allowed
+/// dispatch messages of the vm config are dispatched, and the host must only list
+/// messages which neither execute move nor read or write move state. Dispatching
+/// again from a move execution started by the host aborts.
+///
+/// A message failure must be returned as `BackendError::UserErr`, which is reported
+/// back to the move caller. Any other error aborts the move execution. When the
+/// host runs out of gas, it must report at least the given gas balance as used.
+pub trait CosmosAPI {
+    fn dispatch_message(
+        &self,
+        sender: AccountAddress,
+        message: &[u8],
+        gas_balance: u64,
+    ) -> (
+        anyhow::Result<Vec<u8>>, /* response */
+        u64,                     /* used_gas */
+    );
+}
+
 /***************************************************************************************************
  * native fun create_address
  *
@@ -22,12 +254,52 @@ use crate::{
  *
  **************************************************************************************************/
 /// The native code context.
-#[derive(Default, Tid)]
-pub struct NativeCosmosContext {
+#[derive(Tid)]
+pub struct NativeCosmosContext<'a> {
+    api: &'a dyn CosmosAPI,
     messages: RefCell<Vec<CosmosMessage>>,
+    dispatch_allowed: bool,
+
+    // type urls of the messages allowed to be dispatched; nothing is allowed when empty
+    allowed_dispatch_messages: BTreeSet<String>,
+
+    #[cfg(feature = "testing")]
+    dispatch_responses: BTreeMap<Vec<u8>, (bool, Vec<u8>)>,
 }
 
-impl NativeCosmosContext {
+impl<'a> NativeCosmosContext<'a> {
+    /// Create a new instance of a native cosmos context. This must be passed in via an
+    /// extension into VM session functions.
+    ///
+    /// `dispatch_allowed` must only be set for sessions executing an entry function or
+    /// a script, as dispatched messages change the host state.
+    pub fn new(
+        api: &'a dyn CosmosAPI,
+        dispatch_allowed: bool,
+        allowed_dispatch_messages: &[String],
+    ) -> Self {
+        Self {
+            api,
+            messages: RefCell::new(vec![]),
+            dispatch_allowed,
+            allowed_dispatch_messages: allowed_dispatch_messages.iter().cloned().collect(),
+
+            #[cfg(feature = "testing")]
+            dispatch_responses: BTreeMap::new(),
+        }
+    }
+
+    fn is_allowed_message(&self, data: &[u8]) -> bool {
+        #[derive(Deserialize)]
+        struct TypedMessage {
+            #[serde(rename = "@type")]
+            type_url: String,
+        }
+
+        serde_json::from_slice::<TypedMessage>(data)
+            .is_ok_and(|msg| self.allowed_dispatch_messages.contains(&msg.type_url))
+    }
+
     pub fn into_messages(self) -> CosmosMessages {
         let NativeCosmosContext { messages, .. } = self;
         let messages = messages.into_inner();
@@ -51,9 +323,8 @@ fn native_stargate(
     debug_assert!(ty_args.is_empty());
     debug_assert!(arguments.len() == 3);
 
-    let (allow_failure, callback) = get_stargate_options(safely_pop_arg!(arguments, Struct))?;
-    if callback.is_some() {
-        let callback = callback.as_ref().unwrap();
+    let (allow_failure, mut callback) = get_stargate_options(safely_pop_arg!(arguments, Struct))?;
+    if let Some(callback) = callback.as_mut() {
         context.charge(
             gas_params.cosmos_stargate_per_byte * NumBytes::new(callback.module_name.len() as u64),
         )?;
@@ -61,6 +332,10 @@ fn native_stargate(
             gas_params.cosmos_stargate_per_byte
                 * NumBytes::new(callback.function_name.len() as u64),
         )?;
+
+        let (with_signer, with_result) = validate_callback(context, callback)?;
+        callback.with_signer = with_signer;
+        callback.with_result = with_result;
     }
 
     let data = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
@@ -81,6 +356,101 @@ fn native_stargate(
     Ok(smallvec![])
 }
 
+/// Check the callback function can be called by the host with the message result,
+/// and return whether it takes the sender `&signer` and the typed result.
+///
+/// Accepted signatures are:
+///
+/// ```move
+/// fun callback(id: u64, success: bool);
+/// fun callback(id: u64, success: bool, response: vector<u8>, error: String);
+/// ```
+///
+/// optionally prefixed with `sender: &signer`. The function must be public, and its module
+/// already loaded by the transaction, so loading it here is not left uncharged.
+#[allow(clippy::result_large_err)]
+fn validate_callback(
+    context: &mut SafeNativeContext,
+    callback: &CosmosCallback,
+) -> SafeNativeResult<(bool, bool)> {
+    let invalid_callback = SafeNativeError::Abort {
+        abort_code: EINVALID_CALLBACK,
+    };
+
+    let (module_name, function_name) = match (
+        Identifier::new(callback.module_name.as_str()),
+        Identifier::new(callback.function_name.as_str()),
+    ) {
+        (Ok(module_name), Ok(function_name)) => (module_name, function_name),
+        _ => return Err(invalid_callback),
+    };
+
+    // modules at special addresses are not charged, so they are never visited.
+    let module_id = ModuleId::new(callback.module_address, module_name);
+    if !module_id.address().is_special()
+        && !context
+            .traversal_context()
+            .visited
+            .contains_key(&(module_id.address(), module_id.name()))
+    {
+        return Err(invalid_callback);
+    }
+
+    let function = context
+        .load_function(&module_id, &function_name)
+        .map_err(|_| SafeNativeError::Abort {
+            abort_code: EINVALID_CALLBACK,
+        })?;
+
+    // the host calls the callback with the message result only, so it must not
+    // take type arguments or return values.
+    if function.is_friend_or_private()
+        || function.is_native()
+        || !function.ty_param_abilities().is_empty()
+        || !function.return_tys().is_empty()
+    {
+        return Err(invalid_callback);
+    }
+
+    let mut param_tys = function.param_tys();
+    let with_signer = match param_tys.first() {
+        Some(Type::Reference(inner)) if inner.as_ref() == &Type::Signer => {
+            param_tys = &param_tys[1..];
+            true
+        }
+        _ => false,
+    };
+
+    let with_result = match param_tys {
+        [Type::U64, Type::Bool] => false,
+        [Type::U64, Type::Bool, Type::Vector(elem), string_ty]
+            if elem.as_ref() == &Type::U8 && is_string_type(context, string_ty)? =>
+        {
+            true
+        }
+        _ => return Err(invalid_callback),
+    };
+
+    Ok((with_signer, with_result))
+}
+
+#[allow(clippy::result_large_err)]
+fn is_string_type(context: &SafeNativeContext, ty: &Type) -> SafeNativeResult<bool> {
+    if !matches!(ty, Type::Struct { .. }) {
+        return Ok(false);
+    }
+
+    Ok(match context.type_to_type_tag(ty)? {
+        TypeTag::Struct(tag) => {
+            tag.address == AccountAddress::ONE
+                && tag.module.as_str() == "string"
+                && tag.name.as_str() == "String"
+                && tag.type_args.is_empty()
+        }
+        _ => false,
+    })
+}
+
 #[cfg(feature = "testing")]
 #[allow(clippy::result_large_err)]
 fn native_requested_messages(
@@ -140,13 +510,22 @@ pub fn make_all(
     builder: &SafeNativeBuilder,
 ) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
     let mut natives = vec![];
-    natives.extend([("stargate_internal", native_stargate as RawSafeNative)]);
+    natives.extend([
+        ("stargate_internal", native_stargate as RawSafeNative),
+        ("dispatch_internal", native_dispatch),
+    ]);
 
     #[cfg(feature = "testing")]
-    natives.extend([(
-        "requested_messages",
-        native_requested_messages as RawSafeNative,
-    )]);
+    natives.extend([
+        (
+            "requested_messages",
+            native_requested_messages as RawSafeNative,
+        ),
+        (
+            "set_dispatch_response",
+            native_test_only_set_dispatch_response,
+        ),
+    ]);
 
     builder.make_named_natives(natives)
 }
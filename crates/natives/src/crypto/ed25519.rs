@@ -1,3 +1,4 @@
+use crate::crypto::pop_vec_of_vec_u8;
 use crate::interface::{
     RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
@@ -5,13 +6,8 @@ use crate::{safely_pop_arg, safely_pop_vec_arg};
 
 use initia_move_gas::{NumArgs, NumBytes};
 
-use move_binary_format::errors::PartialVMError;
-use move_core_types::vm_status::StatusCode;
 use move_vm_runtime::native_functions::NativeFunction;
-use move_vm_types::{
-    loaded_data::runtime_types::Type,
-    values::{Struct, Value},
-};
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 
 use ed25519_consensus::{batch, Signature, VerificationKey, VerificationKeyBytes};
 use rand_core::OsRng;
@@ -86,25 +82,6 @@ fn read_pubkey(data: &[u8]) -> Result<[u8; ED25519_PUBLIC_KEY_LENGTH], TryFromSl
     data.try_into()
 }
 
-/// Pops a Vec<T> off the argument stack and converts it to a Vec<Vec<u8>> by reading the first
-/// field of T, which is a Vec<u8> field named `bytes`.
-#[allow(clippy::result_large_err)]
-fn pop_vec_of_vec_u8(arguments: &mut VecDeque<Value>) -> SafeNativeResult<Vec<Vec<u8>>> {
-    let structs: Vec<Struct> = safely_pop_vec_arg!(arguments, Struct);
-    let mut v = Vec::with_capacity(structs.len());
-
-    for s in structs {
-        let field = s
-            .unpack()?
-            .next()
-            .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?;
-
-        v.push(field.value_as::<Vec<u8>>()?);
-    }
-
-    SafeNativeResult::Ok(v)
-}
-
 fn repeats_vec_of_vec_u8(item: Vec<u8>, n: usize) -> Vec<Vec<u8>> {
     let mut result: Vec<Vec<u8>> = vec![];
     let mut item: Vec<Vec<u8>> = vec![item];
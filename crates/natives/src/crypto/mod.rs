@@ -1,2 +1,30 @@
+use move_binary_format::errors::PartialVMError;
+use move_core_types::vm_status::StatusCode;
+use move_vm_types::values::{Struct, Value};
+
+use std::collections::VecDeque;
+
+use crate::{interface::SafeNativeResult, safely_pop_vec_arg};
+
 pub mod ed25519;
+pub mod multi_ed25519;
 pub mod secp256k1;
+
+/// Pops a Vec<T> off the argument stack and converts it to a Vec<Vec<u8>> by reading the first
+/// field of T, which is a Vec<u8> field named `bytes`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn pop_vec_of_vec_u8(arguments: &mut VecDeque<Value>) -> SafeNativeResult<Vec<Vec<u8>>> {
+    let structs: Vec<Struct> = safely_pop_vec_arg!(arguments, Struct);
+    let mut v = Vec::with_capacity(structs.len());
+
+    for s in structs {
+        let field = s
+            .unpack()?
+            .next()
+            .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?;
+
+        v.push(field.value_as::<Vec<u8>>()?);
+    }
+
+    SafeNativeResult::Ok(v)
+}
@@ -0,0 +1,181 @@
+use crate::crypto::ed25519::{ED25519_PUBLIC_KEY_LENGTH, ED25519_SIGNATURE_LENGTH};
+use crate::interface::{RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult};
+use crate::safely_pop_arg;
+
+use initia_move_gas::{NumArgs, NumBytes};
+
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+
+use ed25519_consensus::{Signature, VerificationKey, VerificationKeyBytes};
+
+use smallvec::{smallvec, SmallVec};
+
+use std::collections::VecDeque;
+
+/// The maximum number of sub-keys in a multi-ed25519 public key.
+pub const MAX_NUM_OF_KEYS: usize = 32;
+
+/// The length of the signer bitmap appended to a multi-ed25519 signature.
+pub const BITMAP_NUM_OF_BYTES: usize = 4;
+
+/// The length of the threshold byte appended to a multi-ed25519 public key.
+pub const THRESHOLD_SIZE_BYTES: usize = 1;
+
+/// A parsed multi-ed25519 public key: the sub-keys in order and the number of required signers.
+struct MultiEd25519PublicKey<'a> {
+    public_keys: Vec<&'a [u8]>,
+    threshold: usize,
+}
+
+/// A parsed multi-ed25519 signature: the signatures in bitmap order and the signer bitmap.
+struct MultiEd25519Signature<'a> {
+    signatures: Vec<&'a [u8]>,
+    bitmap: [u8; BITMAP_NUM_OF_BYTES],
+}
+
+/// Parses `public_key_1 || ... || public_key_n || threshold`, where `1 <= threshold <= n <= 32`.
+fn parse_public_key(bytes: &[u8]) -> Option<MultiEd25519PublicKey<'_>> {
+    let (keys, threshold) = bytes.split_last()?;
+    if keys.is_empty() || keys.len() % ED25519_PUBLIC_KEY_LENGTH != 0 {
+        return None;
+    }
+
+    let public_keys: Vec<&[u8]> = keys.chunks_exact(ED25519_PUBLIC_KEY_LENGTH).collect();
+    let threshold = *threshold as usize;
+    if public_keys.len() > MAX_NUM_OF_KEYS || threshold == 0 || threshold > public_keys.len() {
+        return None;
+    }
+
+    Some(MultiEd25519PublicKey {
+        public_keys,
+        threshold,
+    })
+}
+
+/// Parses `signature_1 || ... || signature_k || bitmap`, where the number of signatures must
+/// match the number of bits set in the 4-byte bitmap.
+fn parse_signature(bytes: &[u8]) -> Option<MultiEd25519Signature<'_>> {
+    if bytes.len() < BITMAP_NUM_OF_BYTES {
+        return None;
+    }
+
+    let (sigs, bitmap) = bytes.split_at(bytes.len() - BITMAP_NUM_OF_BYTES);
+    if sigs.len() % ED25519_SIGNATURE_LENGTH != 0 {
+        return None;
+    }
+
+    let signatures: Vec<&[u8]> = sigs.chunks_exact(ED25519_SIGNATURE_LENGTH).collect();
+    let bitmap: [u8; BITMAP_NUM_OF_BYTES] = bitmap.try_into().ok()?;
+    if bitmap_count_ones(&bitmap) as usize != signatures.len() {
+        return None;
+    }
+
+    Some(MultiEd25519Signature { signatures, bitmap })
+}
+
+fn bitmap_count_ones(bitmap: &[u8; BITMAP_NUM_OF_BYTES]) -> u32 {
+    bitmap.iter().map(|b| b.count_ones()).sum()
+}
+
+/// Returns whether the signer at `index` is marked in the bitmap. Bits are read from the most
+/// significant bit of the first byte, so index 0 is `0b1000_0000` of `bitmap[0]`.
+fn bitmap_get_bit(bitmap: &[u8; BITMAP_NUM_OF_BYTES], index: usize) -> bool {
+    let bucket = index / 8;
+    let bucket_pos = index - bucket * 8;
+    (bitmap[bucket] & (0b1000_0000 >> bucket_pos)) != 0
+}
+
+/// Verifies a K-of-N multi-ed25519 signature in a single call.
+///
+/// The public key is the concatenation of N 32-byte ed25519 public keys followed by a 1-byte
+/// threshold K. The signature is the concatenation of the signatures of the participating
+/// signers, ordered by their key index, followed by a 4-byte bitmap marking those signers.
+///
+/// Gas is charged once, up front, as
+/// `base + N * per_pubkey_deserialize + S * (per_sig_deserialize + per_sig_verify
+/// + per_msg_hashing_base + per_msg_byte_hashing * |message|)`, where S is the number of
+/// signatures provided. Malformed inputs return `false` after charging only the base cost.
+#[allow(clippy::result_large_err)]
+pub fn native_verify(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.crypto_multi_ed25519_base)?;
+
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = safely_pop_arg!(arguments, Vec<u8>);
+    let pubkey = safely_pop_arg!(arguments, Vec<u8>);
+    let msg = safely_pop_arg!(arguments, Vec<u8>);
+
+    let (multi_pk, multi_sig) = match (parse_public_key(&pubkey), parse_signature(&signature)) {
+        (Some(pk), Some(sig)) => (pk, sig),
+        _ => return Ok(smallvec![Value::bool(false)]),
+    };
+
+    let num_keys = NumArgs::new(multi_pk.public_keys.len() as u64);
+    let num_sigs = NumArgs::new(multi_sig.signatures.len() as u64);
+    context.charge(
+        gas_params.crypto_ed25519_per_pubkey_deserialize * num_keys
+            + (gas_params.crypto_ed25519_per_sig_deserialize
+                + gas_params.crypto_ed25519_per_sig_verify
+                + gas_params.crypto_ed25519_per_msg_hashing_base)
+                * num_sigs
+            + gas_params.crypto_ed25519_per_msg_byte_hashing
+                * NumBytes::new(msg.len() as u64 * multi_sig.signatures.len() as u64),
+    )?;
+
+    if multi_sig.signatures.len() < multi_pk.threshold {
+        return Ok(smallvec![Value::bool(false)]);
+    }
+
+    // bits beyond the number of keys must not be set
+    if (multi_pk.public_keys.len()..MAX_NUM_OF_KEYS)
+        .any(|index| bitmap_get_bit(&multi_sig.bitmap, index))
+    {
+        return Ok(smallvec![Value::bool(false)]);
+    }
+
+    let mut signatures = multi_sig.signatures.iter();
+    for (index, public_key) in multi_pk.public_keys.iter().enumerate() {
+        if !bitmap_get_bit(&multi_sig.bitmap, index) {
+            continue;
+        }
+
+        // the bitmap popcount equals the number of signatures, checked during parsing
+        let Some(sig) = signatures.next() else {
+            return Ok(smallvec![Value::bool(false)]);
+        };
+
+        let vk = match <[u8; ED25519_PUBLIC_KEY_LENGTH]>::try_from(*public_key) {
+            Ok(pk) => match VerificationKey::try_from(VerificationKeyBytes::from(pk)) {
+                Ok(vk) => vk,
+                Err(_) => return Ok(smallvec![Value::bool(false)]),
+            },
+            Err(_) => return Ok(smallvec![Value::bool(false)]),
+        };
+
+        let sig = match <[u8; ED25519_SIGNATURE_LENGTH]>::try_from(*sig) {
+            Ok(sig) => Signature::from(sig),
+            Err(_) => return Ok(smallvec![Value::bool(false)]),
+        };
+
+        if vk.verify(&sig, &msg).is_err() {
+            return Ok(smallvec![Value::bool(false)]);
+        }
+    }
+
+    Ok(smallvec![Value::bool(true)])
+}
+
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [("verify_internal", native_verify as RawSafeNative)];
+
+    builder.make_named_natives(natives)
+}
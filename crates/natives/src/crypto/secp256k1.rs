@@ -1,4 +1,4 @@
-use initia_move_gas::NumArgs;
+use initia_move_gas::{NumArgs, NumBytes};
 
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
@@ -9,16 +9,20 @@ use libsecp256k1::{
     verify, Message, PublicKey, RecoveryId, Signature,
 };
 
+use k256::schnorr::{Signature as SchnorrSignature, VerifyingKey as SchnorrVerifyingKey};
+
 use smallvec::{smallvec, SmallVec};
+use tiny_keccak::{Hasher as KeccakHasher, Keccak};
 
 use std::array::TryFromSliceError;
 use std::collections::VecDeque;
 
 use crate::{
+    crypto::pop_vec_of_vec_u8,
     interface::{
         RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
     },
-    safely_pop_arg,
+    safely_pop_arg, safely_pop_vec_arg,
 };
 
 // See stdlib/error.move
@@ -26,6 +30,10 @@ const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
 
 // native errors always start from 100
 const UNABLE_TO_DESERIALIZE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 1;
+const UNMATCHED_ARGS_LENGTH: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 2;
+
+/// The length of a BIP-340 x-only public key in bytes.
+pub const SCHNORR_PUBLIC_KEY_LENGTH: usize = 32;
 
 fn read_signature(data: &[u8]) -> Result<[u8; SIGNATURE_SIZE], TryFromSliceError> {
     data.try_into()
@@ -39,6 +47,49 @@ fn read_pubkey(data: &[u8]) -> Result<[u8; COMPRESSED_PUBLIC_KEY_SIZE], TryFromS
     data.try_into()
 }
 
+fn read_xonly_pubkey(data: &[u8]) -> Result<[u8; SCHNORR_PUBLIC_KEY_LENGTH], TryFromSliceError> {
+    data.try_into()
+}
+
+/// Verifies a single BIP-340 signature, charging deserialization and verification gas as it goes.
+/// Returns `false` for malformed public keys or signatures instead of aborting.
+#[allow(clippy::result_large_err)]
+fn schnorr_verify(
+    context: &mut SafeNativeContext,
+    message: &[u8],
+    pubkey: &[u8],
+    signature: &[u8],
+) -> SafeNativeResult<bool> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    if read_hash(message).is_err() {
+        return Err(SafeNativeError::Abort {
+            abort_code: UNABLE_TO_DESERIALIZE,
+        });
+    }
+
+    context.charge(gas_params.crypto_secp256k1_per_xonly_pubkey_deserialize * NumArgs::one())?;
+    let vk = match read_xonly_pubkey(pubkey) {
+        Ok(pk) => match SchnorrVerifyingKey::from_bytes(&pk) {
+            Ok(vk) => vk,
+            Err(_) => return Ok(false),
+        },
+        Err(_) => return Ok(false),
+    };
+
+    context.charge(gas_params.crypto_secp256k1_per_sig_deserialize * NumArgs::one())?;
+    let sig = match read_signature(signature) {
+        Ok(sig) => match SchnorrSignature::try_from(sig.as_slice()) {
+            Ok(sig) => sig,
+            Err(_) => return Ok(false),
+        },
+        Err(_) => return Ok(false),
+    };
+
+    context.charge(gas_params.crypto_secp256k1_per_schnorr_verify * NumArgs::one())?;
+    Ok(vk.verify_raw(message, &sig).is_ok())
+}
+
 #[allow(clippy::result_large_err)]
 pub fn native_verify(
     context: &mut SafeNativeContext,
@@ -86,24 +137,19 @@ pub fn native_verify(
     Ok(smallvec![Value::bool(verify(&msg, &sig, &pk))])
 }
 
+/// Recovers the public key that produced `signature` over the 32-byte `message`, charging
+/// deserialization and recovery gas. Malformed inputs abort with `UNABLE_TO_DESERIALIZE`, and
+/// `None` is returned when no public key can be recovered.
 #[allow(clippy::result_large_err)]
-pub fn native_recover_public_key(
+fn recover_public_key(
     context: &mut SafeNativeContext,
-    _ty_args: Vec<Type>,
-    mut arguments: VecDeque<Value>,
-) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    message: &[u8],
+    recovery_id: u8,
+    signature: &[u8],
+) -> SafeNativeResult<Option<PublicKey>> {
     let gas_params = &context.native_gas_params.initia_stdlib;
-    context.charge(gas_params.crypto_secp256k1_base)?;
-
-    debug_assert!(_ty_args.is_empty());
-    debug_assert!(arguments.len() == 4);
 
-    let compressed = safely_pop_arg!(arguments, bool);
-    let signature = safely_pop_arg!(arguments, Vec<u8>);
-    let message = safely_pop_arg!(arguments, Vec<u8>);
-    let recovery_id = safely_pop_arg!(arguments, u8);
-
-    let msg = match read_hash(&message) {
+    let msg = match read_hash(message) {
         Ok(mh) => Message::parse(&mh),
         Err(_) => {
             return Err(SafeNativeError::Abort {
@@ -122,7 +168,7 @@ pub fn native_recover_public_key(
     };
 
     context.charge(gas_params.crypto_secp256k1_per_sig_deserialize * NumArgs::one())?;
-    let sig = match read_signature(&signature) {
+    let sig = match read_signature(signature) {
         Ok(sig) => match Signature::parse_standard(&sig) {
             Ok(sig) => sig,
             Err(_) => {
@@ -139,8 +185,28 @@ pub fn native_recover_public_key(
     };
 
     context.charge(gas_params.crypto_secp256k1_per_ecdsa_recover * NumArgs::one())?;
-    match recover(&msg, &sig, &rid) {
-        Ok(pk) => Ok(smallvec![
+    Ok(recover(&msg, &sig, &rid).ok())
+}
+
+#[allow(clippy::result_large_err)]
+pub fn native_recover_public_key(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.crypto_secp256k1_base)?;
+
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let compressed = safely_pop_arg!(arguments, bool);
+    let signature = safely_pop_arg!(arguments, Vec<u8>);
+    let message = safely_pop_arg!(arguments, Vec<u8>);
+    let recovery_id = safely_pop_arg!(arguments, u8);
+
+    match recover_public_key(context, &message, recovery_id, &signature)? {
+        Some(pk) => Ok(smallvec![
             Value::vector_u8(if compressed {
                 pk.serialize_compressed().to_vec()
             } else {
@@ -148,8 +214,128 @@ pub fn native_recover_public_key(
             }),
             Value::bool(true)
         ]),
-        Err(_) => Ok(smallvec![Value::vector_u8([0u8; 0]), Value::bool(false)]),
+        None => Ok(smallvec![Value::vector_u8([0u8; 0]), Value::bool(false)]),
+    }
+}
+
+/// Recovers the 20-byte Ethereum address of the signer of a 65-byte `r || s || v` signature
+/// over the 32-byte `message` digest. `v` may be either the raw recovery id (0 or 1) or the
+/// Ethereum encoding (27 or 28).
+///
+/// Returns `(address, true)` on success and `([], false)` if no public key can be recovered.
+#[allow(clippy::result_large_err)]
+pub fn native_recover_eth_address(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.crypto_secp256k1_base)?;
+
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let signature = safely_pop_arg!(arguments, Vec<u8>);
+    let message = safely_pop_arg!(arguments, Vec<u8>);
+
+    let (rs, recovery_id) = match signature.split_last() {
+        Some((v, rs)) if rs.len() == SIGNATURE_SIZE => match *v {
+            0 | 1 => (rs, *v),
+            27 | 28 => (rs, *v - 27),
+            _ => {
+                return Err(SafeNativeError::Abort {
+                    abort_code: UNABLE_TO_DESERIALIZE,
+                });
+            }
+        },
+        _ => {
+            return Err(SafeNativeError::Abort {
+                abort_code: UNABLE_TO_DESERIALIZE,
+            });
+        }
+    };
+
+    let Some(pk) = recover_public_key(context, &message, recovery_id, rs)? else {
+        return Ok(smallvec![Value::vector_u8([0u8; 0]), Value::bool(false)]);
+    };
+
+    // the address is the last 20 bytes of keccak256 over the uncompressed key without its prefix
+    let uncompressed = pk.serialize();
+    context.charge(
+        gas_params.keccak_keccak256_base
+            + gas_params.keccak_keccak256_per_byte * NumBytes::new((uncompressed.len() - 1) as u64),
+    )?;
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&uncompressed[1..]);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    Ok(smallvec![
+        Value::vector_u8(hash[12..].to_vec()),
+        Value::bool(true)
+    ])
+}
+
+/// Verifies a BIP-340 Schnorr `signature` over a 32-byte `message` under an x-only `pubkey`.
+#[allow(clippy::result_large_err)]
+pub fn native_schnorr_verify(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.crypto_secp256k1_base)?;
+
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = safely_pop_arg!(arguments, Vec<u8>);
+    let pubkey = safely_pop_arg!(arguments, Vec<u8>);
+    let message = safely_pop_arg!(arguments, Vec<u8>);
+
+    let verified = schnorr_verify(context, &message, &pubkey, &signature)?;
+    Ok(smallvec![Value::bool(verified)])
+}
+
+/// Verifies a batch of BIP-340 Schnorr signatures and returns `true` only if every
+/// `(message, public_key, signature)` triple is valid.
+///
+/// The input vectors must have the same length; the empty batch returns true. Verification
+/// stops at the first invalid entry, so gas is only charged for the entries actually checked.
+#[allow(clippy::result_large_err)]
+pub fn native_schnorr_batch_verify(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.crypto_secp256k1_base)?;
+
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signatures = pop_vec_of_vec_u8(&mut arguments)?;
+    let public_keys = pop_vec_of_vec_u8(&mut arguments)?;
+    let messages = safely_pop_vec_arg!(arguments, Vec<u8>);
+
+    if messages.len() != public_keys.len() || messages.len() != signatures.len() {
+        return Err(SafeNativeError::Abort {
+            abort_code: UNMATCHED_ARGS_LENGTH,
+        });
     }
+
+    for ((message, public_key), signature) in messages
+        .iter()
+        .zip(public_keys.iter())
+        .zip(signatures.iter())
+    {
+        if !schnorr_verify(context, message, public_key, signature)? {
+            return Ok(smallvec![Value::bool(false)]);
+        }
+    }
+
+    Ok(smallvec![Value::bool(true)])
 }
 
 #[cfg(feature = "testing")]
@@ -203,6 +389,42 @@ pub fn native_test_only_sign(
     ])
 }
 
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+pub fn native_test_only_schnorr_generate_keys(
+    _context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let sk = k256::schnorr::SigningKey::random(&mut OsRng);
+    let vk = sk.verifying_key();
+
+    Ok(smallvec![
+        Value::vector_u8(sk.to_bytes().to_vec()),
+        Value::vector_u8(vk.to_bytes().to_vec())
+    ])
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+pub fn native_test_only_schnorr_sign(
+    _context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use rand_core::RngCore;
+
+    let sk_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let msg_bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    let sk = k256::schnorr::SigningKey::from_bytes(&sk_bytes).unwrap();
+    let mut aux_rand = [0u8; 32];
+    OsRng.fill_bytes(&mut aux_rand);
+    let sig = sk.sign_raw(&msg_bytes, &aux_rand).unwrap();
+
+    Ok(smallvec![Value::vector_u8(sig.to_bytes().to_vec())])
+}
+
 pub fn make_all(
     builder: &SafeNativeBuilder,
 ) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
@@ -210,6 +432,9 @@ pub fn make_all(
     natives.extend([
         ("verify_internal", native_verify as RawSafeNative),
         ("recover_public_key_internal", native_recover_public_key),
+        ("recover_eth_address_internal", native_recover_eth_address),
+        ("schnorr_verify_internal", native_schnorr_verify),
+        ("schnorr_batch_verify_internal", native_schnorr_batch_verify),
     ]);
 
     #[cfg(feature = "testing")]
@@ -219,6 +444,11 @@ pub fn make_all(
             native_test_only_generate_keys as RawSafeNative,
         ),
         ("sign", native_test_only_sign),
+        (
+            "schnorr_generate_keys",
+            native_test_only_schnorr_generate_keys,
+        ),
+        ("schnorr_sign", native_test_only_schnorr_sign),
     ]);
 
     builder.make_named_natives(natives)
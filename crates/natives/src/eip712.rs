@@ -0,0 +1,585 @@
+use bigdecimal::num_bigint::{BigInt, BigUint, Sign};
+use move_core_types::gas_algebra::NumBytes;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Struct, Value},
+};
+use serde_json::{Map, Value as JSONValue};
+use smallvec::{smallvec, SmallVec};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use tiny_keccak::{Hasher as KeccakHasher, Keccak};
+
+use crate::{
+    helpers::get_string,
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
+    safely_pop_arg, safely_pop_vec_arg,
+};
+
+// See stdlib/error.move
+const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+
+// native errors always start from 100
+const EINVALID_TYPE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
+const EINVALID_DATA: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 101;
+
+/// Maximum nesting depth of structs and arrays while encoding a value.
+const MAX_DEPTH: usize = 32;
+
+/*
+    native fun hash_struct_json_internal(types: vector<TypeDefinition>, primary_type: String, data: String): vector<u8>;
+    native fun hash_struct_bcs_internal(types: vector<TypeDefinition>, primary_type: String, data: vector<u8>): vector<u8>;
+*/
+
+/// EIP-712 struct definitions keyed by struct name; each field is a `(name, type)` pair.
+type TypeDefinitions = BTreeMap<String, Vec<(String, String)>>;
+
+fn invalid_type() -> SafeNativeError {
+    SafeNativeError::Abort {
+        abort_code: EINVALID_TYPE,
+    }
+}
+
+fn invalid_data() -> SafeNativeError {
+    SafeNativeError::Abort {
+        abort_code: EINVALID_DATA,
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn utf8(bytes: Vec<u8>) -> SafeNativeResult<String> {
+    String::from_utf8(bytes).map_err(|_| invalid_type())
+}
+
+/// Reads `vector<TypeDefinition>` from the Move side, where
+/// `TypeDefinition { name: String, fields: vector<Field> }` and
+/// `Field { name: String, type_name: String }`.
+#[allow(clippy::result_large_err)]
+fn read_type_definitions(structs: Vec<Struct>) -> SafeNativeResult<TypeDefinitions> {
+    let mut types = TypeDefinitions::new();
+    for s in structs {
+        let mut vals = s.unpack()?;
+        let (name, fields) = match (vals.next(), vals.next()) {
+            (Some(name), Some(fields)) => (name, fields),
+            _ => return Err(invalid_type()),
+        };
+
+        let name = utf8(get_string(name.value_as::<Struct>()?)?)?;
+        let mut parsed_fields = vec![];
+        for field in fields.value_as::<Vec<Value>>()? {
+            let mut field_vals = field.value_as::<Struct>()?.unpack()?;
+            let (field_name, field_type) = match (field_vals.next(), field_vals.next()) {
+                (Some(field_name), Some(field_type)) => (field_name, field_type),
+                _ => return Err(invalid_type()),
+            };
+
+            parsed_fields.push((
+                utf8(get_string(field_name.value_as::<Struct>()?)?)?,
+                utf8(get_string(field_type.value_as::<Struct>()?)?)?,
+            ));
+        }
+
+        // empty structs would let zero-byte BCS payloads expand without bound
+        if parsed_fields.is_empty() || types.insert(name, parsed_fields).is_some() {
+            return Err(invalid_type());
+        }
+    }
+
+    Ok(types)
+}
+
+/// Splits `T[]` into `(T, None)` and `T[k]` into `(T, Some(k))`. Returns `None` if `ty` is
+/// not an array type.
+#[allow(clippy::result_large_err)]
+fn split_array_type(ty: &str) -> SafeNativeResult<Option<(&str, Option<usize>)>> {
+    let Some(stripped) = ty.strip_suffix(']') else {
+        return Ok(None);
+    };
+    let idx = stripped.rfind('[').ok_or_else(invalid_type)?;
+    let (inner, len) = (&stripped[..idx], &stripped[idx + 1..]);
+    if inner.is_empty() {
+        return Err(invalid_type());
+    }
+
+    if len.is_empty() {
+        Ok(Some((inner, None)))
+    } else {
+        let len = len.parse::<usize>().map_err(|_| invalid_type())?;
+        if len == 0 {
+            return Err(invalid_type());
+        }
+        Ok(Some((inner, Some(len))))
+    }
+}
+
+/// Strips all array suffixes from `ty`, e.g. `Person[][2]` becomes `Person`.
+fn base_type(ty: &str) -> &str {
+    ty.find('[').map_or(ty, |idx| &ty[..idx])
+}
+
+/// Returns the bit width of `uintN`/`intN` and whether it is signed.
+fn integer_type(ty: &str) -> Option<(usize, bool)> {
+    let (bits, signed) = if let Some(bits) = ty.strip_prefix("uint") {
+        (bits, false)
+    } else if let Some(bits) = ty.strip_prefix("int") {
+        (bits, true)
+    } else {
+        return None;
+    };
+
+    let bits = bits.parse::<usize>().ok()?;
+    (bits > 0 && bits <= 256 && bits % 8 == 0).then_some((bits, signed))
+}
+
+/// Returns the length of `bytesN`.
+fn fixed_bytes_type(ty: &str) -> Option<usize> {
+    let len = ty.strip_prefix("bytes")?.parse::<usize>().ok()?;
+    (len > 0 && len <= 32).then_some(len)
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_hex(s: &str) -> SafeNativeResult<Vec<u8>> {
+    let s = s.strip_prefix("0x").ok_or_else(invalid_data)?;
+    hex::decode(s).map_err(|_| invalid_data())
+}
+
+/// Computes EIP-712 `encodeType`, `encodeData` and `hashStruct` over a set of type definitions,
+/// charging keccak gas through `charge` for every hash it computes.
+struct Encoder<'t, F> {
+    types: &'t TypeDefinitions,
+    charge: F,
+}
+
+impl<'t, F> Encoder<'t, F>
+where
+    F: FnMut(usize) -> SafeNativeResult<()>,
+{
+    #[allow(clippy::result_large_err)]
+    fn keccak256(&mut self, bytes: &[u8]) -> SafeNativeResult<[u8; 32]> {
+        (self.charge)(bytes.len())?;
+
+        let mut hasher = Keccak::v256();
+        hasher.update(bytes);
+        let mut output = [0u8; 32];
+        hasher.finalize(&mut output);
+        Ok(output)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn fields(&self, ty: &str) -> SafeNativeResult<&'t [(String, String)]> {
+        self.types
+            .get(ty)
+            .map(|fields| fields.as_slice())
+            .ok_or_else(invalid_type)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn collect_dependencies(&self, ty: &str, deps: &mut BTreeSet<String>) -> SafeNativeResult<()> {
+        let ty = base_type(ty);
+        if deps.contains(ty) || !self.types.contains_key(ty) {
+            return Ok(());
+        }
+
+        deps.insert(ty.to_string());
+        for (_, field_type) in self.fields(ty)? {
+            self.collect_dependencies(field_type, deps)?;
+        }
+
+        Ok(())
+    }
+
+    /// `encodeType`: the primary type followed by its referenced struct types sorted by name.
+    #[allow(clippy::result_large_err)]
+    fn encode_type(&self, primary_type: &str) -> SafeNativeResult<String> {
+        let mut deps = BTreeSet::new();
+        self.collect_dependencies(primary_type, &mut deps)?;
+        deps.remove(primary_type);
+
+        let mut encoded = String::new();
+        for ty in std::iter::once(primary_type).chain(deps.iter().map(String::as_str)) {
+            let fields = self
+                .fields(ty)?
+                .iter()
+                .map(|(name, field_type)| format!("{field_type} {name}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            encoded.push_str(&format!("{ty}({fields})"));
+        }
+
+        Ok(encoded)
+    }
+
+    /// `hashStruct(s) = keccak256(typeHash || encodeData(s))`.
+    #[allow(clippy::result_large_err)]
+    fn hash_struct(
+        &mut self,
+        ty: &str,
+        data: &JSONValue,
+        depth: usize,
+    ) -> SafeNativeResult<[u8; 32]> {
+        if depth > MAX_DEPTH {
+            return Err(invalid_data());
+        }
+
+        let object = data.as_object().ok_or_else(invalid_data)?;
+        let fields = self.fields(ty)?;
+        if object.len() != fields.len() {
+            return Err(invalid_data());
+        }
+
+        let encoded_type = self.encode_type(ty)?;
+        let mut encoded = self.keccak256(encoded_type.as_bytes())?.to_vec();
+        for (name, field_type) in fields {
+            let value = object.get(name).ok_or_else(invalid_data)?;
+            encoded.extend(self.encode_field(field_type, value, depth + 1)?);
+        }
+
+        self.keccak256(&encoded)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn encode_field(
+        &mut self,
+        ty: &str,
+        value: &JSONValue,
+        depth: usize,
+    ) -> SafeNativeResult<[u8; 32]> {
+        if depth > MAX_DEPTH {
+            return Err(invalid_data());
+        }
+
+        if let Some((inner, len)) = split_array_type(ty)? {
+            let elems = value.as_array().ok_or_else(invalid_data)?;
+            if len.is_some_and(|len| len != elems.len()) {
+                return Err(invalid_data());
+            }
+
+            let mut encoded = Vec::with_capacity(elems.len() * 32);
+            for elem in elems {
+                encoded.extend(self.encode_field(inner, elem, depth + 1)?);
+            }
+            return self.keccak256(&encoded);
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value, depth);
+        }
+
+        self.encode_atomic(ty, value)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn encode_atomic(&mut self, ty: &str, value: &JSONValue) -> SafeNativeResult<[u8; 32]> {
+        let mut word = [0u8; 32];
+        match ty {
+            "bool" => {
+                word[31] = value.as_bool().ok_or_else(invalid_data)? as u8;
+            }
+            "address" => {
+                let addr = parse_hex(value.as_str().ok_or_else(invalid_data)?)?;
+                if addr.len() != 20 {
+                    return Err(invalid_data());
+                }
+                word[12..].copy_from_slice(&addr);
+            }
+            "string" => {
+                let s = value.as_str().ok_or_else(invalid_data)?;
+                word = self.keccak256(s.as_bytes())?;
+            }
+            "bytes" => {
+                let bytes = parse_hex(value.as_str().ok_or_else(invalid_data)?)?;
+                word = self.keccak256(&bytes)?;
+            }
+            _ => {
+                if let Some(len) = fixed_bytes_type(ty) {
+                    let bytes = parse_hex(value.as_str().ok_or_else(invalid_data)?)?;
+                    if bytes.len() != len {
+                        return Err(invalid_data());
+                    }
+                    word[..len].copy_from_slice(&bytes);
+                } else if let Some((bits, signed)) = integer_type(ty) {
+                    word = encode_integer(value, bits, signed)?;
+                } else {
+                    return Err(invalid_type());
+                }
+            }
+        }
+
+        Ok(word)
+    }
+}
+
+/// Encodes an integer given as a JSON number, a decimal string or a `0x` hex string into a
+/// 32-byte big-endian two's complement word, checking it fits in `bits`.
+#[allow(clippy::result_large_err)]
+fn encode_integer(value: &JSONValue, bits: usize, signed: bool) -> SafeNativeResult<[u8; 32]> {
+    let s = match value {
+        JSONValue::Number(n) => n.to_string(),
+        JSONValue::String(s) => s.clone(),
+        _ => return Err(invalid_data()),
+    };
+
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.as_str()),
+    };
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(digits.as_bytes(), 10),
+    }
+    .ok_or_else(invalid_data)?;
+    let num = BigInt::from_biguint(if negative { Sign::Minus } else { Sign::Plus }, magnitude);
+
+    let (min, max) = if signed {
+        let bound = BigInt::from(1) << (bits - 1);
+        (-bound.clone(), bound - 1)
+    } else {
+        (BigInt::from(0), (BigInt::from(1) << bits) - 1)
+    };
+    if num < min || num > max {
+        return Err(invalid_data());
+    }
+
+    let bytes = if signed {
+        num.to_signed_bytes_be()
+    } else {
+        num.magnitude().to_bytes_be()
+    };
+    let fill = if num.sign() == Sign::Minus {
+        0xff
+    } else {
+        0x00
+    };
+    let mut word = [fill; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+/// Decodes a BCS payload laid out according to the EIP-712 types into the JSON shape accepted
+/// by the encoder:
+///
+/// - `bool`: 1 byte; `address`: 20 raw bytes; `bytesN`: N raw bytes
+/// - `uintN`/`intN`: N/8 bytes little-endian (two's complement for `intN`)
+/// - `bytes`/`string`: ULEB128 length followed by the bytes
+/// - `T[]`: ULEB128 length followed by the elements; `T[k]`: k elements
+/// - structs: their fields in declaration order
+struct BcsDecoder<'t, 'd> {
+    types: &'t TypeDefinitions,
+    data: &'d [u8],
+}
+
+impl<'d> BcsDecoder<'_, 'd> {
+    #[allow(clippy::result_large_err)]
+    fn take(&mut self, len: usize) -> SafeNativeResult<&'d [u8]> {
+        if self.data.len() < len {
+            return Err(invalid_data());
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn read_uleb128(&mut self) -> SafeNativeResult<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return usize::try_from(value).map_err(|_| invalid_data());
+            }
+        }
+        Err(invalid_data())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn decode(&mut self, ty: &str, depth: usize) -> SafeNativeResult<JSONValue> {
+        if depth > MAX_DEPTH {
+            return Err(invalid_data());
+        }
+
+        if let Some((inner, len)) = split_array_type(ty)? {
+            let len = match len {
+                Some(len) => len,
+                None => self.read_uleb128()?,
+            };
+
+            // every element consumes at least one byte, so this bounds the allocation
+            if len > self.data.len() {
+                return Err(invalid_data());
+            }
+
+            let mut elems = Vec::with_capacity(len);
+            for _ in 0..len {
+                elems.push(self.decode(inner, depth + 1)?);
+            }
+            return Ok(JSONValue::Array(elems));
+        }
+
+        if let Some(fields) = self.types.get(ty) {
+            let mut object = Map::new();
+            for (name, field_type) in fields {
+                object.insert(name.clone(), self.decode(field_type, depth + 1)?);
+            }
+            return Ok(JSONValue::Object(object));
+        }
+
+        let value = match ty {
+            "bool" => match self.take(1)?[0] {
+                0 => JSONValue::Bool(false),
+                1 => JSONValue::Bool(true),
+                _ => return Err(invalid_data()),
+            },
+            "address" => JSONValue::String(format!("0x{}", hex::encode(self.take(20)?))),
+            "string" => {
+                let len = self.read_uleb128()?;
+                let s = std::str::from_utf8(self.take(len)?).map_err(|_| invalid_data())?;
+                JSONValue::String(s.to_string())
+            }
+            "bytes" => {
+                let len = self.read_uleb128()?;
+                JSONValue::String(format!("0x{}", hex::encode(self.take(len)?)))
+            }
+            _ => {
+                if let Some(len) = fixed_bytes_type(ty) {
+                    JSONValue::String(format!("0x{}", hex::encode(self.take(len)?)))
+                } else if let Some((bits, signed)) = integer_type(ty) {
+                    let bytes = self.take(bits / 8)?;
+                    let num = if signed {
+                        BigInt::from_signed_bytes_le(bytes)
+                    } else {
+                        BigInt::from(BigUint::from_bytes_le(bytes))
+                    };
+                    JSONValue::String(num.to_string())
+                } else {
+                    return Err(invalid_type());
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn hash_struct(
+    context: &mut SafeNativeContext,
+    types: &TypeDefinitions,
+    primary_type: &str,
+    data: &JSONValue,
+) -> SafeNativeResult<[u8; 32]> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    let mut encoder = Encoder {
+        types,
+        charge: |len: usize| {
+            context.charge(
+                gas_params.keccak_keccak256_base
+                    + gas_params.keccak_keccak256_per_byte * NumBytes::new(len as u64),
+            )
+        },
+    };
+
+    encoder.hash_struct(primary_type, data, 0)
+}
+
+#[allow(clippy::result_large_err)]
+fn pop_types_and_primary_type(
+    arguments: &mut VecDeque<Value>,
+) -> SafeNativeResult<(TypeDefinitions, String)> {
+    let primary_type = utf8(get_string(safely_pop_arg!(arguments, Struct))?)?;
+    let types = read_type_definitions(safely_pop_vec_arg!(arguments, Struct))?;
+    if !types.contains_key(&primary_type) {
+        return Err(invalid_type());
+    }
+
+    Ok((types, primary_type))
+}
+
+/***************************************************************************************************
+ * native fun hash_struct_json_internal
+ *
+ *   gas cost: base_cost + unit_cost * data_len
+ *             + sum(keccak256_base + keccak256_per_byte * hashed_len)
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_hash_struct_json(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 3);
+
+    let data = get_string(safely_pop_arg!(arguments, Struct))?;
+    context.charge(
+        gas_params.eip712_hash_struct_base
+            + gas_params.eip712_hash_struct_per_byte * NumBytes::new(data.len() as u64),
+    )?;
+
+    let (types, primary_type) = pop_types_and_primary_type(&mut arguments)?;
+    let data: JSONValue = serde_json::from_slice(&data).map_err(|_| invalid_data())?;
+
+    let hash = hash_struct(context, &types, &primary_type, &data)?;
+    Ok(smallvec![Value::vector_u8(hash)])
+}
+
+/***************************************************************************************************
+ * native fun hash_struct_bcs_internal
+ *
+ *   gas cost: base_cost + unit_cost * data_len
+ *             + sum(keccak256_base + keccak256_per_byte * hashed_len)
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_hash_struct_bcs(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert_eq!(arguments.len(), 3);
+
+    let data = safely_pop_arg!(arguments, Vec<u8>);
+    context.charge(
+        gas_params.eip712_hash_struct_base
+            + gas_params.eip712_hash_struct_per_byte * NumBytes::new(data.len() as u64),
+    )?;
+
+    let (types, primary_type) = pop_types_and_primary_type(&mut arguments)?;
+    let mut decoder = BcsDecoder {
+        types: &types,
+        data: &data,
+    };
+    let data = decoder.decode(&primary_type, 0)?;
+    if !decoder.data.is_empty() {
+        return Err(invalid_data());
+    }
+
+    let hash = hash_struct(context, &types, &primary_type, &data)?;
+    Ok(smallvec![Value::vector_u8(hash)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        (
+            "hash_struct_json_internal",
+            native_hash_struct_json as RawSafeNative,
+        ),
+        ("hash_struct_bcs_internal", native_hash_struct_bcs),
+    ];
+
+    builder.make_named_natives(natives)
+}
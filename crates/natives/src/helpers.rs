@@ -76,6 +76,9 @@ pub fn get_stargate_options(v: Struct) -> PartialVMResult<(bool, Option<CosmosCa
                 module_address: maddr,
                 module_name: mname,
                 function_name: fname,
+                // filled in by the callback signature validation
+                with_signer: false,
+                with_result: false,
             }),
         ))
     }
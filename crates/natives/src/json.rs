@@ -9,7 +9,10 @@ use move_vm_types::{
 use smallvec::{smallvec, SmallVec};
 use std::collections::VecDeque;
 
-use initia_move_json::{deserialize_json_to_value, serialize_move_value_to_json_value};
+use initia_move_json::{
+    deserialize_json_to_value, serialize_move_value_to_json_value_with_options,
+    validate_json_options, JsonOptions,
+};
 
 use crate::{
     interface::{
@@ -26,12 +29,14 @@ const EUNABLE_TO_MARSHAL_DELAYED_FIELD: u64 = (ECATEGORY_INVALID_ARGUMENT << 16)
 const EUNABLE_TO_UNMARSHAL_DELAYED_FIELD: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 4;
 const EUNABLE_TO_MARSHAL_SERIALIZATION_ERROR: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 5;
 const EUNABLE_TO_UNMARSHAL_DESERIALIZATION_ERROR: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 6;
+const EINVALID_MARSHAL_OPTIONS: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 7;
 
 #[allow(clippy::result_large_err)]
 fn native_marshal_internal(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
     mut arguments: VecDeque<Value>,
+    options: &JsonOptions,
 ) -> SafeNativeResult<Vec<u8>> {
     let gas_params = &context.native_gas_params.initia_stdlib;
 
@@ -53,11 +58,10 @@ fn native_marshal_internal(
     let move_value = value.as_move_value(&layout);
     let annotated_layout = context.type_to_fully_annotated_layout(ty)?;
     let decorated_value = move_value.decorate(&annotated_layout);
-    let serde_value = serialize_move_value_to_json_value(&decorated_value).map_err(|_| {
-        SafeNativeError::Abort {
+    let serde_value = serialize_move_value_to_json_value_with_options(&decorated_value, options)
+        .map_err(|_| SafeNativeError::Abort {
             abort_code: EUNABLE_TO_MARSHAL_SERIALIZATION_ERROR,
-        }
-    })?;
+        })?;
 
     let serde_bytes = serde_value.to_string().into_bytes();
 
@@ -75,7 +79,18 @@ fn native_marshal(
     ty_args: Vec<Type>,
     arguments: VecDeque<Value>,
 ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
-    native_marshal_internal(context, ty_args, arguments)
+    native_marshal_internal(context, ty_args, arguments, &JsonOptions::default())
+        .map(|serde_bytes| smallvec![Value::vector_u8(serde_bytes)])
+}
+
+#[allow(clippy::result_large_err)]
+fn native_marshal_with_options(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let options = pop_json_options(&mut arguments)?;
+    native_marshal_internal(context, ty_args, arguments, &options)
         .map(|serde_bytes| smallvec![Value::vector_u8(serde_bytes)])
 }
 
@@ -85,13 +100,46 @@ fn native_marshal_to_string(
     ty_args: Vec<Type>,
     arguments: VecDeque<Value>,
 ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
-    native_marshal_internal(context, ty_args, arguments).map(|serde_bytes| {
+    native_marshal_internal(context, ty_args, arguments, &JsonOptions::default()).map(
+        |serde_bytes| {
+            smallvec![Value::struct_(Struct::pack(vec![Value::vector_u8(
+                serde_bytes
+            )]))]
+        },
+    )
+}
+
+#[allow(clippy::result_large_err)]
+fn native_marshal_to_string_with_options(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let options = pop_json_options(&mut arguments)?;
+    native_marshal_internal(context, ty_args, arguments, &options).map(|serde_bytes| {
         smallvec![Value::struct_(Struct::pack(vec![Value::vector_u8(
             serde_bytes
         )]))]
     })
 }
 
+// `0x1::json::MarshalOptions` is passed in its bcs encoding, which matches `JsonOptions`.
+// The options are validated before any value is converted, as the conversion is only
+// charged once the output is built.
+#[allow(clippy::result_large_err)]
+fn pop_json_options(arguments: &mut VecDeque<Value>) -> SafeNativeResult<JsonOptions> {
+    let options_bytes = safely_pop_arg!(arguments, Vec<u8>);
+    let options: JsonOptions =
+        bcs::from_bytes(&options_bytes).map_err(|_| SafeNativeError::Abort {
+            abort_code: EINVALID_MARSHAL_OPTIONS,
+        })?;
+    validate_json_options(&options).map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_MARSHAL_OPTIONS,
+    })?;
+
+    Ok(options)
+}
+
 fn invariant_violation() -> SafeNativeError {
     SafeNativeError::InvariantViolation(PartialVMError::new(
         StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
@@ -118,6 +166,7 @@ fn native_unmarshal(
         });
     }
 
+    let ty_tag = context.type_to_type_tag(ty)?;
     let annotated_layout = context.type_to_fully_annotated_layout(ty)?;
     let serde_bytes = safely_pop_arg!(arguments, Vec<u8>);
 
@@ -131,11 +180,9 @@ fn native_unmarshal(
         .clone()
         .ok_or_else(invariant_violation)?;
 
-    let value =
-        deserialize_json_to_value(&caller, &annotated_layout, &serde_bytes).map_err(|_| {
-            SafeNativeError::Abort {
-                abort_code: EUNABLE_TO_UNMARSHAL_DESERIALIZATION_ERROR,
-            }
+    let value = deserialize_json_to_value(&caller, &ty_tag, &annotated_layout, &serde_bytes)
+        .map_err(|_| SafeNativeError::Abort {
+            abort_code: EUNABLE_TO_UNMARSHAL_DESERIALIZATION_ERROR,
         })?;
 
     context.charge(
@@ -156,6 +203,11 @@ pub fn make_all(
     let natives = [
         ("marshal_internal", native_marshal as RawSafeNative),
         ("marshal_to_string_internal", native_marshal_to_string),
+        ("marshal_with_options_internal", native_marshal_with_options),
+        (
+            "marshal_to_string_with_options_internal",
+            native_marshal_to_string_with_options,
+        ),
         ("unmarshal_internal", native_unmarshal),
     ];
 
@@ -8,6 +8,7 @@ pub mod account;
 pub mod account_abstraction;
 pub mod address;
 pub mod any;
+pub mod base58;
 pub mod base64;
 pub mod bech32;
 pub mod biguint;
@@ -17,6 +18,7 @@ pub mod cosmos;
 pub mod crypto;
 pub mod debug;
 pub mod dispatchable_fungible_asset;
+pub mod eip712;
 pub mod event;
 pub mod from_bcs;
 pub mod function_info;
@@ -26,7 +28,9 @@ pub mod move_stdlib;
 pub mod object;
 pub mod oracle;
 pub mod permissioned_signer;
+pub mod protobuf;
 pub mod query;
+pub mod randomness;
 pub mod staking;
 pub mod string_utils;
 pub mod table;
@@ -68,22 +72,27 @@ pub fn initia_move_natives(
     add_natives_from_module!("debug", debug::make_all(builder));
     add_natives_from_module!("event", event::make_all(builder));
     add_natives_from_module!("ed25519", crypto::ed25519::make_all(builder));
+    add_natives_from_module!("multi_ed25519", crypto::multi_ed25519::make_all(builder));
     add_natives_from_module!("secp256k1", crypto::secp256k1::make_all(builder));
     add_natives_from_module!("type_info", type_info::make_all(builder));
     add_natives_from_module!("from_bcs", from_bcs::make_all(builder));
+    add_natives_from_module!("base58", base58::make_all(builder));
     add_natives_from_module!("base64", base64::make_all(builder));
     add_natives_from_module!("bech32", bech32::make_all(builder));
     add_natives_from_module!("keccak", keccak::make_all(builder));
+    add_natives_from_module!("eip712", eip712::make_all(builder));
     add_natives_from_module!("staking", staking::make_all(builder));
     add_natives_from_module!("cosmos", cosmos::make_all(builder));
     add_natives_from_module!("object", object::make_all(builder));
     add_natives_from_module!("json", json::make_all(builder));
+    add_natives_from_module!("protobuf", protobuf::make_all(builder));
     add_natives_from_module!(
         "transaction_context",
         transaction_context::make_all(builder)
     );
     add_natives_from_module!("query", query::make_all(builder));
     add_natives_from_module!("oracle", oracle::make_all(builder));
+    add_natives_from_module!("randomness", randomness::make_all(builder));
     add_natives_from_module!("string_utils", string_utils::make_all(builder));
     add_natives_from_module!("function_info", function_info::make_all(builder));
     add_natives_from_module!(
@@ -1,6 +1,10 @@
 use better_any::{Tid, TidAble};
 use move_binary_format::errors::PartialVMError;
-use move_core_types::{gas_algebra::NumBytes, u256::U256, vm_status::StatusCode};
+use move_core_types::{
+    gas_algebra::{NumArgs, NumBytes},
+    u256::U256,
+    vm_status::StatusCode,
+};
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -11,7 +15,7 @@ use std::collections::{BTreeMap, VecDeque};
 
 use crate::{
     interface::{RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult},
-    safely_pop_arg,
+    safely_pop_arg, safely_pop_vec_arg,
 };
 
 /// API to allow move modules to interact with CosmosSDK's
@@ -25,6 +29,48 @@ pub trait OracleAPI {
         u64,  /* updated_at */
         u64,  /* decimals */
     )>;
+
+    /// Returns the latest prices of all the given pairs, in the same order. The default
+    /// implementation calls `get_price` once per pair; hosts which can read several pairs
+    /// at once may override it.
+    fn get_prices(
+        &self,
+        pair_ids: &[Vec<u8>],
+    ) -> anyhow::Result<
+        Vec<(
+            U256, /* price */
+            u64,  /* updated_at */
+            u64,  /* decimals */
+        )>,
+    > {
+        pair_ids
+            .iter()
+            .map(|pair_id| self.get_price(pair_id))
+            .collect()
+    }
+
+    /// Returns the price of the pair as it was at the given block height.
+    fn get_price_at(
+        &self,
+        pair_id: &[u8],
+        height: u64,
+    ) -> anyhow::Result<(
+        U256, /* price */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )>;
+
+    /// Returns the time weighted average price of the pair over the last `window` seconds,
+    /// with the update time of the latest price included in the average.
+    fn get_twap(
+        &self,
+        pair_id: &[u8],
+        window: u64,
+    ) -> anyhow::Result<(
+        U256, /* twap */
+        u64,  /* updated_at */
+        u64,  /* decimals */
+    )>;
 }
 
 /// The native oracle context extension. This needs to be attached to the NativeContextExtensions
@@ -36,6 +82,8 @@ pub struct NativeOracleContext<'a> {
 
     // cache store to avoid redundant api call
     prices: BTreeMap<Vec<u8>, (U256, u64, u64)>,
+    prices_at: BTreeMap<(Vec<u8>, u64), (U256, u64, u64)>,
+    twaps: BTreeMap<(Vec<u8>, u64), (U256, u64, u64)>,
 }
 
 // =========================================================================================
@@ -48,6 +96,8 @@ impl<'a> NativeOracleContext<'a> {
         Self {
             api,
             prices: BTreeMap::default(),
+            prices_at: BTreeMap::default(),
+            twaps: BTreeMap::default(),
         }
     }
 }
@@ -59,6 +109,18 @@ fn partial_extension_error(msg: impl ToString) -> PartialVMError {
     PartialVMError::new(StatusCode::VM_EXTENSION_ERROR).with_message(msg.to_string())
 }
 
+fn remote_oracle_error(err: anyhow::Error) -> PartialVMError {
+    partial_extension_error(format!("remote oracle api failure: {}", err))
+}
+
+fn price_values((price, updated_at, decimals): (U256, u64, u64)) -> SmallVec<[Value; 1]> {
+    smallvec![
+        Value::u256(price),
+        Value::u64(updated_at),
+        Value::u64(decimals)
+    ]
+}
+
 // =========================================================================================
 // Implementations
 
@@ -80,12 +142,13 @@ fn native_get_price(
     )?;
 
     let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
-    let (price, updated_at, decimals) = if let Some(item) = oracle_context.prices.get(&pair_id) {
+    let item = if let Some(item) = oracle_context.prices.get(&pair_id) {
         item.to_owned()
     } else {
-        let item = oracle_context.api.get_price(&pair_id).map_err(|err| {
-            partial_extension_error(format!("remote oracle api failure: {}", err))
-        })?;
+        let item = oracle_context
+            .api
+            .get_price(&pair_id)
+            .map_err(remote_oracle_error)?;
 
         // insert to cache
         oracle_context.prices.insert(pair_id, item);
@@ -93,13 +156,145 @@ fn native_get_price(
         item
     };
 
+    Ok(price_values(item))
+}
+
+#[allow(clippy::result_large_err)]
+fn native_get_prices(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let pair_ids = safely_pop_vec_arg!(arguments, Vec<u8>);
+    let num_bytes: usize = pair_ids.iter().map(|pair_id| pair_id.len()).sum();
+    context.charge(
+        gas_params.oracle_get_price_base_cost
+            + gas_params.oracle_get_prices_per_pair * NumArgs::new(pair_ids.len() as u64)
+            + gas_params.oracle_get_price_per_byte * NumBytes::new(num_bytes as u64),
+    )?;
+
+    let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
+
+    // fetch the pairs missing from the cache, each distinct pair only once
+    let mut missing = pair_ids
+        .iter()
+        .filter(|pair_id| !oracle_context.prices.contains_key(*pair_id))
+        .cloned()
+        .collect::<Vec<_>>();
+    missing.sort();
+    missing.dedup();
+    if !missing.is_empty() {
+        let items = oracle_context
+            .api
+            .get_prices(&missing)
+            .map_err(remote_oracle_error)?;
+        if items.len() != missing.len() {
+            return Err(partial_extension_error(
+                "remote oracle api returned wrong number of prices",
+            )
+            .into());
+        }
+
+        // insert to cache
+        oracle_context.prices.extend(missing.into_iter().zip(items));
+    }
+
+    let mut prices = Vec::with_capacity(pair_ids.len());
+    let mut updated_ats = Vec::with_capacity(pair_ids.len());
+    let mut decimals = Vec::with_capacity(pair_ids.len());
+    for pair_id in pair_ids.iter() {
+        let (price, updated_at, decimal) = oracle_context.prices[pair_id];
+        prices.push(price);
+        updated_ats.push(updated_at);
+        decimals.push(decimal);
+    }
+
     Ok(smallvec![
-        Value::u256(price),
-        Value::u64(updated_at),
-        Value::u64(decimals)
+        Value::vector_u256(prices),
+        Value::vector_u64(updated_ats),
+        Value::vector_u64(decimals)
     ])
 }
 
+#[allow(clippy::result_large_err)]
+fn native_get_price_at(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let height = safely_pop_arg!(arguments, u64);
+    let pair_id = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    context.charge(
+        gas_params.oracle_get_historical_price_base_cost
+            + gas_params.oracle_get_price_per_byte * NumBytes::new(pair_id.len() as u64),
+    )?;
+
+    let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
+    let key = (pair_id, height);
+    let item = if let Some(item) = oracle_context.prices_at.get(&key) {
+        item.to_owned()
+    } else {
+        let item = oracle_context
+            .api
+            .get_price_at(&key.0, height)
+            .map_err(remote_oracle_error)?;
+
+        // insert to cache
+        oracle_context.prices_at.insert(key, item);
+
+        item
+    };
+
+    Ok(price_values(item))
+}
+
+#[allow(clippy::result_large_err)]
+fn native_get_twap(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let window = safely_pop_arg!(arguments, u64);
+    let pair_id = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    context.charge(
+        gas_params.oracle_get_historical_price_base_cost
+            + gas_params.oracle_get_price_per_byte * NumBytes::new(pair_id.len() as u64),
+    )?;
+
+    let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
+    let key = (pair_id, window);
+    let item = if let Some(item) = oracle_context.twaps.get(&key) {
+        item.to_owned()
+    } else {
+        let item = oracle_context
+            .api
+            .get_twap(&key.0, window)
+            .map_err(remote_oracle_error)?;
+
+        // insert to cache
+        oracle_context.twaps.insert(key, item);
+
+        item
+    };
+
+    Ok(price_values(item))
+}
+
 #[cfg(feature = "testing")]
 #[allow(clippy::result_large_err)]
 fn native_test_only_set_price(
@@ -123,6 +318,54 @@ fn native_test_only_set_price(
     Ok(smallvec![])
 }
 
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_price_at(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 5);
+
+    let decimals = safely_pop_arg!(arguments, u64);
+    let updated_at = safely_pop_arg!(arguments, u64);
+    let price = safely_pop_arg!(arguments, U256);
+    let height = safely_pop_arg!(arguments, u64);
+    let pair_id = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
+    oracle_context
+        .prices_at
+        .insert((pair_id, height), (price, updated_at, decimals));
+
+    Ok(smallvec![])
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_twap(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 5);
+
+    let decimals = safely_pop_arg!(arguments, u64);
+    let updated_at = safely_pop_arg!(arguments, u64);
+    let twap = safely_pop_arg!(arguments, U256);
+    let window = safely_pop_arg!(arguments, u64);
+    let pair_id = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    let oracle_context = context.extensions_mut().get_mut::<NativeOracleContext>();
+    oracle_context
+        .twaps
+        .insert((pair_id, window), (twap, updated_at, decimals));
+
+    Ok(smallvec![])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -131,13 +374,22 @@ pub fn make_all(
     builder: &SafeNativeBuilder,
 ) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
     let mut natives = vec![];
-    natives.extend([("get_price_internal", native_get_price as RawSafeNative)]);
+    natives.extend([
+        ("get_price_internal", native_get_price as RawSafeNative),
+        ("get_prices_internal", native_get_prices),
+        ("get_price_at_internal", native_get_price_at),
+        ("get_twap_internal", native_get_twap),
+    ]);
 
     #[cfg(feature = "testing")]
-    natives.extend([(
-        "set_price_internal",
-        native_test_only_set_price as RawSafeNative,
-    )]);
+    natives.extend([
+        (
+            "set_price_internal",
+            native_test_only_set_price as RawSafeNative,
+        ),
+        ("set_price_at_internal", native_test_only_set_price_at),
+        ("set_twap_internal", native_test_only_set_twap),
+    ]);
 
     builder.make_named_natives(natives)
 }
@@ -0,0 +1,717 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use move_binary_format::errors::PartialVMError;
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::NumBytes,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag},
+    u256::U256,
+    value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
+};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Reference, Struct, Value},
+};
+use smallvec::{smallvec, SmallVec};
+
+use crate::{
+    helpers::get_string,
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
+    safely_pop_arg, safely_pop_vec_arg,
+};
+
+// See stdlib/error.move
+const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+
+// native errors always start from 100
+const EUNABLE_TO_ENCODE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
+const EUNABLE_TO_DECODE: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 101;
+const EINVALID_DESCRIPTOR: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 102;
+
+/// Maximum nesting depth of messages while encoding or decoding a value.
+const MAX_RECURSIVE_DEPTH: usize = 10;
+
+/// The largest field number allowed by the protobuf spec.
+const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+/// Field numbers reserved for the protobuf implementation.
+const RESERVED_FIELD_NUMBERS: std::ops::RangeInclusive<u32> = 19000..=19999;
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+/// Field numbers registered through a `Descriptor`, keyed by the struct type name.
+type FieldNumbers = BTreeMap<String, Vec<u32>>;
+
+fn unable_to_encode() -> SafeNativeError {
+    SafeNativeError::Abort {
+        abort_code: EUNABLE_TO_ENCODE,
+    }
+}
+
+fn unable_to_decode() -> SafeNativeError {
+    SafeNativeError::Abort {
+        abort_code: EUNABLE_TO_DECODE,
+    }
+}
+
+fn invalid_descriptor() -> SafeNativeError {
+    SafeNativeError::Abort {
+        abort_code: EINVALID_DESCRIPTOR,
+    }
+}
+
+/// Reads `vector<MessageDescriptor>` from the Move side, where
+/// `MessageDescriptor { type_name: String, field_numbers: vector<u32> }`.
+#[allow(clippy::result_large_err)]
+fn read_field_numbers(messages: Vec<Struct>) -> SafeNativeResult<FieldNumbers> {
+    let mut field_numbers = FieldNumbers::new();
+    for message in messages {
+        let mut vals = message.unpack()?;
+        let (type_name, numbers) = match (vals.next(), vals.next()) {
+            (Some(type_name), Some(numbers)) => (type_name, numbers),
+            _ => return Err(invalid_descriptor()),
+        };
+
+        let type_name = String::from_utf8(get_string(type_name.value_as::<Struct>()?)?)
+            .map_err(|_| invalid_descriptor())?;
+        let numbers = numbers.value_as::<Vec<u32>>()?;
+
+        let mut seen = BTreeSet::new();
+        for number in numbers.iter() {
+            if *number == 0
+                || *number > MAX_FIELD_NUMBER
+                || RESERVED_FIELD_NUMBERS.contains(number)
+                || !seen.insert(*number)
+            {
+                return Err(invalid_descriptor());
+            }
+        }
+
+        if field_numbers.insert(type_name, numbers).is_some() {
+            return Err(invalid_descriptor());
+        }
+    }
+
+    Ok(field_numbers)
+}
+
+/// Returns the field numbers of the given struct, which are the registered ones if any,
+/// or the 1-based declaration order of the fields.
+#[allow(clippy::result_large_err)]
+fn struct_field_numbers(
+    field_numbers: &FieldNumbers,
+    type_: &StructTag,
+    num_fields: usize,
+) -> SafeNativeResult<Vec<u32>> {
+    match field_numbers.get(&type_.to_string()) {
+        Some(numbers) if numbers.len() == num_fields => Ok(numbers.clone()),
+        Some(_) => Err(invalid_descriptor()),
+        None => Ok((1..=num_fields as u32).collect()),
+    }
+}
+
+fn is_std_struct(type_: &StructTag, module: &str, name: &str) -> bool {
+    type_.address == AccountAddress::ONE
+        && type_.module.as_str() == module
+        && type_.name.as_str() == name
+}
+
+fn is_string(type_: &StructTag) -> bool {
+    is_std_struct(type_, "string", "String")
+}
+
+fn is_option(type_: &StructTag) -> bool {
+    is_std_struct(type_, "option", "Option")
+}
+
+fn is_object(type_: &StructTag) -> bool {
+    is_std_struct(type_, "object", "Object")
+}
+
+// =========================================================================================
+// Encoding
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_key(buf: &mut Vec<u8>, field_number: u32, wire_type: u8) {
+    write_varint(buf, ((field_number as u64) << 3) | wire_type as u64);
+}
+
+fn write_len_delimited(buf: &mut Vec<u8>, field_number: u32, bytes: &[u8]) {
+    write_key(buf, field_number, WIRE_LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn as_varint(value: &MoveValue) -> Option<u64> {
+    match value {
+        MoveValue::Bool(v) => Some(*v as u64),
+        MoveValue::U8(v) => Some(*v as u64),
+        MoveValue::U16(v) => Some(*v as u64),
+        MoveValue::U32(v) => Some(*v as u64),
+        MoveValue::U64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn as_bytes(values: &[MoveValue]) -> SafeNativeResult<Vec<u8>> {
+    values
+        .iter()
+        .map(|v| match v {
+            MoveValue::U8(byte) => Ok(*byte),
+            _ => Err(unable_to_encode()),
+        })
+        .collect()
+}
+
+/// Returns the inner value of the first field of a wrapper struct like `String` or `Option`.
+#[allow(clippy::result_large_err)]
+fn first_field(fields: &[(Identifier, MoveValue)]) -> SafeNativeResult<&MoveValue> {
+    fields.first().map(|(_, v)| v).ok_or_else(unable_to_encode)
+}
+
+#[allow(clippy::result_large_err)]
+fn encode_message(
+    field_numbers: &FieldNumbers,
+    type_: &StructTag,
+    fields: &[(Identifier, MoveValue)],
+    depth: usize,
+) -> SafeNativeResult<Vec<u8>> {
+    if depth > MAX_RECURSIVE_DEPTH {
+        return Err(unable_to_encode());
+    }
+
+    let mut buf = vec![];
+
+    // The move compiler inserts a dummy field with the value of false
+    // for structs with no fields.
+    if fields.len() == 1 && fields[0].0.as_str() == "dummy_field" {
+        return Ok(buf);
+    }
+
+    let numbers = struct_field_numbers(field_numbers, type_, fields.len())?;
+    for (number, (_, value)) in numbers.into_iter().zip(fields.iter()) {
+        encode_field(&mut buf, field_numbers, number, value, false, depth)?;
+    }
+
+    Ok(buf)
+}
+
+/// Encodes a single field. Default values are skipped as in proto3, unless `explicit`
+/// is set for repeated elements and present optional values.
+#[allow(clippy::result_large_err)]
+fn encode_field(
+    buf: &mut Vec<u8>,
+    field_numbers: &FieldNumbers,
+    number: u32,
+    value: &MoveValue,
+    explicit: bool,
+    depth: usize,
+) -> SafeNativeResult<()> {
+    if let Some(v) = as_varint(value) {
+        if explicit || v != 0 {
+            write_key(buf, number, WIRE_VARINT);
+            write_varint(buf, v);
+        }
+
+        return Ok(());
+    }
+
+    match value {
+        MoveValue::U128(v) => {
+            if explicit || *v != 0 {
+                write_len_delimited(buf, number, v.to_string().as_bytes());
+            }
+        }
+        MoveValue::U256(v) => {
+            if explicit || *v != U256::zero() {
+                write_len_delimited(buf, number, v.to_string().as_bytes());
+            }
+        }
+        MoveValue::Address(addr) => {
+            write_len_delimited(buf, number, addr.to_hex_literal().as_bytes());
+        }
+        MoveValue::Vector(elems) => {
+            if elems.is_empty() {
+                return Ok(());
+            }
+
+            if let MoveValue::U8(_) = elems[0] {
+                write_len_delimited(buf, number, &as_bytes(elems)?);
+            } else if explicit {
+                // repeated fields cannot be nested
+                return Err(unable_to_encode());
+            } else if as_varint(&elems[0]).is_some() {
+                // packed encoding for repeated scalars
+                let mut packed = vec![];
+                for elem in elems {
+                    write_varint(&mut packed, as_varint(elem).ok_or_else(unable_to_encode)?);
+                }
+                write_len_delimited(buf, number, &packed);
+            } else {
+                for elem in elems {
+                    encode_field(buf, field_numbers, number, elem, true, depth)?;
+                }
+            }
+        }
+        MoveValue::Struct(MoveStruct::WithTypes { _type_, _fields }) => {
+            if is_string(_type_) {
+                let bytes = match first_field(_fields)? {
+                    MoveValue::Vector(bytes) => as_bytes(bytes)?,
+                    _ => return Err(unable_to_encode()),
+                };
+                if explicit || !bytes.is_empty() {
+                    write_len_delimited(buf, number, &bytes);
+                }
+            } else if is_option(_type_) {
+                match first_field(_fields)? {
+                    MoveValue::Vector(elems) => {
+                        if let Some(elem) = elems.first() {
+                            // a present value is always written, except for repeated
+                            // fields which have no presence in proto3
+                            let explicit = !matches!(elem, MoveValue::Vector(_));
+                            encode_field(buf, field_numbers, number, elem, explicit, depth)?;
+                        }
+                    }
+                    _ => return Err(unable_to_encode()),
+                }
+            } else if is_object(_type_) {
+                encode_field(
+                    buf,
+                    field_numbers,
+                    number,
+                    first_field(_fields)?,
+                    true,
+                    depth,
+                )?;
+            } else {
+                let message = encode_message(field_numbers, _type_, _fields, depth + 1)?;
+                write_len_delimited(buf, number, &message);
+            }
+        }
+        _ => return Err(unable_to_encode()),
+    }
+
+    Ok(())
+}
+
+// =========================================================================================
+// Decoding
+
+/// A raw field value read from the wire.
+#[derive(Clone, Copy)]
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    Len(&'a [u8]),
+}
+
+#[allow(clippy::result_large_err)]
+fn read_varint(bytes: &[u8], pos: &mut usize) -> SafeNativeResult<u64> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos).ok_or_else(unable_to_decode)?;
+        *pos += 1;
+
+        // the tenth byte can only carry the most significant bit
+        if shift == 63 && byte > 1 {
+            return Err(unable_to_decode());
+        }
+
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(unable_to_decode())
+}
+
+#[allow(clippy::result_large_err)]
+fn read_slice<'a>(bytes: &'a [u8], pos: &mut usize, len: usize) -> SafeNativeResult<&'a [u8]> {
+    let end = pos.checked_add(len).ok_or_else(unable_to_decode)?;
+    let slice = bytes.get(*pos..end).ok_or_else(unable_to_decode)?;
+    *pos = end;
+    Ok(slice)
+}
+
+/// Splits a message into its field values grouped by field number, in wire order.
+#[allow(clippy::result_large_err)]
+fn read_message(bytes: &[u8]) -> SafeNativeResult<BTreeMap<u32, Vec<WireValue<'_>>>> {
+    let mut fields: BTreeMap<u32, Vec<WireValue>> = BTreeMap::new();
+
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let key = read_varint(bytes, &mut pos)?;
+        let number = u32::try_from(key >> 3).map_err(|_| unable_to_decode())?;
+        if number == 0 || number > MAX_FIELD_NUMBER {
+            return Err(unable_to_decode());
+        }
+
+        let value = match (key & 0x7) as u8 {
+            WIRE_VARINT => WireValue::Varint(read_varint(bytes, &mut pos)?),
+            WIRE_FIXED64 => {
+                let slice = read_slice(bytes, &mut pos, 8)?;
+                WireValue::Fixed64(u64::from_le_bytes(slice.try_into().unwrap()))
+            }
+            WIRE_LEN => {
+                let len = read_varint(bytes, &mut pos)?;
+                let len = usize::try_from(len).map_err(|_| unable_to_decode())?;
+                WireValue::Len(read_slice(bytes, &mut pos, len)?)
+            }
+            WIRE_FIXED32 => {
+                let slice = read_slice(bytes, &mut pos, 4)?;
+                WireValue::Fixed32(u32::from_le_bytes(slice.try_into().unwrap()))
+            }
+            // groups are deprecated and not supported
+            _ => return Err(unable_to_decode()),
+        };
+
+        fields.entry(number).or_default().push(value);
+    }
+
+    Ok(fields)
+}
+
+#[allow(clippy::result_large_err)]
+fn wire_to_u64(value: WireValue) -> SafeNativeResult<u64> {
+    match value {
+        WireValue::Varint(v) | WireValue::Fixed64(v) => Ok(v),
+        WireValue::Fixed32(v) => Ok(v as u64),
+        WireValue::Len(_) => Err(unable_to_decode()),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn wire_to_str(value: WireValue) -> SafeNativeResult<&str> {
+    match value {
+        WireValue::Len(bytes) => std::str::from_utf8(bytes).map_err(|_| unable_to_decode()),
+        _ => Err(unable_to_decode()),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn scalar_to_value(layout: &MoveTypeLayout, v: u64) -> SafeNativeResult<Value> {
+    let out_of_range = |_| unable_to_decode();
+    Ok(match layout {
+        MoveTypeLayout::Bool => Value::bool(v != 0),
+        MoveTypeLayout::U8 => Value::u8(u8::try_from(v).map_err(out_of_range)?),
+        MoveTypeLayout::U16 => Value::u16(u16::try_from(v).map_err(out_of_range)?),
+        MoveTypeLayout::U32 => Value::u32(u32::try_from(v).map_err(out_of_range)?),
+        MoveTypeLayout::U64 => Value::u64(v),
+        _ => return Err(unable_to_decode()),
+    })
+}
+
+fn is_scalar(layout: &MoveTypeLayout) -> bool {
+    matches!(
+        layout,
+        MoveTypeLayout::Bool
+            | MoveTypeLayout::U8
+            | MoveTypeLayout::U16
+            | MoveTypeLayout::U32
+            | MoveTypeLayout::U64
+    )
+}
+
+#[allow(clippy::result_large_err)]
+fn decode_message(
+    caller: &ModuleId,
+    field_numbers: &FieldNumbers,
+    layout: &MoveStructLayout,
+    bytes: &[u8],
+    depth: usize,
+) -> SafeNativeResult<Value> {
+    if depth > MAX_RECURSIVE_DEPTH {
+        return Err(unable_to_decode());
+    }
+
+    let (type_, fields) = match layout {
+        MoveStructLayout::WithTypes { type_, fields } => (type_, fields),
+        _ => return Err(unable_to_decode()),
+    };
+
+    // The move compiler inserts a dummy field with the value of false
+    // for structs with no fields.
+    if fields.len() == 1 && fields[0].name.as_str() == "dummy_field" {
+        return Ok(Value::struct_(Struct::pack(vec![Value::bool(false)])));
+    }
+
+    if !type_.type_args.is_empty() {
+        return Err(unable_to_decode());
+    }
+
+    // Enforce that the struct creation is in the same module
+    if !type_.module_id().eq(caller) {
+        return Err(unable_to_decode());
+    }
+
+    let numbers = struct_field_numbers(field_numbers, type_, fields.len())?;
+    let mut wire_fields = read_message(bytes)?;
+    let values = numbers
+        .into_iter()
+        .zip(fields.iter())
+        .map(|(number, field)| {
+            let occurrences = wire_fields.remove(&number).unwrap_or_default();
+            decode_field(caller, field_numbers, &field.layout, &occurrences, depth)
+        })
+        .collect::<SafeNativeResult<Vec<_>>>()?;
+
+    Ok(Value::struct_(Struct::pack(values)))
+}
+
+/// Decodes a field from all of its occurrences on the wire. As in protobuf, the last
+/// occurrence wins for singular fields and missing fields take the default value.
+#[allow(clippy::result_large_err)]
+fn decode_field(
+    caller: &ModuleId,
+    field_numbers: &FieldNumbers,
+    layout: &MoveTypeLayout,
+    occurrences: &[WireValue],
+    depth: usize,
+) -> SafeNativeResult<Value> {
+    let last = occurrences.last().copied();
+
+    if is_scalar(layout) {
+        let v = last.map(wire_to_u64).transpose()?.unwrap_or_default();
+        return scalar_to_value(layout, v);
+    }
+
+    Ok(match layout {
+        MoveTypeLayout::U128 => Value::u128(match last {
+            Some(v) => wire_to_str(v)?.parse().map_err(|_| unable_to_decode())?,
+            None => 0,
+        }),
+        MoveTypeLayout::U256 => Value::u256(match last {
+            Some(v) => wire_to_str(v)?.parse().map_err(|_| unable_to_decode())?,
+            None => U256::zero(),
+        }),
+        MoveTypeLayout::Address => Value::address(match last {
+            Some(v) => {
+                AccountAddress::from_hex_literal(wire_to_str(v)?).map_err(|_| unable_to_decode())?
+            }
+            None => AccountAddress::ZERO,
+        }),
+        MoveTypeLayout::Vector(elem) if elem.as_ref() == &MoveTypeLayout::U8 => {
+            Value::vector_u8(match last {
+                Some(WireValue::Len(bytes)) => bytes.to_vec(),
+                Some(_) => return Err(unable_to_decode()),
+                None => vec![],
+            })
+        }
+        MoveTypeLayout::Vector(elem) if is_scalar(elem) => {
+            let mut values = vec![];
+            for occurrence in occurrences {
+                match occurrence {
+                    // packed encoding
+                    WireValue::Len(bytes) => {
+                        let mut pos = 0;
+                        while pos < bytes.len() {
+                            values.push(scalar_to_value(elem, read_varint(bytes, &mut pos)?)?);
+                        }
+                    }
+                    v => values.push(scalar_to_value(elem, wire_to_u64(*v)?)?),
+                }
+            }
+
+            Value::vector_for_testing_only(values)
+        }
+        MoveTypeLayout::Vector(elem) => {
+            if matches!(elem.as_ref(), MoveTypeLayout::Vector(inner) if inner.as_ref() != &MoveTypeLayout::U8)
+            {
+                // repeated fields cannot be nested
+                return Err(unable_to_decode());
+            }
+
+            let values = occurrences
+                .iter()
+                .map(|occurrence| {
+                    decode_field(
+                        caller,
+                        field_numbers,
+                        elem,
+                        std::slice::from_ref(occurrence),
+                        depth,
+                    )
+                })
+                .collect::<SafeNativeResult<Vec<_>>>()?;
+
+            Value::vector_for_testing_only(values)
+        }
+        MoveTypeLayout::Struct(struct_layout) => {
+            let (type_, fields) = match struct_layout {
+                MoveStructLayout::WithTypes { type_, fields } => (type_, fields),
+                _ => return Err(unable_to_decode()),
+            };
+
+            if is_string(type_) {
+                let bytes = match last {
+                    Some(v) => wire_to_str(v)?.as_bytes().to_vec(),
+                    None => vec![],
+                };
+                Value::struct_(Struct::pack(vec![Value::vector_u8(bytes)]))
+            } else if is_option(type_) {
+                let elem = match fields.first().map(|f| &f.layout) {
+                    Some(MoveTypeLayout::Vector(elem)) => elem,
+                    _ => return Err(unable_to_decode()),
+                };
+
+                let values = if occurrences.is_empty() {
+                    vec![]
+                } else {
+                    vec![decode_field(
+                        caller,
+                        field_numbers,
+                        elem,
+                        occurrences,
+                        depth,
+                    )?]
+                };
+                Value::struct_(Struct::pack(vec![Value::vector_for_testing_only(values)]))
+            } else {
+                // `Object<T>` is not special cased, so it is rejected as a struct of
+                // another module; decoding it would forge object handles.
+                let bytes = match last {
+                    Some(WireValue::Len(bytes)) => bytes,
+                    Some(_) => return Err(unable_to_decode()),
+                    None => &[],
+                };
+                decode_message(caller, field_numbers, struct_layout, bytes, depth + 1)?
+            }
+        }
+        _ => return Err(unable_to_decode()),
+    })
+}
+
+/***************************************************************************************************
+ * native fun encode_internal
+ *
+ *   gas cost: base_cost + unit_cost * encoded_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_encode(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert_eq!(arguments.len(), 2);
+
+    let field_numbers = read_field_numbers(safely_pop_vec_arg!(arguments, Struct))?;
+
+    let ty = &ty_args[0];
+    let x = safely_pop_arg!(arguments, Reference);
+    let value = x.read_ref().map_err(SafeNativeError::InvariantViolation)?;
+
+    let (layout, has_identifier_mappings) =
+        context.type_to_type_layout_with_identifier_mappings(ty)?;
+    if has_identifier_mappings {
+        return Err(unable_to_encode());
+    }
+
+    let move_value = value.as_move_value(&layout);
+    let annotated_layout = context.type_to_fully_annotated_layout(ty)?;
+    let bytes = match move_value.decorate(&annotated_layout) {
+        MoveValue::Struct(MoveStruct::WithTypes { _type_, _fields })
+            if !is_string(&_type_) && !is_option(&_type_) && !is_object(&_type_) =>
+        {
+            encode_message(&field_numbers, &_type_, &_fields, 1)?
+        }
+        // only messages can be encoded at the top level
+        _ => return Err(unable_to_encode()),
+    };
+
+    context.charge(
+        gas_params.protobuf_encode_base
+            + gas_params.protobuf_encode_per_byte * NumBytes::new(bytes.len() as u64),
+    )?;
+
+    Ok(smallvec![Value::vector_u8(bytes)])
+}
+
+/***************************************************************************************************
+ * native fun decode_internal
+ *
+ *   gas cost: base_cost + unit_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_decode(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert_eq!(arguments.len(), 2);
+
+    let field_numbers = read_field_numbers(safely_pop_vec_arg!(arguments, Struct))?;
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    context.charge(
+        gas_params.protobuf_decode_base
+            + gas_params.protobuf_decode_per_byte * NumBytes::new(bytes.len() as u64),
+    )?;
+
+    let ty = &ty_args[0];
+    let (_, has_identifier_mappings) = context.type_to_type_layout_with_identifier_mappings(ty)?;
+    if has_identifier_mappings {
+        return Err(unable_to_decode());
+    }
+
+    let struct_layout = match context.type_to_fully_annotated_layout(ty)? {
+        MoveTypeLayout::Struct(struct_layout) => struct_layout,
+        // only messages can be decoded at the top level
+        _ => return Err(unable_to_decode()),
+    };
+
+    // Extract caller from the stack to assert the struct creation module permission.
+    let caller = context
+        .stack_frames(1)
+        .stack_trace()
+        .first()
+        .and_then(|(id, _, _)| id.clone())
+        .ok_or_else(|| {
+            SafeNativeError::InvariantViolation(PartialVMError::new(
+                StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+            ))
+        })?;
+
+    let value = decode_message(&caller, &field_numbers, &struct_layout, &bytes, 1)?;
+
+    Ok(smallvec![value])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        ("encode_internal", native_encode as RawSafeNative),
+        ("decode_internal", native_decode),
+    ];
+
+    builder.make_named_natives(natives)
+}
@@ -10,8 +10,9 @@ use move_vm_types::{
     values::{Value, Vector},
 };
 
+use sha3::{Digest, Sha3_256};
 use smallvec::{smallvec, SmallVec};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 use crate::{
     interface::{
@@ -22,9 +23,11 @@ use crate::{
 
 // See stdlib/error.move
 const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+const ECATEGORY_PERMISSION_DENIED: u64 = 0x5;
 
 // native errors always start from 100
 const UNABLE_TO_PARSE_STRING: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
+const QUERY_PATH_NOT_ALLOWED: u64 = (ECATEGORY_PERMISSION_DENIED << 16) + 101;
 
 // API to allow move modules to query information from the environment
 // it is executed in. This is typically used to query a custom function
@@ -43,6 +46,12 @@ pub trait QueryAPI {
 pub struct NativeQueryContext<'a> {
     api: &'a dyn QueryAPI,
 
+    // stargate query paths allowed to be queried; every path is allowed when empty
+    allowed_paths: BTreeSet<String>,
+
+    // cache store to avoid redundant api call, keyed by the request hash
+    responses_cache: BTreeMap<[u8; 32], Vec<u8>>,
+
     #[cfg(feature = "testing")]
     responses: BTreeMap<String, Vec<u8>>,
 }
@@ -51,14 +60,70 @@ pub struct NativeQueryContext<'a> {
 impl<'a> NativeQueryContext<'a> {
     /// Create a new instance of a native query context. This must be passed in via an
     /// extension into VM session functions.
-    pub fn new(api: &'a dyn QueryAPI) -> Self {
+    pub fn new(api: &'a dyn QueryAPI, allowed_query_paths: &[String]) -> Self {
         Self {
             api,
+            allowed_paths: allowed_query_paths.iter().cloned().collect(),
+            responses_cache: BTreeMap::new(),
 
             #[cfg(feature = "testing")]
             responses: BTreeMap::new(),
         }
     }
+
+    fn is_allowed_path(&self, path: &str) -> bool {
+        self.allowed_paths.is_empty() || self.allowed_paths.contains(path)
+    }
+
+    /// Drop the cached responses, which are stale once the host state changed.
+    pub(crate) fn clear_cache(&mut self) {
+        self.responses_cache.clear();
+    }
+}
+
+/// Send the query request to the host, or return the cached response when the same
+/// request was already made in this session since the last dispatched message.
+///
+/// The host reported gas is charged on a cache miss, unless `gas_override` is given;
+/// a cache hit is not charged beyond the base cost of the native.
+#[allow(clippy::result_large_err)]
+fn query_with_cache(
+    context: &mut SafeNativeContext,
+    req: QueryRequest,
+    gas_override: Option<InternalGas>,
+) -> SafeNativeResult<Vec<u8>> {
+    let req = serde_json::to_vec(&req)
+        .map_err(|err| partial_error(StatusCode::VALUE_SERIALIZATION_ERROR, err))?;
+    let key: [u8; 32] = Sha3_256::digest(&req).into();
+
+    let query_context = context.extensions().get::<NativeQueryContext>();
+    if let Some(res) = query_context.responses_cache.get(&key) {
+        return Ok(res.clone());
+    }
+
+    let gas_balance: u64 = context.gas_balance().into();
+    let (res, used_gas) = query_context
+        .api
+        .query(req.as_slice(), gas_balance / GAS_UNIT_SCALING_FACTOR);
+    let used_gas =
+        gas_override.unwrap_or_else(|| InternalGas::from(used_gas * GAS_UNIT_SCALING_FACTOR));
+    context.charge(used_gas)?;
+
+    let res = match res {
+        Ok(val) => val,
+        Err(err) => {
+            return Err(SafeNativeError::InvariantViolation(partial_error(
+                StatusCode::ABORTED,
+                err,
+            )))
+        }
+    };
+
+    // insert to cache
+    let query_context = context.extensions_mut().get_mut::<NativeQueryContext>();
+    query_context.responses_cache.insert(key, res.clone());
+
+    Ok(res)
 }
 
 #[allow(clippy::result_large_err)]
@@ -86,8 +151,6 @@ fn native_query_custom(
         abort_code: UNABLE_TO_PARSE_STRING,
     })?;
 
-    let query_context = context.extensions().get::<NativeQueryContext>();
-
     #[cfg(feature = "testing")]
     if !name.is_empty() {
         match name.as_str() {
@@ -103,6 +166,7 @@ fn native_query_custom(
                 hasher.update(&data);
                 let hash = hex::encode(hasher.finalize());
 
+                let query_context = context.extensions().get::<NativeQueryContext>();
                 let res = query_context
                     .responses
                     .get(&hash)
@@ -116,26 +180,7 @@ fn native_query_custom(
     }
 
     let custom_query = CustomQuery { name, data };
-    let req = QueryRequest::Custom(custom_query);
-    let req = serde_json::to_vec(&req)
-        .map_err(|err| partial_error(StatusCode::VALUE_SERIALIZATION_ERROR, err))?;
-
-    let gas_balance: u64 = context.gas_balance().into();
-    let (res, used_gas) = query_context
-        .api
-        .query(req.as_slice(), gas_balance / GAS_UNIT_SCALING_FACTOR);
-    let used_gas = InternalGas::from(used_gas * GAS_UNIT_SCALING_FACTOR);
-    context.charge(used_gas)?;
-
-    let res = match res {
-        Ok(val) => val,
-        Err(err) => {
-            return Err(SafeNativeError::InvariantViolation(partial_error(
-                StatusCode::ABORTED,
-                err,
-            )))
-        }
-    };
+    let res = query_with_cache(context, QueryRequest::Custom(custom_query), None)?;
 
     Ok(smallvec![Value::vector_u8(res)])
 }
@@ -166,7 +211,13 @@ fn native_query_stargate(
     })?;
 
     let query_context = context.extensions().get::<NativeQueryContext>();
+    if !query_context.is_allowed_path(&path) {
+        return Err(SafeNativeError::Abort {
+            abort_code: QUERY_PATH_NOT_ALLOWED,
+        });
+    }
 
+    // queries without a registered response are sent to the host
     #[cfg(feature = "testing")]
     if !path.is_empty() {
         let mut hasher = Sha3_256::new();
@@ -174,37 +225,21 @@ fn native_query_stargate(
         hasher.update(&data);
         let hash = hex::encode(hasher.finalize());
 
-        let res = query_context
-            .responses
-            .get(&hash)
-            .ok_or(SafeNativeError::Abort {
-                abort_code: UNKNOWN_QUERY,
-            })?;
-
-        return Ok(smallvec![Value::vector_u8(res.clone())]);
+        if let Some(res) = query_context.responses.get(&hash) {
+            return Ok(smallvec![Value::vector_u8(res.clone())]);
+        }
     }
 
+    let gas_override = context
+        .native_gas_params
+        .query
+        .stargate_override(path.as_str());
     let stargate_query = StargateQuery { path, data };
-    let req = QueryRequest::Stargate(stargate_query);
-    let req = serde_json::to_vec(&req)
-        .map_err(|err| partial_error(StatusCode::VALUE_SERIALIZATION_ERROR, err))?;
-
-    let gas_balance: u64 = context.gas_balance().into();
-    let (res, used_gas) = query_context
-        .api
-        .query(req.as_slice(), gas_balance / GAS_UNIT_SCALING_FACTOR);
-    let used_gas = InternalGas::from(used_gas * GAS_UNIT_SCALING_FACTOR);
-    context.charge(used_gas)?;
-
-    let res = match res {
-        Ok(val) => val,
-        Err(err) => {
-            return Err(SafeNativeError::InvariantViolation(partial_error(
-                StatusCode::ABORTED,
-                err,
-            )))
-        }
-    };
+    let res = query_with_cache(
+        context,
+        QueryRequest::Stargate(stargate_query),
+        gas_override,
+    )?;
 
     Ok(smallvec![Value::vector_u8(res)])
 }
@@ -240,12 +275,6 @@ use bech32::{Bech32, Hrp};
 use move_core_types::account_address::AccountAddress;
 #[cfg(feature = "testing")]
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "testing")]
-use std::collections::BTreeMap;
-
-#[cfg(feature = "testing")]
-use sha3::{Digest, Sha3_256};
-
 #[cfg(feature = "testing")]
 const UNKNOWN_QUERY: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 110;
 
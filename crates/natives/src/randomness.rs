@@ -0,0 +1,181 @@
+use better_any::{Tid, TidAble};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use sha3::{Digest, Sha3_256};
+use smallvec::{smallvec, SmallVec};
+
+use std::collections::VecDeque;
+
+use crate::{
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
+    transaction_context::NativeTransactionContext,
+};
+
+/// Domain separation tag of the randomness derivation.
+const RANDOMNESS_DST: &[u8] = b"INITIA_MOVE_RANDOMNESS";
+
+// native errors always start from 100
+const ECATEGORY_INVALID_ARGUMENT: u64 = 0x1;
+const ECATEGORY_INVALID_STATE: u64 = 0x3;
+const ECATEGORY_UNAVAILABLE: u64 = 0xD;
+
+const EINVALID_SEED: u64 = (ECATEGORY_INVALID_ARGUMENT << 16) + 100;
+const ERANDOMNESS_NOT_UNBIASABLE: u64 = (ECATEGORY_INVALID_STATE << 16) + 101;
+const ERANDOMNESS_NOT_AVAILABLE: u64 = (ECATEGORY_UNAVAILABLE << 16) + 102;
+
+/// The native randomness context. The seed is provided by the chain per block and
+/// the random bytes are derived from the seed, the session id and a counter, so
+/// every call in a transaction returns different bytes.
+#[derive(Tid)]
+pub struct NativeRandomnessContext {
+    seed: Option<[u8; 32]>,
+    /// Number of random values drawn during the execution of this session.
+    counter: u64,
+    /// Whether the current execution started from a `#[randomness]` entry function.
+    unbiasable: bool,
+}
+
+impl NativeRandomnessContext {
+    pub fn new(seed: Option<[u8; 32]>) -> Self {
+        Self {
+            seed,
+            counter: 0,
+            unbiasable: false,
+        }
+    }
+
+    /// Mark the current execution as unbiasable, which means the entry function
+    /// is annotated with `#[randomness]`.
+    pub fn mark_unbiasable(&mut self) {
+        self.unbiasable = true;
+    }
+
+    pub fn is_unbiasable(&self) -> bool {
+        self.unbiasable
+    }
+
+    #[cfg(feature = "testing")]
+    pub fn set_seed(&mut self, seed: [u8; 32]) {
+        self.seed = Some(seed);
+    }
+}
+
+/***************************************************************************************************
+ * native fun next_32_bytes
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_next_32_bytes(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.is_empty());
+
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.randomness_next_32_bytes_base)?;
+
+    let session_id = context
+        .extensions()
+        .get::<NativeTransactionContext>()
+        .session_id();
+
+    let randomness_context = context
+        .extensions_mut()
+        .get_mut::<NativeRandomnessContext>();
+    if !randomness_context.unbiasable {
+        return Err(SafeNativeError::Abort {
+            abort_code: ERANDOMNESS_NOT_UNBIASABLE,
+        });
+    }
+
+    let seed = randomness_context.seed.ok_or(SafeNativeError::Abort {
+        abort_code: ERANDOMNESS_NOT_AVAILABLE,
+    })?;
+
+    randomness_context.counter += 1;
+
+    let mut digest = Sha3_256::new();
+    Digest::update(&mut digest, RANDOMNESS_DST);
+    Digest::update(&mut digest, seed);
+    Digest::update(&mut digest, session_id);
+    Digest::update(&mut digest, randomness_context.counter.to_le_bytes());
+    let bytes = digest.finalize().to_vec();
+
+    Ok(smallvec![Value::vector_u8(bytes)])
+}
+
+/***************************************************************************************************
+ * native fun is_unbiasable
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_is_unbiasable(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.is_empty());
+
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.randomness_is_unbiasable_base)?;
+
+    let randomness_context = context.extensions().get::<NativeRandomnessContext>();
+    Ok(smallvec![Value::bool(randomness_context.unbiasable)])
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_initialize_for_testing(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use crate::safely_pop_arg;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let seed = safely_pop_arg!(arguments, Vec<u8>);
+    let seed: [u8; 32] = seed.try_into().map_err(|_| SafeNativeError::Abort {
+        abort_code: EINVALID_SEED,
+    })?;
+
+    let randomness_context = context
+        .extensions_mut()
+        .get_mut::<NativeRandomnessContext>();
+    randomness_context.set_seed(seed);
+    randomness_context.mark_unbiasable();
+
+    Ok(smallvec![])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let mut natives = vec![];
+    natives.extend([
+        ("next_32_bytes", native_next_32_bytes as RawSafeNative),
+        ("is_unbiasable", native_is_unbiasable as RawSafeNative),
+    ]);
+
+    #[cfg(feature = "testing")]
+    natives.extend([(
+        "initialize_for_testing",
+        native_test_only_initialize_for_testing as RawSafeNative,
+    )]);
+
+    builder.make_named_natives(natives)
+}
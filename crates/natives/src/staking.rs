@@ -15,11 +15,15 @@ use crate::{
     interface::{RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult},
     safely_pop_arg,
 };
-use initia_move_types::staking_change_set::StakingChangeSet;
+use initia_move_types::staking_change_set::{
+    StakingChangeSet, StakingRedelegation, UnbondingEntry,
+};
 
 use move_binary_format::errors::PartialVMError;
 use move_core_types::{
-    account_address::AccountAddress, gas_algebra::NumBytes, vm_status::StatusCode,
+    account_address::AccountAddress,
+    gas_algebra::{NumArgs, NumBytes},
+    vm_status::StatusCode,
 };
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
@@ -46,6 +50,13 @@ pub trait StakingAPI {
         amount: u64,
     ) -> anyhow::Result<String>;
     fn unbond_timestamp(&self) -> anyhow::Result<u64>;
+    /// Returns the pending unbonding entries of the delegation, held by the staking
+    /// module, to the validator.
+    fn unbonding_entries(
+        &self,
+        validator: &[u8],
+        metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>>;
 }
 
 /// The native staking context extension. This needs to be attached to the NativeContextExtensions
@@ -58,6 +69,8 @@ pub struct NativeStakingContext<'a> {
     #[cfg(feature = "testing")]
     share_ratios:
         BTreeMap<Vec<u8>, BTreeMap<AccountAddress, (BigDecimal /* share */, u64 /* amount */)>>,
+    #[cfg(feature = "testing")]
+    unbonding_entries: BTreeMap<(Vec<u8>, AccountAddress), Vec<UnbondingEntry>>,
 }
 
 // ===========================================================================================
@@ -77,6 +90,12 @@ struct StakingData {
             ),
         >,
     >,
+    redelegations: Vec<(
+        Vec<u8>, /* src validator */
+        Vec<u8>, /* dst validator */
+        AccountAddress,
+        BigDecimal, /* redelegation share amount */
+    )>,
 }
 
 // =========================================================================================
@@ -91,12 +110,17 @@ impl<'a> NativeStakingContext<'a> {
             staking_data: Default::default(),
             #[cfg(feature = "testing")]
             share_ratios: BTreeMap::default(),
+            #[cfg(feature = "testing")]
+            unbonding_entries: BTreeMap::default(),
         }
     }
 
     pub fn into_change_set(self) -> StakingChangeSet {
         let NativeStakingContext { staking_data, .. } = self;
-        let StakingData { changes } = staking_data;
+        let StakingData {
+            changes,
+            redelegations,
+        } = staking_data;
 
         StakingChangeSet::new(
             changes
@@ -116,6 +140,17 @@ impl<'a> NativeStakingContext<'a> {
                     )
                 })
                 .collect(),
+            redelegations
+                .into_iter()
+                .map(
+                    |(src_validator, dst_validator, metadata, share)| StakingRedelegation {
+                        src_validator,
+                        dst_validator,
+                        metadata,
+                        share: big_decimal_to_string(share),
+                    },
+                )
+                .collect(),
         )
     }
 
@@ -276,6 +311,144 @@ fn native_undelegate(
     Ok(smallvec![Value::u64(amount), Value::u64(unbond_timestamp)])
 }
 
+#[allow(clippy::result_large_err)]
+fn native_redelegate(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let share = read_big_decimal(safely_pop_arg!(arguments, StructRef))?;
+    let metadata = get_metadata_address(&safely_pop_arg!(arguments, StructRef))?;
+    let dst_validator = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    let src_validator = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    context.charge(
+        gas_params.staking_redelegate_base
+            + gas_params.staking_redelegate_per_byte
+                * NumBytes::new((src_validator.len() + dst_validator.len()) as u64),
+    )?;
+
+    let staking_context = context.extensions_mut().get_mut::<NativeStakingContext>();
+    staking_context.staking_data.redelegations.push((
+        src_validator.clone(),
+        dst_validator.clone(),
+        metadata,
+        share.clone(),
+    ));
+
+    #[cfg(feature = "testing")]
+    if let (Some(src_ratio), Some(dst_ratio)) = (
+        staking_context
+            .share_ratios
+            .get(&src_validator)
+            .and_then(|ratios| ratios.get(&metadata)),
+        staking_context
+            .share_ratios
+            .get(&dst_validator)
+            .and_then(|ratios| ratios.get(&metadata)),
+    ) {
+        let amount: u64 = (share * src_ratio.1 / src_ratio.0.clone())
+            .to_bigint()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let dst_share = BigDecimal::from(amount) * dst_ratio.0.clone() / dst_ratio.1;
+
+        let block_context = context.extensions().get::<NativeBlockContext>();
+        let (_, timestamp) = block_context.get_block_info();
+        let completion_time = timestamp + 60 * 60 * 24 * 7;
+
+        return Ok(smallvec![
+            write_big_decimal(dst_share)?,
+            Value::u64(completion_time)
+        ]);
+    }
+
+    // convert the source share to the destination share through the delegation amount
+    let amount = staking_context
+        .api
+        .share_to_amount(&src_validator, metadata, big_decimal_to_string(share))
+        .map_err(|err| partial_extension_error(format!("remote staking api failure: {}", err)))?;
+
+    let dst_share = staking_context
+        .api
+        .amount_to_share(&dst_validator, metadata, amount)
+        .map_err(|err| partial_extension_error(format!("remote staking api failure: {}", err)))?;
+
+    // redelegation is completed after the unbonding period
+    let completion_time = staking_context
+        .api
+        .unbond_timestamp()
+        .map_err(|err| partial_extension_error(format!("remote staking api failure: {}", err)))?;
+
+    Ok(smallvec![
+        write_big_decimal(string_to_big_decimal(dst_share)?)?,
+        Value::u64(completion_time)
+    ])
+}
+
+#[allow(clippy::result_large_err)]
+fn native_unbonding_entries(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let metadata = get_metadata_address(&safely_pop_arg!(arguments, StructRef))?;
+    let validator = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    context.charge(
+        gas_params.staking_unbonding_entries_base
+            + gas_params.staking_unbonding_entries_per_byte
+                * NumBytes::new((validator.len()) as u64),
+    )?;
+
+    let staking_context = context.extensions().get::<NativeStakingContext>();
+
+    #[cfg(feature = "testing")]
+    let entries = match staking_context
+        .unbonding_entries
+        .get(&(validator.clone(), metadata))
+    {
+        Some(entries) => entries.clone(),
+        None => staking_context
+            .api
+            .unbonding_entries(&validator, metadata)
+            .map_err(|err| {
+                partial_extension_error(format!("remote staking api failure: {}", err))
+            })?,
+    };
+
+    #[cfg(not(feature = "testing"))]
+    let entries = staking_context
+        .api
+        .unbonding_entries(&validator, metadata)
+        .map_err(|err| partial_extension_error(format!("remote staking api failure: {}", err)))?;
+
+    context.charge(
+        gas_params.staking_unbonding_entries_per_entry * NumArgs::new(entries.len() as u64),
+    )?;
+
+    let (amounts, completion_times): (Vec<u64>, Vec<u64>) = entries
+        .into_iter()
+        .map(|entry| (entry.amount, entry.completion_time))
+        .unzip();
+
+    Ok(smallvec![
+        Value::vector_u64(amounts),
+        Value::vector_u64(completion_times)
+    ])
+}
+
 #[allow(clippy::result_large_err)]
 fn native_share_to_amount(
     context: &mut SafeNativeContext,
@@ -372,15 +545,23 @@ pub fn make_all(
     natives.extend([
         ("delegate_internal", native_delegate as RawSafeNative),
         ("undelegate_internal", native_undelegate),
+        ("redelegate_internal", native_redelegate),
+        ("unbonding_entries_internal", native_unbonding_entries),
         ("share_to_amount", native_share_to_amount),
         ("amount_to_share", native_amount_to_share),
     ]);
 
     #[cfg(feature = "testing")]
-    natives.extend([(
-        "set_staking_share_ratio",
-        native_test_only_set_staking_share_ratio as RawSafeNative,
-    )]);
+    natives.extend([
+        (
+            "set_staking_share_ratio",
+            native_test_only_set_staking_share_ratio as RawSafeNative,
+        ),
+        (
+            "set_unbonding_entries",
+            native_test_only_set_unbonding_entries,
+        ),
+    ]);
 
     builder.make_named_natives(natives)
 }
@@ -406,6 +587,38 @@ fn native_test_only_set_staking_share_ratio(
     Ok(smallvec![])
 }
 
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_unbonding_entries(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let completion_times = safely_pop_arg!(arguments, Vec<u64>);
+    let amounts = safely_pop_arg!(arguments, Vec<u64>);
+    let metadata = get_metadata_address(&safely_pop_arg!(arguments, StructRef))?;
+    let validator = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    let entries = amounts
+        .into_iter()
+        .zip(completion_times)
+        .map(|(amount, completion_time)| UnbondingEntry {
+            amount,
+            completion_time,
+        })
+        .collect();
+
+    let staking_context = context.extensions_mut().get_mut::<NativeStakingContext>();
+    staking_context
+        .unbonding_entries
+        .insert((validator, metadata), entries);
+
+    Ok(smallvec![])
+}
+
 // =========================================================================================
 // Helpers
 
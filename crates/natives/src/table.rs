@@ -84,6 +84,8 @@ struct Table {
     key_layout: MoveTypeLayout,
     value_layout: MoveTypeLayout,
     content: BTreeMap<Vec<u8>, GlobalValue>,
+    /// Net change of the number of entries during this session
+    length_delta: i64,
 }
 
 /// A structure representing a table iterator
@@ -95,6 +97,8 @@ struct TableIter {
     changes: BTreeSet<Vec<u8>>,
     /// next item loaded from the iterator
     next: Option<(Value, Value)>,
+    /// next key loaded from the iterator by `prepare_key_box`
+    next_key: Option<Value>,
     order: Order,
 }
 
@@ -131,12 +135,18 @@ impl<'a> NativeTableContext<'a> {
             tables,
         } = table_data.into_inner();
         let mut changes = BTreeMap::new();
+        let mut length_changes = BTreeMap::new();
         for (handle, table) in tables {
             let Table {
                 value_layout,
                 content,
+                length_delta,
                 ..
             } = table;
+            if length_delta != 0 {
+                length_changes.insert(handle, length_delta);
+            }
+
             let mut entries = BTreeMap::new();
             for (key, gv) in content {
                 let op = match gv.into_effect() {
@@ -172,6 +182,7 @@ impl<'a> NativeTableContext<'a> {
             new_tables,
             removed_tables,
             changes,
+            length_changes,
         })
     }
 }
@@ -195,6 +206,7 @@ impl TableData {
                     key_layout,
                     value_layout,
                     content: Default::default(),
+                    length_delta: 0,
                 };
                 e.insert(table)
             }
@@ -299,6 +311,11 @@ pub fn all_natives(
             ("new_table_iter_mut", native_new_table_iter),
             ("prepare_box_mut", native_prepare_box),
             ("next_box_mut", native_next_box),
+            ("new_table_prefix_iter", native_new_table_prefix_iter),
+            ("new_table_prefix_iter_mut", native_new_table_prefix_iter),
+            ("prepare_key_box", native_prepare_key_box),
+            ("next_key_box", native_next_key_box),
+            ("remove_range_box", native_remove_range_box),
         ])
         .map(|(func_name, func)| {
             (
@@ -377,7 +394,10 @@ fn native_add_box(
     let (gv, loaded) = table.get_or_create_global_value(context, key_bytes)?;
 
     let res = match gv.move_to(val) {
-        Ok(_) => Ok(smallvec![]),
+        Ok(_) => {
+            table.length_delta += 1;
+            Ok(smallvec![])
+        }
         Err(_) => Err(SafeNativeError::Abort {
             abort_code: ALREADY_EXISTS,
         }),
@@ -504,7 +524,10 @@ fn native_remove_box(
     let (gv, loaded) = table.get_or_create_global_value(context, key_bytes)?;
 
     let res = match gv.move_from() {
-        Ok(val) => Ok(smallvec![val]),
+        Ok(val) => {
+            table.length_delta -= 1;
+            Ok(smallvec![val])
+        }
         Err(_) => Err(SafeNativeError::Abort {
             abort_code: NOT_FOUND,
         }),
@@ -592,12 +615,67 @@ fn native_new_table_iter(
 
     let handle = get_table_handle(&safely_pop_arg!(arguments, StructRef))?;
 
+    create_table_iter(
+        context,
+        &ty_args,
+        handle,
+        None,
+        start_option,
+        end_option,
+        order,
+    )
+}
+
+#[allow(clippy::result_large_err)]
+fn native_new_table_prefix_iter(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.table;
+
+    assert_eq!(ty_args.len(), 3);
+    assert_eq!(arguments.len(), 3);
+
+    context.charge(gas_params.new_table_iter_base)?;
+
+    let order = Order::try_from(safely_pop_arg!(arguments, u8) as i32)
+        .map_err(|_| PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR))?;
+    let prefix_bytes = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    // empty prefix means the whole table
+    let prefix_option: Option<&[u8]> = if prefix_bytes.is_empty() {
+        None
+    } else {
+        Some(prefix_bytes.as_ref())
+    };
+
+    let handle = get_table_handle(&safely_pop_arg!(arguments, StructRef))?;
+
+    create_table_iter(context, &ty_args, handle, prefix_option, None, None, order)
+}
+
+/// Create a table iterator over the keys in `[start, end)` which start with `prefix`,
+/// and return the reference of the iterator.
+#[allow(clippy::result_large_err)]
+fn create_table_iter(
+    context: &mut SafeNativeContext,
+    ty_args: &[Type],
+    handle: TableHandle,
+    prefix_option: Option<&[u8]>,
+    start_option: Option<&[u8]>,
+    end_option: Option<&[u8]>,
+    order: Order,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.table;
+
     // create iterator and store this to table context
     let changes = iter_table_changes(
         context,
         handle,
         &ty_args[0],
         &ty_args[2],
+        prefix_option,
         start_option,
         end_option,
         order,
@@ -610,7 +688,7 @@ fn native_new_table_iter(
     let table_context = context.extensions_mut().get_mut::<NativeTableContext>();
     let iterator_id = table_context
         .resolver
-        .create_iterator(&handle, start_option, end_option, order)
+        .create_iterator(&handle, prefix_option, start_option, end_option, order)
         .map_err(|err| {
             partial_extension_error(format!("remote table resolver failure: {}", err))
         })?;
@@ -622,6 +700,7 @@ fn native_new_table_iter(
         handle,
         changes,
         next: None,
+        next_key: None,
         order,
     });
 
@@ -711,6 +790,176 @@ fn native_next_box(
     Ok(smallvec![key, value])
 }
 
+/// Check the `next_key` exist or not and store the computed key
+/// to the `iterator.next_key` for the function `next_key_box`.
+///
+/// Unlike `prepare_box`, the value is not loaded from the storage.
+#[allow(clippy::result_large_err)]
+fn native_prepare_key_box(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.table;
+
+    assert_eq!(ty_args.len(), 3);
+    assert_eq!(arguments.len(), 1);
+
+    context.charge(gas_params.prepare_key_box_base)?;
+
+    let iterator_id = get_iterator_id(&safely_pop_arg!(arguments, StructRef))? as usize;
+
+    loop {
+        let ((next_key, loaded), handle) = get_next_key_with_table_handle(context, iterator_id)?;
+        charge_load_cost(context, loaded)?;
+
+        if next_key.is_none() {
+            return Ok(smallvec![Value::bool(false)]);
+        }
+
+        let key_bytes = next_key.unwrap();
+        let (next_key, serialized) =
+            load_table_key(context, handle, &ty_args[0], &ty_args[2], key_bytes)?;
+        if let Some(num_bytes) = serialized {
+            context.charge(gas_params.prepare_key_box_per_byte_serialized * num_bytes)?;
+        }
+
+        if next_key.is_some() {
+            set_next_key(context, iterator_id, next_key);
+            return Ok(smallvec![Value::bool(true)]);
+        }
+    }
+}
+
+/// Return `iterator.next_key` which was computed from
+/// the function `prepare_key_box`.
+#[allow(clippy::result_large_err)]
+fn native_next_key_box(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.table;
+
+    assert_eq!(ty_args.len(), 3);
+    assert_eq!(arguments.len(), 1);
+
+    context.charge(gas_params.next_key_box_base)?;
+
+    let iterator_id = get_iterator_id(&safely_pop_arg!(arguments, StructRef))? as usize;
+
+    let table_context = context.extensions().get::<NativeTableContext>();
+    let mut iterators = table_context.iterators.borrow_mut();
+    let iterator = iterators.get_mut(iterator_id).unwrap();
+    let key = iterator
+        .next_key
+        .take()
+        .ok_or_else(|| partial_extension_error("next_key_box called without prepare_key_box"))?;
+
+    Ok(smallvec![key])
+}
+
+/// Remove at most `limit` entries in the range `[start, end)` in ascending key order
+/// and return the number of removed entries.
+#[allow(clippy::result_large_err)]
+fn native_remove_range_box(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.table;
+
+    assert_eq!(ty_args.len(), 3);
+    assert_eq!(arguments.len(), 4);
+
+    context.charge(gas_params.remove_range_box_base)?;
+
+    let limit = safely_pop_arg!(arguments, u64);
+    let end_bytes = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+    let start_bytes = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    // convert vector start end args into option range arguments
+    let start_option: Option<&[u8]> = if start_bytes.is_empty() {
+        None
+    } else {
+        Some(start_bytes.as_ref())
+    };
+    let end_option: Option<&[u8]> = if end_bytes.is_empty() {
+        None
+    } else {
+        Some(end_bytes.as_ref())
+    };
+
+    let handle = get_table_handle(&safely_pop_arg!(arguments, StructRef))?;
+
+    let changes = iter_table_changes(
+        context,
+        handle,
+        &ty_args[0],
+        &ty_args[2],
+        None,
+        start_option,
+        end_option,
+        Order::Ascending,
+    )?;
+
+    // charge gas cost for sorting
+    context
+        .charge(NumArgs::new(changes.len() as u64) * gas_params.remove_range_box_per_item_sorted)?;
+
+    let table_context = context.extensions_mut().get_mut::<NativeTableContext>();
+    let iterator_id = table_context
+        .resolver
+        .create_iterator(&handle, None, start_option, end_option, Order::Ascending)
+        .map_err(|err| {
+            partial_extension_error(format!("remote table resolver failure: {}", err))
+        })?;
+
+    // the iterator is only used inside of this function, so no need to register
+    // it to the table context.
+    let mut iterator = TableIter {
+        iterator_id,
+        handle,
+        changes,
+        next: None,
+        next_key: None,
+        order: Order::Ascending,
+    };
+
+    let mut removed: u64 = 0;
+    while removed < limit {
+        let table_context = context.extensions_mut().get_mut::<NativeTableContext>();
+        let (next_key, loaded) = iterator.load_next_key(table_context.resolver)?;
+        charge_load_cost(context, loaded)?;
+
+        let key_bytes = match next_key {
+            Some(key_bytes) => key_bytes,
+            None => break,
+        };
+
+        let table_context = context.extensions().get::<NativeTableContext>();
+        let mut table_data = table_context.table_data.borrow_mut();
+        let table = table_data.get_or_create_table(context, handle, &ty_args[0], &ty_args[2])?;
+
+        let (gv, loaded) = table.get_or_create_global_value(context, key_bytes)?;
+        let exists = gv.exists()?;
+        if exists {
+            gv.move_from()?;
+            table.length_delta -= 1;
+        }
+
+        drop(table_data);
+
+        charge_load_cost(context, loaded)?;
+        if exists {
+            context.charge(gas_params.remove_range_box_per_item_removed * NumArgs::new(1))?;
+            removed += 1;
+        }
+    }
+
+    Ok(smallvec![Value::u64(removed)])
+}
+
 // =========================================================================================
 // Helpers
 
@@ -767,11 +1016,13 @@ fn get_type_layout(context: &NativeContext, ty: &Type) -> PartialVMResult<MoveTy
     context.type_to_type_layout(ty)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn iter_table_changes(
     context: &NativeContext,
     handle: TableHandle,
     key_type: &Type,
     value_type: &Type,
+    prefix: Option<&[u8]>,
     start: Option<&[u8]>,
     end: Option<&[u8]>,
     order: Order,
@@ -781,7 +1032,8 @@ fn iter_table_changes(
     let table = table_data.get_or_create_table(context, handle, key_type, value_type)?;
 
     // change set iterator
-    let bounds = range_bounds(start, end);
+    let (start, end) = prefixed_bounds(prefix, start, end);
+    let bounds = range_bounds(start.as_deref(), end.as_deref());
 
     // BTreeMap.range panics if range is start > end.
     // However, this cases represent just empty range and we treat it as such.
@@ -819,6 +1071,15 @@ fn set_next(context: &mut NativeContext, iterator_id: usize, next: Option<(Value
     iterator.unwrap().next = next;
 }
 
+fn set_next_key(context: &mut NativeContext, iterator_id: usize, next_key: Option<Value>) {
+    let table_context = context.extensions().get::<NativeTableContext>();
+    let mut iterators = table_context.iterators.borrow_mut();
+    let iterator = iterators.get_mut(iterator_id);
+    assert!(iterator.is_some());
+
+    iterator.unwrap().next_key = next_key;
+}
+
 #[allow(clippy::type_complexity)]
 fn get_next_key_with_table_handle(
     context: &mut NativeContext,
@@ -868,6 +1129,69 @@ fn load_table_entry(
     Ok((key_value, loaded, serialized))
 }
 
+/// Convert the bounds relative to the `prefix` into the absolute key bounds.
+fn prefixed_bounds(
+    prefix: Option<&[u8]>,
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+    match prefix {
+        None => (start.map(|s| s.to_vec()), end.map(|e| e.to_vec())),
+        Some(prefix) => {
+            let start = [prefix, start.unwrap_or_default()].concat();
+            let end = match end {
+                Some(end) => Some([prefix, end].concat()),
+                None => prefix_end_bytes(prefix),
+            };
+            (Some(start), end)
+        }
+    }
+}
+
+/// Return the smallest key which is greater than all keys starting with `prefix`,
+/// or `None` if there is no such key.
+fn prefix_end_bytes(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last != u8::MAX {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+
+    None
+}
+
+/// Deserialize the key without loading the value. The keys which are not cached in the
+/// table content come from the storage and are not touched in this session, so they exist.
+fn load_table_key(
+    context: &mut NativeContext,
+    handle: TableHandle,
+    key_type: &Type,
+    value_type: &Type,
+    key_bytes: Vec<u8>,
+) -> PartialVMResult<(Option<Value>, Option<NumBytes>)> {
+    let table_context = context.extensions().get::<NativeTableContext>();
+    let mut table_data = table_context.table_data.borrow_mut();
+    let table = table_data.get_or_create_table(context, handle, key_type, value_type)?;
+
+    let exists = match table.content.get(&key_bytes) {
+        Some(gv) => gv.exists()?,
+        None => true,
+    };
+    if !exists {
+        return Ok((None, None));
+    }
+
+    let function_value_extension = context.function_value_extension();
+    let key = deserialize(
+        Some(&function_value_extension),
+        &table.key_layout,
+        &key_bytes,
+    )?;
+    Ok((Some(key), Some(NumBytes::new(key_bytes.len() as u64))))
+}
+
 fn range_bounds(start: Option<&[u8]>, end: Option<&[u8]>) -> impl RangeBounds<Vec<u8>> {
     (
         start.map_or(Bound::Unbounded, |x| Bound::Included(x.to_vec())),
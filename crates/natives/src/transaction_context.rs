@@ -1,5 +1,6 @@
 use better_any::{Tid, TidAble};
-use initia_move_gas::{NumArgs, NumBytes};
+use bigdecimal::{num_bigint::ToBigInt, BigDecimal, Signed, Zero};
+use initia_move_gas::{NumArgs, NumBytes, GAS_UNIT_SCALING_FACTOR};
 use initia_move_types::user_transaction_context::{EntryFunctionPayload, UserTransactionContext};
 use move_binary_format::errors::PartialVMError;
 use move_core_types::{account_address::AccountAddress, vm_status::StatusCode};
@@ -11,10 +12,13 @@ use move_vm_types::{
 use sha3::{Digest, Sha3_256};
 use smallvec::{smallvec, SmallVec};
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, str::FromStr};
 
-use crate::interface::{
-    RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+use crate::{
+    helpers::partial_extension_error,
+    interface::{
+        RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError, SafeNativeResult,
+    },
 };
 
 /// UID prefix is used to generate unique address from the txn hash.
@@ -30,6 +34,12 @@ pub struct NativeTransactionContext {
     session_id: [u8; 32],
     /// This is the number of UIDs issued during the execution of this transaction
     uid_counter: u64,
+    /// Index of the transaction in the block
+    tx_index: u64,
+    /// Index of the message in the transaction
+    msg_index: u64,
+    /// Gas price of the transaction in decimal string
+    gas_price: String,
     user_transaction_context_opt: Option<UserTransactionContext>,
 }
 
@@ -39,15 +49,25 @@ impl NativeTransactionContext {
     pub fn new(
         tx_hash: [u8; 32],
         session_id: [u8; 32],
+        tx_index: u64,
+        msg_index: u64,
+        gas_price: String,
         user_transaction_context_opt: Option<UserTransactionContext>,
     ) -> Self {
         Self {
             tx_hash,
             session_id,
             uid_counter: 0,
+            tx_index,
+            msg_index,
+            gas_price,
             user_transaction_context_opt,
         }
     }
+
+    pub fn session_id(&self) -> [u8; 32] {
+        self.session_id
+    }
 }
 
 /***************************************************************************************************
@@ -187,6 +207,109 @@ fn native_fee_payer(
     Ok(smallvec![value])
 }
 
+/***************************************************************************************************
+ * native fun get_transaction_index
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_get_transaction_index(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.transaction_context_get_transaction_index_base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+    Ok(smallvec![Value::u64(transaction_context.tx_index)])
+}
+
+/***************************************************************************************************
+ * native fun get_message_index
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_get_message_index(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.transaction_context_get_message_index_base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+    Ok(smallvec![Value::u64(transaction_context.msg_index)])
+}
+
+/***************************************************************************************************
+ * native fun gas_price_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_gas_price(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.transaction_context_gas_price_base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+    let scaled_le_bytes = gas_price_to_scaled_le_bytes(&transaction_context.gas_price)?;
+
+    Ok(smallvec![Value::vector_u8(scaled_le_bytes)])
+}
+
+/***************************************************************************************************
+ * native fun remaining_gas
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[allow(clippy::result_large_err)]
+fn native_remaining_gas(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let gas_params = &context.native_gas_params.initia_stdlib;
+    context.charge(gas_params.transaction_context_remaining_gas_base)?;
+
+    // report the remaining gas in sdk gas units
+    let gas_balance: u64 = context.gas_balance().into();
+    Ok(smallvec![Value::u64(gas_balance / GAS_UNIT_SCALING_FACTOR)])
+}
+
+/// Convert the decimal gas price string into the little endian bytes of the
+/// 1e18 scaled integer, which is the representation of `bigdecimal::BigDecimal`.
+#[allow(clippy::result_large_err)]
+fn gas_price_to_scaled_le_bytes(gas_price: &str) -> SafeNativeResult<Vec<u8>> {
+    const DECIMAL_SCALE: u128 = 1_000_000_000_000_000_000;
+
+    let gas_price = if gas_price.is_empty() {
+        BigDecimal::zero()
+    } else {
+        BigDecimal::from_str(gas_price)
+            .map_err(|_| partial_extension_error("failed to parse gas price"))?
+    };
+
+    let scaled = (gas_price * DECIMAL_SCALE)
+        .to_bigint()
+        .ok_or_else(|| partial_extension_error("invalid gas price"))?;
+    if scaled.is_negative() {
+        return Err(partial_extension_error("negative gas price").into());
+    }
+
+    let (_, bytes) = scaled.to_bytes_le();
+    Ok(bytes)
+}
+
 fn create_option_some_value(value: Value) -> Value {
     Value::struct_(Struct::pack(vec![create_singleton_vector(value)]))
 }
@@ -378,6 +501,52 @@ fn native_test_only_set_fee_payer(
     Ok(smallvec![])
 }
 
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_transaction_index(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use crate::safely_pop_arg;
+
+    debug_assert_eq!(arguments.len(), 2);
+
+    let msg_index = safely_pop_arg!(arguments, u64);
+    let tx_index = safely_pop_arg!(arguments, u64);
+
+    let transaction_context = context
+        .extensions_mut()
+        .get_mut::<NativeTransactionContext>();
+    transaction_context.tx_index = tx_index;
+    transaction_context.msg_index = msg_index;
+
+    Ok(smallvec![])
+}
+
+#[cfg(feature = "testing")]
+#[allow(clippy::result_large_err)]
+fn native_test_only_set_gas_price(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    use crate::{helpers::get_string, safely_pop_arg};
+
+    debug_assert_eq!(arguments.len(), 1);
+
+    let gas_price = get_string(safely_pop_arg!(arguments, Struct))?;
+    let gas_price = String::from_utf8(gas_price)
+        .map_err(|_| partial_extension_error("invalid gas price string"))?;
+
+    let transaction_context = context
+        .extensions_mut()
+        .get_mut::<NativeTransactionContext>();
+    transaction_context.gas_price = gas_price;
+
+    Ok(smallvec![])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -398,6 +567,10 @@ pub fn make_all(
         ),
         ("senders", native_senders),
         ("fee_payer_internal", native_fee_payer),
+        ("get_transaction_index", native_get_transaction_index),
+        ("get_message_index", native_get_message_index),
+        ("gas_price_internal", native_gas_price),
+        ("remaining_gas", native_remaining_gas),
     ]);
 
     #[cfg(feature = "testing")]
@@ -412,6 +585,11 @@ pub fn make_all(
         ),
         ("set_senders_internal", native_test_only_set_senders),
         ("set_fee_payer_internal", native_test_only_set_fee_payer),
+        (
+            "set_transaction_index_internal",
+            native_test_only_set_transaction_index,
+        ),
+        ("set_gas_price_internal", native_test_only_set_gas_price),
     ]);
 
     builder.make_named_natives(natives)
@@ -9,6 +9,7 @@
 pub mod module_view;
 
 use crate::module_view::ModuleView;
+use anyhow::bail;
 use initia_move_storage::state_view::StateView;
 use move_binary_format::CompiledModule;
 use move_core_types::{
@@ -96,4 +97,20 @@ impl<'a, S: StateView> InitiaValueAnnotator<'a, S> {
     ) -> anyhow::Result<MoveTypeLayout> {
         self.0.get_type_layout_with_types(type_tag)
     }
+
+    /// Resolve the fully decorated layout of an event type. Events are always
+    /// structs.
+    pub fn view_event_layout(&self, type_tag: &TypeTag) -> anyhow::Result<MoveTypeLayout> {
+        if !matches!(type_tag, TypeTag::Struct(_)) {
+            bail!("event type must be a struct: {}", type_tag);
+        }
+        self.view_fully_decorated_ty_layout(type_tag)
+    }
+
+    /// Decode a bcs encoded event into a fully decorated value, the same value
+    /// that is marshaled to json when the event is emitted.
+    pub fn view_event(&self, type_tag: &TypeTag, blob: &[u8]) -> anyhow::Result<MoveValue> {
+        let layout = self.view_event_layout(type_tag)?;
+        MoveValue::simple_deserialize(blob, &layout)
+    }
 }
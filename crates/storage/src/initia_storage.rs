@@ -63,8 +63,8 @@ impl<'s, S: StateView> InitiaStorage<'s, S> {
         self.storage.runtime_environment()
     }
 
-    pub fn struct_resolver(&self) -> StructResolverImpl<'_> {
-        StructResolverImpl::new(self.runtime_environment())
+    pub fn struct_resolver(&self) -> StructResolverImpl<'_, Self> {
+        StructResolverImpl::new(self)
     }
 
     pub fn state_view_impl(&self) -> &StateViewImpl<'s, S> {
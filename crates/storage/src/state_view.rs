@@ -21,6 +21,16 @@ pub trait StateView {
     fn get(&self, access_path: &AccessPath) -> Result<Option<Bytes>>;
 }
 
+/// A [`StateView`] that can also enumerate its entries, as needed to export state.
+pub trait IterableStateView: StateView {
+    /// Iterates over the entries stored under `address`, or over the whole store
+    /// when `address` is `None`, in storage key order.
+    fn iter_entries(
+        &self,
+        address: Option<AccountAddress>,
+    ) -> Box<dyn Iterator<Item = Result<(AccessPath, Bytes)>> + '_>;
+}
+
 pub trait ChecksumStorage {
     fn fetch_checksum(
         &self,
@@ -2,35 +2,44 @@ use move_binary_format::errors::{Location, PartialVMResult, VMResult};
 use move_core_types::{
     identifier::Identifier,
     language_storage::{ModuleId, TypeTag},
+    value::MoveTypeLayout,
 };
-use move_vm_runtime::RuntimeEnvironment;
+use move_vm_runtime::{LayoutConverter, ModuleStorage, StorageLayoutConverter};
 use move_vm_types::loaded_data::runtime_types::Type;
 
 pub trait StructResolver {
     fn get_struct_name(&self, ty: &Type) -> PartialVMResult<Option<(ModuleId, Identifier)>>;
     fn type_to_type_tag(&self, ty: &Type) -> VMResult<TypeTag>;
+    fn type_to_fully_annotated_layout(&self, ty: &Type) -> VMResult<MoveTypeLayout>;
 }
 
-pub struct StructResolverImpl<'a> {
-    runtime_environment: &'a RuntimeEnvironment,
+pub struct StructResolverImpl<'a, M> {
+    module_storage: &'a M,
 }
 
-impl<'a> StructResolverImpl<'a> {
-    pub fn new(runtime_environment: &'a RuntimeEnvironment) -> Self {
-        Self {
-            runtime_environment,
-        }
+impl<'a, M: ModuleStorage> StructResolverImpl<'a, M> {
+    pub fn new(module_storage: &'a M) -> Self {
+        Self { module_storage }
     }
 }
 
-impl StructResolver for StructResolverImpl<'_> {
+impl<M: ModuleStorage> StructResolver for StructResolverImpl<'_, M> {
     fn get_struct_name(&self, ty: &Type) -> PartialVMResult<Option<(ModuleId, Identifier)>> {
-        self.runtime_environment.get_struct_name(ty)
+        self.module_storage
+            .runtime_environment()
+            .get_struct_name(ty)
     }
 
     fn type_to_type_tag(&self, ty: &Type) -> VMResult<TypeTag> {
-        self.runtime_environment
+        self.module_storage
+            .runtime_environment()
             .ty_to_ty_tag(ty)
             .map_err(|e| e.finish(Location::Undefined))
     }
+
+    fn type_to_fully_annotated_layout(&self, ty: &Type) -> VMResult<MoveTypeLayout> {
+        StorageLayoutConverter::new(self.module_storage)
+            .type_to_fully_annotated_layout(ty)
+            .map_err(|e| e.finish(Location::Undefined))
+    }
 }
@@ -24,9 +24,13 @@ pub trait TableResolver {
     /// The bound `start` is inclusive and `end` is exclusive.
     ///
     /// If `start` is lexicographically greater than or equal to `end`, an empty range is described, mo matter of the order.
+    ///
+    /// If `prefix` is given, only the keys starting with the `prefix` are iterated and
+    /// `start` and `end` are relative to the `prefix`. The returned keys include the `prefix`.
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
+        prefix: Option<&[u8]>,
         start: Option<&[u8]>,
         end: Option<&[u8]>,
         order: Order,
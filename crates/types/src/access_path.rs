@@ -124,6 +124,17 @@ impl AccessPath {
         Ok(out)
     }
 
+    /// Recovers from the physical storage key produced by [`AccessPath::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.len() <= AccountAddress::LENGTH {
+            bail!("invalid access path bytes: {}", encode_hex(bytes));
+        }
+
+        let (address, path) = bytes.split_at(AccountAddress::LENGTH);
+        let address = AccountAddress::from_bytes(address).map_err(|e| anyhow!(e))?;
+        Ok(AccessPath::new(address, DataPath::decode(path)?))
+    }
+
     pub fn size(&self) -> usize {
         self.address.len() + self.path.size()
     }
@@ -53,4 +53,9 @@ pub struct CosmosCallback {
     pub module_address: AccountAddress,
     pub module_name: String,
     pub function_name: String,
+    /// Whether the callback takes the message sender as a leading `&signer`.
+    pub with_signer: bool,
+    /// Whether the callback takes the message response bytes and the error
+    /// string after the success flag.
+    pub with_result: bool,
 }
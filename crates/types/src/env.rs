@@ -19,9 +19,27 @@ pub struct Env {
     /// Optional fee payer for the current transaction. `None` means the
     /// sender pays gas (or no fee payer concept applies).
     fee_payer: Option<AccountAddress>,
+    /// Block level seed of the on-chain randomness. `None` means the randomness
+    /// is not available for this execution.
+    random_seed: Option<[u8; 32]>,
+    /// Hash of the block where this message was executed.
+    block_hash: [u8; 32],
+    /// Address of the block proposer.
+    proposer: AccountAddress,
+    /// Index of the transaction in the block.
+    tx_index: u64,
+    /// Index of the message in the transaction.
+    msg_index: u64,
+    /// Number of the messages in the transaction. Randomness is only available to the
+    /// last message, so the host must count every execution which can follow the
+    /// message and abort the transaction, such as stargate messages and callbacks.
+    num_msgs: u64,
+    /// Gas price of the transaction in decimal string. Ex) "0.015"
+    gas_price: String,
 }
 
 impl Env {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: String,
         block_height: u64,
@@ -30,6 +48,13 @@ impl Env {
         tx_hash: [u8; 32],
         session_id: [u8; 32],
         fee_payer: Option<AccountAddress>,
+        random_seed: Option<[u8; 32]>,
+        block_hash: [u8; 32],
+        proposer: AccountAddress,
+        tx_index: u64,
+        msg_index: u64,
+        num_msgs: u64,
+        gas_price: String,
     ) -> Self {
         Self {
             chain_id,
@@ -39,6 +64,13 @@ impl Env {
             tx_hash,
             session_id,
             fee_payer,
+            random_seed,
+            block_hash,
+            proposer,
+            tx_index,
+            msg_index,
+            num_msgs,
+            gas_price,
         }
     }
 
@@ -72,4 +104,40 @@ impl Env {
     pub fn fee_payer(&self) -> Option<AccountAddress> {
         self.fee_payer
     }
+
+    /// Return the block level randomness seed.
+    pub fn random_seed(&self) -> Option<[u8; 32]> {
+        self.random_seed
+    }
+
+    /// Return block_hash
+    pub fn block_hash(&self) -> &[u8] {
+        &self.block_hash
+    }
+
+    pub fn proposer(&self) -> AccountAddress {
+        self.proposer
+    }
+
+    pub fn tx_index(&self) -> u64 {
+        self.tx_index
+    }
+
+    pub fn msg_index(&self) -> u64 {
+        self.msg_index
+    }
+
+    pub fn num_msgs(&self) -> u64 {
+        self.num_msgs
+    }
+
+    /// Return whether the message is the last message of the transaction.
+    pub fn is_last_msg(&self) -> bool {
+        self.msg_index.checked_add(1) == Some(self.num_msgs)
+    }
+
+    /// Return gas price in decimal string.
+    pub fn gas_price(&self) -> &str {
+        &self.gas_price
+    }
 }
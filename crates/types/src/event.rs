@@ -44,3 +44,14 @@ impl std::fmt::Debug for ContractEvent {
         )
     }
 }
+
+/// A bcs encoded event, as kept by indexers and historical stores, to be
+/// decoded outside of execution.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RawEvent {
+    /// The type of the data
+    pub type_tag: TypeTag,
+    /// The bcs encoded payload of the event
+    #[serde(with = "serde_bytes")]
+    pub event_data: Vec<u8>,
+}
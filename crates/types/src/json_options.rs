@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// Options controlling the shape of the JSON produced for Move values.
+///
+/// The default reproduces the legacy output: integers wider than 32 bits are
+/// strings, addresses are hex literals, objects are flattened to their address
+/// and decimals are printed at full precision.
+///
+/// The field order is mirrored by `0x1::json::MarshalOptions`, which is passed
+/// to the vm in its bcs encoding.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JsonOptions {
+    /// Emit u64, u128 and u256 values as JSON numbers when they fit in the
+    /// integer range a double can represent exactly (2^53 - 1).
+    pub numbers_as_json: bool,
+    /// Encode addresses in bech32 with the given human readable part instead of
+    /// hex. Addresses with 12 leading zero bytes are encoded from their last 20
+    /// bytes, as cosmos accounts are.
+    pub address_hrp: Option<String>,
+    /// Wrap every struct value as `{"type": <struct tag>, "value": <json>}`.
+    pub type_annotations: bool,
+    /// Emit `Object<T>` as `{"inner": <address>, "type": <T>}` instead of the
+    /// bare object address.
+    pub object_inner_types: bool,
+    /// Number of fractional digits to print for decimals and fixed point
+    /// numbers; extra digits are truncated. At most 20 digits are allowed.
+    pub decimal_precision: Option<u32>,
+}
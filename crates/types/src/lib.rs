@@ -11,6 +11,7 @@ pub mod function_info;
 pub mod gas_usage;
 pub mod iterator;
 pub mod json_event;
+pub mod json_options;
 pub mod message;
 pub mod metadata;
 pub mod module;
@@ -18,6 +18,7 @@ use crate::gas_usage::GasUsageSet;
 use crate::json_event::JsonEvents;
 use crate::script::Script;
 use crate::staking_change_set::StakingChangeSet;
+use crate::table::TableLengthChange;
 use crate::write_set::WriteSet;
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -95,6 +96,7 @@ pub struct MessageOutput {
     cosmos_messages: CosmosMessages,
     new_accounts: Accounts,
     gas_usage_set: GasUsageSet,
+    table_length_changes: Vec<TableLengthChange>,
 }
 
 impl MessageOutput {
@@ -106,6 +108,7 @@ impl MessageOutput {
         cosmos_messages: CosmosMessages,
         new_accounts: Accounts,
         gas_usage_set: GasUsageSet,
+        table_length_changes: Vec<TableLengthChange>,
     ) -> Self {
         MessageOutput {
             events,
@@ -114,6 +117,7 @@ impl MessageOutput {
             cosmos_messages,
             new_accounts,
             gas_usage_set,
+            table_length_changes,
         }
     }
 
@@ -141,6 +145,10 @@ impl MessageOutput {
         &self.gas_usage_set
     }
 
+    pub fn table_length_changes(&self) -> &[TableLengthChange] {
+        &self.table_length_changes
+    }
+
     pub fn into_inner(
         self,
     ) -> (
@@ -150,6 +158,7 @@ impl MessageOutput {
         CosmosMessages,
         Accounts,
         GasUsageSet,
+        Vec<TableLengthChange>,
     ) {
         let Self {
             events,
@@ -158,6 +167,7 @@ impl MessageOutput {
             cosmos_messages,
             new_accounts,
             gas_usage_set,
+            table_length_changes,
         } = self;
 
         (
@@ -167,6 +177,7 @@ impl MessageOutput {
             cosmos_messages,
             new_accounts,
             gas_usage_set,
+            table_length_changes,
         )
     }
 }
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 pub const ERROR_PREFIX: &str = "E";
 pub const VIEW_FUN_ATTRIBUTE: &str = "view";
 pub const EVENT_STRUCT_ATTRIBUTE: &str = "event";
+pub const RANDOMNESS_ATTRIBUTE: &str = "randomness";
 pub const CODE_MODULE_NAME: &str = "code";
 pub const INIT_MODULE_FUNCTION_NAME: &str = "init_module";
 pub const INIT_GENESIS_FUNCTION_NAME: &str = "init_genesis";
@@ -53,6 +54,7 @@ pub struct KnownAttribute {
 pub enum KnownAttributeKind {
     ViewFunction = 1,
     Event = 4,
+    Randomness = 5,
 }
 
 impl KnownAttribute {
@@ -77,11 +79,26 @@ impl KnownAttribute {
     pub fn is_event(&self) -> bool {
         self.kind == KnownAttributeKind::Event as u8
     }
+
+    pub fn randomness() -> Self {
+        Self {
+            kind: KnownAttributeKind::Randomness as u8,
+            args: vec![],
+        }
+    }
+
+    pub fn is_randomness(&self) -> bool {
+        self.kind == KnownAttributeKind::Randomness as u8
+    }
 }
 
 // top-level attribute names, only.
 pub fn get_all_attribute_names() -> &'static BTreeSet<String> {
-    const ALL_ATTRIBUTE_NAMES: [&str; 2] = [VIEW_FUN_ATTRIBUTE, EVENT_STRUCT_ATTRIBUTE];
+    const ALL_ATTRIBUTE_NAMES: [&str; 3] = [
+        VIEW_FUN_ATTRIBUTE,
+        EVENT_STRUCT_ATTRIBUTE,
+        RANDOMNESS_ATTRIBUTE,
+    ];
 
     fn extended_attribute_names() -> BTreeSet<String> {
         ALL_ATTRIBUTE_NAMES
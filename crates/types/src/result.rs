@@ -1,6 +1,10 @@
 use crate::{
-    account::Account, cosmos::CosmosMessage, gas_usage::GasUsage, json_event::JsonEvent,
-    staking_change_set::StakingDelta,
+    account::Account,
+    cosmos::CosmosMessage,
+    gas_usage::GasUsage,
+    json_event::JsonEvent,
+    staking_change_set::{StakingDelta, StakingRedelegation},
+    table::TableLengthChange,
 };
 use serde::{Deserialize, Serialize};
 
@@ -8,25 +12,31 @@ use serde::{Deserialize, Serialize};
 pub struct ExecutionResult {
     events: Vec<JsonEvent>,
     staking_deltas: Vec<StakingDelta>,
+    staking_redelegations: Vec<StakingRedelegation>,
     cosmos_messages: Vec<CosmosMessage>,
     new_accounts: Vec<Account>,
     gas_usages: Vec<GasUsage>,
+    table_length_changes: Vec<TableLengthChange>,
 }
 
 impl ExecutionResult {
     pub fn new(
         events: Vec<JsonEvent>,
         staking_deltas: Vec<StakingDelta>,
+        staking_redelegations: Vec<StakingRedelegation>,
         cosmos_messages: Vec<CosmosMessage>,
         new_accounts: Vec<Account>,
         gas_usages: Vec<GasUsage>,
+        table_length_changes: Vec<TableLengthChange>,
     ) -> Self {
         Self {
             events,
             staking_deltas,
+            staking_redelegations,
             cosmos_messages,
             new_accounts,
             gas_usages,
+            table_length_changes,
         }
     }
 }
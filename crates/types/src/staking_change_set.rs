@@ -15,9 +15,30 @@ pub struct StakingDelta {
     undelegation: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StakingRedelegation {
+    /// The validator to move the delegation from
+    pub src_validator: Vec<u8>,
+    /// The validator to move the delegation to
+    pub dst_validator: Vec<u8>,
+    /// The coin type of staking denom
+    pub metadata: AccountAddress,
+    /// The redelegated share amount of the source validator
+    pub share: String,
+}
+
+/// A pending unbonding entry of the delegations held by the staking module.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnbondingEntry {
+    /// The unbonding amount
+    pub amount: u64,
+    /// The timestamp when the unbonding is completed
+    pub completion_time: u64,
+}
+
 #[derive(Default, Debug, Clone)]
-pub struct StakingChangeSet(
-    BTreeMap<
+pub struct StakingChangeSet {
+    changes: BTreeMap<
         Vec<u8>,
         BTreeMap<
             AccountAddress,
@@ -27,21 +48,31 @@ pub struct StakingChangeSet(
             ),
         >,
     >,
-);
+    redelegations: Vec<StakingRedelegation>,
+}
 
 impl StakingChangeSet {
     pub fn new(
-        map: BTreeMap<Vec<u8>, BTreeMap<AccountAddress, (u64, String)>>,
+        changes: BTreeMap<Vec<u8>, BTreeMap<AccountAddress, (u64, String)>>,
+        redelegations: Vec<StakingRedelegation>,
     ) -> StakingChangeSet {
-        Self(map)
+        Self {
+            changes,
+            redelegations,
+        }
     }
 
     pub fn changes(&self) -> &BTreeMap<Vec<u8>, BTreeMap<AccountAddress, (u64, String)>> {
-        &self.0
+        &self.changes
+    }
+
+    pub fn redelegations(&self) -> &[StakingRedelegation] {
+        &self.redelegations
     }
 
-    pub fn into_inner(self) -> Vec<StakingDelta> {
-        self.0
+    pub fn into_inner(self) -> (Vec<StakingDelta>, Vec<StakingRedelegation>) {
+        let deltas = self
+            .changes
             .into_iter()
             .flat_map(
                 |(validator, changes): (Vec<u8>, BTreeMap<AccountAddress, (u64, String)>)| {
@@ -56,10 +87,12 @@ impl StakingChangeSet {
                         .collect::<Vec<StakingDelta>>()
                 },
             )
-            .collect()
+            .collect();
+
+        (deltas, self.redelegations)
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.changes.is_empty() && self.redelegations.is_empty()
     }
 }
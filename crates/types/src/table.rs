@@ -49,6 +49,33 @@ pub struct TableChangeSet {
     pub new_tables: BTreeMap<TableHandle, TableInfo>,
     pub removed_tables: BTreeSet<TableHandle>,
     pub changes: BTreeMap<TableHandle, TableChange>,
+    /// Net change of the number of entries per table, tracked by the table natives.
+    /// Tables without entry count change are omitted.
+    pub length_changes: BTreeMap<TableHandle, i64>,
+}
+
+/// The net change of the number of entries of a table, reported to the host.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableLengthChange {
+    handle: AccountAddress,
+    delta: i64,
+}
+
+impl TableLengthChange {
+    pub fn new(handle: TableHandle, delta: i64) -> Self {
+        Self {
+            handle: handle.0,
+            delta,
+        }
+    }
+
+    pub fn handle(&self) -> TableHandle {
+        TableHandle(self.handle)
+    }
+
+    pub fn delta(&self) -> i64 {
+        self.delta
+    }
 }
 
 /// A change of a single table.
@@ -1,4 +1,5 @@
 use crate::json_event::JsonEvent;
+use crate::json_options::JsonOptions;
 use crate::serde_helper::vec_bytes;
 
 use move_core_types::identifier::{IdentStr, Identifier};
@@ -17,6 +18,9 @@ pub struct ViewFunction {
 
     // whether the args are json encoded
     is_json: bool,
+
+    // shape of the json encoded return values
+    json_options: JsonOptions,
 }
 
 impl ViewFunction {
@@ -33,9 +37,15 @@ impl ViewFunction {
             ty_args,
             args,
             is_json,
+            json_options: JsonOptions::default(),
         }
     }
 
+    pub fn with_json_options(mut self, json_options: JsonOptions) -> Self {
+        self.json_options = json_options;
+        self
+    }
+
     pub fn module(&self) -> &ModuleId {
         &self.module
     }
@@ -59,6 +69,10 @@ impl ViewFunction {
     pub fn is_json(&self) -> bool {
         self.is_json
     }
+
+    pub fn json_options(&self) -> &JsonOptions {
+        &self.json_options
+    }
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -6,6 +6,13 @@ pub struct InitiaVMConfig {
     pub allow_unstable: bool,
     pub script_cache_capacity: usize,
     pub module_cache_capacity: usize,
+    /// Stargate query paths which move modules are allowed to query.
+    /// Every path is allowed when empty.
+    pub allowed_query_paths: Vec<String>,
+    /// Type urls of the cosmos messages which move modules are allowed to dispatch
+    /// synchronously. The messages must not execute move or access move state.
+    /// Nothing is allowed when empty.
+    pub allowed_dispatch_messages: Vec<String>,
 }
 
 impl Default for InitiaVMConfig {
@@ -14,6 +21,8 @@ impl Default for InitiaVMConfig {
             allow_unstable: true,
             script_cache_capacity: 100,
             module_cache_capacity: 500,
+            allowed_query_paths: vec![],
+            allowed_dispatch_messages: vec![],
         }
     }
 }
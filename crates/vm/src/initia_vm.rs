@@ -28,15 +28,16 @@ use initia_move_gas::MiscGasParameters;
 use initia_move_gas::{
     Gas, InitiaGasMeter, InitiaGasParameters, InitialGasSchedule, NativeGasParameters,
 };
-use initia_move_json::serialize_move_value_to_json_value;
+use initia_move_json::serialize_move_value_to_json_value_with_options;
 use initia_move_natives::{
     account::{AccountAPI, NativeAccountContext},
     all_natives,
     code::{NativeCodeContext, PublishRequest, UpgradePolicy},
-    cosmos::NativeCosmosContext,
+    cosmos::{is_dispatching, CosmosAPI, NativeCosmosContext},
     event::NativeEventContext,
     oracle::{NativeOracleContext, OracleAPI},
     query::{NativeQueryContext, QueryAPI},
+    randomness::NativeRandomnessContext,
     staking::NativeStakingContext,
     transaction_context::NativeTransactionContext,
 };
@@ -53,10 +54,12 @@ use initia_move_types::{
     env::Env,
     gas_usage::GasUsageSet,
     json_event::JsonEvents,
+    json_options::JsonOptions,
     message::{AuthenticateMessage, Message, MessageOutput, MessagePayload},
     module::ModuleBundle,
     move_utils::as_move_value::AsMoveValue,
     staking_change_set::StakingChangeSet,
+    table::TableLengthChange,
     user_transaction_context::{EntryFunctionPayload, UserTransactionContext},
     view_function::{ViewFunction, ViewOutput},
     vm_config::InitiaVMConfig,
@@ -67,7 +70,8 @@ use crate::{
     session::{SessionExt, SessionOutput},
     verifier::{
         config::verifier_config, event_validation::verify_no_event_emission_in_script,
-        metadata::get_vm_metadata, script::reject_unstable_bytecode_for_script,
+        metadata::get_vm_metadata, randomness::determine_is_randomness,
+        script::reject_unstable_bytecode_for_script,
         transaction_arg_validation::validate_combine_signer_and_txn_args,
         view_function::validate_view_function_and_construct,
     },
@@ -146,7 +150,7 @@ impl InitiaVM {
 
     fn create_session<
         'r,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
         R: ResourceResolver,
         T: TableResolver,
     >(
@@ -156,6 +160,7 @@ impl InitiaVM {
         resolver: &'r R,
         table_resolver: &'r mut T,
         user_transaction_context_opt: Option<UserTransactionContext>,
+        dispatch_allowed: bool,
     ) -> SessionExt<'r, R> {
         let mut extensions = NativeContextExtensions::default();
         let tx_hash: [u8; 32] = env
@@ -173,18 +178,33 @@ impl InitiaVM {
             env.chain_id().to_string(),
             env.block_height(),
             env.block_timestamp_nanos(),
+            env.block_hash()
+                .try_into()
+                .expect("HashValue should be converted to [u8; 32]"),
+            env.proposer(),
         ));
         extensions.add(NativeCodeContext::default());
         extensions.add(NativeStakingContext::new(api));
-        extensions.add(NativeQueryContext::new(api));
-        extensions.add(NativeCosmosContext::default());
+        extensions.add(NativeQueryContext::new(
+            api,
+            &self.initia_vm_config.allowed_query_paths,
+        ));
+        extensions.add(NativeCosmosContext::new(
+            api,
+            dispatch_allowed,
+            &self.initia_vm_config.allowed_dispatch_messages,
+        ));
         extensions.add(NativeTransactionContext::new(
             tx_hash,
             session_id,
+            env.tx_index(),
+            env.msg_index(),
+            env.gas_price().to_string(),
             user_transaction_context_opt,
         ));
         extensions.add(NativeEventContext::default());
         extensions.add(NativeOracleContext::new(api));
+        extensions.add(NativeRandomnessContext::new(env.random_seed()));
 
         SessionExt::new(extensions, resolver)
     }
@@ -193,7 +213,7 @@ impl InitiaVM {
     pub fn initialize<
         S: StateView,
         T: TableResolver,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
     >(
         &mut self,
         api: &A,
@@ -216,7 +236,7 @@ impl InitiaVM {
         let gas_params = self.gas_params.clone();
         let mut gas_meter = InitiaGasMeter::new(gas_params, gas_limit);
 
-        let session = self.create_session(api, env, move_resolver, table_resolver, None);
+        let session = self.create_session(api, env, move_resolver, table_resolver, None, false);
         let traversal_storage = TraversalStorage::new();
         let mut traversal_context = TraversalContext::new(&traversal_storage);
 
@@ -243,7 +263,7 @@ impl InitiaVM {
     pub fn execute_message<
         S: StateView,
         T: TableResolver,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
     >(
         &mut self,
         gas_meter: &mut InitiaGasMeter,
@@ -286,7 +306,7 @@ impl InitiaVM {
     pub fn execute_view_function<
         S: StateView,
         T: TableResolver,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
     >(
         &self,
         gas_meter: &mut InitiaGasMeter,
@@ -304,7 +324,7 @@ impl InitiaVM {
             self.module_cache.clone(),
         );
         let move_resolver = code_storage.state_view_impl();
-        let mut session = self.create_session(api, env, move_resolver, table_resolver, None);
+        let mut session = self.create_session(api, env, move_resolver, table_resolver, None, false);
         let traversal_storage = TraversalStorage::new();
         let mut traversal_context = TraversalContext::new(&traversal_storage);
 
@@ -353,7 +373,7 @@ impl InitiaVM {
         let session_output = session.finish(&code_storage)?;
         let (events, _, _, _, _) = session_output;
         let json_events = JsonEvents::new(events.into_iter().map(|e| e.into_inner()).collect());
-        let ret = serialize_response_to_json(&ret_ty_layouts, res)?
+        let ret = serialize_response_to_json(&ret_ty_layouts, res, view_fn.json_options())?
             .expect("view function must return value");
 
         Ok(ViewOutput::new(ret, json_events.into_inner()))
@@ -362,7 +382,7 @@ impl InitiaVM {
     pub fn execute_authenticate<
         S: StateView,
         T: TableResolver,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
     >(
         &self,
         gas_meter: &mut InitiaGasMeter,
@@ -391,7 +411,7 @@ impl InitiaVM {
         gas_meter.charge_intrinsic_gas_for_transaction((abstraction_data.size() as u64).into())?;
 
         let move_resolver = code_storage.state_view_impl();
-        let mut session = self.create_session(api, env, move_resolver, table_resolver, None);
+        let mut session = self.create_session(api, env, move_resolver, table_resolver, None, false);
 
         // helper function to create invariant violation error
         let invariant_violation_error = |msg: &str| {
@@ -459,7 +479,7 @@ impl InitiaVM {
     fn execute_script_or_entry_function<
         S: StateView,
         T: TableResolver,
-        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI,
+        A: AccountAPI + StakingAPI + QueryAPI + OracleAPI + CosmosAPI,
     >(
         &self,
         api: &A,
@@ -501,6 +521,7 @@ impl InitiaVM {
             move_resolver,
             table_resolver,
             user_transaction_context_opt,
+            true,
         );
 
         match payload {
@@ -586,6 +607,30 @@ impl InitiaVM {
 
                 // need check function.is_friend_or_private() ??
 
+                // allow randomness only when the entry function is annotated with `#[randomness]`.
+                //
+                // the outcome must not be observable by any move code which can still abort
+                // the transaction, so randomness is not allowed in a dispatched message or
+                // when other messages follow in the same transaction.
+                let metadata = get_vm_metadata(code_storage, entry_fn.module());
+                if determine_is_randomness(metadata.as_ref(), entry_fn.function())
+                    && env.is_last_msg()
+                    && !is_dispatching()
+                {
+                    // require a minimum gas limit to prevent undergasing attacks
+                    if !gas_meter.allows_randomness() {
+                        return Err(VMStatus::error(
+                            StatusCode::MAX_GAS_UNITS_BELOW_MIN_TRANSACTION_GAS_UNITS,
+                            Some(
+                                "gas limit is below the minimum required by randomness entry function"
+                                    .to_string(),
+                            ),
+                        ));
+                    }
+
+                    session.mark_randomness_unbiasable();
+                }
+
                 let args = validate_combine_signer_and_txn_args(
                     &mut session,
                     code_storage,
@@ -633,7 +678,14 @@ impl InitiaVM {
         session_output: SessionOutput,
         gas_meter: &mut InitiaGasMeter,
     ) -> VMResult<MessageOutput> {
-        let (events, write_set, staking_change_set, cosmos_messages, new_accounts) = session_output;
+        let (
+            events,
+            write_set,
+            staking_change_set,
+            cosmos_messages,
+            new_accounts,
+            table_length_changes,
+        ) = session_output;
         let json_events = JsonEvents::new(events.into_iter().map(|e| e.into_inner()).collect());
         let gas_usage_set = gas_meter.into_usage_set();
 
@@ -644,6 +696,7 @@ impl InitiaVM {
             cosmos_messages,
             new_accounts,
             gas_usage_set,
+            table_length_changes,
         ))
     }
 }
@@ -651,6 +704,7 @@ impl InitiaVM {
 fn serialize_response_to_json(
     ty_layouts: &[MoveTypeLayout],
     response: SerializedReturnValues,
+    json_options: &JsonOptions,
 ) -> VMResult<Option<String>> {
     if Vec::len(&response.mutable_reference_outputs) != 0 {
         return Err(
@@ -665,7 +719,7 @@ fn serialize_response_to_json(
         let move_val = MoveValue::simple_deserialize(blob, ty_layout).map_err(|_| {
             PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).finish(Location::Undefined)
         })?;
-        let serde_value = serialize_move_value_to_json_value(&move_val)?;
+        let serde_value = serialize_move_value_to_json_value_with_options(&move_val, json_options)?;
         serde_vals.push(serde_value);
     }
     if serde_vals.is_empty() {
@@ -685,6 +739,7 @@ pub(crate) fn get_message_output(
     cosmos_messages: CosmosMessages,
     new_accounts: Accounts,
     gas_usage_set: GasUsageSet,
+    table_length_changes: Vec<TableLengthChange>,
 ) -> MessageOutput {
     MessageOutput::new(
         events,
@@ -693,5 +748,6 @@ pub(crate) fn get_message_output(
         cosmos_messages,
         new_accounts,
         gas_usage_set,
+        table_length_changes,
     )
 }
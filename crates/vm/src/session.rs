@@ -6,6 +6,7 @@ use initia_move_natives::{
     code::{NativeCodeContext, PublishRequest},
     cosmos::NativeCosmosContext,
     event::NativeEventContext,
+    randomness::NativeRandomnessContext,
     staking::NativeStakingContext,
     table::NativeTableContext,
 };
@@ -15,6 +16,7 @@ use initia_move_types::{
     cosmos::CosmosMessages,
     event::ContractEvent,
     staking_change_set::StakingChangeSet,
+    table::TableLengthChange,
     write_set::{WriteOp, WriteSet},
 };
 
@@ -40,6 +42,7 @@ pub type SessionOutput<'r> = (
     StakingChangeSet,
     CosmosMessages,
     Accounts,
+    Vec<TableLengthChange>,
 );
 
 pub struct SessionExt<'r, R> {
@@ -187,6 +190,11 @@ impl<'r, R: ResourceResolver> SessionExt<'r, R> {
         let table_change_set = table_context
             .into_change_set(Some(&module_storage.as_function_value_extension()))
             .map_err(|e| e.finish(Location::Undefined))?;
+        let table_length_changes = table_change_set
+            .length_changes
+            .iter()
+            .map(|(handle, delta)| TableLengthChange::new(*handle, *delta))
+            .collect();
 
         let cosmos_context: NativeCosmosContext = extensions.remove::<NativeCosmosContext>();
         let cosmos_messages = cosmos_context.into_messages();
@@ -208,6 +216,7 @@ impl<'r, R: ResourceResolver> SessionExt<'r, R> {
             staking_change_set,
             cosmos_messages,
             new_accounts,
+            table_length_changes,
         ))
     }
 
@@ -216,6 +225,13 @@ impl<'r, R: ResourceResolver> SessionExt<'r, R> {
         ctx.requested_module_bundle.take()
     }
 
+    /// Allow the randomness natives for this session; only called when the entry
+    /// function is annotated with `#[randomness]`.
+    pub fn mark_randomness_unbiasable(&mut self) {
+        let ctx = self.extensions.get_mut::<NativeRandomnessContext>();
+        ctx.mark_unbiasable();
+    }
+
     /// Converts module bytes and their compiled representation extracted from publish request into
     /// write ops. Only used by V2 loader implementation.
     pub fn convert_modules_into_write_set(
@@ -5,6 +5,7 @@ pub(crate) mod metadata;
 pub(crate) mod module_init;
 pub(crate) mod module_metadata;
 pub(crate) mod native_validation;
+pub(crate) mod randomness;
 pub(crate) mod script;
 pub(crate) mod view_function;
 
@@ -9,7 +9,7 @@ use move_binary_format::{
     access::ModuleAccess,
     check_complexity::check_module_complexity,
     errors::{Location, PartialVMError, VMResult},
-    file_format::{FunctionDefinition, FunctionHandle},
+    file_format::{FunctionDefinition, FunctionHandle, Visibility},
     CompiledModule,
 };
 use move_core_types::{
@@ -76,6 +76,8 @@ fn validate_module_metadata(module: &CompiledModule) -> Result<(), MetaDataValid
         for attr in attrs {
             if attr.is_view_function() {
                 is_valid_view_function(module, &functions, fun)?
+            } else if attr.is_randomness() {
+                is_valid_randomness_function(&functions, fun)?
             } else {
                 return Err(AttributeValidationError {
                     key: fun.clone(),
@@ -153,3 +155,23 @@ pub fn is_valid_view_function(
         attribute: KnownAttributeKind::ViewFunction as u8,
     })
 }
+
+/// Randomness functions must be non-public entry functions, so they cannot be
+/// composed with other functions which can abort on unfavorable outcomes.
+pub fn is_valid_randomness_function(
+    functions: &BTreeMap<&IdentStr, (&FunctionHandle, &FunctionDefinition)>,
+    fun: &str,
+) -> Result<(), AttributeValidationError> {
+    if let Ok(ident_fun) = Identifier::new(fun) {
+        if let Some((_func_handle, func_def)) = functions.get(ident_fun.as_ident_str()) {
+            if func_def.is_entry && func_def.visibility != Visibility::Public {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(AttributeValidationError {
+        key: fun.to_string(),
+        attribute: KnownAttributeKind::Randomness as u8,
+    })
+}
@@ -0,0 +1,18 @@
+use initia_move_types::metadata::RuntimeModuleMetadataV0;
+use move_core_types::identifier::IdentStr;
+
+/// Based on the function attributes in the module metadata, determine whether an
+/// entry function is annotated with `#[randomness]`.
+pub fn determine_is_randomness(
+    module_metadata: Option<&RuntimeModuleMetadataV0>,
+    fun_name: &IdentStr,
+) -> bool {
+    if let Some(data) = module_metadata {
+        data.fun_attributes
+            .get(fun_name.as_str())
+            .map(|attrs| attrs.iter().any(|attr| attr.is_randomness()))
+            .unwrap_or_default()
+    } else {
+        false
+    }
+}
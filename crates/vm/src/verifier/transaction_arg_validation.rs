@@ -1,11 +1,13 @@
 use initia_move_storage::initia_storage::InitiaStorage;
 use initia_move_storage::state_view::StateView;
+use initia_move_storage::struct_resolver::{StructResolver, StructResolverImpl};
 use move_binary_format::errors::{Location, PartialVMError};
 use move_binary_format::file_format::FunctionDefinitionIndex;
 use move_binary_format::file_format_common::read_uleb128_as_u64;
 use move_core_types::ident_str;
 use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout};
 use move_core_types::vm_status::VMStatus;
 use move_core_types::{account_address::AccountAddress, value::MoveValue, vm_status::StatusCode};
 use move_vm_runtime::module_traversal::{TraversalContext, TraversalStorage};
@@ -145,11 +147,14 @@ pub fn validate_combine_signer_and_txn_args<S: StateView>(
     let allowed_structs = &ALLOWED_STRUCTS;
     let ty_builder = &code_storage.runtime_environment().vm_config().ty_builder;
 
+    // Enums declared in the module of the entry function can only be passed as json arguments.
+    let enum_module = if is_json { func.module_id() } else { None };
+
     // Need to keep this here to ensure we return the historic correct error code for replay
     for ty in func.param_tys()[signer_param_cnt..].iter() {
         let subst_res = ty_builder.create_ty_with_subst(ty, func.ty_args());
         let ty = subst_res.map_err(|e| e.finish(Location::Undefined).into_vm_status())?;
-        let valid = is_valid_txn_arg(code_storage, &ty, allowed_structs);
+        let valid = is_valid_txn_arg(code_storage, &ty, allowed_structs, enum_module);
         if !valid {
             return Err(VMStatus::error(
                 StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE,
@@ -185,6 +190,7 @@ pub fn validate_combine_signer_and_txn_args<S: StateView>(
         &func.param_tys()[signer_param_cnt..],
         args,
         func.ty_args(),
+        func.module_id(),
         allowed_structs,
         false,
         is_json,
@@ -204,16 +210,18 @@ pub fn validate_combine_signer_and_txn_args<S: StateView>(
 }
 
 // Return whether the argument is valid/allowed and whether it needs construction.
+// Non-generic enums declared in `enum_module` are allowed as well.
 pub(crate) fn is_valid_txn_arg(
     module_storage: &impl ModuleStorage,
     ty: &Type,
     allowed_structs: &ConstructorMap,
+    enum_module: Option<&ModuleId>,
 ) -> bool {
     use move_vm_types::loaded_data::runtime_types::Type::*;
 
     match ty {
         Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address => true,
-        Vector(inner) => is_valid_txn_arg(module_storage, inner, allowed_structs),
+        Vector(inner) => is_valid_txn_arg(module_storage, inner, allowed_structs, enum_module),
         Struct { .. } | StructInstantiation { .. } => module_storage
             .runtime_environment()
             .get_struct_name(ty)
@@ -221,6 +229,9 @@ pub(crate) fn is_valid_txn_arg(
                 Some(st) => {
                     let full_name = format!("{}::{}", st.0.short_str_lossless(), st.1);
                     allowed_structs.contains_key(&full_name)
+                        || (matches!(ty, Struct { .. })
+                            && enum_module.is_some_and(|m| m == &st.0)
+                            && is_enum_type(module_storage, ty))
                 }
                 None => false,
             }),
@@ -228,6 +239,17 @@ pub(crate) fn is_valid_txn_arg(
     }
 }
 
+fn is_enum_type(module_storage: &impl ModuleStorage, ty: &Type) -> bool {
+    StructResolverImpl::new(module_storage)
+        .type_to_fully_annotated_layout(ty)
+        .is_ok_and(|layout| {
+            matches!(
+                layout,
+                MoveTypeLayout::Struct(MoveStructLayout::WithVariants(_))
+            )
+        })
+}
+
 #[allow(clippy::too_many_arguments)]
 // Construct arguments. Walk through the arguments and according to the signature
 // construct arguments that require so.
@@ -238,6 +260,7 @@ pub(crate) fn construct_args<S: StateView>(
     types: &[Type],
     args: Vec<Vec<u8>>,
     ty_args: &[Type],
+    module_id: Option<&ModuleId>,
     allowed_structs: &ConstructorMap,
     is_view: bool,
     is_json: bool,
@@ -250,19 +273,30 @@ pub(crate) fn construct_args<S: StateView>(
     }
 
     let ty_builder = &code_storage.runtime_environment().vm_config().ty_builder;
-    for (ty, arg) in types.iter().zip(args) {
+    for (idx, (ty, arg)) in types.iter().zip(args).enumerate() {
         let subst_res = ty_builder.create_ty_with_subst(ty, ty_args);
         let ty = subst_res.map_err(|e| e.finish(Location::Undefined).into_vm_status())?;
-        let arg = construct_arg(
-            session,
-            code_storage,
-            &ty,
-            allowed_structs,
-            arg,
-            &mut gas_meter,
-            is_view,
-            is_json,
-        )?;
+        let arg = if is_json {
+            deserialize_json_args(
+                code_storage,
+                &code_storage.struct_resolver(),
+                module_id,
+                &format!("args[{}]", idx),
+                &ty,
+                &arg,
+            )
+            .map_err(|e| e.into_vm_status())?
+        } else {
+            construct_arg(
+                session,
+                code_storage,
+                &ty,
+                allowed_structs,
+                arg,
+                &mut gas_meter,
+                is_view,
+            )?
+        };
         res_args.push(arg);
     }
     Ok(res_args)
@@ -281,13 +315,7 @@ fn construct_arg<S: StateView>(
     arg: Vec<u8>,
     gas_meter: &mut impl GasMeter,
     is_view: bool,
-    is_json: bool,
 ) -> Result<Vec<u8>, VMStatus> {
-    if is_json {
-        return deserialize_json_args(code_storage, &code_storage.struct_resolver(), ty, &arg)
-            .map_err(|e| e.into_vm_status());
-    }
-
     use move_vm_types::loaded_data::runtime_types::Type::*;
     match ty {
         Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address => Ok(arg),
@@ -61,6 +61,7 @@ pub(crate) fn validate_view_function_and_construct<S: StateView>(
         func.param_tys(),
         args,
         func.ty_args(),
+        func.module_id(),
         allowed_structs,
         true,
         is_string,
@@ -406,6 +406,34 @@ func Test_DecodeResource(t *testing.T) {
 	require.Equal(t, bz, []byte(`{"type":"0x2::TestCoin::Coin<0x2::TestCoin::Initia>","data":{"test":true,"value":"300"}}`))
 }
 
+func Test_DecodeEvent(t *testing.T) {
+	vm, kvStore := initializeVM(t, true)
+	defer vm.Destroy()
+
+	publishModuleBundle(t, vm, kvStore)
+
+	structTag, err := api.ParseStructTag("0x2::TestCoin::MintEvent")
+	require.NoError(t, err)
+	typeTag := &types.TypeTag__Struct{Value: structTag}
+
+	amount, err := types.SerializeUint64(100)
+	require.NoError(t, err)
+
+	bz, err := api.DecodeEvent(kvStore, typeTag, amount, nil)
+	require.NoError(t, err)
+	require.Equal(t, `{"amount":"100"}`, string(bz))
+
+	otherAmount, err := types.SerializeUint64(7)
+	require.NoError(t, err)
+
+	bz, err = api.DecodeEvents(kvStore, []types.RawEvent{
+		{TypeTag: typeTag, EventData: amount},
+		{TypeTag: typeTag, EventData: otherAmount},
+	}, &types.JsonOptions{NumbersAsJson: true})
+	require.NoError(t, err)
+	require.Equal(t, `[{"amount":100},{"amount":7}]`, string(bz))
+}
+
 func Test_DecodeModule(t *testing.T) {
 	vm, _ := initializeVM(t, true)
 	defer vm.Destroy()
@@ -418,6 +446,51 @@ func Test_DecodeModule(t *testing.T) {
 	require.Contains(t, string(bz), `"address":"0x2","name":"TestCoin"`)
 }
 
+func Test_GenerateJSONSchema(t *testing.T) {
+	vm, _ := initializeVM(t, true)
+	defer vm.Destroy()
+
+	f, err := os.ReadFile("./precompile/binaries/tests/TestCoin.mv")
+	require.NoError(t, err)
+
+	bz, err := api.GenerateJSONSchema(f)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"address":"0x2","name":"TestCoin"`)
+	require.Contains(t, string(bz), `"$schema":"https://json-schema.org/draft/2020-12/schema"`)
+}
+
+func Test_DiffModules(t *testing.T) {
+	vm, _ := initializeVM(t, true)
+	defer vm.Destroy()
+
+	f, err := os.ReadFile("./precompile/binaries/tests/TestCoin.mv")
+	require.NoError(t, err)
+
+	bz, err := api.DiffModules(f, f)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), `"functions":[],"structs":[],"friends":[]`)
+	require.Contains(t, string(bz), `"compatibility":{"compatible":true,"reasons":[]}`)
+}
+
+func Test_Disassemble(t *testing.T) {
+	vm, _ := initializeVM(t, true)
+	defer vm.Destroy()
+
+	f, err := os.ReadFile("./precompile/binaries/tests/TestCoin.mv")
+	require.NoError(t, err)
+
+	bz, err := api.DisassembleModule(f, nil)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), "module 0x2::TestCoin {")
+
+	f, err = os.ReadFile("./precompile/binaries/tests/main.mv")
+	require.NoError(t, err)
+
+	bz, err = api.DisassembleScript(f, nil)
+	require.NoError(t, err)
+	require.Contains(t, string(bz), "fun main")
+}
+
 func Test_DecodeScript(t *testing.T) {
 	vm, _ := initializeVM(t, true)
 	defer vm.Destroy()
@@ -213,12 +213,38 @@ typedef struct {
                              uint64_t*,
                              libmovevm_UnmanagedVector*);
   int32_t (*unbond_timestamp)(const libmovevm_ApiT*, uint64_t*, libmovevm_UnmanagedVector*);
+  int32_t (*unbonding_entries)(const libmovevm_ApiT*,
+                               libmovevm_U8SliceView,
+                               libmovevm_U8SliceView,
+                               libmovevm_UnmanagedVector*,
+                               libmovevm_UnmanagedVector*);
   int32_t (*get_price)(const libmovevm_ApiT*,
                        libmovevm_U8SliceView,
                        libmovevm_UnmanagedVector*,
                        uint64_t*,
                        uint64_t*,
                        libmovevm_UnmanagedVector*);
+  int32_t (*get_price_at)(const libmovevm_ApiT*,
+                          libmovevm_U8SliceView,
+                          uint64_t,
+                          libmovevm_UnmanagedVector*,
+                          uint64_t*,
+                          uint64_t*,
+                          libmovevm_UnmanagedVector*);
+  int32_t (*get_twap)(const libmovevm_ApiT*,
+                      libmovevm_U8SliceView,
+                      uint64_t,
+                      libmovevm_UnmanagedVector*,
+                      uint64_t*,
+                      uint64_t*,
+                      libmovevm_UnmanagedVector*);
+  int32_t (*dispatch_message)(const libmovevm_ApiT*,
+                              libmovevm_U8SliceView,
+                              libmovevm_U8SliceView,
+                              uint64_t,
+                              libmovevm_UnmanagedVector*,
+                              uint64_t*,
+                              libmovevm_UnmanagedVector*);
 } libmovevm_ApiVTable;
 
 typedef struct {
@@ -3,7 +3,10 @@ use crate::memory::{U8SliceView, UnmanagedVector};
 
 use anyhow::anyhow;
 use initia_move_natives::oracle::OracleAPI;
-use initia_move_natives::{account::AccountAPI, query::QueryAPI, staking::StakingAPI};
+use initia_move_natives::{
+    account::AccountAPI, cosmos::CosmosAPI, query::QueryAPI, staking::StakingAPI,
+};
+use initia_move_types::staking_change_set::UnbondingEntry;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::u256::U256;
 
@@ -58,6 +61,13 @@ pub struct ApiVTable {
         *mut u64,             // unbond_timestamp
         *mut UnmanagedVector, // error_msg
     ) -> i32,
+    pub unbonding_entries: extern "C" fn(
+        *const ApiT,
+        U8SliceView,          // validator
+        U8SliceView,          // metadata
+        *mut UnmanagedVector, // entries
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
     pub get_price: extern "C" fn(
         *const ApiT,
         U8SliceView,          // pair_id
@@ -66,6 +76,33 @@ pub struct ApiVTable {
         *mut u64,             // decimals
         *mut UnmanagedVector, // error_msg
     ) -> i32,
+    pub get_price_at: extern "C" fn(
+        *const ApiT,
+        U8SliceView,          // pair_id
+        u64,                  // height
+        *mut UnmanagedVector, // price
+        *mut u64,             // updated_at
+        *mut u64,             // decimals
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub get_twap: extern "C" fn(
+        *const ApiT,
+        U8SliceView,          // pair_id
+        u64,                  // window
+        *mut UnmanagedVector, // twap
+        *mut u64,             // updated_at
+        *mut u64,             // decimals
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
+    pub dispatch_message: extern "C" fn(
+        *const ApiT,
+        U8SliceView, // sender
+        U8SliceView, // message
+        u64,
+        *mut UnmanagedVector, // response
+        *mut u64,
+        *mut UnmanagedVector, // error_msg
+    ) -> i32,
 }
 
 #[repr(C)]
@@ -217,6 +254,43 @@ impl StakingAPI for GoApi {
 
         Ok(unbond_timestamp)
     }
+
+    fn unbonding_entries(
+        &self,
+        validator: &[u8],
+        metadata: AccountAddress,
+    ) -> anyhow::Result<Vec<UnbondingEntry>> {
+        // DO NOT DELETE; same reason with KeepAlive in go
+        let metadata_bytes = metadata.into_bytes();
+
+        let validator = U8SliceView::new(Some(validator));
+        let metadata = U8SliceView::new(Some(&metadata_bytes));
+        let mut entries = UnmanagedVector::default();
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.unbonding_entries)(
+            self.state,
+            validator,
+            metadata,
+            &mut entries as *mut UnmanagedVector,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+
+        // We destruct the UnmanagedVector here, no matter if we need the data.
+        let entries = entries.consume();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to get unbonding entries".to_string();
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        let entries_bytes = entries.ok_or_else(|| anyhow!("Unset unbonding entries"))?;
+        bcs::from_bytes(&entries_bytes).map_err(|_| anyhow!("Invalid unbonding entries"))
+    }
 }
 
 impl OracleAPI for GoApi {
@@ -253,6 +327,76 @@ impl OracleAPI for GoApi {
 
         Ok((price, updated_at, decimals))
     }
+
+    fn get_price_at(&self, pair_id: &[u8], height: u64) -> anyhow::Result<(U256, u64, u64)> {
+        let pair_id = U8SliceView::new(Some(pair_id));
+        let mut price = UnmanagedVector::default();
+        let mut updated_at = 0_u64;
+        let mut decimals = 0_u64;
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.get_price_at)(
+            self.state,
+            pair_id,
+            height,
+            &mut price as *mut UnmanagedVector,
+            &mut updated_at as *mut u64,
+            &mut decimals as *mut u64,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+
+        // We destruct the UnmanagedVector here, no matter if we need the data.
+        let price = price.consume();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to get historical price from the remote oracle".to_string();
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        let price_bytes = price.ok_or_else(|| anyhow!("Unset price"))?;
+        let price: U256 = bcs::from_bytes(&price_bytes).map_err(|_| anyhow!("Unset price"))?;
+
+        Ok((price, updated_at, decimals))
+    }
+
+    fn get_twap(&self, pair_id: &[u8], window: u64) -> anyhow::Result<(U256, u64, u64)> {
+        let pair_id = U8SliceView::new(Some(pair_id));
+        let mut twap = UnmanagedVector::default();
+        let mut updated_at = 0_u64;
+        let mut decimals = 0_u64;
+        let mut error_msg = UnmanagedVector::default();
+
+        let go_error: GoError = (self.vtable.get_twap)(
+            self.state,
+            pair_id,
+            window,
+            &mut twap as *mut UnmanagedVector,
+            &mut updated_at as *mut u64,
+            &mut decimals as *mut u64,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+
+        // We destruct the UnmanagedVector here, no matter if we need the data.
+        let twap = twap.consume();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to get twap from the remote oracle".to_string();
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return Err(anyhow!(err));
+            }
+        }
+
+        let twap_bytes = twap.ok_or_else(|| anyhow!("Unset twap"))?;
+        let twap: U256 = bcs::from_bytes(&twap_bytes).map_err(|_| anyhow!("Unset twap"))?;
+
+        Ok((twap, updated_at, decimals))
+    }
 }
 
 impl QueryAPI for GoApi {
@@ -289,3 +433,45 @@ impl QueryAPI for GoApi {
         }
     }
 }
+
+impl CosmosAPI for GoApi {
+    fn dispatch_message(
+        &self,
+        sender: AccountAddress,
+        message: &[u8],
+        gas_balance: u64,
+    ) -> (anyhow::Result<Vec<u8>>, u64) {
+        // DO NOT DELETE; same reason with KeepAlive in go
+        let sender_bytes = sender.into_bytes();
+
+        let sender = U8SliceView::new(Some(&sender_bytes));
+        let message = U8SliceView::new(Some(message));
+        let mut response: UnmanagedVector = UnmanagedVector::default();
+        let mut error_msg = UnmanagedVector::default();
+        let mut used_gas = 0_u64;
+
+        let go_error: GoError = (self.vtable.dispatch_message)(
+            self.state,
+            sender,
+            message,
+            gas_balance,
+            &mut response as *mut UnmanagedVector,
+            &mut used_gas as *mut u64,
+            &mut error_msg as *mut UnmanagedVector,
+        )
+        .into();
+
+        // We destruct the UnmanagedVector here, no matter if we need the data.
+        let output = response.consume();
+
+        // return complete error message (reading from buffer for GoError::Other)
+        let default = || "Failed to dispatch message".to_string();
+        unsafe {
+            if let Err(err) = go_error.into_result(error_msg, default) {
+                return (Err(anyhow!(err)), used_gas);
+            }
+        }
+
+        (Ok(output.unwrap_or_default()), used_gas)
+    }
+}
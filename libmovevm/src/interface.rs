@@ -279,12 +279,14 @@ pub extern "C" fn decode_move_resource(
     errmsg: Option<&mut UnmanagedVector>,
     struct_tag: ByteSliceView,
     resource_bytes: ByteSliceView,
+    json_options: ByteSliceView,
 ) -> UnmanagedVector {
     let struct_tag = struct_tag.read().unwrap();
     let payload = resource_bytes.read().unwrap();
+    let json_options = json_options.read();
 
     let res = catch_unwind(AssertUnwindSafe(move || {
-        api_handler::decode_move_resource(db, struct_tag, payload)
+        api_handler::decode_move_resource(db, struct_tag, payload, json_options)
     }))
     .unwrap_or_else(|_| Err(Error::panic()));
 
@@ -298,12 +300,14 @@ pub extern "C" fn decode_move_value(
     errmsg: Option<&mut UnmanagedVector>,
     type_tag: ByteSliceView,
     value_bytes: ByteSliceView,
+    json_options: ByteSliceView,
 ) -> UnmanagedVector {
     let type_tag = type_tag.read().unwrap();
     let payload = value_bytes.read().unwrap();
+    let json_options = json_options.read();
 
     let res = catch_unwind(AssertUnwindSafe(move || {
-        api_handler::decode_move_value(db, type_tag, payload)
+        api_handler::decode_move_value(db, type_tag, payload, json_options)
     }))
     .unwrap_or_else(|_| Err(Error::panic()));
 
@@ -327,6 +331,139 @@ pub extern "C" fn decode_module_bytes(
     UnmanagedVector::new(Some(ret))
 }
 
+#[export_name = "libmovevm_diff_modules"]
+pub extern "C" fn diff_modules(
+    errmsg: Option<&mut UnmanagedVector>,
+    old_module_bytes: ByteSliceView,
+    new_module_bytes: ByteSliceView,
+) -> UnmanagedVector {
+    let old_module_bytes = old_module_bytes.read().unwrap().to_vec();
+    let new_module_bytes = new_module_bytes.read().unwrap().to_vec();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::diff_module_bytes(old_module_bytes, new_module_bytes)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_disassemble_module"]
+pub extern "C" fn disassemble_module(
+    errmsg: Option<&mut UnmanagedVector>,
+    module_bytes: ByteSliceView,
+    source_map: ByteSliceView,
+) -> UnmanagedVector {
+    let module_bytes = module_bytes.read().unwrap().to_vec();
+    let source_map = source_map.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::disassemble_module_bytes(module_bytes, source_map)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_disassemble_script"]
+pub extern "C" fn disassemble_script(
+    errmsg: Option<&mut UnmanagedVector>,
+    script_bytes: ByteSliceView,
+    source_map: ByteSliceView,
+) -> UnmanagedVector {
+    let script_bytes = script_bytes.read().unwrap().to_vec();
+    let source_map = source_map.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::disassemble_script_bytes(script_bytes, source_map)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_generate_json_schema"]
+pub extern "C" fn generate_json_schema(
+    errmsg: Option<&mut UnmanagedVector>,
+    module_bytes: ByteSliceView,
+) -> UnmanagedVector {
+    let module_bytes = module_bytes.read().unwrap().to_vec();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::generate_json_schema(module_bytes)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_decode_event"]
+pub extern "C" fn decode_event(
+    db: GoDb,
+    errmsg: Option<&mut UnmanagedVector>,
+    type_tag: ByteSliceView,
+    event_bytes: ByteSliceView,
+    json_options: ByteSliceView,
+) -> UnmanagedVector {
+    let type_tag = type_tag.read().unwrap();
+    let payload = event_bytes.read().unwrap();
+    let json_options = json_options.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::decode_event(db, type_tag, payload, json_options)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_decode_events"]
+pub extern "C" fn decode_events(
+    db: GoDb,
+    errmsg: Option<&mut UnmanagedVector>,
+    events: ByteSliceView,
+    json_options: ByteSliceView,
+) -> UnmanagedVector {
+    let events = events.read().unwrap();
+    let json_options = json_options.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::decode_events(db, events, json_options)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
+#[export_name = "libmovevm_decode_table_item"]
+pub extern "C" fn decode_table_item(
+    db: GoDb,
+    errmsg: Option<&mut UnmanagedVector>,
+    table_handle: ByteSliceView,
+    key_bytes: ByteSliceView,
+    value_bytes: ByteSliceView,
+    json_options: ByteSliceView,
+) -> UnmanagedVector {
+    let table_handle = table_handle.read().unwrap();
+    let key = key_bytes.read().unwrap();
+    let value = value_bytes.read();
+    let json_options = json_options.read();
+
+    let res = catch_unwind(AssertUnwindSafe(move || {
+        api_handler::decode_table_item(db, table_handle, key, value, json_options)
+    }))
+    .unwrap_or_else(|_| Err(Error::panic()));
+
+    let ret = handle_c_error_binary(res, errmsg);
+    UnmanagedVector::new(Some(ret))
+}
+
 #[export_name = "libmovevm_decode_script_bytes"]
 pub extern "C" fn decode_script_bytes(
     errmsg: Option<&mut UnmanagedVector>,
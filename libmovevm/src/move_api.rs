@@ -5,18 +5,33 @@ use crate::storage::GoStorage;
 use initia_move_api::convert::MoveConverter;
 use initia_move_api::handler as api_handler;
 
+use initia_move_types::event::RawEvent;
+use initia_move_types::json_options::JsonOptions;
 use initia_move_types::module::ModuleBundle;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{StructTag, TypeTag};
 
+/// Decode a resource to json. Without `options` the resource is returned in the
+/// api resource format, otherwise in the json shape of view functions.
 pub fn decode_move_resource(
     db_handle: GoDb,
     struct_tag: &[u8],
     blob: &[u8],
+    options: Option<&[u8]>,
 ) -> Result<Vec<u8>, Error> {
     let storage = GoStorage::new(&db_handle);
     let struct_tag: StructTag = bcs::from_bytes(struct_tag).unwrap();
 
     let converter = MoveConverter::new(&storage);
+    if let Some(options) = options {
+        return decode_json_value(
+            &converter,
+            &TypeTag::Struct(Box::new(struct_tag)),
+            blob,
+            options,
+        );
+    }
+
     let resource = converter
         .try_into_resource(&struct_tag, blob)
         .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
@@ -25,11 +40,22 @@ pub fn decode_move_resource(
     serde_json::to_vec(&resource).map_err(|e| Error::BackendFailure { msg: e.to_string() })
 }
 
-pub fn decode_move_value(db_handle: GoDb, type_tag: &[u8], blob: &[u8]) -> Result<Vec<u8>, Error> {
+/// Decode a value to json. Without `options` the value is returned in the api
+/// value format, otherwise in the json shape of view functions.
+pub fn decode_move_value(
+    db_handle: GoDb,
+    type_tag: &[u8],
+    blob: &[u8],
+    options: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
     let storage = GoStorage::new(&db_handle);
     let type_tag: TypeTag = bcs::from_bytes(type_tag).unwrap();
 
     let converter = MoveConverter::new(&storage);
+    if let Some(options) = options {
+        return decode_json_value(&converter, &type_tag, blob, options);
+    }
+
     let value = converter
         .try_into_value(&type_tag, blob)
         .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
@@ -38,6 +64,93 @@ pub fn decode_move_value(db_handle: GoDb, type_tag: &[u8], blob: &[u8]) -> Resul
     serde_json::to_vec(&value).map_err(|e| Error::BackendFailure { msg: e.to_string() })
 }
 
+fn decode_json_value(
+    converter: &MoveConverter<'_, GoStorage<'_>>,
+    type_tag: &TypeTag,
+    blob: &[u8],
+    options: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let options: JsonOptions =
+        bcs::from_bytes(options).map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+    let value = converter
+        .try_into_json_value(type_tag, blob, &options)
+        .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+
+    serde_json::to_vec(&value).map_err(|e| Error::BackendFailure { msg: e.to_string() })
+}
+
+/// Decode a bcs encoded event to json in the shape emitted during execution,
+/// customized by `options` when given.
+pub fn decode_event(
+    db_handle: GoDb,
+    type_tag: &[u8],
+    blob: &[u8],
+    options: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let type_tag: TypeTag =
+        bcs::from_bytes(type_tag).map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+    let options = read_json_options(options)?;
+
+    let converter = MoveConverter::new(&storage);
+    let value = converter
+        .try_into_event(&type_tag, blob, &options)
+        .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+
+    serde_json::to_vec(&value).map_err(|e| Error::BackendFailure { msg: e.to_string() })
+}
+
+/// Decode a batch of bcs encoded `RawEvent`s to a json array of event data, in
+/// the order of the input.
+pub fn decode_events(
+    db_handle: GoDb,
+    events: &[u8],
+    options: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let events: Vec<RawEvent> =
+        bcs::from_bytes(events).map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+    let options = read_json_options(options)?;
+
+    let converter = MoveConverter::new(&storage);
+    let values = converter
+        .try_into_events(&events, &options)
+        .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+
+    serde_json::to_vec(&values).map_err(|e| Error::BackendFailure { msg: e.to_string() })
+}
+
+/// Decode a table key, and the value when given, to json using the key and
+/// value types recorded for the table.
+pub fn decode_table_item(
+    db_handle: GoDb,
+    table_handle: &[u8],
+    key: &[u8],
+    value: Option<&[u8]>,
+    options: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    let storage = GoStorage::new(&db_handle);
+    let table_handle = AccountAddress::from_bytes(table_handle)
+        .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+    let options = read_json_options(options)?;
+
+    let converter = MoveConverter::new(&storage);
+    let item = converter
+        .try_into_table_item(&table_handle, key, value, &options)
+        .map_err(|e| Error::BackendFailure { msg: e.to_string() })?;
+
+    serde_json::to_vec(&item).map_err(|e| Error::BackendFailure { msg: e.to_string() })
+}
+
+fn read_json_options(options: Option<&[u8]>) -> Result<JsonOptions, Error> {
+    match options {
+        Some(options) => {
+            bcs::from_bytes(options).map_err(|e| Error::BackendFailure { msg: e.to_string() })
+        }
+        None => Ok(JsonOptions::default()),
+    }
+}
+
 pub fn decode_script_bytes(script_bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
     api_handler::decode_script_bytes(script_bytes)
         .map_err(|e| Error::backend_failure(e.to_string()))
@@ -48,6 +161,35 @@ pub fn decode_module_bytes(module_bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
         .map_err(|e| Error::backend_failure(e.to_string()))
 }
 
+pub fn diff_module_bytes(
+    old_module_bytes: Vec<u8>,
+    new_module_bytes: Vec<u8>,
+) -> Result<Vec<u8>, Error> {
+    api_handler::diff_module_bytes(old_module_bytes, new_module_bytes)
+        .map_err(|e| Error::backend_failure(e.to_string()))
+}
+
+pub fn disassemble_module_bytes(
+    module_bytes: Vec<u8>,
+    source_map: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    api_handler::disassemble_module_bytes(module_bytes, source_map, None)
+        .map_err(|e| Error::backend_failure(e.to_string()))
+}
+
+pub fn disassemble_script_bytes(
+    script_bytes: Vec<u8>,
+    source_map: Option<&[u8]>,
+) -> Result<Vec<u8>, Error> {
+    api_handler::disassemble_script_bytes(script_bytes, source_map, None)
+        .map_err(|e| Error::backend_failure(e.to_string()))
+}
+
+pub fn generate_json_schema(module_bytes: Vec<u8>) -> Result<Vec<u8>, Error> {
+    api_handler::generate_json_schema(module_bytes)
+        .map_err(|e| Error::backend_failure(e.to_string()))
+}
+
 pub fn read_module_info(compiled: &[u8]) -> Result<Vec<u8>, Error> {
     api_handler::read_module_info(compiled).map_err(|e| Error::backend_failure(e.to_string()))
 }
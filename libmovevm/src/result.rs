@@ -12,14 +12,25 @@ where
 }
 
 pub fn generate_result(output: MessageOutput) -> Result<ExecutionResult, Error> {
-    let (events, _write_set, staking_change_set, cosmos_messages, new_accounts, gas_usage_set) =
-        output.into_inner();
+    let (
+        events,
+        _write_set,
+        staking_change_set,
+        cosmos_messages,
+        new_accounts,
+        gas_usage_set,
+        table_length_changes,
+    ) = output.into_inner();
+
+    let (staking_deltas, staking_redelegations) = staking_change_set.into_inner();
 
     Ok(ExecutionResult::new(
         events.into_inner(),
-        staking_change_set.into_inner(),
+        staking_deltas,
+        staking_redelegations,
         cosmos_messages.into_inner(),
         new_accounts.into_inner(),
         gas_usage_set.into_inner(),
+        table_length_changes,
     ))
 }
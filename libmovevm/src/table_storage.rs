@@ -60,14 +60,17 @@ impl TableResolver for GoTableStorage<'_> {
     fn create_iterator(
         &mut self,
         handle: &TableHandle,
+        key_prefix: Option<&[u8]>,
         start: Option<&[u8]>,
         end: Option<&[u8]>,
         order: Order,
     ) -> anyhow::Result<u32> {
-        let prefix = AccessPath::table_item_access_path(handle.0, vec![]).to_bytes()?;
+        let table_prefix = AccessPath::table_item_access_path(handle.0, vec![]).to_bytes()?;
+        let prefix = [table_prefix.as_slice(), key_prefix.unwrap_or_default()].concat();
 
         let mut error_msg = UnmanagedVector::default();
-        let mut iter = GoIter::new(prefix.len());
+        // only strip the table prefix, so the returned keys include the key prefix
+        let mut iter = GoIter::new(table_prefix.len());
 
         let go_error: GoError = (self.db.vtable.scan_db)(
             self.db.state,
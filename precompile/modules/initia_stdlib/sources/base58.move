@@ -0,0 +1,66 @@
+module initia_std::base58 {
+    use std::string::{Self, String};
+
+    public fun to_string(bytes: vector<u8>): String {
+        string::utf8(encode(bytes))
+    }
+
+    public fun from_string(str: String): vector<u8> {
+        decode(*string::bytes(&str))
+    }
+
+    /// Encode `bytes` with a trailing 4-byte double-sha256 checksum (Base58Check).
+    public fun to_string_check(bytes: vector<u8>): String {
+        string::utf8(encode_check(bytes))
+    }
+
+    /// Decode a Base58Check string, verifying and stripping the checksum.
+    public fun from_string_check(str: String): vector<u8> {
+        decode_check(*string::bytes(&str))
+    }
+
+    native public fun encode(bytes: vector<u8>): vector<u8>;
+
+    native public fun decode(bytes: vector<u8>): vector<u8>;
+
+    native public fun encode_check(bytes: vector<u8>): vector<u8>;
+
+    native public fun decode_check(bytes: vector<u8>): vector<u8>;
+
+    #[test]
+    fun test_base58() {
+        assert!(encode(b"hello world") == b"StV1DL6CwTryKyV", 0);
+        assert!(decode(b"StV1DL6CwTryKyV") == b"hello world", 1);
+
+        // leading zero bytes are encoded as '1'
+        assert!(encode(x"0000287fb4cd") == b"11233QC4", 2);
+        assert!(decode(b"11233QC4") == x"0000287fb4cd", 3);
+
+        assert!(encode(vector[]) == vector[], 4);
+        assert!(from_string(to_string(b"initia")) == b"initia", 5);
+    }
+
+    #[test]
+    fun test_base58_check() {
+        let payload = x"00f54a5851e9372b87810a8e60cdd2e7cfd80b6e31";
+        let addr = b"1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs";
+        assert!(encode_check(payload) == addr, 0);
+        assert!(decode_check(addr) == payload, 1);
+        assert!(
+            from_string_check(to_string_check(payload)) == payload,
+            2
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10064, location = Self)]
+    fun test_base58_decode_invalid_char() {
+        decode(b"0OIl");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10064, location = Self)]
+    fun test_base58_decode_check_invalid_checksum() {
+        decode_check(b"1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt");
+    }
+}
@@ -5,6 +5,20 @@ module initia_std::bech32 {
 
     native public fun decode(addr: String): (String, vector<u8>);
 
+    native public fun encode_bech32m(prefix: String, data: vector<u8>): String;
+
+    /// Decode an address, accepting only a bech32m checksum.
+    native public fun decode_bech32m(addr: String): (String, vector<u8>);
+
+    /// Encode a segwit address, e.g. a Bitcoin taproot address for version 1.
+    /// Version 0 uses the bech32 checksum and versions 1 to 16 use bech32m.
+    native public fun encode_segwit(
+        prefix: String, version: u8, program: vector<u8>
+    ): String;
+
+    /// Decode a segwit address into its prefix, witness version and witness program.
+    native public fun decode_segwit(addr: String): (String, u8, vector<u8>);
+
     #[test_only]
     use initia_std::string;
 
@@ -35,4 +49,46 @@ module initia_std::bech32 {
         assert!(prefix == string::utf8(b"celestia"), 2);
         assert!(data == x"12eafdba79c3dd7b90e3712ee475423153a722c7", 3);
     }
+
+    #[test]
+    fun test_bech32m() {
+        let prefix = string::utf8(b"init");
+        let data = x"12eafdba79c3dd7b90e3712ee475423153a722c7";
+        let got = encode_bech32m(prefix, data);
+        let expected = string::utf8(b"init1zt40mwnec0whhy8rwyhwga2zx9f6wgk85dkrq9");
+        assert!(got == expected, 0);
+
+        let (prefix, data) = decode_bech32m(expected);
+        assert!(prefix == string::utf8(b"init"), 1);
+        assert!(data == x"12eafdba79c3dd7b90e3712ee475423153a722c7", 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    fun test_decode_bech32m_rejects_bech32() {
+        decode_bech32m(string::utf8(b"init1zt40mwnec0whhy8rwyhwga2zx9f6wgk8p3x098"));
+    }
+
+    #[test]
+    fun test_segwit() {
+        // taproot
+        let program = x"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let addr =
+            string::utf8(
+                b"bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+            );
+        assert!(encode_segwit(string::utf8(b"bc"), 1, program) == addr, 0);
+        let (prefix, version, got) = decode_segwit(addr);
+        assert!(prefix == string::utf8(b"bc"), 1);
+        assert!(version == 1, 2);
+        assert!(got == program, 3);
+
+        // p2wpkh
+        let program = x"751e76e8199196d454941c45d1b3a323f1433bd6";
+        let addr = string::utf8(b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+        assert!(encode_segwit(string::utf8(b"bc"), 0, program) == addr, 4);
+        let (_, version, got) = decode_segwit(addr);
+        assert!(version == 0, 5);
+        assert!(got == program, 6);
+    }
 }
@@ -3,6 +3,7 @@ module initia_std::biguint {
     const EDIVISION_BY_ZERO: u64 = 101;
     const CAST_OVERFLOW: u64 = 102;
     const INVALID_NUMERIC_TYPE: u64 = 103;
+    const EXPONENT_TOO_LARGE: u64 = 104;
 
     struct BigUint has copy, drop, store {
         bytes: vector<u8>
@@ -136,6 +137,70 @@ module initia_std::biguint {
         self.div(num2)
     }
 
+    public fun mod(self: BigUint, num2: BigUint): BigUint {
+        let result_bytes = mod_internal(self.bytes, num2.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
+    public fun mod_by_u64(self: BigUint, num2: u64): BigUint {
+        let num2 = from_u64(num2);
+        self.mod(num2)
+    }
+
+    public fun mod_by_u128(self: BigUint, num2: u128): BigUint {
+        let num2 = from_u128(num2);
+        self.mod(num2)
+    }
+
+    public fun mod_by_u256(self: BigUint, num2: u256): BigUint {
+        let num2 = from_u256(num2);
+        self.mod(num2)
+    }
+
+    /// Return `self` raised to the power of `exp`.
+    public fun pow(self: BigUint, exp: u64): BigUint {
+        let result_bytes = pow_internal(self.bytes, exp);
+        BigUint { bytes: result_bytes }
+    }
+
+    /// Return `self` raised to the power of `exp`, reduced modulo `modulus`.
+    public fun modpow(self: BigUint, exp: BigUint, modulus: BigUint): BigUint {
+        let result_bytes = modpow_internal(self.bytes, exp.bytes, modulus.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
+    /// Return the integer square root of `self`, rounded down.
+    public fun sqrt(self: BigUint): BigUint {
+        let result_bytes = sqrt_internal(self.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
+    // bitwise
+    public fun shl(self: BigUint, shift: u64): BigUint {
+        let result_bytes = shl_internal(self.bytes, shift);
+        BigUint { bytes: result_bytes }
+    }
+
+    public fun shr(self: BigUint, shift: u64): BigUint {
+        let result_bytes = shr_internal(self.bytes, shift);
+        BigUint { bytes: result_bytes }
+    }
+
+    public fun and(self: BigUint, num2: BigUint): BigUint {
+        let result_bytes = and_internal(self.bytes, num2.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
+    public fun or(self: BigUint, num2: BigUint): BigUint {
+        let result_bytes = or_internal(self.bytes, num2.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
+    public fun xor(self: BigUint, num2: BigUint): BigUint {
+        let result_bytes = xor_internal(self.bytes, num2.bytes);
+        BigUint { bytes: result_bytes }
+    }
+
     // cmp
     public fun eq(self: BigUint, num2: BigUint): bool {
         eq_internal(self.bytes, num2.bytes)
@@ -181,6 +246,34 @@ module initia_std::biguint {
         num1_bytes: vector<u8>, num2_bytes: vector<u8>
     ): vector<u8>;
 
+    native fun mod_internal(
+        num1_bytes: vector<u8>, num2_bytes: vector<u8>
+    ): vector<u8>;
+
+    native fun pow_internal(num_bytes: vector<u8>, exp: u64): vector<u8>;
+
+    native fun modpow_internal(
+        num_bytes: vector<u8>, exp_bytes: vector<u8>, modulus_bytes: vector<u8>
+    ): vector<u8>;
+
+    native fun sqrt_internal(num_bytes: vector<u8>): vector<u8>;
+
+    native fun shl_internal(num_bytes: vector<u8>, shift: u64): vector<u8>;
+
+    native fun shr_internal(num_bytes: vector<u8>, shift: u64): vector<u8>;
+
+    native fun and_internal(
+        num1_bytes: vector<u8>, num2_bytes: vector<u8>
+    ): vector<u8>;
+
+    native fun or_internal(
+        num1_bytes: vector<u8>, num2_bytes: vector<u8>
+    ): vector<u8>;
+
+    native fun xor_internal(
+        num1_bytes: vector<u8>, num2_bytes: vector<u8>
+    ): vector<u8>;
+
     native fun new_internal<T>(num: T): vector<u8>;
 
     native fun cast_internal<T>(num_bytes: vector<u8>): T;
@@ -366,4 +459,79 @@ module initia_std::biguint {
         let num2 = from_le_bytes(bytes);
         assert!(eq(num1, num2), 1);
     }
+
+    #[test]
+    fun test_biguint_mod() {
+        let num1 = from_u64(17);
+        assert!(to_u64(num1.mod(from_u64(5))) == 2, 1);
+        assert!(to_u64(num1.mod_by_u64(17)) == 0, 2);
+        assert!(to_u128(num1.mod_by_u128(20)) == 17, 3);
+        assert!(to_u256(num1.mod_by_u256(3)) == 2, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    fun test_biguint_mod_by_zero() {
+        let _ = from_u64(1).mod(zero());
+    }
+
+    #[test]
+    fun test_biguint_pow() {
+        assert!(to_u64(from_u64(3).pow(4)) == 81, 1);
+        assert!(to_u64(from_u64(3).pow(0)) == 1, 2);
+        assert!(to_u64(zero().pow(0)) == 1, 3);
+        assert!(to_u64(zero().pow(18446744073709551615)) == 0, 4);
+        assert!(to_u64(one().pow(18446744073709551615)) == 1, 5);
+        assert!(
+            to_u256(from_u64(2).pow(255))
+                == 57896044618658097711785492504343953926634992332820282019728792003956564819968u256,
+            6
+        );
+    }
+
+    #[test]
+    fun test_biguint_modpow() {
+        let result = from_u64(4).modpow(from_u64(13), from_u64(497));
+        assert!(to_u64(result) == 445, 1);
+
+        let result = from_u64(4).modpow(zero(), from_u64(497));
+        assert!(to_u64(result) == 1, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    fun test_biguint_modpow_by_zero() {
+        let _ = from_u64(4).modpow(from_u64(13), zero());
+    }
+
+    #[test]
+    fun test_biguint_sqrt() {
+        assert!(to_u64(zero().sqrt()) == 0, 1);
+        assert!(to_u64(from_u64(16).sqrt()) == 4, 2);
+        assert!(to_u64(from_u64(17).sqrt()) == 4, 3);
+        assert!(
+            to_u128(from_u256(340282366920938463463374607431768211456u256).sqrt())
+                == 18446744073709551616u128,
+            4
+        );
+    }
+
+    #[test]
+    fun test_biguint_shift() {
+        assert!(to_u64(from_u64(1).shl(10)) == 1024, 1);
+        assert!(to_u64(from_u64(1024).shr(10)) == 1, 2);
+        assert!(to_u64(from_u64(1023).shr(10)) == 0, 3);
+        assert!(to_u256(from_u64(1).shl(200).shr(200)) == 1, 4);
+        assert!(to_u64(zero().shl(18446744073709551615)) == 0, 5);
+    }
+
+    #[test]
+    fun test_biguint_bitwise() {
+        let num1 = from_u64(12);
+        let num2 = from_u64(10);
+        assert!(to_u64(num1.and(num2)) == 8, 1);
+        assert!(to_u64(num1.or(num2)) == 14, 2);
+        assert!(to_u64(num1.xor(num2)) == 6, 3);
+        assert!(num1.xor(num1).is_zero(), 4);
+    }
 }
@@ -22,6 +22,12 @@ module initia_std::block {
 
     native public fun get_block_info_nanos(): (u64, u64);
 
+    /// Return the hash of the current block.
+    native public fun get_block_hash(): vector<u8>;
+
+    /// Return the address of the current block proposer.
+    native public fun get_proposer(): address;
+
     #[test_only]
     native public fun set_block_info(height: u64, timestamp: u64);
 
@@ -39,6 +45,23 @@ module initia_std::block {
         assert!(timestamp == 9999999u64, 1);
     }
 
+    #[test_only]
+    native public fun set_block_hash_for_test(block_hash: vector<u8>);
+
+    #[test_only]
+    native public fun set_proposer_for_test(proposer: address);
+
+    #[test]
+    public fun test_get_block_hash_and_proposer() {
+        let block_hash =
+            x"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+        set_block_hash_for_test(block_hash);
+        set_proposer_for_test(@0x123);
+
+        assert!(get_block_hash() == block_hash, 0);
+        assert!(get_proposer() == @0x123, 1);
+    }
+
     #[test]
     public fun test_get_block_info_nanos() {
         set_block_info_nanos(54321u64, 8888888u64 * 1000000000u64);
@@ -1,4 +1,5 @@
 module initia_std::json {
+    use std::bcs;
     use std::vector;
     use std::string::{Self, String};
     use std::option::{Self, Option};
@@ -19,6 +20,42 @@ module initia_std::json {
         value: vector<u8>
     }
 
+    /// MarshalOptions customizes the JSON produced by `marshal_with_options`.
+    struct MarshalOptions has copy, drop {
+        /// Emit u64, u128 and u256 as JSON numbers when they are at most 2^53 - 1.
+        numbers_as_json: bool,
+        /// Encode addresses in bech32 with this prefix instead of hex.
+        address_hrp: Option<String>,
+        /// Wrap struct values as `{"type": <struct tag>, "value": <json>}`.
+        type_annotations: bool,
+        /// Emit `Object<T>` as `{"inner": <address>, "type": <T>}` instead of its address.
+        object_inner_types: bool,
+        /// Number of fractional digits printed for decimals and fixed point numbers, at most 20.
+        decimal_precision: Option<u32>
+    }
+
+    /// Create marshal options.
+    public fun new_marshal_options(
+        numbers_as_json: bool,
+        address_hrp: Option<String>,
+        type_annotations: bool,
+        object_inner_types: bool,
+        decimal_precision: Option<u32>
+    ): MarshalOptions {
+        MarshalOptions {
+            numbers_as_json,
+            address_hrp,
+            type_annotations,
+            object_inner_types,
+            decimal_precision
+        }
+    }
+
+    /// Marshal options producing the same output as `marshal`.
+    public fun default_marshal_options(): MarshalOptions {
+        new_marshal_options(false, option::none(), false, false, option::none())
+    }
+
     /// Unmarshal JSON value to the given type.
     public fun unmarshal_json_value<T: drop>(json_value: JSONValue): T {
         unmarshal_internal(json_value.value)
@@ -142,6 +179,26 @@ module initia_std::json {
         marshal_to_string_internal(value)
     }
 
+    /// Marshal data to JSON bytes in the shape described by `options`.
+    ///
+    /// NOTE: key `_type_` is converted to `@type`
+    /// NOTE: key `_move_` is converted to `move`
+    /// NOTE: key `_signer_` is converted to `signer`
+    public fun marshal_with_options<T>(value: &T, options: &MarshalOptions): vector<u8> {
+        marshal_with_options_internal(value, bcs::to_bytes(options))
+    }
+
+    /// Marshal data to JSON string in the shape described by `options`.
+    ///
+    /// NOTE: key `_type_` is converted to `@type`
+    /// NOTE: key `_move_` is converted to `move`
+    /// NOTE: key `_signer_` is converted to `signer`
+    public fun marshal_to_string_with_options<T>(
+        value: &T, options: &MarshalOptions
+    ): String {
+        marshal_to_string_with_options_internal(value, bcs::to_bytes(options))
+    }
+
     /// Unmarshal JSON bytes to the given struct.
     ///
     /// NOTE: key `@type` is converted to `_type_`
@@ -157,6 +214,14 @@ module initia_std::json {
 
     native fun marshal_to_string_internal<T>(value: &T): String;
 
+    native fun marshal_with_options_internal<T>(
+        value: &T, options: vector<u8>
+    ): vector<u8>;
+
+    native fun marshal_to_string_with_options_internal<T>(
+        value: &T, options: vector<u8>
+    ): String;
+
     native fun unmarshal_internal<T: drop>(json: vector<u8>): T;
 
     #[test_only]
@@ -337,6 +402,56 @@ module initia_std::json {
         );
     }
 
+    #[test]
+    fun test_marshal_with_options() {
+        let obj = TestObject2 { a: 42, b: true, c: vector[1, 2, 3] };
+        assert!(
+            marshal_with_options(&obj, &default_marshal_options()) == marshal(&obj),
+            0
+        );
+
+        let options =
+            new_marshal_options(
+                true,
+                option::some(string::utf8(b"init")),
+                false,
+                false,
+                option::none()
+            );
+        assert!(
+            marshal_with_options(&obj, &options)
+                == b"{\"a\":42,\"b\":true,\"c\":\"010203\"}",
+            1
+        );
+        assert!(
+            marshal_to_string_with_options(&@0x1, &options)
+                == string::utf8(b"\"init1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqr5e3d\""),
+            2
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10007, location = Self)]
+    fun test_marshal_with_options_invalid_precision() {
+        let options =
+            new_marshal_options(false, option::none(), false, false, option::some(21));
+        marshal_with_options(&@0x1, &options);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10007, location = Self)]
+    fun test_marshal_with_options_invalid_hrp() {
+        let options =
+            new_marshal_options(
+                false,
+                option::some(string::utf8(b"")),
+                false,
+                false,
+                option::none()
+            );
+        marshal_with_options(&42u64, &options);
+    }
+
     #[test_only]
     use std::object::{Self, ConstructorRef};
 
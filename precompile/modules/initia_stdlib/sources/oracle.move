@@ -1,4 +1,6 @@
 module initia_std::oracle {
+    use std::vector;
+
     use initia_std::string::{Self, String};
 
     #[view]
@@ -6,8 +8,36 @@ module initia_std::oracle {
         get_price_internal(*string::bytes(&pair_id))
     }
 
+    #[view]
+    /// Returns the prices, updated times and decimals of the given pairs, in the same order.
+    public fun get_prices(pair_ids: vector<String>): (vector<u256>, vector<u64>, vector<u64>) {
+        get_prices_internal(
+            vector::map(pair_ids, |pair_id| *string::bytes(&pair_id))
+        )
+    }
+
+    #[view]
+    /// Returns the price of the pair as it was at the given block height.
+    public fun get_price_at(pair_id: String, height: u64): (u256, u64, u64) {
+        get_price_at_internal(*string::bytes(&pair_id), height)
+    }
+
+    #[view]
+    /// Returns the time weighted average price of the pair over the last `window` seconds.
+    public fun get_twap(pair_id: String, window: u64): (u256, u64, u64) {
+        get_twap_internal(*string::bytes(&pair_id), window)
+    }
+
     native fun get_price_internal(pair_id: vector<u8>): (u256, u64, u64);
 
+    native fun get_prices_internal(
+        pair_ids: vector<vector<u8>>
+    ): (vector<u256>, vector<u64>, vector<u64>);
+
+    native fun get_price_at_internal(pair_id: vector<u8>, height: u64): (u256, u64, u64);
+
+    native fun get_twap_internal(pair_id: vector<u8>, window: u64): (u256, u64, u64);
+
     #[test_only]
     public fun set_price(
         pair_id: &String, price: u256, updated_at: u64, decimals: u64
@@ -20,11 +50,63 @@ module initia_std::oracle {
         )
     }
 
+    #[test_only]
+    public fun set_price_at(
+        pair_id: &String,
+        height: u64,
+        price: u256,
+        updated_at: u64,
+        decimals: u64
+    ) {
+        set_price_at_internal(
+            *string::bytes(pair_id),
+            height,
+            price,
+            updated_at,
+            decimals
+        )
+    }
+
+    #[test_only]
+    public fun set_twap(
+        pair_id: &String,
+        window: u64,
+        twap: u256,
+        updated_at: u64,
+        decimals: u64
+    ) {
+        set_twap_internal(
+            *string::bytes(pair_id),
+            window,
+            twap,
+            updated_at,
+            decimals
+        )
+    }
+
     #[test_only]
     native fun set_price_internal(
         pair_id: vector<u8>, price: u256, updated_at: u64, decimals: u64
     );
 
+    #[test_only]
+    native fun set_price_at_internal(
+        pair_id: vector<u8>,
+        height: u64,
+        price: u256,
+        updated_at: u64,
+        decimals: u64
+    );
+
+    #[test_only]
+    native fun set_twap_internal(
+        pair_id: vector<u8>,
+        window: u64,
+        twap: u256,
+        updated_at: u64,
+        decimals: u64
+    );
+
     #[test]
     public fun test_get_price() {
         let btc_usd_pair_id = string::utf8(b"BITCOIN/USD");
@@ -62,4 +144,48 @@ module initia_std::oracle {
         assert!(eth_updated_at == updated_at, 0);
         assert!(eth_decimals == decimals, 0);
     }
+
+    #[test]
+    public fun test_get_prices() {
+        let btc_usd_pair_id = string::utf8(b"BITCOIN/USD");
+        let eth_usd_pair_id = string::utf8(b"ETHEREUM/USD");
+
+        set_price(&btc_usd_pair_id, 100_00000000_u256, 1000002, 8);
+        set_price(&eth_usd_pair_id, 10_000000000000000000_u256, 1000001, 18);
+
+        let (prices, updated_ats, decimals) =
+            get_prices(vector[eth_usd_pair_id, btc_usd_pair_id]);
+        assert!(prices == vector[10_000000000000000000_u256, 100_00000000_u256], 0);
+        assert!(updated_ats == vector[1000001, 1000002], 0);
+        assert!(decimals == vector[18, 8], 0);
+
+        let (prices, updated_ats, decimals) = get_prices(vector[]);
+        assert!(vector::is_empty(&prices), 0);
+        assert!(vector::is_empty(&updated_ats), 0);
+        assert!(vector::is_empty(&decimals), 0);
+    }
+
+    #[test]
+    public fun test_get_price_at_and_twap() {
+        let btc_usd_pair_id = string::utf8(b"BITCOIN/USD");
+
+        set_price_at(&btc_usd_pair_id, 100, 99_00000000_u256, 999990, 8);
+        set_twap(&btc_usd_pair_id, 3600, 98_50000000_u256, 1000002, 8);
+
+        let (price, updated_at, decimals) = get_price_at(btc_usd_pair_id, 100);
+        assert!(price == 99_00000000_u256, 0);
+        assert!(updated_at == 999990, 0);
+        assert!(decimals == 8, 0);
+
+        let (twap, updated_at, decimals) = get_twap(btc_usd_pair_id, 3600);
+        assert!(twap == 98_50000000_u256, 0);
+        assert!(updated_at == 1000002, 0);
+        assert!(decimals == 8, 0);
+    }
+
+    #[test]
+    #[expected_failure]
+    public fun test_get_price_at_not_found() {
+        get_price_at(string::utf8(b"BITCOIN/USD"), 100);
+    }
 }
@@ -0,0 +1,194 @@
+/// Proto3 binary encoding of move structs, to build cosmos messages and queries
+/// with typed structs instead of hand written JSON.
+///
+/// A struct is encoded as a message whose field numbers follow the declaration
+/// order of its fields (1, 2, 3, ...), unless different numbers are registered
+/// for its type in a `Descriptor`. Field types are mapped as follows:
+///
+/// - `bool`, `u8`, `u16`, `u32`, `u64`: varint
+/// - `u128`, `u256`: decimal string, like cosmos `math.Int`
+/// - `address`, `Object<T>`: hex string with `0x` prefix; `Object<T>` can only be
+///   encoded, as decoding would forge object handles
+/// - `String`: string
+/// - `vector<u8>`: bytes
+/// - `vector<T>`: repeated field, packed for the numeric types and `bool`
+/// - `Option<T>`: the field is omitted when none
+/// - other structs: embedded message
+///
+/// As in proto3, fields with default values are omitted from the encoding, and
+/// missing fields are decoded to their default values.
+module initia_std::protobuf {
+    use std::string::String;
+    use std::vector;
+
+    use initia_std::type_info;
+
+    /// Field numbers registered for the encoded struct types.
+    struct Descriptor has copy, drop {
+        messages: vector<MessageDescriptor>
+    }
+
+    struct MessageDescriptor has copy, drop {
+        /// The struct type name as returned by `type_info::type_name`.
+        type_name: String,
+        /// The field number of each struct field, in declaration order.
+        field_numbers: vector<u32>
+    }
+
+    public fun new_descriptor(): Descriptor {
+        Descriptor { messages: vector[] }
+    }
+
+    /// Register the field numbers of `T`, one for each field of `T` in declaration order.
+    public fun register<T>(self: &mut Descriptor, field_numbers: vector<u32>) {
+        vector::push_back(
+            &mut self.messages,
+            MessageDescriptor { type_name: type_info::type_name<T>(), field_numbers }
+        );
+    }
+
+    /// Encode the given struct to proto3 binary.
+    public fun encode<T>(value: &T): vector<u8> {
+        encode_internal(value, vector[])
+    }
+
+    /// Encode the given struct to proto3 binary, using the field numbers registered
+    /// in `descriptor`.
+    public fun encode_with_descriptor<T>(
+        value: &T, descriptor: &Descriptor
+    ): vector<u8> {
+        encode_internal(value, descriptor.messages)
+    }
+
+    /// Decode proto3 binary to the given struct. Unknown fields are ignored.
+    ///
+    /// As with `json::unmarshal`, the struct types must be defined in the calling module,
+    /// so `Object<T>` fields cannot be decoded.
+    public fun decode<T: drop>(bytes: vector<u8>): T {
+        decode_internal(bytes, vector[])
+    }
+
+    /// Decode proto3 binary to the given struct, using the field numbers registered
+    /// in `descriptor`.
+    public fun decode_with_descriptor<T: drop>(
+        bytes: vector<u8>, descriptor: &Descriptor
+    ): T {
+        decode_internal(bytes, descriptor.messages)
+    }
+
+    native fun encode_internal<T>(
+        value: &T, messages: vector<MessageDescriptor>
+    ): vector<u8>;
+
+    native fun decode_internal<T: drop>(
+        bytes: vector<u8>, messages: vector<MessageDescriptor>
+    ): T;
+
+    #[test_only]
+    use std::option::{Self, Option};
+
+    #[test_only]
+    use std::string::utf8;
+
+    #[test_only]
+    struct Coin has copy, drop {
+        denom: String,
+        amount: String
+    }
+
+    #[test_only]
+    struct TestMsg has copy, drop {
+        id: u64,
+        flag: bool,
+        coins: vector<Coin>,
+        nums: vector<u32>,
+        memo: Option<String>
+    }
+
+    #[test_only]
+    struct EmptyMsg has copy, drop {}
+
+    #[test_only]
+    use std::object::{Self, Object, ObjectCore};
+
+    #[test_only]
+    struct ObjectMsg has copy, drop {
+        obj: Object<ObjectCore>
+    }
+
+    #[test]
+    fun test_encode_decode() {
+        let coin = Coin { denom: utf8(b"uinit"), amount: utf8(b"100") };
+        assert!(encode(&coin) == x"0a0575696e69741203313030", 0);
+        assert!(decode<Coin>(x"0a0575696e69741203313030") == coin, 1);
+
+        let msg = TestMsg {
+            id: 150,
+            flag: false,
+            coins: vector[coin],
+            nums: vector[1, 2, 300],
+            memo: option::some(utf8(b""))
+        };
+        let bytes = encode(&msg);
+        assert!(bytes == x"0896011a0c0a0575696e6974120331303022040102ac022a00", 2);
+        assert!(decode<TestMsg>(bytes) == msg, 3);
+    }
+
+    #[test]
+    fun test_decode_defaults() {
+        // missing fields are decoded to their default values
+        let msg = decode<TestMsg>(x"");
+        assert!(msg.id == 0, 0);
+        assert!(!msg.flag, 1);
+        assert!(vector::is_empty(&msg.coins), 2);
+        assert!(vector::is_empty(&msg.nums), 3);
+        assert!(option::is_none(&msg.memo), 4);
+
+        // unknown fields are skipped, and unpacked repeated scalars are accepted
+        let msg = decode<TestMsg>(x"0801200120025005");
+        assert!(msg.id == 1, 5);
+        assert!(msg.nums == vector[1, 2], 6);
+
+        let _ = decode<EmptyMsg>(x"0801");
+        assert!(encode(&EmptyMsg {}) == x"", 7);
+    }
+
+    #[test]
+    fun test_descriptor() {
+        let descriptor = new_descriptor();
+        register<Coin>(&mut descriptor, vector[2, 1]);
+
+        let coin = Coin { denom: utf8(b"uinit"), amount: utf8(b"100") };
+        let bytes = encode_with_descriptor(&coin, &descriptor);
+        assert!(bytes == x"120575696e69740a03313030", 0);
+        assert!(decode_with_descriptor<Coin>(bytes, &descriptor) == coin, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    fun test_decode_truncated() {
+        decode<Coin>(x"0a0575696e");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    fun test_decode_object() {
+        let ref = object::create_object(@std, true);
+        let msg = ObjectMsg { obj: object::object_from_constructor_ref(&ref) };
+
+        // objects are encoded as their address, but cannot be decoded
+        decode<ObjectMsg>(encode(&msg));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10066, location = Self)]
+    fun test_invalid_descriptor() {
+        let descriptor = new_descriptor();
+        register<Coin>(&mut descriptor, vector[1]);
+
+        encode_with_descriptor(
+            &Coin { denom: utf8(b"uinit"), amount: utf8(b"100") },
+            &descriptor
+        );
+    }
+}
@@ -113,6 +113,17 @@ module initia_std::staking {
         release_time: u64
     }
 
+    #[event]
+    /// Event emitted when a Delegation is moved to another validator.
+    struct RedelegationEvent has drop, store {
+        metadata: Object<Metadata>,
+        src_validator: String,
+        dst_validator: String,
+        share: BigDecimal,
+        dst_share: BigDecimal,
+        completion_time: u64
+    }
+
     // Query responses
     struct DelegationResponse has drop {
         metadata: Object<Metadata>,
@@ -128,6 +139,12 @@ module initia_std::staking {
         release_time: u64
     }
 
+    /// Pending unbonding entry of the delegations held by this module on the chain.
+    struct UnbondingEntryResponse has drop {
+        amount: u64,
+        completion_time: u64
+    }
+
     // Errors
 
     /// triggered when delegation store is already exists.
@@ -181,6 +198,9 @@ module initia_std::staking {
     /// Invalid slash fraction
     const EINVALID_SLASH_FRACTION: u64 = 17;
 
+    /// Redelegation source and destination validators are the same
+    const ESAME_VALIDATOR: u64 = 18;
+
     // Constants
 
     /// Max number of view function response items.
@@ -235,6 +255,51 @@ module initia_std::staking {
         table::borrow_mut(states, validator)
     }
 
+    fun create_staking_state_if_not_exists(
+        states: &mut Table<String, StakingState>,
+        metadata: Object<Metadata>,
+        validator: String
+    ) {
+        if (table::contains(states, validator)) { return };
+
+        let reward_coin_store_ref = &object::create_object(@initia_std, false);
+        let unbonding_coin_store_ref = &object::create_object(@initia_std, false);
+
+        let reward_coin_store_address =
+            object::address_from_constructor_ref(reward_coin_store_ref);
+        let reward_coin_store =
+            primary_fungible_store::ensure_primary_store_exists(
+                reward_coin_store_address, reward_metadata()
+            );
+
+        let unbonding_coin_store_address =
+            object::address_from_constructor_ref(unbonding_coin_store_ref);
+        let unbonding_coin_store =
+            primary_fungible_store::ensure_primary_store_exists(
+                unbonding_coin_store_address, metadata
+            );
+
+        table::add(
+            states,
+            validator,
+            StakingState {
+                metadata,
+                validator,
+                total_share: bigdecimal::zero(),
+                unbonding_share: bigdecimal::zero(),
+                reward_index: bigdecimal::zero(),
+                reward_coin_store_ref: object::generate_extend_ref(
+                    reward_coin_store_ref
+                ),
+                unbonding_coin_store_ref: object::generate_extend_ref(
+                    unbonding_coin_store_ref
+                ),
+                reward_coin_store,
+                unbonding_coin_store
+            }
+        )
+    }
+
     fun load_delegation(
         delegations: &Table<Object<Metadata>, Table<String, Delegation>>,
         metadata: Object<Metadata>,
@@ -515,6 +580,22 @@ module initia_std::staking {
         res
     }
 
+    #[view]
+    /// Get the pending unbonding entries of the delegation held by this module
+    /// on the chain, which are not released to the module yet.
+    public fun get_unbonding_entries(
+        metadata: Object<Metadata>, validator: String
+    ): vector<UnbondingEntryResponse> {
+        let (amounts, completion_times) =
+            unbonding_entries_internal(*string::bytes(&validator), &metadata);
+
+        vector::zip_map(
+            amounts,
+            completion_times,
+            |amount, completion_time| UnbondingEntryResponse { amount, completion_time }
+        )
+    }
+
     // Query helpers
 
     /// get `metadata` from `DelegationResponse`
@@ -573,6 +654,20 @@ module initia_std::staking {
         unbonding_res.unbonding_amount
     }
 
+    /// get `amount` from `UnbondingEntryResponse`
+    public fun get_amount_from_unbonding_entry_response(
+        entry: &UnbondingEntryResponse
+    ): u64 {
+        entry.amount
+    }
+
+    /// get `completion_time` from `UnbondingEntryResponse`
+    public fun get_completion_time_from_unbonding_entry_response(
+        entry: &UnbondingEntryResponse
+    ): u64 {
+        entry.completion_time
+    }
+
     // Chain operations
 
     /// Check signer is chain
@@ -790,45 +885,7 @@ module initia_std::staking {
             error::not_found(ESTAKING_STATE_NOT_EXISTS)
         );
         let states = table::borrow_mut(&mut module_store.staking_states, metadata);
-
-        if (!table::contains(states, validator)) {
-            let reward_coin_store_ref = &object::create_object(@initia_std, false);
-            let unbonding_coin_store_ref = &object::create_object(@initia_std, false);
-
-            let reward_coin_store_address =
-                object::address_from_constructor_ref(reward_coin_store_ref);
-            let reward_coin_store =
-                primary_fungible_store::ensure_primary_store_exists(
-                    reward_coin_store_address, reward_metadata()
-                );
-
-            let unbonding_coin_store_address =
-                object::address_from_constructor_ref(unbonding_coin_store_ref);
-            let unbonding_coin_store =
-                primary_fungible_store::ensure_primary_store_exists(
-                    unbonding_coin_store_address, metadata
-                );
-
-            table::add(
-                states,
-                validator,
-                StakingState {
-                    metadata,
-                    validator,
-                    total_share: bigdecimal::zero(),
-                    unbonding_share: bigdecimal::zero(),
-                    reward_index: bigdecimal::zero(),
-                    reward_coin_store_ref: object::generate_extend_ref(
-                        reward_coin_store_ref
-                    ),
-                    unbonding_coin_store_ref: object::generate_extend_ref(
-                        unbonding_coin_store_ref
-                    ),
-                    reward_coin_store,
-                    unbonding_coin_store
-                }
-            )
-        };
+        create_staking_state_if_not_exists(states, metadata, validator);
 
         let share_diff =
             delegate_internal(
@@ -852,6 +909,103 @@ module initia_std::staking {
         }
     }
 
+    /// Redelegate coin from a validator to another validator and deposit reward to signer.
+    /// redelegated amount can be slightly different with input amount due to round error.
+    public entry fun redelegate_script(
+        account: &signer,
+        metadata: Object<Metadata>,
+        src_validator: String,
+        dst_validator: String,
+        amount: u64
+    ) acquires DelegationStore, ModuleStore {
+        let account_addr = signer::address_of(account);
+
+        assert!(
+            is_account_registered(account_addr),
+            error::not_found(EDELEGATION_STORE_NOT_EXISTS)
+        );
+
+        let share = amount_to_share(*string::bytes(&src_validator), &metadata, amount);
+
+        let delegation = withdraw_delegation(account, metadata, src_validator, share);
+        let (reward, delegation) = redelegate(delegation, dst_validator);
+
+        event::emit(
+            RewardEvent {
+                account: account_addr,
+                metadata,
+                amount: fungible_asset::amount(&reward)
+            }
+        );
+
+        coin::deposit(account_addr, reward);
+
+        let remaining = deposit_delegation(account_addr, delegation);
+        coin::deposit(account_addr, remaining);
+    }
+
+    /// Move a delegation to `dst_validator`. The reward of the delegation is
+    /// returned with the delegation to the destination validator.
+    public fun redelegate(
+        delegation: Delegation, dst_validator: String
+    ): (FungibleAsset, Delegation) acquires ModuleStore {
+        let share = delegation.share;
+        let src_validator = delegation.validator;
+        let metadata = delegation.metadata;
+
+        assert!(
+            src_validator != dst_validator,
+            error::invalid_argument(ESAME_VALIDATOR)
+        );
+
+        let (dst_share, completion_time) =
+            redelegate_internal(
+                *string::bytes(&src_validator),
+                *string::bytes(&dst_validator),
+                &metadata,
+                &share
+            );
+        let reward = destroy_delegation_and_extract_reward(delegation);
+
+        let module_store = borrow_global_mut<ModuleStore>(@initia_std);
+        let src_state =
+            load_staking_state_mut(
+                &mut module_store.staking_states, metadata, src_validator
+            );
+
+        assert!(
+            bigdecimal::ge(src_state.total_share, share),
+            error::invalid_state(EINSUFFICIENT_UNBONDING_DELEGATION_TOTAL_SHARE)
+        );
+        src_state.total_share = bigdecimal::sub(src_state.total_share, share);
+
+        let states = table::borrow_mut(&mut module_store.staking_states, metadata);
+        create_staking_state_if_not_exists(states, metadata, dst_validator);
+        let dst_state = table::borrow_mut(states, dst_validator);
+        dst_state.total_share = bigdecimal::add(dst_state.total_share, dst_share);
+
+        event::emit(
+            RedelegationEvent {
+                metadata,
+                src_validator,
+                dst_validator,
+                share,
+                dst_share,
+                completion_time
+            }
+        );
+
+        (
+            reward,
+            Delegation {
+                metadata,
+                validator: dst_validator,
+                share: dst_share,
+                reward_index: dst_state.reward_index
+            }
+        )
+    }
+
     /// Undelegate coin from a validator and deposit reward to signer.
     /// unbonding amount can be slightly different with input amount due to round error.
     public entry fun undelegate_script(
@@ -1507,6 +1661,17 @@ module initia_std::staking {
         validator: vector<u8>, metadata: &Object<Metadata>, share: &BigDecimal
     ): (u64 /* unbonding amount */, u64 /* unbond timestamp */);
 
+    native fun redelegate_internal(
+        src_validator: vector<u8>,
+        dst_validator: vector<u8>,
+        metadata: &Object<Metadata>,
+        share: &BigDecimal
+    ): (BigDecimal /* dst share amount */, u64 /* completion timestamp */);
+
+    native fun unbonding_entries_internal(
+        validator: vector<u8>, metadata: &Object<Metadata>
+    ): (vector<u64> /* amounts */, vector<u64> /* completion timestamps */);
+
     native public fun share_to_amount(
         validator: vector<u8>, metadata: &Object<Metadata>, share: &BigDecimal
     ): u64 /* delegation amount */;
@@ -1523,6 +1688,14 @@ module initia_std::staking {
         amount: u64
     );
 
+    #[test_only]
+    native public fun set_unbonding_entries(
+        validator: vector<u8>,
+        metadata: &Object<Metadata>,
+        amounts: vector<u64>,
+        completion_times: vector<u64>
+    );
+
     #[test_only]
     const STAKING_SYMBOL: vector<u8> = b"ustake";
 
@@ -1972,6 +2145,120 @@ module initia_std::staking {
         assert!(coin::balance(user_addr, metadata) == 920000, 13);
     }
 
+    #[test(chain = @0x1, user = @0x1234)]
+    public fun test_redelegate(chain: &signer, user: &signer) acquires DelegationStore, ModuleStore {
+        test_setup();
+
+        let user_addr = signer::address_of(user);
+        let src_validator = string::utf8(b"validator1");
+        let dst_validator = string::utf8(b"validator2");
+
+        fund_stake_coin(chain, user_addr, 1000000);
+        set_block_info(100, 10000);
+
+        register(user);
+
+        let metadata = staking_metadata_for_test();
+        let reward_metadata = reward_metadata();
+
+        set_staking_share_ratio(
+            *string::bytes(&src_validator),
+            &metadata,
+            &bigdecimal::from_u64(1),
+            1
+        );
+        set_staking_share_ratio(
+            *string::bytes(&dst_validator),
+            &metadata,
+            &bigdecimal::from_u64(2),
+            1
+        );
+
+        delegate_script(user, metadata, src_validator, 100000);
+
+        fund_reward_coin(chain, @relayer, 100000);
+        deposit_reward_for_chain(
+            chain,
+            metadata,
+            vector[src_validator],
+            vector[100000]
+        );
+
+        redelegate_script(
+            user,
+            metadata,
+            src_validator,
+            dst_validator,
+            40000
+        );
+
+        let delegation = get_delegation(user_addr, metadata, src_validator);
+        assert!(bigdecimal::truncate_u64(delegation.share) == 60000, 0);
+        assert!(delegation.unclaimed_reward == 60000, 1);
+
+        let delegation = get_delegation(user_addr, metadata, dst_validator);
+        assert!(bigdecimal::truncate_u64(delegation.share) == 80000, 2);
+        assert!(delegation.unclaimed_reward == 0, 3);
+
+        // reward of the redelegated share is claimed
+        assert!(coin::balance(user_addr, reward_metadata) == 40000, 4);
+        assert!(coin::balance(user_addr, metadata) == 900000, 5);
+
+        let module_store = borrow_global<ModuleStore>(@initia_std);
+        let src_state =
+            load_staking_state(&module_store.staking_states, metadata, src_validator);
+        assert!(bigdecimal::truncate_u64(src_state.total_share) == 60000, 6);
+        let dst_state =
+            load_staking_state(&module_store.staking_states, metadata, dst_validator);
+        assert!(bigdecimal::truncate_u64(dst_state.total_share) == 80000, 7);
+    }
+
+    #[test(chain = @0x1, user = @0x1234)]
+    #[expected_failure(abort_code = 0x10012, location = Self)]
+    public fun test_redelegate_same_validator(
+        chain: &signer, user: &signer
+    ) acquires DelegationStore, ModuleStore {
+        test_setup();
+
+        let user_addr = signer::address_of(user);
+        let validator = string::utf8(b"validator");
+
+        fund_stake_coin(chain, user_addr, 1000000);
+        register(user);
+
+        let metadata = staking_metadata_for_test();
+        set_staking_share_ratio(
+            *string::bytes(&validator),
+            &metadata,
+            &bigdecimal::from_u64(1),
+            1
+        );
+
+        delegate_script(user, metadata, validator, 100000);
+        redelegate_script(user, metadata, validator, validator, 40000);
+    }
+
+    #[test]
+    public fun test_get_unbonding_entries() acquires ModuleStore {
+        test_setup();
+
+        let validator = string::utf8(b"validator");
+        let metadata = staking_metadata_for_test();
+        set_unbonding_entries(
+            *string::bytes(&validator),
+            &metadata,
+            vector[100, 200],
+            vector[1000, 2000]
+        );
+
+        let entries = get_unbonding_entries(metadata, validator);
+        assert!(vector::length(&entries) == 2, 0);
+
+        let entry = vector::borrow(&entries, 1);
+        assert!(get_amount_from_unbonding_entry_response(entry) == 200, 1);
+        assert!(get_completion_time_from_unbonding_entry_response(entry) == 2000, 2);
+    }
+
     #[test(chain = @0x1, user1 = @0x1234, user2 = @0x4321)]
     fun test_claim_reward(
         chain: &signer, user1: &signer, user2: &signer
@@ -160,29 +160,21 @@ module minitia_std::ethereum_derivable_account {
         *full_message
     }
 
-    fun recover_public_key(
+    fun recover_address(
         signature_bytes: &vector<u8>, message: &vector<u8>
     ): vector<u8> {
-        let rs = vector::slice(signature_bytes, 0, 64);
         let v = *vector::borrow(signature_bytes, 64);
         assert!(v == 27 || v == 28, EUNEXPECTED_V);
-        let signature = secp256k1::ecdsa_signature_from_bytes(rs);
 
-        let maybe_recovered = secp256k1::ecdsa_recover(*message, v - 27, &signature);
+        // Only the leading r || s || v bytes are part of the signature, trailing
+        // bytes are ignored.
+        let signature = vector::slice(signature_bytes, 0, 65);
+        let maybe_recovered =
+            secp256k1::ecdsa_recover_eth_address(*message, signature);
 
         assert!(option::is_some(&maybe_recovered), EINVALID_SIGNATURE);
 
-        let pubkey = option::borrow(&maybe_recovered);
-
-        let pubkey_bytes = secp256k1::ecdsa_raw_public_key_to_bytes(pubkey);
-
-        // Add 0x04 prefix to the public key, to match the
-        // full uncompressed format from ethers.js
-        let full_pubkey = &mut vector[];
-        vector::push_back(full_pubkey, 4u8);
-        vector::append(full_pubkey, pubkey_bytes);
-
-        *full_pubkey
+        option::extract(&mut maybe_recovered)
     }
 
     fun authenticate_auth_data(aa_auth_data: AbstractionAuthData) {
@@ -203,18 +195,10 @@ module minitia_std::ethereum_derivable_account {
                 scheme
             );
         let hashed_message = keccak256(message);
-        let public_key_bytes =
-            recover_public_key(&abstract_signature.signature, &hashed_message);
+        let recovered_addr =
+            recover_address(&abstract_signature.signature, &hashed_message);
 
-        // 1. Skip the 0x04 prefix (take the bytes after the first byte)
-        let public_key_without_prefix = vector::slice(
-            &public_key_bytes, 1, vector::length(&public_key_bytes)
-        );
-        // 2. Run Keccak256 on the public key (without the 0x04 prefix)
-        let kexHash = keccak256(public_key_without_prefix);
-        // 3. Slice the last 20 bytes (this is the Ethereum address)
-        let recovered_addr = vector::slice(&kexHash, 12, 32);
-        // 4. Remove the 0x prefix from the utf8 account address
+        // Remove the 0x prefix from the utf8 account address
         let ethereum_address_without_prefix = vector::slice(
             &abstract_public_key.ethereum_address,
             2,
@@ -384,7 +368,7 @@ module minitia_std::ethereum_derivable_account {
     }
 
     #[test]
-    fun test_recover_public_key() {
+    fun test_recover_address() {
         set_chain_id_for_test(string::utf8(b"test"));
         let ethereum_address = b"0xfe9d103fe5e9f0eb55c654174a4b3d9d6a75d2cd";
         let domain = b"localhost:3001";
@@ -406,17 +390,13 @@ module minitia_std::ethereum_derivable_account {
             126, 86, 221, 17, 221, 193, 109, 129, 139, 83, 109, 163, 88, 108, 244, 140, 77,
             181, 197, 79, 156, 10, 238, 95, 79, 140, 167, 216, 226, 2, 27
         ];
-        let base64_public_key = recover_public_key(&signature_bytes, &hashed_message);
-        assert!(
-            base64_public_key
-                == vector[
-                    4, 148, 150, 223, 27, 162, 228, 138, 169, 39, 173, 25, 198, 189, 68,
-                    144, 21, 144, 62, 13, 253, 189, 67, 220, 2, 253, 21, 22, 39, 210, 27,
-                    9, 255, 142, 148, 255, 85, 235, 2, 44, 126, 177, 161, 163, 27, 107,
-                    215, 19, 94, 233, 237, 78, 153, 253, 214, 121, 252, 27, 82, 106, 16,
-                    118, 145, 59, 58
-                ]
-        );
+        let recovered_addr = recover_address(&signature_bytes, &hashed_message);
+        assert!(recovered_addr == x"fe9d103fe5e9f0eb55c654174a4b3d9d6a75d2cd");
+
+        // trailing bytes after the 65-byte signature are ignored
+        vector::append(&mut signature_bytes, vector[1, 2, 3]);
+        let recovered_addr = recover_address(&signature_bytes, &hashed_message);
+        assert!(recovered_addr == x"fe9d103fe5e9f0eb55c654174a4b3d9d6a75d2cd");
     }
 
     #[test]
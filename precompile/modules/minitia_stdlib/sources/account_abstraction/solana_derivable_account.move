@@ -18,6 +18,7 @@
 /// - OKX
 module minitia_std::solana_derivable_account {
     use minitia_std::auth_data::AbstractionAuthData;
+    use minitia_std::base58;
     use std::ed25519::{
         Self,
         signature_from_bytes,
@@ -26,7 +27,6 @@ module minitia_std::solana_derivable_account {
     use std::bcs_stream::{Self, deserialize_u8};
     use std::block::get_chain_id;
     use std::string_utils;
-    use std::vector;
 
     /// Signature failed to verify.
     const EINVALID_SIGNATURE: u64 = 1;
@@ -113,44 +113,18 @@ module minitia_std::solana_derivable_account {
     }
 
     fun to_public_key_bytes(base58_public_key: &vector<u8>): vector<u8> {
-        let bytes = vector[0u8];
-        let base = 58u16;
-
+        // Reject invalid characters up front, so they abort with this module's
+        // error code instead of the native decoder's.
         let i = 0;
         while (i < base58_public_key.length()) {
-            let char = base58_public_key[i];
-            let (found, char_index) = BASE_58_ALPHABET.index_of(&char);
-            assert!(found, EINVALID_BASE_58_PUBLIC_KEY);
-
-            let j = 0;
-            let carry = (char_index as u16);
-
-            // For each existing byte, multiply by 58 and add carry
-            while (j < bytes.length()) {
-                let current = (bytes[j] as u16);
-                let new_carry = current * base + carry;
-                bytes[j] = ((new_carry & 0xff) as u8);
-                carry = new_carry >> 8;
-                j = j + 1;
-            };
-
-            // Add any remaining carry as new bytes
-            while (carry > 0) {
-                bytes.push_back((carry & 0xff) as u8);
-                carry = carry >> 8;
-            };
-
+            assert!(
+                BASE_58_ALPHABET.contains(&base58_public_key[i]),
+                EINVALID_BASE_58_PUBLIC_KEY
+            );
             i = i + 1;
         };
 
-        // Handle leading zeros (1's in Base58)
-        let i = 0;
-        while (i < base58_public_key.length() && base58_public_key[i] == 49) { // '1' is 49 in ASCII
-            bytes.push_back(0);
-            i = i + 1;
-        };
-
-        vector::reverse(&mut bytes);
+        let bytes = base58::decode(*base58_public_key);
         assert!(bytes.length() == PUBLIC_KEY_NUM_BYTES, EINVALID_PUBLIC_KEY_LENGTH);
         bytes
     }
@@ -309,6 +283,13 @@ module minitia_std::solana_derivable_account {
         );
     }
 
+    #[test]
+    #[expected_failure(abort_code = EINVALID_BASE_58_PUBLIC_KEY)]
+    fun test_to_public_key_bytes_invalid_base58() {
+        let base58_public_key = b"G56zT1K6AQab7FzwHdQ8hiHXusR14Rmddw6Vz5MFbbm0";
+        to_public_key_bytes(&base58_public_key);
+    }
+
     #[test]
     fun test_authenticate_auth_data() {
         set_chain_id_for_test(utf8(b"test"));
@@ -282,6 +282,30 @@ module minitia_std::bigdecimal {
         BigDecimal { scaled: self.scaled.div_by_u256(other) }
     }
 
+    /// Return `self` raised to the power of `exp`, computed by square-and-multiply
+    /// with every intermediate product truncated to the fractional precision.
+    public fun pow(self: BigDecimal, exp: u64): BigDecimal {
+        let result = one();
+        let base = self;
+        while (exp > 0) {
+            if (exp & 1 == 1) {
+                result = result.mul(base);
+            };
+
+            exp = exp >> 1;
+            if (exp > 0) {
+                base = base.mul(base);
+            };
+        };
+
+        result
+    }
+
+    /// Return the square root of `self`, truncated to the fractional precision.
+    public fun sqrt(self: BigDecimal): BigDecimal {
+        BigDecimal { scaled: self.scaled.mul(f()).sqrt() }
+    }
+
     // cast
     public fun truncate(self: BigDecimal): BigUint {
         self.scaled.div(f())
@@ -625,4 +649,27 @@ module minitia_std::bigdecimal {
         let num2 = from_scaled_le_bytes(le_bytes);
         assert!(eq(num1, num2), 1);
     }
+
+    #[test]
+    fun test_bigdecimal_pow() {
+        let num1 = from_ratio_u64(3, 2);
+        assert!(num1.pow(0).is_one(), 1);
+        assert!(num1.pow(1).eq(num1), 2);
+        assert!(num1.pow(2).eq(from_ratio_u64(9, 4)), 3);
+        assert!(num1.pow(3).eq(from_ratio_u64(27, 8)), 4);
+
+        // 1.01^365
+        let num2 = from_ratio_u64(101, 100);
+        assert!(num2.pow(365).truncate_u64() == 37, 5);
+    }
+
+    #[test]
+    fun test_bigdecimal_sqrt() {
+        assert!(from_u64(4).sqrt().eq(from_u64(2)), 1);
+        assert!(from_ratio_u64(1, 4).sqrt().eq(from_ratio_u64(1, 2)), 2);
+        assert!(zero().sqrt().is_zero(), 3);
+        assert!(
+            from_u64(2).sqrt().get_scaled().to_u64() == 1414213562373095048, 4
+        );
+    }
 }
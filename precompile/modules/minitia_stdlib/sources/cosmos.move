@@ -1,5 +1,6 @@
 /// This module provides interfaces to allow CosmosMessage
-/// execution after the move execution finished.
+/// execution after the move execution finished, or synchronously
+/// in the middle of the move execution with `dispatch`.
 module minitia_std::cosmos {
     use std::address;
     use std::signer;
@@ -31,18 +32,71 @@ module minitia_std::cosmos {
     /// - allow_failure_with_callback(id: u64, fid: String)
     /// - disallow_failure_with_callback(id: u64, fid: String)
     ///
-    /// The callback function should be defined with the following signature:
+    /// The callback function should be defined with one of the following signatures:
     /// ```rust
     /// public fun callback(id: u64, success: bool);
     /// public fun callback(sender: &signer, id: u64, success: bool);
+    /// public fun callback(id: u64, success: bool, response: vector<u8>, error: String);
+    /// public fun callback(sender: &signer, id: u64, success: bool, response: vector<u8>, error: String);
     /// ```
     ///
+    /// The callback signature is checked when the message is requested, and the call
+    /// aborts if the function does not exist, is not public, does not match any of the
+    /// above, or belongs to a module the transaction has not loaded yet.
+    ///
     public fun stargate_with_options(
         sender: &signer, data: vector<u8>, options: Options
     ) {
         stargate_internal(signer::address_of(sender), data, options)
     }
 
+    /// Result of a message executed with `dispatch`.
+    struct DispatchResult has copy, drop {
+        success: bool,
+        /// The message response bytes, empty on failure.
+        response: vector<u8>,
+        /// The error message, empty on success.
+        error: String
+    }
+
+    /// Execute a cosmos message synchronously and return its result.
+    ///
+    /// Unlike `stargate`, the message is executed immediately, so its effects
+    /// are visible to the rest of the move execution. If the message fails and
+    /// `allow_failure` is false, the move execution aborts and every state change
+    /// is rolled back. If `allow_failure` is true, the failure is returned to the
+    /// caller and the message has no effect.
+    ///
+    /// Dispatching is only allowed while executing an entry function or a script;
+    /// it aborts in view functions and account authentication. Only the message
+    /// types allowed by the chain can be dispatched, which never execute move or
+    /// access move state. Host errors other than the message failure abort the
+    /// move execution regardless of `allow_failure`.
+    public fun dispatch(
+        sender: &signer, data: vector<u8>, allow_failure: bool
+    ): DispatchResult {
+        let (success, response, error) =
+            dispatch_internal(signer::address_of(sender), data, allow_failure);
+        DispatchResult { success, response, error: string::utf8(error) }
+    }
+
+    public fun is_dispatch_success(result: &DispatchResult): bool {
+        result.success
+    }
+
+    public fun dispatch_response(result: &DispatchResult): vector<u8> {
+        result.response
+    }
+
+    public fun dispatch_error(result: &DispatchResult): String {
+        result.error
+    }
+
+    /// Unpack dispatch result for external use
+    public fun unpack_dispatch_result(result: DispatchResult): (bool, vector<u8>, String) {
+        (result.success, result.response, result.error)
+    }
+
     struct ExecuteRequest has copy, drop {
         _type_: String,
         sender: String,
@@ -449,6 +503,17 @@ module minitia_std::cosmos {
         sender: address, data: vector<u8>, option: Options
     );
 
+    native fun dispatch_internal(
+        sender: address, data: vector<u8>, allow_failure: bool
+    ): (bool, vector<u8>, vector<u8>);
+
+    #[test_only]
+    /// Register the result of dispatching `data`; `output` is the response
+    /// on success and the error message on failure.
+    native public fun set_dispatch_response(
+        data: vector<u8>, success: bool, output: vector<u8>
+    );
+
     #[test_only]
     native public fun requested_messages(): (vector<String>, vector<Options>);
 
@@ -476,10 +541,12 @@ module minitia_std::cosmos {
         /// callback_id is the unique identifier for this message execution.
         callback_id: u64,
         /// function identifier which will be called after the message execution.
-        /// The function should be defined with the following signature:
+        /// The function should be defined with one of the following signatures:
         /// ```rust
         /// public fun callback(id: u64, success: bool);
         /// public fun callback(sender: &signer, id: u64, success: bool);
+        /// public fun callback(id: u64, success: bool, response: vector<u8>, error: String);
+        /// public fun callback(sender: &signer, id: u64, success: bool, response: vector<u8>, error: String);
         /// ```
         ///
         /// Ex) 0xaddr::test_module::callback
@@ -587,15 +654,123 @@ module minitia_std::cosmos {
         stargate_with_options(
             sender,
             *bytes(&msg),
-            allow_failure_with_callback(1, utf8(b"0x1::test::test_fn"))
+            allow_failure_with_callback(1, utf8(b"0x1::cosmos::test_callback"))
         );
 
         assert!(
             was_message_requested_with_options(
                 &msg,
-                &allow_failure_with_callback(1, utf8(b"0x1::test::test_fn"))
+                &allow_failure_with_callback(1, utf8(b"0x1::cosmos::test_callback"))
             ),
             1
         );
     }
+
+    #[test_only]
+    public fun test_callback(_id: u64, _success: bool) {}
+
+    #[test_only]
+    public fun test_result_callback(
+        _sender: &signer,
+        _id: u64,
+        _success: bool,
+        _response: vector<u8>,
+        _error: String
+    ) {}
+
+    #[test_only]
+    public fun test_invalid_callback(_id: u64, _success: bool): bool {
+        true
+    }
+
+    #[test(sender = @0xcafe)]
+    public fun test_stargate_with_result_callback(sender: &signer) {
+        use std::string::utf8;
+
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        let options =
+            disallow_failure_with_callback(
+                1, utf8(b"0x1::cosmos::test_result_callback")
+            );
+        stargate_with_options(sender, msg, options);
+
+        assert!(
+            was_message_requested_with_options(&string::utf8(msg), &options),
+            1
+        );
+    }
+
+    #[test(sender = @0xcafe)]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    public fun test_stargate_with_invalid_callback(sender: &signer) {
+        use std::string::utf8;
+
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        stargate_with_options(
+            sender,
+            msg,
+            allow_failure_with_callback(1, utf8(b"0x1::cosmos::test_invalid_callback"))
+        );
+    }
+
+    #[test_only]
+    fun test_private_callback(_id: u64, _success: bool) {}
+
+    #[test(sender = @0xcafe)]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    public fun test_stargate_with_private_callback(sender: &signer) {
+        use std::string::utf8;
+
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        stargate_with_options(
+            sender,
+            msg,
+            allow_failure_with_callback(1, utf8(b"0x1::cosmos::test_private_callback"))
+        );
+    }
+
+    #[test(sender = @0xcafe)]
+    #[expected_failure(abort_code = 0x10065, location = Self)]
+    public fun test_stargate_with_missing_callback(sender: &signer) {
+        use std::string::utf8;
+
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        stargate_with_options(
+            sender,
+            msg,
+            allow_failure_with_callback(1, utf8(b"0x1::cosmos::missing_callback"))
+        );
+    }
+
+    #[test(sender = @0xcafe)]
+    public fun test_dispatch(sender: &signer) {
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        set_dispatch_response(msg, true, b"{}");
+
+        let result = dispatch(sender, msg, false);
+        assert!(is_dispatch_success(&result), 1);
+        assert!(dispatch_response(&result) == b"{}", 2);
+        assert!(string::is_empty(&dispatch_error(&result)), 3);
+    }
+
+    #[test(sender = @0xcafe)]
+    public fun test_dispatch_allow_failure(sender: &signer) {
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        set_dispatch_response(msg, false, b"insufficient funds");
+
+        let (success, response, error) =
+            unpack_dispatch_result(dispatch(sender, msg, true));
+        assert!(!success, 1);
+        assert!(vector::is_empty(&response), 2);
+        assert!(error == string::utf8(b"insufficient funds"), 3);
+    }
+
+    #[test(sender = @0xcafe)]
+    #[expected_failure(abort_code = 0x70064, location = Self)]
+    public fun test_dispatch_disallow_failure(sender: &signer) {
+        let msg = b"{\"@type\":\"/cosmos.bank.v1beta1.MsgSend\"}";
+        set_dispatch_response(msg, false, b"insufficient funds");
+
+        dispatch(sender, msg, false);
+    }
 }
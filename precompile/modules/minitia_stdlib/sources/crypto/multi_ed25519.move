@@ -0,0 +1,281 @@
+/// Contains functions for K-of-N multi-ed25519 threshold signatures.
+///
+/// A multi-ed25519 public key is the concatenation of up to 32 ed25519 public keys followed by a
+/// 1-byte threshold. A multi-ed25519 signature is the concatenation of the signatures of the
+/// participating signers, ordered by the index of their public key, followed by a 4-byte bitmap
+/// whose most significant bit of the first byte marks the signer at index 0.
+module minitia_std::multi_ed25519 {
+    use std::error;
+    use std::vector;
+
+    use minitia_std::ed25519;
+
+    //
+    // Error codes
+    //
+
+    /// Wrong number of bytes were given as input when deserializing a multi-ed25519 public key.
+    const E_WRONG_PUBKEY_SIZE: u64 = 1;
+
+    /// Wrong number of bytes were given as input when deserializing a multi-ed25519 signature.
+    const E_WRONG_SIGNATURE_SIZE: u64 = 2;
+
+    /// The threshold must be in the range `[1, number of public keys]`.
+    const E_INVALID_THRESHOLD: u64 = 3;
+
+    /// The number of public keys exceeds `MAX_NUMBER_OF_PUBLIC_KEYS`.
+    const E_TOO_MANY_PUBLIC_KEYS: u64 = 4;
+
+    /// The signer indices must be strictly increasing and smaller than `MAX_NUMBER_OF_PUBLIC_KEYS`.
+    const E_INVALID_SIGNER_INDEX: u64 = 5;
+
+    /// The number of signatures does not match the number of signer indices.
+    const E_UNMATCHED_ARGS_LENGTH: u64 = 6;
+
+    //
+    // Constants
+    //
+
+    /// The size of an individual ed25519 public key, in bytes.
+    const INDIVIDUAL_PUBLIC_KEY_SIZE: u64 = 32;
+
+    /// The size of an individual ed25519 signature, in bytes.
+    const INDIVIDUAL_SIGNATURE_SIZE: u64 = 64;
+
+    /// The size of the threshold suffix of a multi-ed25519 public key, in bytes.
+    const THRESHOLD_SIZE: u64 = 1;
+
+    /// The size of the signer bitmap suffix of a multi-ed25519 signature, in bytes.
+    const BITMAP_SIZE: u64 = 4;
+
+    /// The maximum number of ed25519 public keys in a multi-ed25519 public key.
+    const MAX_NUMBER_OF_PUBLIC_KEYS: u64 = 32;
+
+    /// A multi-ed25519 public key: `public_key_1 || ... || public_key_n || threshold`.
+    struct PublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A multi-ed25519 signature: `signature_1 || ... || signature_k || bitmap`.
+    struct Signature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    //
+    // Functions
+    //
+
+    /// Constructs a PublicKey struct from its serialized representation, checking the number of
+    /// sub-keys and the threshold.
+    public fun public_key_from_bytes(bytes: vector<u8>): PublicKey {
+        let len = vector::length(&bytes);
+        assert!(
+            len > THRESHOLD_SIZE
+                && (len - THRESHOLD_SIZE) % INDIVIDUAL_PUBLIC_KEY_SIZE == 0,
+            error::invalid_argument(E_WRONG_PUBKEY_SIZE)
+        );
+
+        let num_keys = (len - THRESHOLD_SIZE) / INDIVIDUAL_PUBLIC_KEY_SIZE;
+        assert!(
+            num_keys <= MAX_NUMBER_OF_PUBLIC_KEYS,
+            error::invalid_argument(E_TOO_MANY_PUBLIC_KEYS)
+        );
+
+        let threshold = (*vector::borrow(&bytes, len - 1) as u64);
+        assert!(
+            threshold > 0 && threshold <= num_keys,
+            error::invalid_argument(E_INVALID_THRESHOLD)
+        );
+
+        PublicKey { bytes }
+    }
+
+    /// Constructs a K-of-N PublicKey struct from N ed25519 public keys and the threshold K.
+    public fun create_public_key(
+        public_keys: vector<ed25519::PublicKey>, threshold: u8
+    ): PublicKey {
+        let bytes = vector::empty();
+        vector::for_each_ref(
+            &public_keys,
+            |pk| vector::append(&mut bytes, ed25519::public_key_to_bytes(pk))
+        );
+        vector::push_back(&mut bytes, threshold);
+
+        public_key_from_bytes(bytes)
+    }
+
+    /// Constructs a Signature struct from its serialized representation.
+    public fun signature_from_bytes(bytes: vector<u8>): Signature {
+        let len = vector::length(&bytes);
+        assert!(
+            len >= BITMAP_SIZE && (len - BITMAP_SIZE) % INDIVIDUAL_SIGNATURE_SIZE == 0,
+            error::invalid_argument(E_WRONG_SIGNATURE_SIZE)
+        );
+        Signature { bytes }
+    }
+
+    /// Constructs a Signature struct from the ed25519 signatures of the participating signers
+    /// and their public key indices. `signer_indices` must be strictly increasing, and
+    /// `signatures[i]` must be produced by the public key at `signer_indices[i]`.
+    public fun create_signature(
+        signatures: vector<ed25519::Signature>, signer_indices: vector<u8>
+    ): Signature {
+        assert!(
+            vector::length(&signatures) == vector::length(&signer_indices),
+            error::invalid_argument(E_UNMATCHED_ARGS_LENGTH)
+        );
+
+        let bytes = vector::empty();
+        vector::for_each_ref(
+            &signatures,
+            |sig| vector::append(&mut bytes, ed25519::signature_to_bytes(sig))
+        );
+
+        let bitmap = vector[0u8, 0u8, 0u8, 0u8];
+        let prev_index: u64 = 0;
+        vector::enumerate_ref(
+            &signer_indices,
+            |i, index| {
+                let index = (*index as u64);
+                assert!(
+                    index < MAX_NUMBER_OF_PUBLIC_KEYS && (i == 0 || index > prev_index),
+                    error::invalid_argument(E_INVALID_SIGNER_INDEX)
+                );
+                prev_index = index;
+
+                let bucket = vector::borrow_mut(&mut bitmap, index / 8);
+                *bucket = *bucket | (0x80 >> ((index % 8) as u8));
+            }
+        );
+        vector::append(&mut bytes, bitmap);
+
+        Signature { bytes }
+    }
+
+    /// Serializes a PublicKey struct to bytes.
+    public fun public_key_to_bytes(pk: &PublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Serializes a Signature struct to bytes.
+    public fun signature_to_bytes(sig: &Signature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Returns the number of ed25519 public keys in a multi-ed25519 public key.
+    public fun public_key_num_sub_keys(pk: &PublicKey): u64 {
+        (vector::length(&pk.bytes) - THRESHOLD_SIZE) / INDIVIDUAL_PUBLIC_KEY_SIZE
+    }
+
+    /// Returns the number of signatures required by a multi-ed25519 public key.
+    public fun public_key_threshold(pk: &PublicKey): u8 {
+        *vector::borrow(&pk.bytes, vector::length(&pk.bytes) - 1)
+    }
+
+    /// Verifies a multi-ed25519 `signature` under a `public_key` on the specified `message`.
+    /// Returns `true` only if at least `threshold` distinct sub-keys signed the message.
+    public fun verify(
+        message: vector<u8>, public_key: &PublicKey, signature: &Signature
+    ): bool {
+        verify_internal(message, public_key.bytes, signature.bytes)
+    }
+
+    native fun verify_internal(
+        message: vector<u8>, public_key: vector<u8>, signature: vector<u8>
+    ): bool;
+
+    //
+    // Tests
+    //
+
+    #[test_only]
+    fun generate_signers(n: u64): (vector<vector<u8>>, vector<ed25519::PublicKey>) {
+        let sks = vector::empty();
+        let pks = vector::empty();
+        let i = 0;
+        while (i < n) {
+            let (sk, vk) = ed25519::generate_keys();
+            vector::push_back(&mut sks, sk);
+            vector::push_back(&mut pks, ed25519::public_key_from_bytes(vk));
+            i = i + 1;
+        };
+
+        (sks, pks)
+    }
+
+    #[test_only]
+    fun sign_with(
+        message: vector<u8>, sks: &vector<vector<u8>>, signer_indices: vector<u8>
+    ): Signature {
+        let sigs = vector::empty();
+        vector::for_each_ref(
+            &signer_indices,
+            |index| {
+                let sk = *vector::borrow(sks, (*index as u64));
+                let sig = ed25519::sign(message, sk);
+                vector::push_back(&mut sigs, ed25519::signature_from_bytes(sig));
+            }
+        );
+
+        create_signature(sigs, signer_indices)
+    }
+
+    #[test]
+    fun test_threshold_verify() {
+        let (sks, pks) = generate_signers(3);
+        let pk = create_public_key(pks, 2);
+        assert!(public_key_num_sub_keys(&pk) == 3, 1);
+        assert!(public_key_threshold(&pk) == 2, 2);
+
+        let msg = b"test initia multi_ed25519";
+        let sig = sign_with(msg, &sks, vector[0, 2]);
+        assert!(verify(msg, &pk, &sig), 3);
+
+        let sig = sign_with(msg, &sks, vector[0, 1, 2]);
+        assert!(verify(msg, &pk, &sig), 4);
+
+        // below threshold
+        let sig = sign_with(msg, &sks, vector[1]);
+        assert!(!verify(msg, &pk, &sig), 5);
+
+        // wrong message
+        let sig = sign_with(msg, &sks, vector[0, 1]);
+        assert!(!verify(b"wrong message", &pk, &sig), 6);
+    }
+
+    #[test]
+    fun test_verify_rejects_mismatched_bitmap() {
+        let (sks, pks) = generate_signers(3);
+        let pk = create_public_key(pks, 2);
+
+        let msg = b"test initia multi_ed25519";
+        let sig0 = ed25519::signature_from_bytes(
+            ed25519::sign(msg, *vector::borrow(&sks, 0))
+        );
+        let sig1 = ed25519::signature_from_bytes(
+            ed25519::sign(msg, *vector::borrow(&sks, 1))
+        );
+
+        // signatures attributed to the wrong signers
+        let sig = create_signature(vector[sig0, sig1], vector[1, 2]);
+        assert!(!verify(msg, &pk, &sig), 1);
+
+        // signer index beyond the number of public keys
+        let sig = create_signature(vector[sig0, sig1], vector[0, 5]);
+        assert!(!verify(msg, &pk, &sig), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10003, location = Self)]
+    fun test_invalid_threshold() {
+        let (_sks, pks) = generate_signers(2);
+        create_public_key(pks, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10005, location = Self)]
+    fun test_unordered_signer_indices() {
+        let (sks, _pks) = generate_signers(2);
+        sign_with(b"msg", &sks, vector[1, 0]);
+    }
+}
@@ -1,4 +1,5 @@
-/// This module implements ECDSA signatures based on the prime-order secp256k1 ellptic curve (i.e., cofactor is 1).
+/// This module implements ECDSA and BIP-340 Schnorr signatures based on the prime-order secp256k1 ellptic curve
+/// (i.e., cofactor is 1).
 module minitia_std::secp256k1 {
     use std::option::Option;
 
@@ -9,6 +10,9 @@ module minitia_std::secp256k1 {
     /// An error occurred while deserializing, for example due to wrong input size.
     const E_DESERIALIZE: u64 = 1; // This code must be the same, if ever returned from the native Rust implementation.
 
+    /// The number of messages, public keys, and signatures do not match.
+    const E_UNMATCHED_ARGS_LENGTH: u64 = 2; // This code must be the same, if ever returned from the native Rust implementation.
+
     //
     // constants
     //
@@ -25,6 +29,15 @@ module minitia_std::secp256k1 {
     /// The size of a hashed message for secp256k1-based ECDSA signing
     const MESSAGE_SIZE: u64 = 32;
 
+    /// The size of an Ethereum `r || s || v` signature, in bytes.
+    const ETH_SIGNATURE_NUM_BYTES: u64 = 65;
+
+    /// The size of a BIP-340 x-only public key, in bytes.
+    const SCHNORR_PUBLIC_KEY_NUM_BYTES: u64 = 32;
+
+    /// The size of a BIP-340 Schnorr signature, in bytes.
+    const SCHNORR_SIGNATURE_NUM_BYTES: u64 = 64;
+
     /// A 64-byte ECDSA public key.
     struct ECDSARawPublicKey has copy, drop, store {
         bytes: vector<u8>
@@ -40,6 +53,16 @@ module minitia_std::secp256k1 {
         bytes: vector<u8>
     }
 
+    /// A 32-byte BIP-340 x-only public key.
+    struct SchnorrPublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A 64-byte BIP-340 Schnorr signature.
+    struct SchnorrSignature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
     /// Constructs an ECDSASignature struct from the given 64 bytes.
     public fun ecdsa_signature_from_bytes(bytes: vector<u8>): ECDSASignature {
         assert!(
@@ -150,6 +173,114 @@ module minitia_std::secp256k1 {
         }
     }
 
+    /// Recovers the signer's 20-byte Ethereum address from a 65-byte `r || s || v` `signature`
+    /// over the signed `message` (32 byte digest). `v` can be either 0/1 or 27/28.
+    ///
+    /// Returns `none` if no public key can be recovered. As with `ecdsa_recover`, a signature over a
+    /// different message recovers an unrelated address, so the result must be compared against the
+    /// expected signer.
+    public fun ecdsa_recover_eth_address(
+        message: vector<u8>, signature: vector<u8>
+    ): Option<vector<u8>> {
+        assert!(
+            std::vector::length(&message) == MESSAGE_SIZE,
+            std::error::invalid_argument(E_DESERIALIZE)
+        );
+        assert!(
+            std::vector::length(&signature) == ETH_SIGNATURE_NUM_BYTES,
+            std::error::invalid_argument(E_DESERIALIZE)
+        );
+
+        let (addr, success) = recover_eth_address_internal(message, signature);
+        if (success) {
+            std::option::some(addr)
+        } else {
+            std::option::none<vector<u8>>()
+        }
+    }
+
+    /// Constructs a SchnorrPublicKey struct, given a 32-byte x-only representation.
+    public fun schnorr_public_key_from_bytes(bytes: vector<u8>): SchnorrPublicKey {
+        assert!(
+            std::vector::length(&bytes) == SCHNORR_PUBLIC_KEY_NUM_BYTES,
+            std::error::invalid_argument(E_DESERIALIZE)
+        );
+        SchnorrPublicKey { bytes }
+    }
+
+    /// Converts a 33-byte compressed public key to its BIP-340 x-only form by dropping the
+    /// parity prefix. Both keys verify the same signatures, since BIP-340 implicitly picks the
+    /// point with the even y-coordinate.
+    public fun schnorr_public_key_from_compressed(
+        pk: &ECDSACompressedPublicKey
+    ): SchnorrPublicKey {
+        let bytes = std::vector::slice(&pk.bytes, 1, COMPRESSED_PUBLIC_KEY_SIZE);
+        SchnorrPublicKey { bytes }
+    }
+
+    /// Constructs a SchnorrSignature struct from the given 64 bytes.
+    public fun schnorr_signature_from_bytes(bytes: vector<u8>): SchnorrSignature {
+        assert!(
+            std::vector::length(&bytes) == SCHNORR_SIGNATURE_NUM_BYTES,
+            std::error::invalid_argument(E_DESERIALIZE)
+        );
+        SchnorrSignature { bytes }
+    }
+
+    /// Serializes a SchnorrPublicKey struct to 32-bytes.
+    public fun schnorr_public_key_to_bytes(pk: &SchnorrPublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Serializes a SchnorrSignature struct to 64-bytes.
+    public fun schnorr_signature_to_bytes(sig: &SchnorrSignature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Returns `true` if the BIP-340 Schnorr `signature` verifies under the x-only `public_key`
+    /// on the 32-byte `message`.
+    public fun schnorr_verify(
+        message: vector<u8>,
+        public_key: &SchnorrPublicKey,
+        signature: &SchnorrSignature
+    ): bool {
+        assert!(
+            std::vector::length(&message) == MESSAGE_SIZE,
+            std::error::invalid_argument(E_DESERIALIZE)
+        );
+
+        schnorr_verify_internal(message, public_key.bytes, signature.bytes)
+    }
+
+    /// Returns `true` if every BIP-340 Schnorr signature verifies under the public key and
+    /// message at the same index. The three vectors must have the same length, and the empty
+    /// batch is considered valid.
+    public fun schnorr_batch_verify(
+        messages: vector<vector<u8>>,
+        public_keys: vector<SchnorrPublicKey>,
+        signatures: vector<SchnorrSignature>
+    ): bool {
+        let message_length = std::vector::length(&messages);
+        assert!(
+            message_length == std::vector::length(&public_keys)
+                && message_length == std::vector::length(&signatures),
+            std::error::invalid_argument(E_UNMATCHED_ARGS_LENGTH)
+        );
+        if (message_length == 0) return true;
+
+        std::vector::for_each_ref(
+            &messages,
+            |message| {
+                assert!(
+                    std::vector::length(message) == MESSAGE_SIZE,
+                    std::error::invalid_argument(E_DESERIALIZE)
+                );
+            }
+        );
+
+        schnorr_batch_verify_internal(messages, public_keys, signatures)
+    }
+
     //
     // Native functions
     //
@@ -173,6 +304,30 @@ module minitia_std::secp256k1 {
         compressed: bool
     ): (vector<u8>, bool);
 
+    /// Returns `(address, true)` with the 20-byte Ethereum address recovered from a 65-byte
+    /// `r || s || v` `signature` over `message`, and returns `([], false)` otherwise.
+    native fun recover_eth_address_internal(
+        message: vector<u8>, signature: vector<u8>
+    ): (vector<u8>, bool);
+
+    /// Returns `true` if the BIP-340 Schnorr `signature` verifies on `public_key` and `message`
+    /// and returns `false` otherwise.
+    ///
+    /// - `message`: A 32-byte hashed message.
+    /// - `public_key`: A 32-byte x-only public key.
+    /// - `signature`: A 64-byte Schnorr signature.
+    native fun schnorr_verify_internal(
+        message: vector<u8>, public_key: vector<u8>, signature: vector<u8>
+    ): bool;
+
+    /// Returns `true` if all BIP-340 Schnorr signatures verify on the corresponding
+    /// public keys and messages, and returns `false` otherwise.
+    native fun schnorr_batch_verify_internal(
+        messages: vector<vector<u8>>,
+        public_keys: vector<SchnorrPublicKey>,
+        signatures: vector<SchnorrSignature>
+    ): bool;
+
     #[test_only]
     /// Generates an secp256k1 ECDSA key pair.
     native public fun generate_keys(compressed: bool): (vector<u8>, vector<u8>);
@@ -181,6 +336,16 @@ module minitia_std::secp256k1 {
     /// Generates an secp256k1 ECDSA signature for a given byte array using a given signing key.
     native public fun sign(message: vector<u8>, secrete_key: vector<u8>): (u8, vector<u8>);
 
+    #[test_only]
+    /// Generates a BIP-340 Schnorr key pair, returning the secret key and the x-only public key.
+    native public fun schnorr_generate_keys(): (vector<u8>, vector<u8>);
+
+    #[test_only]
+    /// Generates a BIP-340 Schnorr signature for a given byte array using a given signing key.
+    native public fun schnorr_sign(
+        message: vector<u8>, secrete_key: vector<u8>
+    ): vector<u8>;
+
     //
     // Tests
     //
@@ -247,4 +412,108 @@ module minitia_std::secp256k1 {
         assert!(std::option::is_some(&recovered_pk), 3);
         assert!(std::option::extract(&mut recovered_pk).bytes != pk.bytes, 4);
     }
+
+    #[test]
+    fun test_schnorr_sign_verify() {
+        use std::hash;
+
+        let (sk, vk) = schnorr_generate_keys();
+        let pk = schnorr_public_key_from_bytes(vk);
+
+        let msg: vector<u8> = hash::sha2_256(b"test initia schnorr");
+        let sig = schnorr_signature_from_bytes(schnorr_sign(msg, sk));
+        assert!(schnorr_verify(msg, &pk, &sig), 1);
+
+        // Test with an incorrect message
+        let wrong_msg: vector<u8> = hash::sha2_256(b"wrong message");
+        assert!(!schnorr_verify(wrong_msg, &pk, &sig), 2);
+
+        // Test with an incorrect signature
+        let invalid_sig_bytes = sig.bytes;
+        *std::vector::borrow_mut(&mut invalid_sig_bytes, 63) =
+            *std::vector::borrow(&invalid_sig_bytes, 63) ^ 0x1;
+        let invalid_sig = schnorr_signature_from_bytes(invalid_sig_bytes);
+        assert!(!schnorr_verify(msg, &pk, &invalid_sig), 3);
+    }
+
+    #[test]
+    fun test_schnorr_public_key_from_compressed() {
+        let (_sk, vk) = generate_keys(true);
+        let pk = ecdsa_compressed_public_key_from_bytes(vk);
+        let xonly = schnorr_public_key_from_compressed(&pk);
+        assert!(std::vector::length(&xonly.bytes) == SCHNORR_PUBLIC_KEY_NUM_BYTES, 1);
+        assert!(xonly.bytes == std::vector::slice(&vk, 1, 33), 2);
+    }
+
+    #[test]
+    fun test_schnorr_batch_verify() {
+        use std::hash;
+
+        let (sk1, vk1) = schnorr_generate_keys();
+        let (sk2, vk2) = schnorr_generate_keys();
+        let msg1: vector<u8> = hash::sha2_256(b"test initia schnorr 1");
+        let msg2: vector<u8> = hash::sha2_256(b"test initia schnorr 2");
+
+        let sig1 = schnorr_signature_from_bytes(schnorr_sign(msg1, sk1));
+        let sig2 = schnorr_signature_from_bytes(schnorr_sign(msg2, sk2));
+        let pk1 = schnorr_public_key_from_bytes(vk1);
+        let pk2 = schnorr_public_key_from_bytes(vk2);
+
+        assert!(
+            schnorr_batch_verify(
+                vector[msg1, msg2],
+                vector[pk1, pk2],
+                vector[sig1, sig2]
+            ),
+            1
+        );
+
+        // swapped signatures must fail
+        assert!(
+            !schnorr_batch_verify(
+                vector[msg1, msg2],
+                vector[pk1, pk2],
+                vector[sig2, sig1]
+            ),
+            2
+        );
+
+        assert!(schnorr_batch_verify(vector[], vector[], vector[]), 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002, location = Self)]
+    fun test_schnorr_batch_verify_unmatched_length() {
+        use std::hash;
+
+        let (sk, vk) = schnorr_generate_keys();
+        let msg: vector<u8> = hash::sha2_256(b"test initia schnorr");
+        let sig = schnorr_signature_from_bytes(schnorr_sign(msg, sk));
+        let pk = schnorr_public_key_from_bytes(vk);
+
+        schnorr_batch_verify(vector[msg, msg], vector[pk], vector[sig]);
+    }
+
+    #[test]
+    fun test_ecdsa_recover_eth_address() {
+        use std::hash;
+
+        let (sk, vk) = generate_keys(false);
+        let expected = std::vector::slice(&minitia_std::keccak::keccak256(vk), 12, 32);
+
+        let msg: vector<u8> = hash::sha2_256(b"test initia secp256k1");
+        let (rid, sig_bytes) = sign(msg, sk);
+
+        // raw recovery id
+        let signature = sig_bytes;
+        std::vector::push_back(&mut signature, rid);
+        let recovered = ecdsa_recover_eth_address(msg, signature);
+        assert!(std::option::extract(&mut recovered) == expected, 1);
+
+        // ethereum encoded recovery id
+        let signature = sig_bytes;
+        std::vector::push_back(&mut signature, rid + 27);
+        let recovered = ecdsa_recover_eth_address(msg, signature);
+        assert!(std::option::extract(&mut recovered) == expected, 2);
+    }
 }
@@ -0,0 +1,263 @@
+/// EIP-712 typed structured data hashing.
+///
+/// The EIP-712 type schema is described with `TypeDefinition`s, and the struct payload can be
+/// provided either as JSON (the `message`/`domain` shape used by `eth_signTypedData_v4`) or as
+/// BCS, where `bool` is 1 byte, `address` is 20 raw bytes, `bytesN` is N raw bytes, `uintN`/`intN`
+/// are N/8 little-endian bytes, `bytes`/`string`/`T[]` are length prefixed, `T[k]` holds k elements
+/// and structs hold their fields in declaration order.
+module minitia_std::eip712 {
+    use std::string::{Self, String};
+    use std::vector;
+
+    use minitia_std::keccak::keccak256;
+
+    //
+    // Error codes
+    //
+
+    /// The type schema is malformed or references an unknown type.
+    const EINVALID_TYPE: u64 = 0x10064; // This code must be the same as the native Rust implementation.
+
+    /// The payload does not match the type schema.
+    const EINVALID_DATA: u64 = 0x10065; // This code must be the same as the native Rust implementation.
+
+    /// The size of a domain separator or struct hash, in bytes.
+    const HASH_SIZE: u64 = 32;
+
+    /// A member of an EIP-712 struct type, e.g. `address wallet`.
+    struct Field has copy, drop, store {
+        name: String,
+        type_name: String
+    }
+
+    /// An EIP-712 struct type, e.g. `Person(string name,address wallet)`.
+    struct TypeDefinition has copy, drop, store {
+        name: String,
+        fields: vector<Field>
+    }
+
+    /// Creates a struct member of type `type_name`.
+    public fun new_field(name: String, type_name: String): Field {
+        Field { name, type_name }
+    }
+
+    /// Creates an EIP-712 struct type.
+    public fun new_type_definition(name: String, fields: vector<Field>): TypeDefinition {
+        TypeDefinition { name, fields }
+    }
+
+    /// Returns the standard `EIP712Domain` type with the given fields, which must be a subset of
+    /// `string name`, `string version`, `uint256 chainId`, `address verifyingContract` and
+    /// `bytes32 salt` in that order. Aborts with `EINVALID_TYPE` otherwise.
+    public fun domain_type_definition(fields: vector<Field>): TypeDefinition {
+        let domain_fields = vector[
+            new_field(string::utf8(b"name"), string::utf8(b"string")),
+            new_field(string::utf8(b"version"), string::utf8(b"string")),
+            new_field(string::utf8(b"chainId"), string::utf8(b"uint256")),
+            new_field(string::utf8(b"verifyingContract"), string::utf8(b"address")),
+            new_field(string::utf8(b"salt"), string::utf8(b"bytes32"))
+        ];
+
+        // each field must come after the previous one in the canonical order
+        let num_domain_fields = vector::length(&domain_fields);
+        let next = 0;
+        for (i in 0..vector::length(&fields)) {
+            let field = vector::borrow(&fields, i);
+            while (next < num_domain_fields
+                && vector::borrow(&domain_fields, next) != field) {
+                next = next + 1;
+            };
+            assert!(next < num_domain_fields, EINVALID_TYPE);
+            next = next + 1;
+        };
+
+        TypeDefinition { name: string::utf8(b"EIP712Domain"), fields }
+    }
+
+    /// Returns `hashStruct(data)` for `primary_type`, with `data` given as a JSON object.
+    public fun hash_struct_from_json(
+        types: vector<TypeDefinition>, primary_type: String, data: String
+    ): vector<u8> {
+        hash_struct_json_internal(types, primary_type, data)
+    }
+
+    /// Returns `hashStruct(data)` for `primary_type`, with `data` given as BCS bytes.
+    public fun hash_struct_from_bcs(
+        types: vector<TypeDefinition>, primary_type: String, data: vector<u8>
+    ): vector<u8> {
+        hash_struct_bcs_internal(types, primary_type, data)
+    }
+
+    /// Returns the domain separator, `hashStruct(domain)` for the `EIP712Domain` type in `types`.
+    public fun domain_separator(
+        types: vector<TypeDefinition>, domain: String
+    ): vector<u8> {
+        hash_struct_json_internal(types, string::utf8(b"EIP712Domain"), domain)
+    }
+
+    /// Returns the digest to be signed, `keccak256("\x19\x01" || domain_separator || struct_hash)`.
+    public fun hash_typed_data(
+        domain_separator: vector<u8>, struct_hash: vector<u8>
+    ): vector<u8> {
+        assert!(
+            vector::length(&domain_separator) == HASH_SIZE
+                && vector::length(&struct_hash) == HASH_SIZE,
+            EINVALID_DATA
+        );
+
+        let message = x"1901";
+        vector::append(&mut message, domain_separator);
+        vector::append(&mut message, struct_hash);
+        keccak256(message)
+    }
+
+    native fun hash_struct_json_internal(
+        types: vector<TypeDefinition>, primary_type: String, data: String
+    ): vector<u8>;
+
+    native fun hash_struct_bcs_internal(
+        types: vector<TypeDefinition>, primary_type: String, data: vector<u8>
+    ): vector<u8>;
+
+    //
+    // Tests
+    //
+
+    #[test_only]
+    fun mail_types(): vector<TypeDefinition> {
+        vector[
+            domain_type_definition(
+                vector[
+                    new_field(string::utf8(b"name"), string::utf8(b"string")),
+                    new_field(string::utf8(b"version"), string::utf8(b"string")),
+                    new_field(string::utf8(b"chainId"), string::utf8(b"uint256")),
+                    new_field(
+                        string::utf8(b"verifyingContract"), string::utf8(b"address")
+                    )
+                ]
+            ),
+            new_type_definition(
+                string::utf8(b"Person"),
+                vector[
+                    new_field(string::utf8(b"name"), string::utf8(b"string")),
+                    new_field(string::utf8(b"wallet"), string::utf8(b"address"))
+                ]
+            ),
+            new_type_definition(
+                string::utf8(b"Mail"),
+                vector[
+                    new_field(string::utf8(b"from"), string::utf8(b"Person")),
+                    new_field(string::utf8(b"to"), string::utf8(b"Person")),
+                    new_field(string::utf8(b"contents"), string::utf8(b"string"))
+                ]
+            )
+        ]
+    }
+
+    #[test]
+    fun test_hash_typed_data_from_json() {
+        let types = mail_types();
+        let separator =
+            domain_separator(
+                types,
+                string::utf8(
+                    b"{\"name\":\"Ether Mail\",\"version\":\"1\",\"chainId\":1,\"verifyingContract\":\"0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC\"}"
+                )
+            );
+        assert!(
+            separator
+                == x"f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f",
+            1
+        );
+
+        let struct_hash =
+            hash_struct_from_json(
+                types,
+                string::utf8(b"Mail"),
+                string::utf8(
+                    b"{\"from\":{\"name\":\"Cow\",\"wallet\":\"0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826\"},\"to\":{\"name\":\"Bob\",\"wallet\":\"0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB\"},\"contents\":\"Hello, Bob!\"}"
+                )
+            );
+        assert!(
+            struct_hash
+                == x"c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e",
+            2
+        );
+
+        assert!(
+            hash_typed_data(separator, struct_hash)
+                == x"be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2",
+            3
+        );
+    }
+
+    #[test]
+    fun test_hash_struct_from_bcs() {
+        let data = vector[3u8];
+        vector::append(&mut data, b"Cow");
+        vector::append(&mut data, x"CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826");
+        vector::push_back(&mut data, 3u8);
+        vector::append(&mut data, b"Bob");
+        vector::append(&mut data, x"bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB");
+        vector::push_back(&mut data, 11u8);
+        vector::append(&mut data, b"Hello, Bob!");
+
+        let struct_hash = hash_struct_from_bcs(mail_types(), string::utf8(b"Mail"), data);
+        assert!(
+            struct_hash
+                == x"c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e",
+            1
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = EINVALID_DATA, location = Self)]
+    fun test_hash_struct_missing_field() {
+        hash_struct_from_json(
+            mail_types(),
+            string::utf8(b"Person"),
+            string::utf8(b"{\"name\":\"Cow\"}")
+        );
+    }
+
+    #[test]
+    fun test_domain_type_definition_subset() {
+        let domain =
+            domain_type_definition(
+                vector[
+                    new_field(string::utf8(b"name"), string::utf8(b"string")),
+                    new_field(string::utf8(b"salt"), string::utf8(b"bytes32"))
+                ]
+            );
+        assert!(vector::length(&domain.fields) == 2, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = EINVALID_TYPE, location = Self)]
+    fun test_domain_type_definition_wrong_order() {
+        domain_type_definition(
+            vector[
+                new_field(string::utf8(b"version"), string::utf8(b"string")),
+                new_field(string::utf8(b"name"), string::utf8(b"string"))
+            ]
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = EINVALID_TYPE, location = Self)]
+    fun test_domain_type_definition_unknown_field() {
+        domain_type_definition(
+            vector[new_field(string::utf8(b"chainId"), string::utf8(b"uint64"))]
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = EINVALID_TYPE, location = Self)]
+    fun test_hash_struct_unknown_type() {
+        hash_struct_from_json(
+            mail_types(),
+            string::utf8(b"Unknown"),
+            string::utf8(b"{}")
+        );
+    }
+}
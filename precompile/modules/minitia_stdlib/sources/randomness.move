@@ -0,0 +1,153 @@
+/// On-chain randomness derived from the block level seed provided by the chain.
+///
+/// Random values are only available to private or friend entry functions annotated
+/// with `#[randomness]`. Public functions calling this module are rejected at compile
+/// time, so callers cannot inspect the outcome and abort on unfavorable results
+/// (test-and-abort attack). For the same reason, random values are not available
+/// when the entry function is executed by `cosmos::dispatch` or when other messages
+/// follow it in the same transaction.
+///
+/// Entry functions annotated with `#[randomness]` also require a minimum gas limit
+/// (`txn.randomness_min_gas_limit`), so callers cannot pick a gas limit that only runs
+/// out of gas on unfavorable outcomes (undergasing attack). Keep the gas cost of every
+/// outcome below this limit.
+module minitia_std::randomness {
+    use std::error;
+    use std::vector;
+
+    /// `min_incl` must be smaller than `max_excl`.
+    const EINVALID_RANGE: u64 = 1;
+
+    /// Generate `n` random bytes.
+    public fun bytes(n: u64): vector<u8> {
+        let v = vector[];
+        while (v.length() < n) {
+            v.append(next_32_bytes());
+        };
+        v.trim(n);
+        v
+    }
+
+    /// Generate a random u8.
+    public fun u8_integer(): u8 {
+        let raw = next_32_bytes();
+        raw[0]
+    }
+
+    /// Generate a random u64.
+    public fun u64_integer(): u64 {
+        let raw = next_32_bytes();
+        let r: u64 = 0;
+        for (i in 0..8) {
+            r = r | ((raw[i] as u64) << ((8 * i) as u8));
+        };
+        r
+    }
+
+    /// Generate a random u256.
+    public fun u256_integer(): u256 {
+        let raw = next_32_bytes();
+        let r: u256 = 0;
+        for (i in 0..32) {
+            r = r | ((raw[i] as u256) << ((8 * i) as u8));
+        };
+        r
+    }
+
+    /// Generate a u64 uniformly at random from the range `[min_incl, max_excl)`.
+    public fun u64_range(min_incl: u64, max_excl: u64): u64 {
+        assert!(
+            min_incl < max_excl,
+            error::invalid_argument(EINVALID_RANGE)
+        );
+
+        // sample from u256 to keep the modulo bias negligible
+        let range = ((max_excl - min_incl) as u256);
+        let sample = ((u256_integer() % range) as u64);
+        min_incl + sample
+    }
+
+    /// Generate a random permutation of `[0, 1, ..., n-1]`.
+    public fun permutation(n: u64): vector<u64> {
+        let values = vector::range(0, n);
+        if (n <= 1) {
+            return values
+        };
+
+        // Fisher-Yates shuffle
+        let tail = n - 1;
+        while (tail > 0) {
+            let pop_position = u64_range(0, tail + 1);
+            values.swap(pop_position, tail);
+            tail = tail - 1;
+        };
+
+        values
+    }
+
+    /// Return true if the current execution started from a `#[randomness]` entry function.
+    native public fun is_unbiasable(): bool;
+
+    /// Return 32 random bytes derived from the block seed, the session id and a counter.
+    /// Aborts if the execution did not start from a `#[randomness]` entry function or
+    /// the chain did not provide a seed.
+    native fun next_32_bytes(): vector<u8>;
+
+    #[test_only]
+    /// Set the randomness seed and allow randomness for the current test.
+    native public fun initialize_for_testing(seed: vector<u8>);
+
+    #[test_only]
+    const TEST_SEED: vector<u8> = x"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+    #[test]
+    fun test_bytes() {
+        initialize_for_testing(TEST_SEED);
+        assert!(is_unbiasable(), 0);
+
+        let a = bytes(10);
+        assert!(a.length() == 10, 1);
+
+        let b = bytes(40);
+        assert!(b.length() == 40, 2);
+
+        // every draw returns new bytes
+        assert!(bytes(32) != bytes(32), 3);
+    }
+
+    #[test]
+    fun test_u64_range() {
+        initialize_for_testing(TEST_SEED);
+        for (i in 0..100) {
+            let v = u64_range(10, 20);
+            assert!(v >= 10 && v < 20, i);
+        };
+        assert!(u64_range(5, 6) == 5, 100);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001, location = Self)]
+    fun test_u64_range_invalid() {
+        initialize_for_testing(TEST_SEED);
+        u64_range(10, 10);
+    }
+
+    #[test]
+    fun test_permutation() {
+        initialize_for_testing(TEST_SEED);
+        let n = 20;
+        let perm = permutation(n);
+        assert!(perm.length() == n, 0);
+        for (i in 0..n) {
+            assert!(perm.contains(&i), i + 1);
+        };
+        assert!(permutation(0) == vector[], 100);
+        assert!(permutation(1) == vector[0], 101);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x30065, location = Self)]
+    fun test_not_unbiasable() {
+        u64_integer();
+    }
+}
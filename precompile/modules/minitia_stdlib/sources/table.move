@@ -115,6 +115,36 @@ module minitia_std::table {
         val
     }
 
+    /// Remove at most `limit` entries in the range `[start, end)` from `table` in
+    /// ascending key order and return the number of removed entries. The removed
+    /// values are dropped, so call this repeatedly to clean up a large range.
+    ///
+    /// NOTE: The range is compared on the BCS encoded key bytes, see `iter`.
+    public fun remove_range<K: copy + drop, V: drop>(
+        self: &mut Table<K, V>,
+        start: Option<K>, /* inclusive */
+        end: Option<K>, /* exclusive */
+        limit: u64
+    ): u64 {
+        let start_bytes: vector<u8> =
+            if (start.is_some()) {
+                bcs::to_bytes<K>(&start.extract())
+            } else {
+                vector::empty()
+            };
+
+        let end_bytes: vector<u8> =
+            if (end.is_some()) {
+                bcs::to_bytes<K>(&end.extract())
+            } else {
+                vector::empty()
+            };
+
+        let removed = remove_range_box<K, V, Box<V>>(self, start_bytes, end_bytes, limit);
+        self.length = self.length - removed;
+        removed
+    }
+
     /// Returns true iff `table` contains an entry for `key`.
     public fun contains<K: copy + drop, V>(self: &Table<K, V>, key: K): bool {
         contains_box<K, V, Box<V>>(self, key)
@@ -230,6 +260,67 @@ module minitia_std::table {
         (key, &mut box.val)
     }
 
+    /// Create iterator for the entries of `table` whose BCS encoded key starts with `prefix`.
+    /// A user has to check `prepare` before calling `next` to prevent abort.
+    ///
+    /// This is useful for the tables with composite struct keys, i.e. `prefix` can be
+    /// the BCS bytes of the first field of the key struct.
+    public fun iter_prefix<K: copy + drop, V>(
+        self: &Table<K, V>,
+        prefix: vector<u8>,
+        order: u8 /* 1: Ascending, 2: Descending */
+    ): &TableIter<K, V> {
+        new_table_prefix_iter<K, V, Box<V>>(self, prefix, order)
+    }
+
+    /// Create mutable iterator for the entries of `table` whose BCS encoded key starts
+    /// with `prefix`. A user has to check `prepare_mut` before calling `next_mut` to prevent abort.
+    public fun iter_prefix_mut<K: copy + drop, V>(
+        self: &mut Table<K, V>,
+        prefix: vector<u8>,
+        order: u8 /* 1: Ascending, 2: Descending */
+    ): &mut TableIter<K, V> {
+        new_table_prefix_iter_mut<K, V, Box<V>>(self, prefix, order)
+    }
+
+    /// Create iterator over the keys of `table`. The values are not loaded, so this is
+    /// cheaper than `iter` when only the keys are needed.
+    /// A user has to check `prepare_key` before calling `next_key` to prevent abort.
+    ///
+    /// let iter = table::iter_keys(&t, start, end, order);
+    /// while (table::prepare_key(iter)) {
+    ///     let key = table::next_key(iter);
+    /// }
+    ///
+    public fun iter_keys<K: copy + drop, V>(
+        self: &Table<K, V>,
+        start: Option<K>, /* inclusive */
+        end: Option<K>, /* exclusive */
+        order: u8 /* 1: Ascending, 2: Descending */
+    ): &TableIter<K, V> {
+        self.iter(start, end, order)
+    }
+
+    /// Create iterator over the keys of `table` whose BCS encoded key starts with `prefix`.
+    /// A user has to check `prepare_key` before calling `next_key` to prevent abort.
+    public fun iter_keys_prefix<K: copy + drop, V>(
+        self: &Table<K, V>,
+        prefix: vector<u8>,
+        order: u8 /* 1: Ascending, 2: Descending */
+    ): &TableIter<K, V> {
+        self.iter_prefix(prefix, order)
+    }
+
+    /// Prepares the next key from the iterator without loading its value.
+    /// Returns true if a next key exists, false if iteration is complete.
+    public fun prepare_key<K: copy + drop, V>(self: &TableIter<K, V>): bool {
+        prepare_key_box<K, V, Box<V>>(self)
+    }
+
+    public fun next_key<K: copy + drop, V>(self: &TableIter<K, V>): K {
+        next_key_box<K, V, Box<V>>(self)
+    }
+
     public fun to_simple_map<K: store + copy + drop, V: store + copy>(
         self: &Table<K, V>
     ): std::simple_map::SimpleMap<K, V> {
@@ -324,6 +415,25 @@ module minitia_std::table {
         table_iter: &mut TableIter<K, V>
     ): bool;
 
+    native fun new_table_prefix_iter<K: copy + drop, V, B>(
+        table: &Table<K, V>, prefix: vector<u8>, order: u8
+    ): &TableIter<K, V>;
+
+    native fun new_table_prefix_iter_mut<K: copy + drop, V, B>(
+        table: &mut Table<K, V>, prefix: vector<u8>, order: u8
+    ): &mut TableIter<K, V>;
+
+    native fun prepare_key_box<K: copy + drop, V, B>(table_iter: &TableIter<K, V>): bool;
+
+    native fun next_key_box<K: copy + drop, V, B>(table_iter: &TableIter<K, V>): K;
+
+    native fun remove_range_box<K: copy + drop, V, B>(
+        table: &mut Table<K, V>,
+        start: vector<u8>,
+        end: vector<u8>,
+        limit: u64
+    ): u64;
+
     // ======================================================================================================
     // Tests
     #[test_only]
@@ -390,4 +500,95 @@ module minitia_std::table {
         move_to(account, TableHolder { t: t1 });
         move_to(account2, TableHolder { t: t2 });
     }
+
+    #[test(account = @0x1)]
+    fun test_remove_range(account: &signer) {
+        let t = new<vector<u8>, u64>();
+        let i = 0;
+        while (i < 10) {
+            add(&mut t, vector[(i as u8)], i);
+            i = i + 1;
+        };
+
+        // bounded by limit
+        let removed = remove_range(&mut t, option::some(vector[2]), option::none(), 3);
+        assert!(removed == 3, 0);
+        assert!(length(&t) == 7, 1);
+        assert!(!contains(&t, vector[2]), 2);
+        assert!(!contains(&t, vector[4]), 3);
+        assert!(contains(&t, vector[5]), 4);
+
+        // bounded by range
+        let removed =
+            remove_range(&mut t, option::some(vector[5]), option::some(vector[8]), 100);
+        assert!(removed == 3, 5);
+        assert!(length(&t) == 4, 6);
+        assert!(contains(&t, vector[1]), 7);
+        assert!(contains(&t, vector[8]), 8);
+
+        // remove all
+        let removed = remove_range(&mut t, option::none(), option::none(), 100);
+        assert!(removed == 4, 9);
+        assert!(empty(&t), 10);
+
+        move_to(account, TableHolder { t });
+    }
+
+    #[test_only]
+    struct IndexKey has copy, drop {
+        owner: address,
+        id: u64
+    }
+
+    #[test(account = @0x1)]
+    fun test_iter_prefix(account: &signer) {
+        let t = new<IndexKey, u64>();
+        add(&mut t, IndexKey { owner: @0x1, id: 1 }, 11);
+        add(&mut t, IndexKey { owner: @0x2, id: 1 }, 21);
+        add(&mut t, IndexKey { owner: @0x2, id: 2 }, 22);
+        add(&mut t, IndexKey { owner: @0x3, id: 1 }, 31);
+
+        let iter = iter_prefix(&t, bcs::to_bytes(&@0x2), 1);
+        let count = 0;
+        while (prepare(iter)) {
+            let (key, value) = next(iter);
+            assert!(key.owner == @0x2, 0);
+            assert!(*value == 20 + key.id, 1);
+            count = count + 1;
+        };
+        assert!(count == 2, 2);
+
+        let iter = iter_prefix_mut(&mut t, bcs::to_bytes(&@0x3), 2);
+        assert!(prepare_mut(iter), 3);
+        let (key, value) = next_mut(iter);
+        assert!(key.owner == @0x3, 4);
+        *value = 32;
+        assert!(!prepare_mut(iter), 5);
+        assert!(*borrow(&t, IndexKey { owner: @0x3, id: 1 }) == 32, 6);
+
+        move_to(account, TableHolder { t });
+    }
+
+    #[test(account = @0x1)]
+    fun test_iter_keys(account: &signer) {
+        let t = new<u64, u8>();
+        add(&mut t, 1, 1);
+        add(&mut t, 2, 2);
+        add(&mut t, 3, 3);
+        remove(&mut t, 2);
+
+        let iter = iter_keys(&t, option::none(), option::none(), 1);
+        assert!(prepare_key(iter), 0);
+        assert!(next_key(iter) == 1, 1);
+        assert!(prepare_key(iter), 2);
+        assert!(next_key(iter) == 3, 3);
+        assert!(!prepare_key(iter), 4);
+
+        let iter = iter_keys_prefix(&t, bcs::to_bytes(&3u64), 1);
+        assert!(prepare_key(iter), 5);
+        assert!(next_key(iter) == 3, 6);
+        assert!(!prepare_key(iter), 7);
+
+        move_to(account, TableHolder { t });
+    }
 }
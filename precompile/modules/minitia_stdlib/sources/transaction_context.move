@@ -3,6 +3,8 @@ module minitia_std::transaction_context {
     use std::option::Option;
     use std::string::String;
 
+    use minitia_std::bigdecimal::{Self, BigDecimal};
+
     /// Return a transaction hash of this execution.
     native public fun get_transaction_hash(): vector<u8>;
 
@@ -25,6 +27,22 @@ module minitia_std::transaction_context {
 
     native fun fee_payer_internal(): Option<address>;
 
+    /// Return the index of the current transaction in the block.
+    native public fun get_transaction_index(): u64;
+
+    /// Return the index of the current message in the transaction.
+    native public fun get_message_index(): u64;
+
+    /// Return the gas price of the current transaction.
+    public fun gas_price(): BigDecimal {
+        bigdecimal::from_scaled_le_bytes(gas_price_internal())
+    }
+
+    native fun gas_price_internal(): vector<u8>;
+
+    /// Return the remaining gas of the current execution in sdk gas units.
+    native public fun remaining_gas(): u64;
+
     /// Represents the entry function payload.
     struct EntryFunctionPayload has copy, drop {
         account_address: address,
@@ -123,6 +141,22 @@ module minitia_std::transaction_context {
     #[test_only]
     native fun set_fee_payer_internal(fee_payer: vector<address>);
 
+    #[test_only]
+    public fun set_transaction_index(tx_index: u64, msg_index: u64) {
+        set_transaction_index_internal(tx_index, msg_index);
+    }
+
+    #[test_only]
+    native fun set_transaction_index_internal(tx_index: u64, msg_index: u64);
+
+    #[test_only]
+    public fun set_gas_price(gas_price: String) {
+        set_gas_price_internal(gas_price);
+    }
+
+    #[test_only]
+    native fun set_gas_price_internal(gas_price: String);
+
     #[test]
     fun test_address_uniquess() {
         use std::vector;
@@ -226,4 +260,31 @@ module minitia_std::transaction_context {
         assert!(option::is_some(&fp), 0);
         assert!(option::extract(&mut fp) == @0x42, 1);
     }
+
+    #[test]
+    fun test_get_transaction_and_message_index() {
+        set_transaction_index(3, 7);
+        assert!(get_transaction_index() == 3, 0);
+        assert!(get_message_index() == 7, 1);
+    }
+
+    #[test]
+    fun test_gas_price() {
+        use std::string;
+
+        assert!(bigdecimal::is_zero(gas_price()), 0);
+
+        set_gas_price(string::utf8(b"0.015"));
+        assert!(
+            bigdecimal::eq(gas_price(), bigdecimal::from_ratio_u64(15, 1000)),
+            1
+        );
+    }
+
+    #[test]
+    fun test_remaining_gas() {
+        let before = remaining_gas();
+        let after = remaining_gas();
+        assert!(after <= before, 0);
+    }
 }
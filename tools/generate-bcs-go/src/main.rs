@@ -14,13 +14,14 @@ use initia_move_types::{
     cosmos::CosmosMessage,
     entry_function::EntryFunction,
     env::Env,
+    event::RawEvent,
     function_info::FunctionInfo,
     gas_usage::GasUsage,
     module::ModuleBundle,
     result::ExecutionResult,
     script::Script,
-    staking_change_set::StakingDelta,
-    table::TableInfo,
+    staking_change_set::{StakingDelta, StakingRedelegation, UnbondingEntry},
+    table::{TableInfo, TableLengthChange},
     view_function::{ViewFunction, ViewOutput},
     vm_config::InitiaVMConfig,
 };
@@ -39,6 +40,8 @@ fn main() {
     tracer.trace_simple_type::<ModuleId>().unwrap();
     tracer.trace_simple_type::<ResourceKey>().unwrap();
     tracer.trace_simple_type::<StakingDelta>().unwrap();
+    tracer.trace_simple_type::<StakingRedelegation>().unwrap();
+    tracer.trace_simple_type::<UnbondingEntry>().unwrap();
     tracer.trace_simple_type::<CosmosMessage>().unwrap();
     tracer.trace_simple_type::<Account>().unwrap();
     tracer.trace_simple_type::<GasUsage>().unwrap();
@@ -50,6 +53,7 @@ fn main() {
     tracer.trace_simple_type::<Script>().unwrap();
     tracer.trace_simple_type::<Env>().unwrap();
     tracer.trace_simple_type::<TableInfo>().unwrap();
+    tracer.trace_simple_type::<TableLengthChange>().unwrap();
     tracer.trace_simple_type::<InitiaVMConfig>().unwrap();
     tracer.trace_simple_type::<CompilerArguments>().unwrap();
     tracer.trace_simple_type::<CompilerBuildConfig>().unwrap();
@@ -67,6 +71,7 @@ fn main() {
     tracer.trace_simple_type::<AbstractionAuthData>().unwrap();
     tracer.trace_simple_type::<AbstractionData>().unwrap();
     tracer.trace_simple_type::<FunctionInfo>().unwrap();
+    tracer.trace_simple_type::<RawEvent>().unwrap();
 
     // aliases within StructTag
     tracer
@@ -0,0 +1,88 @@
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use std::{fs, path::PathBuf};
+
+use crate::{InitiaCLI, InitiaCommand};
+
+use initia_move_api::handler::generate_typescript;
+
+#[derive(Parser)]
+#[command(
+    name = "codegen",
+    about = "Generate client code from Move modules",
+    long_about = "Generate typed client code from the ABIs of compiled Move modules"
+)]
+pub struct Codegen {
+    #[command(subcommand)]
+    pub command: CodegenCommands,
+}
+
+#[derive(Subcommand)]
+pub enum CodegenCommands {
+    #[command(
+        name = "typescript",
+        about = "Generate a TypeScript client",
+        long_about = "Generate TypeScript payload builders for entry functions, callers for view functions \
+        and interfaces for the structs of the given modules. Directories are searched for `.mv` files.\n\
+        Example: initia-move-cli codegen typescript ./build/package/bytecode_modules -o ./src/package.ts"
+    )]
+    Typescript {
+        #[arg(value_name = "PATH", required = true)]
+        paths: Vec<String>,
+
+        /// Write the generated code to the file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+}
+
+pub trait Generator {
+    fn codegen(self) -> anyhow::Result<()>;
+}
+
+fn read_modules(paths: &[String]) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut files = vec![];
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            let mut entries = fs::read_dir(&path)
+                .with_context(|| format!("Failed to read directory: {}", path.display()))?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.retain(|p| p.extension().is_some_and(|ext| ext == "mv"));
+            entries.sort();
+            files.extend(entries);
+        } else {
+            files.push(path);
+        }
+    }
+
+    files
+        .iter()
+        .map(|file| {
+            fs::read(file).with_context(|| format!("Failed to read file: {}", file.display()))
+        })
+        .collect()
+}
+
+impl Generator for InitiaCLI {
+    fn codegen(self) -> anyhow::Result<()> {
+        match &self.cmd {
+            InitiaCommand::Codegen(cmd) => {
+                match &cmd.command {
+                    CodegenCommands::Typescript { paths, output } => {
+                        let modules = read_modules(paths)?;
+                        let code = generate_typescript(modules)?;
+                        match output {
+                            Some(output) => fs::write(output, code)
+                                .with_context(|| format!("Failed to write file: {}", output))?,
+                            None => print!("{}", String::from_utf8(code)?),
+                        }
+                    }
+                }
+                Ok(())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -4,7 +4,10 @@ use std::{fs, path::PathBuf};
 
 use crate::{InitiaCLI, InitiaCommand};
 
-use initia_move_api::handler::{decode_module_bytes, decode_script_bytes, read_module_info};
+use initia_move_api::handler::{
+    decode_module_bytes, decode_script_bytes, diff_module_bytes, disassemble_module_bytes,
+    disassemble_script_bytes, read_module_info,
+};
 
 #[derive(Parser)]
 #[command(
@@ -51,6 +54,46 @@ pub enum DecodeCommands {
         #[arg(value_name = "PATH")]
         path: String,
     },
+
+    #[command(
+        name = "diff",
+        about = "Diff two versions of a Move module",
+        long_about = "Diff the ABIs of two versions of a Move module and report whether the new version \
+        can be published as a compatible upgrade of the old one.\n\
+        Example: initia-move-cli decode diff ./old/my_module.mv ./build/package/bytecode_modules/my_module.mv"
+    )]
+    Diff {
+        #[arg(value_name = "OLD")]
+        old: String,
+
+        #[arg(value_name = "NEW")]
+        new: String,
+    },
+
+    #[command(
+        name = "disassemble",
+        about = "Disassemble Move bytecode",
+        long_about = "Disassemble Move module or script bytecode into human readable text, optionally \
+        annotated with the source map and source file produced by the build.\n\
+        Example: initia-move-cli decode disassemble ./build/package/bytecode_modules/my_module.mv \
+        --source-map ./build/package/source_maps/my_module.mvsm --source ./sources/my_module.move"
+    )]
+    Disassemble {
+        #[arg(value_name = "PATH")]
+        path: String,
+
+        /// Disassemble the bytecode as a script instead of a module
+        #[arg(long)]
+        script: bool,
+
+        /// The source map (`.mvsm`) of the bytecode
+        #[arg(long, value_name = "FILE")]
+        source_map: Option<String>,
+
+        /// The source file the source map refers to
+        #[arg(long, value_name = "FILE", requires = "source_map")]
+        source: Option<String>,
+    },
 }
 
 pub trait Decoder {
@@ -99,6 +142,40 @@ impl Decoder for InitiaCLI {
                         let json: serde_json::Value = serde_json::from_slice(&result)?;
                         println!("{}", serde_json::to_string_pretty(&json)?);
                     }
+                    DecodeCommands::Diff { old, new } => {
+                        let result = diff_module_bytes(read_file(old)?, read_file(new)?)?;
+                        let json: serde_json::Value = serde_json::from_slice(&result)?;
+                        println!("{}", serde_json::to_string_pretty(&json)?);
+                    }
+                    DecodeCommands::Disassemble {
+                        path,
+                        script,
+                        source_map,
+                        source,
+                    } => {
+                        let bytes = read_file(path)?;
+                        let source_map = source_map.as_deref().map(read_file).transpose()?;
+                        let source = source
+                            .as_deref()
+                            .map(read_file)
+                            .transpose()?
+                            .map(String::from_utf8)
+                            .transpose()?;
+                        let result = if *script {
+                            disassemble_script_bytes(
+                                bytes,
+                                source_map.as_deref(),
+                                source.as_deref(),
+                            )?
+                        } else {
+                            disassemble_module_bytes(
+                                bytes,
+                                source_map.as_deref(),
+                                source.as_deref(),
+                            )?
+                        };
+                        print!("{}", String::from_utf8(result)?);
+                    }
                 }
                 Ok(())
             }
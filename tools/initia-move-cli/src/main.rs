@@ -1,13 +1,17 @@
+mod codegen;
 mod decode;
 mod execute;
+mod state;
 
 use clap::Parser;
+use codegen::{Codegen, Generator};
 use decode::{Decode, Decoder};
 use execute::Execute;
 use initia_move_compiler::{
     base::{build::Build, coverage::Coverage, test::Test},
     Move, New,
 };
+use state::{State, StateManager};
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -32,6 +36,14 @@ pub enum InitiaCommand {
     /// Decode Move modules and scripts
     #[command()]
     Decode(Decode),
+
+    /// Generate client code from Move modules
+    #[command()]
+    Codegen(Codegen),
+
+    /// Export and import Move state
+    #[command()]
+    State(State),
 }
 
 #[derive(Parser)]
@@ -48,6 +60,8 @@ fn main() -> anyhow::Result<()> {
     let cli = InitiaCLI::parse();
     match cli.cmd {
         InitiaCommand::Decode(_) => cli.decode()?,
+        InitiaCommand::Codegen(_) => cli.codegen()?,
+        InitiaCommand::State(_) => cli.state()?,
         InitiaCommand::Build(_)
         | InitiaCommand::Coverage(_)
         | InitiaCommand::New(_)
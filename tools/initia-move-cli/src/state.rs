@@ -0,0 +1,109 @@
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use std::fs;
+
+use crate::{InitiaCLI, InitiaCommand};
+
+use initia_move_api::handler::{export_state, import_state};
+
+#[derive(Parser)]
+#[command(
+    name = "state",
+    about = "Export and import Move state",
+    long_about = "Export the Move state of a local state file as annotated JSON, or import such a dump back. \
+    A state file is a JSON object mapping hex encoded storage keys to hex encoded values."
+)]
+pub struct State {
+    #[command(subcommand)]
+    pub command: StateCommands,
+}
+
+#[derive(Subcommand)]
+pub enum StateCommands {
+    #[command(
+        name = "export",
+        about = "Export modules, resources and tables as annotated JSON",
+        long_about = "Export every module, resource and table under an address, or the whole state, \
+        as annotated JSON.\n\
+        Example: initia-move-cli state export --state ./state.json --address 0x1 -o ./dump.json"
+    )]
+    Export {
+        /// The state file to export from
+        #[arg(long, value_name = "FILE")]
+        state: String,
+
+        /// Only export the state stored under this address
+        #[arg(long, value_name = "ADDRESS")]
+        address: Option<String>,
+
+        /// Write the dump to the file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+    #[command(
+        name = "import",
+        about = "Import an annotated JSON dump into a state file",
+        long_about = "Write the entries of a dump produced by `state export` on top of a state file, \
+        or of an empty state.\n\
+        Example: initia-move-cli state import ./dump.json --state ./state.json -o ./state.json"
+    )]
+    Import {
+        #[arg(value_name = "DUMP")]
+        dump: String,
+
+        /// The state file to import into
+        #[arg(long, value_name = "FILE")]
+        state: Option<String>,
+
+        /// Write the resulting state file to the file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+}
+
+pub trait StateManager {
+    fn state(self) -> anyhow::Result<()>;
+}
+
+fn read_file(path: &str) -> anyhow::Result<Vec<u8>> {
+    fs::read(path).with_context(|| format!("Failed to read file: {}", path))
+}
+
+fn write_output(output: &Option<String>, bytes: Vec<u8>) -> anyhow::Result<()> {
+    match output {
+        Some(output) => {
+            fs::write(output, bytes).with_context(|| format!("Failed to write file: {}", output))
+        }
+        None => {
+            println!("{}", String::from_utf8(bytes)?);
+            Ok(())
+        }
+    }
+}
+
+impl StateManager for InitiaCLI {
+    fn state(self) -> anyhow::Result<()> {
+        match &self.cmd {
+            InitiaCommand::State(cmd) => match &cmd.command {
+                StateCommands::Export {
+                    state,
+                    address,
+                    output,
+                } => {
+                    let dump = export_state(&read_file(state)?, address.as_deref())?;
+                    write_output(output, dump)
+                }
+                StateCommands::Import {
+                    dump,
+                    state,
+                    output,
+                } => {
+                    let base = state.as_deref().map(read_file).transpose()?;
+                    let state = import_state(&read_file(dump)?, base.as_deref())?;
+                    write_output(output, state)
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
@@ -663,6 +663,8 @@ type CosmosCallback struct {
 	ModuleAddress AccountAddress
 	ModuleName string
 	FunctionName string
+	WithSigner bool
+	WithResult bool
 }
 
 func (obj *CosmosCallback) Serialize(serializer serde.Serializer) error {
@@ -671,6 +673,8 @@ func (obj *CosmosCallback) Serialize(serializer serde.Serializer) error {
 	if err := obj.ModuleAddress.Serialize(serializer); err != nil { return err }
 	if err := serializer.SerializeStr(obj.ModuleName); err != nil { return err }
 	if err := serializer.SerializeStr(obj.FunctionName); err != nil { return err }
+	if err := serializer.SerializeBool(obj.WithSigner); err != nil { return err }
+	if err := serializer.SerializeBool(obj.WithResult); err != nil { return err }
 	serializer.DecreaseContainerDepth()
 	return nil
 }
@@ -691,6 +695,8 @@ func DeserializeCosmosCallback(deserializer serde.Deserializer) (CosmosCallback,
 	if val, err := DeserializeAccountAddress(deserializer); err == nil { obj.ModuleAddress = val } else { return obj, err }
 	if val, err := deserializer.DeserializeStr(); err == nil { obj.ModuleName = val } else { return obj, err }
 	if val, err := deserializer.DeserializeStr(); err == nil { obj.FunctionName = val } else { return obj, err }
+	if val, err := deserializer.DeserializeBool(); err == nil { obj.WithSigner = val } else { return obj, err }
+	if val, err := deserializer.DeserializeBool(); err == nil { obj.WithResult = val } else { return obj, err }
 	deserializer.DecreaseContainerDepth()
 	return obj, nil
 }
@@ -819,6 +825,13 @@ type Env struct {
 	TxHash [32]uint8
 	SessionId [32]uint8
 	FeePayer *AccountAddress
+	RandomSeed *[32]uint8
+	BlockHash [32]uint8
+	Proposer AccountAddress
+	TxIndex uint64
+	MsgIndex uint64
+	NumMsgs uint64
+	GasPrice string
 }
 
 func (obj *Env) Serialize(serializer serde.Serializer) error {
@@ -830,6 +843,13 @@ func (obj *Env) Serialize(serializer serde.Serializer) error {
 	if err := serialize_array32_u8_array(obj.TxHash, serializer); err != nil { return err }
 	if err := serialize_array32_u8_array(obj.SessionId, serializer); err != nil { return err }
 	if err := serialize_option_AccountAddress(obj.FeePayer, serializer); err != nil { return err }
+	if err := serialize_option_array32_u8_array(obj.RandomSeed, serializer); err != nil { return err }
+	if err := serialize_array32_u8_array(obj.BlockHash, serializer); err != nil { return err }
+	if err := obj.Proposer.Serialize(serializer); err != nil { return err }
+	if err := serializer.SerializeU64(obj.TxIndex); err != nil { return err }
+	if err := serializer.SerializeU64(obj.MsgIndex); err != nil { return err }
+	if err := serializer.SerializeU64(obj.NumMsgs); err != nil { return err }
+	if err := serializer.SerializeStr(obj.GasPrice); err != nil { return err }
 	serializer.DecreaseContainerDepth()
 	return nil
 }
@@ -853,6 +873,13 @@ func DeserializeEnv(deserializer serde.Deserializer) (Env, error) {
 	if val, err := deserialize_array32_u8_array(deserializer); err == nil { obj.TxHash = val } else { return obj, err }
 	if val, err := deserialize_array32_u8_array(deserializer); err == nil { obj.SessionId = val } else { return obj, err }
 	if val, err := deserialize_option_AccountAddress(deserializer); err == nil { obj.FeePayer = val } else { return obj, err }
+	if val, err := deserialize_option_array32_u8_array(deserializer); err == nil { obj.RandomSeed = val } else { return obj, err }
+	if val, err := deserialize_array32_u8_array(deserializer); err == nil { obj.BlockHash = val } else { return obj, err }
+	if val, err := DeserializeAccountAddress(deserializer); err == nil { obj.Proposer = val } else { return obj, err }
+	if val, err := deserializer.DeserializeU64(); err == nil { obj.TxIndex = val } else { return obj, err }
+	if val, err := deserializer.DeserializeU64(); err == nil { obj.MsgIndex = val } else { return obj, err }
+	if val, err := deserializer.DeserializeU64(); err == nil { obj.NumMsgs = val } else { return obj, err }
+	if val, err := deserializer.DeserializeStr(); err == nil { obj.GasPrice = val } else { return obj, err }
 	deserializer.DecreaseContainerDepth()
 	return obj, nil
 }
@@ -873,18 +900,22 @@ func BcsDeserializeEnv(input []byte) (Env, error) {
 type ExecutionResult struct {
 	Events []JsonEvent
 	StakingDeltas []StakingDelta
+	StakingRedelegations []StakingRedelegation
 	CosmosMessages []CosmosMessage
 	NewAccounts []Account
 	GasUsages []GasUsage
+	TableLengthChanges []TableLengthChange
 }
 
 func (obj *ExecutionResult) Serialize(serializer serde.Serializer) error {
 	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
 	if err := serialize_vector_JsonEvent(obj.Events, serializer); err != nil { return err }
 	if err := serialize_vector_StakingDelta(obj.StakingDeltas, serializer); err != nil { return err }
+	if err := serialize_vector_StakingRedelegation(obj.StakingRedelegations, serializer); err != nil { return err }
 	if err := serialize_vector_CosmosMessage(obj.CosmosMessages, serializer); err != nil { return err }
 	if err := serialize_vector_Account(obj.NewAccounts, serializer); err != nil { return err }
 	if err := serialize_vector_GasUsage(obj.GasUsages, serializer); err != nil { return err }
+	if err := serialize_vector_TableLengthChange(obj.TableLengthChanges, serializer); err != nil { return err }
 	serializer.DecreaseContainerDepth()
 	return nil
 }
@@ -903,9 +934,11 @@ func DeserializeExecutionResult(deserializer serde.Deserializer) (ExecutionResul
 	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
 	if val, err := deserialize_vector_JsonEvent(deserializer); err == nil { obj.Events = val } else { return obj, err }
 	if val, err := deserialize_vector_StakingDelta(deserializer); err == nil { obj.StakingDeltas = val } else { return obj, err }
+	if val, err := deserialize_vector_StakingRedelegation(deserializer); err == nil { obj.StakingRedelegations = val } else { return obj, err }
 	if val, err := deserialize_vector_CosmosMessage(deserializer); err == nil { obj.CosmosMessages = val } else { return obj, err }
 	if val, err := deserialize_vector_Account(deserializer); err == nil { obj.NewAccounts = val } else { return obj, err }
 	if val, err := deserialize_vector_GasUsage(deserializer); err == nil { obj.GasUsages = val } else { return obj, err }
+	if val, err := deserialize_vector_TableLengthChange(deserializer); err == nil { obj.TableLengthChanges = val } else { return obj, err }
 	deserializer.DecreaseContainerDepth()
 	return obj, nil
 }
@@ -1104,6 +1137,8 @@ type InitiaVMConfig struct {
 	AllowUnstable bool
 	ScriptCacheCapacity uint64
 	ModuleCacheCapacity uint64
+	AllowedQueryPaths []string
+	AllowedDispatchMessages []string
 }
 
 func (obj *InitiaVMConfig) Serialize(serializer serde.Serializer) error {
@@ -1111,6 +1146,8 @@ func (obj *InitiaVMConfig) Serialize(serializer serde.Serializer) error {
 	if err := serializer.SerializeBool(obj.AllowUnstable); err != nil { return err }
 	if err := serializer.SerializeU64(obj.ScriptCacheCapacity); err != nil { return err }
 	if err := serializer.SerializeU64(obj.ModuleCacheCapacity); err != nil { return err }
+	if err := serialize_vector_str(obj.AllowedQueryPaths, serializer); err != nil { return err }
+	if err := serialize_vector_str(obj.AllowedDispatchMessages, serializer); err != nil { return err }
 	serializer.DecreaseContainerDepth()
 	return nil
 }
@@ -1130,6 +1167,8 @@ func DeserializeInitiaVMConfig(deserializer serde.Deserializer) (InitiaVMConfig,
 	if val, err := deserializer.DeserializeBool(); err == nil { obj.AllowUnstable = val } else { return obj, err }
 	if val, err := deserializer.DeserializeU64(); err == nil { obj.ScriptCacheCapacity = val } else { return obj, err }
 	if val, err := deserializer.DeserializeU64(); err == nil { obj.ModuleCacheCapacity = val } else { return obj, err }
+	if val, err := deserialize_vector_str(deserializer); err == nil { obj.AllowedQueryPaths = val } else { return obj, err }
+	if val, err := deserialize_vector_str(deserializer); err == nil { obj.AllowedDispatchMessages = val } else { return obj, err }
 	deserializer.DecreaseContainerDepth()
 	return obj, nil
 }
@@ -1191,6 +1230,59 @@ func BcsDeserializeJsonEvent(input []byte) (JsonEvent, error) {
 	return obj, err
 }
 
+type JsonOptions struct {
+	NumbersAsJson bool
+	AddressHrp *string
+	TypeAnnotations bool
+	ObjectInnerTypes bool
+	DecimalPrecision *uint32
+}
+
+func (obj *JsonOptions) Serialize(serializer serde.Serializer) error {
+	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
+	if err := serializer.SerializeBool(obj.NumbersAsJson); err != nil { return err }
+	if err := serialize_option_str(obj.AddressHrp, serializer); err != nil { return err }
+	if err := serializer.SerializeBool(obj.TypeAnnotations); err != nil { return err }
+	if err := serializer.SerializeBool(obj.ObjectInnerTypes); err != nil { return err }
+	if err := serialize_option_u32(obj.DecimalPrecision, serializer); err != nil { return err }
+	serializer.DecreaseContainerDepth()
+	return nil
+}
+
+func (obj *JsonOptions) BcsSerialize() ([]byte, error) {
+	if obj == nil {
+		return nil, fmt.Errorf("Cannot serialize null object")
+	}
+	serializer := bcs.NewSerializer();
+	if err := obj.Serialize(serializer); err != nil { return nil, err }
+	return serializer.GetBytes(), nil
+}
+
+func DeserializeJsonOptions(deserializer serde.Deserializer) (JsonOptions, error) {
+	var obj JsonOptions
+	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
+	if val, err := deserializer.DeserializeBool(); err == nil { obj.NumbersAsJson = val } else { return obj, err }
+	if val, err := deserialize_option_str(deserializer); err == nil { obj.AddressHrp = val } else { return obj, err }
+	if val, err := deserializer.DeserializeBool(); err == nil { obj.TypeAnnotations = val } else { return obj, err }
+	if val, err := deserializer.DeserializeBool(); err == nil { obj.ObjectInnerTypes = val } else { return obj, err }
+	if val, err := deserialize_option_u32(deserializer); err == nil { obj.DecimalPrecision = val } else { return obj, err }
+	deserializer.DecreaseContainerDepth()
+	return obj, nil
+}
+
+func BcsDeserializeJsonOptions(input []byte) (JsonOptions, error) {
+	if input == nil {
+		var obj JsonOptions
+		return obj, fmt.Errorf("Cannot deserialize null array")
+	}
+	deserializer := bcs.NewDeserializer(input);
+	obj, err := DeserializeJsonOptions(deserializer)
+	if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+		return obj, fmt.Errorf("Some input bytes were not read")
+	}
+	return obj, err
+}
+
 type Module struct {
 	Code []uint8
 }
@@ -1317,6 +1409,50 @@ func BcsDeserializeModuleId(input []byte) (ModuleId, error) {
 	return obj, err
 }
 
+type RawEvent struct {
+	TypeTag TypeTag
+	EventData []byte
+}
+
+func (obj *RawEvent) Serialize(serializer serde.Serializer) error {
+	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
+	if err := obj.TypeTag.Serialize(serializer); err != nil { return err }
+	if err := serializer.SerializeBytes(obj.EventData); err != nil { return err }
+	serializer.DecreaseContainerDepth()
+	return nil
+}
+
+func (obj *RawEvent) BcsSerialize() ([]byte, error) {
+	if obj == nil {
+		return nil, fmt.Errorf("Cannot serialize null object")
+	}
+	serializer := bcs.NewSerializer();
+	if err := obj.Serialize(serializer); err != nil { return nil, err }
+	return serializer.GetBytes(), nil
+}
+
+func DeserializeRawEvent(deserializer serde.Deserializer) (RawEvent, error) {
+	var obj RawEvent
+	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
+	if val, err := DeserializeTypeTag(deserializer); err == nil { obj.TypeTag = val } else { return obj, err }
+	if val, err := deserializer.DeserializeBytes(); err == nil { obj.EventData = val } else { return obj, err }
+	deserializer.DecreaseContainerDepth()
+	return obj, nil
+}
+
+func BcsDeserializeRawEvent(input []byte) (RawEvent, error) {
+	if input == nil {
+		var obj RawEvent
+		return obj, fmt.Errorf("Cannot deserialize null array")
+	}
+	deserializer := bcs.NewDeserializer(input);
+	obj, err := DeserializeRawEvent(deserializer)
+	if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+		return obj, fmt.Errorf("Some input bytes were not read")
+	}
+	return obj, err
+}
+
 type ResourceKey struct {
 	Address AccountAddress
 	Type StructTag
@@ -1461,6 +1597,56 @@ func BcsDeserializeStakingDelta(input []byte) (StakingDelta, error) {
 	return obj, err
 }
 
+type StakingRedelegation struct {
+	SrcValidator []uint8
+	DstValidator []uint8
+	Metadata AccountAddress
+	Share string
+}
+
+func (obj *StakingRedelegation) Serialize(serializer serde.Serializer) error {
+	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
+	if err := serialize_vector_u8(obj.SrcValidator, serializer); err != nil { return err }
+	if err := serialize_vector_u8(obj.DstValidator, serializer); err != nil { return err }
+	if err := obj.Metadata.Serialize(serializer); err != nil { return err }
+	if err := serializer.SerializeStr(obj.Share); err != nil { return err }
+	serializer.DecreaseContainerDepth()
+	return nil
+}
+
+func (obj *StakingRedelegation) BcsSerialize() ([]byte, error) {
+	if obj == nil {
+		return nil, fmt.Errorf("Cannot serialize null object")
+	}
+	serializer := bcs.NewSerializer();
+	if err := obj.Serialize(serializer); err != nil { return nil, err }
+	return serializer.GetBytes(), nil
+}
+
+func DeserializeStakingRedelegation(deserializer serde.Deserializer) (StakingRedelegation, error) {
+	var obj StakingRedelegation
+	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
+	if val, err := deserialize_vector_u8(deserializer); err == nil { obj.SrcValidator = val } else { return obj, err }
+	if val, err := deserialize_vector_u8(deserializer); err == nil { obj.DstValidator = val } else { return obj, err }
+	if val, err := DeserializeAccountAddress(deserializer); err == nil { obj.Metadata = val } else { return obj, err }
+	if val, err := deserializer.DeserializeStr(); err == nil { obj.Share = val } else { return obj, err }
+	deserializer.DecreaseContainerDepth()
+	return obj, nil
+}
+
+func BcsDeserializeStakingRedelegation(input []byte) (StakingRedelegation, error) {
+	if input == nil {
+		var obj StakingRedelegation
+		return obj, fmt.Errorf("Cannot deserialize null array")
+	}
+	deserializer := bcs.NewDeserializer(input);
+	obj, err := DeserializeStakingRedelegation(deserializer)
+	if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+		return obj, fmt.Errorf("Some input bytes were not read")
+	}
+	return obj, err
+}
+
 type StructTag struct {
 	Address AccountAddress
 	Module Identifier
@@ -1555,6 +1741,50 @@ func BcsDeserializeTableInfo(input []byte) (TableInfo, error) {
 	return obj, err
 }
 
+type TableLengthChange struct {
+	Handle AccountAddress
+	Delta int64
+}
+
+func (obj *TableLengthChange) Serialize(serializer serde.Serializer) error {
+	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
+	if err := obj.Handle.Serialize(serializer); err != nil { return err }
+	if err := serializer.SerializeI64(obj.Delta); err != nil { return err }
+	serializer.DecreaseContainerDepth()
+	return nil
+}
+
+func (obj *TableLengthChange) BcsSerialize() ([]byte, error) {
+	if obj == nil {
+		return nil, fmt.Errorf("Cannot serialize null object")
+	}
+	serializer := bcs.NewSerializer();
+	if err := obj.Serialize(serializer); err != nil { return nil, err }
+	return serializer.GetBytes(), nil
+}
+
+func DeserializeTableLengthChange(deserializer serde.Deserializer) (TableLengthChange, error) {
+	var obj TableLengthChange
+	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
+	if val, err := DeserializeAccountAddress(deserializer); err == nil { obj.Handle = val } else { return obj, err }
+	if val, err := deserializer.DeserializeI64(); err == nil { obj.Delta = val } else { return obj, err }
+	deserializer.DecreaseContainerDepth()
+	return obj, nil
+}
+
+func BcsDeserializeTableLengthChange(input []byte) (TableLengthChange, error) {
+	if input == nil {
+		var obj TableLengthChange
+		return obj, fmt.Errorf("Cannot deserialize null array")
+	}
+	deserializer := bcs.NewDeserializer(input);
+	obj, err := DeserializeTableLengthChange(deserializer)
+	if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+		return obj, fmt.Errorf("Some input bytes were not read")
+	}
+	return obj, err
+}
+
 type TypeTag interface {
 	isTypeTag()
 	Serialize(serializer serde.Serializer) error
@@ -2013,12 +2243,57 @@ func load_TypeTag__Function(deserializer serde.Deserializer) (TypeTag__Function,
 	return obj, nil
 }
 
+type UnbondingEntry struct {
+	Amount uint64
+	CompletionTime uint64
+}
+
+func (obj *UnbondingEntry) Serialize(serializer serde.Serializer) error {
+	if err := serializer.IncreaseContainerDepth(); err != nil { return err }
+	if err := serializer.SerializeU64(obj.Amount); err != nil { return err }
+	if err := serializer.SerializeU64(obj.CompletionTime); err != nil { return err }
+	serializer.DecreaseContainerDepth()
+	return nil
+}
+
+func (obj *UnbondingEntry) BcsSerialize() ([]byte, error) {
+	if obj == nil {
+		return nil, fmt.Errorf("Cannot serialize null object")
+	}
+	serializer := bcs.NewSerializer();
+	if err := obj.Serialize(serializer); err != nil { return nil, err }
+	return serializer.GetBytes(), nil
+}
+
+func DeserializeUnbondingEntry(deserializer serde.Deserializer) (UnbondingEntry, error) {
+	var obj UnbondingEntry
+	if err := deserializer.IncreaseContainerDepth(); err != nil { return obj, err }
+	if val, err := deserializer.DeserializeU64(); err == nil { obj.Amount = val } else { return obj, err }
+	if val, err := deserializer.DeserializeU64(); err == nil { obj.CompletionTime = val } else { return obj, err }
+	deserializer.DecreaseContainerDepth()
+	return obj, nil
+}
+
+func BcsDeserializeUnbondingEntry(input []byte) (UnbondingEntry, error) {
+	if input == nil {
+		var obj UnbondingEntry
+		return obj, fmt.Errorf("Cannot deserialize null array")
+	}
+	deserializer := bcs.NewDeserializer(input);
+	obj, err := DeserializeUnbondingEntry(deserializer)
+	if err == nil && deserializer.GetBufferOffset() < uint64(len(input)) {
+		return obj, fmt.Errorf("Some input bytes were not read")
+	}
+	return obj, err
+}
+
 type ViewFunction struct {
 	Module ModuleId
 	Function Identifier
 	TyArgs []TypeTag
 	Args [][]byte
 	IsJson bool
+	JsonOptions JsonOptions
 }
 
 func (obj *ViewFunction) Serialize(serializer serde.Serializer) error {
@@ -2028,6 +2303,7 @@ func (obj *ViewFunction) Serialize(serializer serde.Serializer) error {
 	if err := serialize_vector_TypeTag(obj.TyArgs, serializer); err != nil { return err }
 	if err := serialize_vector_bytes(obj.Args, serializer); err != nil { return err }
 	if err := serializer.SerializeBool(obj.IsJson); err != nil { return err }
+	if err := obj.JsonOptions.Serialize(serializer); err != nil { return err }
 	serializer.DecreaseContainerDepth()
 	return nil
 }
@@ -2049,6 +2325,7 @@ func DeserializeViewFunction(deserializer serde.Deserializer) (ViewFunction, err
 	if val, err := deserialize_vector_TypeTag(deserializer); err == nil { obj.TyArgs = val } else { return obj, err }
 	if val, err := deserialize_vector_bytes(deserializer); err == nil { obj.Args = val } else { return obj, err }
 	if val, err := deserializer.DeserializeBool(); err == nil { obj.IsJson = val } else { return obj, err }
+	if val, err := DeserializeJsonOptions(deserializer); err == nil { obj.JsonOptions = val } else { return obj, err }
 	deserializer.DecreaseContainerDepth()
 	return obj, nil
 }
@@ -2146,6 +2423,28 @@ func deserialize_option_AccountAddress(deserializer serde.Deserializer) (*Accoun
 	}
 }
 
+func serialize_option_array32_u8_array(value *[32]uint8, serializer serde.Serializer) error {
+	if value != nil {
+		if err := serializer.SerializeOptionTag(true); err != nil { return err }
+		if err := serialize_array32_u8_array((*value), serializer); err != nil { return err }
+	} else {
+		if err := serializer.SerializeOptionTag(false); err != nil { return err }
+	}
+	return nil
+}
+
+func deserialize_option_array32_u8_array(deserializer serde.Deserializer) (*[32]uint8, error) {
+	tag, err := deserializer.DeserializeOptionTag()
+	if err != nil { return nil, err }
+	if tag {
+		value := new([32]uint8)
+		if val, err := deserialize_array32_u8_array(deserializer); err == nil { *value = val } else { return nil, err }
+	        return value, nil
+	} else {
+		return nil, nil
+	}
+}
+
 func serialize_option_CosmosCallback(value *CosmosCallback, serializer serde.Serializer) error {
 	if value != nil {
 		if err := serializer.SerializeOptionTag(true); err != nil { return err }
@@ -2190,6 +2489,28 @@ func deserialize_option_str(deserializer serde.Deserializer) (*string, error) {
 	}
 }
 
+func serialize_option_u32(value *uint32, serializer serde.Serializer) error {
+	if value != nil {
+		if err := serializer.SerializeOptionTag(true); err != nil { return err }
+		if err := serializer.SerializeU32((*value)); err != nil { return err }
+	} else {
+		if err := serializer.SerializeOptionTag(false); err != nil { return err }
+	}
+	return nil
+}
+
+func deserialize_option_u32(deserializer serde.Deserializer) (*uint32, error) {
+	tag, err := deserializer.DeserializeOptionTag()
+	if err != nil { return nil, err }
+	if tag {
+		value := new(uint32)
+		if val, err := deserializer.DeserializeU32(); err == nil { *value = val } else { return nil, err }
+	        return value, nil
+	} else {
+		return nil, nil
+	}
+}
+
 func serialize_tuple2_str_AccountAddress(value struct {Field0 string; Field1 AccountAddress}, serializer serde.Serializer) error {
 	if err := serializer.SerializeStr(value.Field0); err != nil { return err }
 	if err := value.Field1.Serialize(serializer); err != nil { return err }
@@ -2311,6 +2632,42 @@ func deserialize_vector_StakingDelta(deserializer serde.Deserializer) ([]Staking
 	return obj, nil
 }
 
+func serialize_vector_StakingRedelegation(value []StakingRedelegation, serializer serde.Serializer) error {
+	if err := serializer.SerializeLen(uint64(len(value))); err != nil { return err }
+	for _, item := range(value) {
+		if err := item.Serialize(serializer); err != nil { return err }
+	}
+	return nil
+}
+
+func deserialize_vector_StakingRedelegation(deserializer serde.Deserializer) ([]StakingRedelegation, error) {
+	length, err := deserializer.DeserializeLen()
+	if err != nil { return nil, err }
+	obj := make([]StakingRedelegation, length)
+	for i := range(obj) {
+		if val, err := DeserializeStakingRedelegation(deserializer); err == nil { obj[i] = val } else { return nil, err }
+	}
+	return obj, nil
+}
+
+func serialize_vector_TableLengthChange(value []TableLengthChange, serializer serde.Serializer) error {
+	if err := serializer.SerializeLen(uint64(len(value))); err != nil { return err }
+	for _, item := range(value) {
+		if err := item.Serialize(serializer); err != nil { return err }
+	}
+	return nil
+}
+
+func deserialize_vector_TableLengthChange(deserializer serde.Deserializer) ([]TableLengthChange, error) {
+	length, err := deserializer.DeserializeLen()
+	if err != nil { return nil, err }
+	obj := make([]TableLengthChange, length)
+	for i := range(obj) {
+		if val, err := DeserializeTableLengthChange(deserializer); err == nil { obj[i] = val } else { return nil, err }
+	}
+	return obj, nil
+}
+
 func serialize_vector_TypeTag(value []TypeTag, serializer serde.Serializer) error {
 	if err := serializer.SerializeLen(uint64(len(value))); err != nil { return err }
 	for _, item := range(value) {
@@ -2347,6 +2704,24 @@ func deserialize_vector_bytes(deserializer serde.Deserializer) ([][]byte, error)
 	return obj, nil
 }
 
+func serialize_vector_str(value []string, serializer serde.Serializer) error {
+	if err := serializer.SerializeLen(uint64(len(value))); err != nil { return err }
+	for _, item := range(value) {
+		if err := serializer.SerializeStr(item); err != nil { return err }
+	}
+	return nil
+}
+
+func deserialize_vector_str(deserializer serde.Deserializer) ([]string, error) {
+	length, err := deserializer.DeserializeLen()
+	if err != nil { return nil, err }
+	obj := make([]string, length)
+	for i := range(obj) {
+		if val, err := deserializer.DeserializeStr(); err == nil { obj[i] = val } else { return nil, err }
+	}
+	return obj, nil
+}
+
 func serialize_vector_tuple2_str_AccountAddress(value []struct {Field0 string; Field1 AccountAddress}, serializer serde.Serializer) error {
 	if err := serializer.SerializeLen(uint64(len(value))); err != nil { return err }
 	for _, item := range(value) {
@@ -164,6 +164,22 @@ func SerializeBytesVector(bz [][]byte) ([]byte, error) {
 	return s.GetBytes(), nil
 }
 
+// SerializeRawEventVector serialize raw event vector to BCS bytes
+func SerializeRawEventVector(events []RawEvent) ([]byte, error) {
+	s := NewSerializer()
+	if err := s.SerializeLen(uint64(len(events))); err != nil {
+		return nil, err
+	}
+
+	for _, item := range events {
+		if err := item.Serialize(s); err != nil {
+			return nil, err
+		}
+	}
+
+	return s.GetBytes(), nil
+}
+
 // DeserializeBytesVector deserialize BCS bytes to bytes vector
 func DeserializeBytesVector(bz []byte) ([][]byte, error) {
 	d := NewDeserializer(bz)
@@ -258,3 +274,19 @@ func DeserializeUint64Vector(bz []byte) ([]uint64, error) {
 
 	return resUint64Vector, nil
 }
+
+// SerializeUnbondingEntries serialize unbonding entries to BCS bytes
+func SerializeUnbondingEntries(entries []UnbondingEntry) ([]byte, error) {
+	s := NewSerializer()
+	if err := s.SerializeLen(uint64(len(entries))); err != nil {
+		return nil, err
+	}
+
+	for _, entry := range entries {
+		if err := entry.Serialize(s); err != nil {
+			return nil, err
+		}
+	}
+
+	return s.GetBytes(), nil
+}